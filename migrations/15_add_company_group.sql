@@ -0,0 +1,8 @@
+CREATE TABLE company_group(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL
+);
+
+ALTER TABLE company ADD group_id INTEGER REFERENCES company_group(id);
+
+INSERT INTO company_group ("name") VALUES ('Dream'), ('Backup'), ('Recruiters');
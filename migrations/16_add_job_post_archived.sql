@@ -0,0 +1 @@
+ALTER TABLE job_post ADD archived INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,6 @@
+CREATE TABLE api_request(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider VARCHAR NOT NULL,
+    requested_at INTEGER NOT NULL,
+    "status" INTEGER
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE fetch_log(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    provider VARCHAR NOT NULL,
+    fetched_at INTEGER NOT NULL,
+    "query" VARCHAR NOT NULL,
+    results INTEGER NOT NULL,
+    inserted INTEGER NOT NULL DEFAULT 0
+);
\ No newline at end of file
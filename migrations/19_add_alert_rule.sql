@@ -0,0 +1,17 @@
+CREATE TABLE alert_rule(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL,
+    provider VARCHAR NOT NULL,
+    job_title VARCHAR NOT NULL DEFAULT '',
+    "location" VARCHAR NOT NULL DEFAULT '',
+    country VARCHAR NOT NULL DEFAULT '',
+    min_yoe INTEGER NOT NULL DEFAULT 0,
+    min_salary INTEGER NOT NULL DEFAULT 0,
+    onsite INTEGER NOT NULL DEFAULT 0,
+    hybrid INTEGER NOT NULL DEFAULT 0,
+    remote INTEGER NOT NULL DEFAULT 0,
+    tracked_companies INTEGER NOT NULL DEFAULT 1,
+    interval_hours INTEGER NOT NULL DEFAULT 24,
+    last_run INTEGER,
+    enabled INTEGER NOT NULL DEFAULT 1
+);
\ No newline at end of file
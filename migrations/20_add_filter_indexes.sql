@@ -0,0 +1,5 @@
+CREATE INDEX idx_job_post_company_id ON job_post(company_id);
+CREATE INDEX idx_job_post_location_type ON job_post(location_type);
+CREATE INDEX idx_job_post_date_posted ON job_post(date_posted);
+CREATE INDEX idx_job_application_job_post_id ON job_application(job_post_id);
+CREATE INDEX idx_company_name ON company("name");
\ No newline at end of file
@@ -0,0 +1,12 @@
+CREATE TABLE job_post_template(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL,
+    job_title VARCHAR NOT NULL DEFAULT '',
+    "location" VARCHAR NOT NULL DEFAULT '',
+    location_type VARCHAR,
+    skills VARCHAR NOT NULL DEFAULT '',
+    benefits VARCHAR NOT NULL DEFAULT '',
+    min_yoe INTEGER,
+    max_yoe INTEGER,
+    currency VARCHAR
+);
\ No newline at end of file
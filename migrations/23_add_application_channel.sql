@@ -0,0 +1,2 @@
+ALTER TABLE job_application
+ADD "channel" TEXT;
\ No newline at end of file
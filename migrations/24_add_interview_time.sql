@@ -0,0 +1,5 @@
+ALTER TABLE job_application
+ADD "interview_at" INTEGER;
+
+ALTER TABLE job_application
+ADD "interview_timezone" TEXT;
\ No newline at end of file
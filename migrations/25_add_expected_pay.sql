@@ -0,0 +1,2 @@
+ALTER TABLE job_post
+ADD "expected_pay_cents" INTEGER;
\ No newline at end of file
@@ -0,0 +1,12 @@
+ALTER TABLE job_post ADD COLUMN tags TEXT;
+
+CREATE TABLE post_rule(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL,
+    title_contains VARCHAR NOT NULL DEFAULT '',
+    skills_contains VARCHAR NOT NULL DEFAULT '',
+    location_contains VARCHAR NOT NULL DEFAULT '',
+    min_pay INTEGER NOT NULL DEFAULT 0,
+    tag VARCHAR NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+ALTER TABLE post_rule ADD COLUMN company_contains VARCHAR NOT NULL DEFAULT '';
+ALTER TABLE post_rule ADD COLUMN pay_below INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE post_rule ADD COLUMN "action" VARCHAR NOT NULL DEFAULT 'Tag';
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE job_post
+ADD snoozed_until INTEGER;
\ No newline at end of file
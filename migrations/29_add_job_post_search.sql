@@ -0,0 +1,35 @@
+CREATE VIRTUAL TABLE job_post_search USING fts5(
+    job_title,
+    skills,
+    benefits,
+    notes,
+    company_name,
+    tokenize = 'porter unicode61 remove_diacritics 2'
+);
+
+INSERT INTO job_post_search(rowid, job_title, skills, benefits, notes, company_name)
+SELECT job_post.id, job_post.job_title, job_post.skills, job_post.benefits, job_post.notes, company.name
+FROM job_post
+JOIN company ON job_post.company_id = company.id;
+
+CREATE TRIGGER job_post_search_insert AFTER INSERT ON job_post BEGIN
+    INSERT INTO job_post_search(rowid, job_title, skills, benefits, notes, company_name)
+    SELECT new.id, new.job_title, new.skills, new.benefits, new.notes, company.name
+    FROM company WHERE company.id = new.company_id;
+END;
+
+CREATE TRIGGER job_post_search_update AFTER UPDATE OF job_title, skills, benefits, notes, company_id ON job_post BEGIN
+    DELETE FROM job_post_search WHERE rowid = old.id;
+    INSERT INTO job_post_search(rowid, job_title, skills, benefits, notes, company_name)
+    SELECT new.id, new.job_title, new.skills, new.benefits, new.notes, company.name
+    FROM company WHERE company.id = new.company_id;
+END;
+
+CREATE TRIGGER job_post_search_delete AFTER DELETE ON job_post BEGIN
+    DELETE FROM job_post_search WHERE rowid = old.id;
+END;
+
+CREATE TRIGGER job_post_search_company_update AFTER UPDATE OF name ON company BEGIN
+    UPDATE job_post_search SET company_name = new.name
+    WHERE rowid IN (SELECT id FROM job_post WHERE company_id = new.id);
+END;
\ No newline at end of file
@@ -0,0 +1,68 @@
+CREATE TABLE tag(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL UNIQUE COLLATE NOCASE
+);
+
+CREATE TABLE job_post_tag(
+    job_post_id INTEGER NOT NULL REFERENCES job_post(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tag(id) ON DELETE CASCADE,
+    PRIMARY KEY (job_post_id, tag_id)
+);
+
+CREATE INDEX job_post_tag_tag_id ON job_post_tag(tag_id);
+
+-- job_post.tags becomes a read-only list of the post's tags, in the order they were added
+CREATE TRIGGER job_post_tag_insert AFTER INSERT ON job_post_tag BEGIN
+    UPDATE job_post SET tags = (
+        SELECT group_concat("name", ', ') FROM (
+            SELECT tag."name" FROM job_post_tag
+            JOIN tag ON job_post_tag.tag_id = tag.id
+            WHERE job_post_tag.job_post_id = new.job_post_id
+            ORDER BY job_post_tag.rowid
+        )
+    ) WHERE id = new.job_post_id;
+END;
+
+CREATE TRIGGER job_post_tag_delete AFTER DELETE ON job_post_tag BEGIN
+    UPDATE job_post SET tags = (
+        SELECT group_concat("name", ', ') FROM (
+            SELECT tag."name" FROM job_post_tag
+            JOIN tag ON job_post_tag.tag_id = tag.id
+            WHERE job_post_tag.job_post_id = old.job_post_id
+            ORDER BY job_post_tag.rowid
+        )
+    ) WHERE id = old.job_post_id;
+END;
+
+CREATE TRIGGER tag_rename AFTER UPDATE OF name ON tag BEGIN
+    UPDATE job_post SET tags = (
+        SELECT group_concat("name", ', ') FROM (
+            SELECT tag."name" FROM job_post_tag
+            JOIN tag ON job_post_tag.tag_id = tag.id
+            WHERE job_post_tag.job_post_id = job_post.id
+            ORDER BY job_post_tag.rowid
+        )
+    ) WHERE id IN (SELECT job_post_id FROM job_post_tag WHERE tag_id = new.id);
+END;
+
+-- Move the comma-separated tags over
+CREATE TEMP TABLE split_tag(job_post_id INTEGER NOT NULL, position INTEGER NOT NULL, "name" VARCHAR NOT NULL);
+
+INSERT INTO split_tag(job_post_id, position, "name")
+WITH RECURSIVE split(job_post_id, position, "name", rest) AS (
+    SELECT id, 0, '', tags || ',' FROM job_post WHERE tags IS NOT NULL AND tags != ''
+    UNION ALL
+    SELECT job_post_id, position + 1, TRIM(SUBSTR(rest, 1, INSTR(rest, ',') - 1)), SUBSTR(rest, INSTR(rest, ',') + 1)
+    FROM split WHERE rest != ''
+)
+SELECT job_post_id, position, "name" FROM split WHERE "name" != '';
+
+INSERT OR IGNORE INTO tag("name")
+SELECT "name" FROM split_tag ORDER BY job_post_id, position;
+
+INSERT OR IGNORE INTO job_post_tag(job_post_id, tag_id)
+SELECT split_tag.job_post_id, tag.id FROM split_tag
+JOIN tag ON tag."name" = split_tag."name"
+ORDER BY split_tag.job_post_id, split_tag.position;
+
+DROP TABLE split_tag;
\ No newline at end of file
@@ -0,0 +1,22 @@
+CREATE TABLE job_application_event(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    job_application_id INTEGER NOT NULL REFERENCES job_application(id) ON DELETE CASCADE,
+    from_status VARCHAR,
+    to_status VARCHAR NOT NULL,
+    occurred_at INTEGER NOT NULL
+);
+
+CREATE INDEX job_application_event_job_application_id ON job_application_event(job_application_id);
+
+-- Rebuild what the dates tell of existing applications: when they were sent, and when they
+-- reached the status they're in
+INSERT INTO job_application_event(job_application_id, from_status, to_status, occurred_at)
+SELECT id, NULL, CASE WHEN status = 'New' THEN 'New' ELSE 'Applied' END,
+    COALESCE(date_applied, CAST(strftime('%s', 'now') AS INTEGER))
+FROM job_application;
+
+INSERT INTO job_application_event(job_application_id, from_status, to_status, occurred_at)
+SELECT id, 'Applied', status,
+    COALESCE(date_responded, date_applied, CAST(strftime('%s', 'now') AS INTEGER))
+FROM job_application
+WHERE status NOT IN ('New', 'Applied');
\ No newline at end of file
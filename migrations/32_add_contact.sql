@@ -0,0 +1,18 @@
+CREATE TABLE contact(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL,
+    email VARCHAR NOT NULL DEFAULT '',
+    company_id INTEGER REFERENCES company(id) ON DELETE SET NULL,
+    "role" VARCHAR NOT NULL DEFAULT '',
+    linkedin_url VARCHAR NOT NULL DEFAULT '',
+    notes VARCHAR NOT NULL DEFAULT ''
+);
+
+-- A post has at most one application, so this covers who was involved in that too
+CREATE TABLE job_post_contact(
+    job_post_id INTEGER NOT NULL REFERENCES job_post(id) ON DELETE CASCADE,
+    contact_id INTEGER NOT NULL REFERENCES contact(id) ON DELETE CASCADE,
+    PRIMARY KEY (job_post_id, contact_id)
+);
+
+CREATE INDEX job_post_contact_contact_id ON job_post_contact(contact_id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE job_application ADD COLUMN cover_letter_path TEXT;
+ALTER TABLE job_application ADD COLUMN cover_letter TEXT;
\ No newline at end of file
@@ -0,0 +1,17 @@
+-- Rebuilt so alt names go with their company and each resolves to one company
+CREATE TABLE company_alt_name_new(
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    "name" VARCHAR NOT NULL UNIQUE COLLATE NOCASE,
+    company_id INTEGER NOT NULL REFERENCES company(id) ON DELETE CASCADE
+);
+
+INSERT OR IGNORE INTO company_alt_name_new (id, "name", company_id)
+    SELECT id, "name", company_id FROM company_alt_name
+    WHERE company_id IN (SELECT id FROM company)
+    ORDER BY id;
+
+DROP TABLE company_alt_name;
+
+ALTER TABLE company_alt_name_new RENAME TO company_alt_name;
+
+CREATE INDEX company_alt_name_company_id ON company_alt_name(company_id);
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE job_post
+ADD favorite INTEGER NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,33 @@
+ALTER TABLE job_post ADD description TEXT;
+
+DROP TRIGGER job_post_search_insert;
+DROP TRIGGER job_post_search_update;
+DROP TABLE job_post_search;
+
+CREATE VIRTUAL TABLE job_post_search USING fts5(
+    job_title,
+    skills,
+    benefits,
+    notes,
+    company_name,
+    description,
+    tokenize = 'porter unicode61 remove_diacritics 2'
+);
+
+INSERT INTO job_post_search(rowid, job_title, skills, benefits, notes, company_name, description)
+SELECT job_post.id, job_post.job_title, job_post.skills, job_post.benefits, job_post.notes, company.name, job_post.description
+FROM job_post
+JOIN company ON job_post.company_id = company.id;
+
+CREATE TRIGGER job_post_search_insert AFTER INSERT ON job_post BEGIN
+    INSERT INTO job_post_search(rowid, job_title, skills, benefits, notes, company_name, description)
+    SELECT new.id, new.job_title, new.skills, new.benefits, new.notes, company.name, new.description
+    FROM company WHERE company.id = new.company_id;
+END;
+
+CREATE TRIGGER job_post_search_update AFTER UPDATE OF job_title, skills, benefits, notes, company_id, description ON job_post BEGIN
+    DELETE FROM job_post_search WHERE rowid = old.id;
+    INSERT INTO job_post_search(rowid, job_title, skills, benefits, notes, company_name, description)
+    SELECT new.id, new.job_title, new.skills, new.benefits, new.notes, company.name, new.description
+    FROM company WHERE company.id = new.company_id;
+END;
@@ -1,20 +1,205 @@
+pub mod remoteok;
+
+use remoteok::RemoteOkProvider;
+
+use crate::db::alert_rule::AlertRule;
+use crate::db::api_request::ApiRequest;
 use crate::db::company::Company;
+use crate::db::company_alt_name::CompanyAltName;
+use crate::db::fetch_log::FetchLog;
 use crate::db::job_post::{JobPost, JobPostLocationType};
+use crate::db::post_rule::InsertCounts;
 use crate::db::{NullableSqliteDateTime, SqliteBoolean, SqliteDateTime};
-use crate::utils::format_location;
-use chrono::Utc;
+use crate::utils::compensation::annualize_cents;
+use crate::utils::{find_yoe_naive, format_location, split_comma_separated};
+use chrono::{DateTime, Datelike, Utc};
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Deserialize;
 use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/* HTTP client */
+
+/// Proxy and request identity applied to API requests and the scraping browser
+#[derive(Debug, Clone, Default)]
+pub struct NetworkSettings {
+    /// e.g. `http://host:8080` or `socks5://host:1080` (empty = no proxy)
+    pub proxy_url: String,
+    pub proxy_username: String,
+    pub proxy_password: String,
+    /// Empty = client default
+    pub user_agent: String,
+    /// Sent with every API request (not by the browser)
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Builds the client shared by every provider, importer, and board sync
+pub fn http_client(network: &NetworkSettings) -> anyhow::Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+    if !network.proxy_url.trim().is_empty() {
+        let mut proxy = reqwest::Proxy::all(network.proxy_url.trim())?;
+        if !network.proxy_username.is_empty() {
+            proxy = proxy.basic_auth(&network.proxy_username, &network.proxy_password);
+        }
+        builder = builder.proxy(proxy);
+    }
+    if !network.user_agent.trim().is_empty() {
+        builder = builder.user_agent(network.user_agent.trim());
+    }
+    let mut headers = HeaderMap::new();
+    for (name, value) in &network.headers {
+        headers.insert(
+            HeaderName::from_bytes(name.as_bytes())?,
+            HeaderValue::from_str(value)?,
+        );
+    }
+    builder.default_headers(headers).build().map_err(Into::into)
+}
+
+/// Parses one `Name: value` header per line
+pub fn parse_headers(text: &str) -> anyhow::Result<BTreeMap<String, String>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| match line.split_once(':') {
+            Some((name, value)) => Ok((name.trim().to_string(), value.trim().to_string())),
+            None => anyhow::bail!("Expected \"Name: value\", got \"{}\"", line.trim()),
+        })
+        .collect()
+}
+
+pub fn format_headers(headers: &BTreeMap<String, String>) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Any HTTP response counts as online; only connection failures count as offline
+const CONNECTIVITY_CHECK_URL: &str = "https://clients3.google.com/generate_204";
+
+pub async fn check_connectivity(client: reqwest::Client) -> bool {
+    client
+        .head(CONNECTIVITY_CHECK_URL)
+        .timeout(std::time::Duration::from_secs(5))
+        .send()
+        .await
+        .is_ok()
+}
+
+/* Careers URL health checks */
+
+// Checks are one request each, so keep a bounded number in flight
+const MAX_CONCURRENT_URL_CHECKS: usize = 10;
+
+/// Why a careers page looks broken, or `None` if it still resolves
+pub async fn check_careers_url(client: &reqwest::Client, url: &str) -> Option<String> {
+    let timeout = std::time::Duration::from_secs(10);
+    let res = match client.head(url).timeout(timeout).send().await {
+        // Some servers only answer GET
+        Ok(res) if res.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED => {
+            client.get(url).timeout(timeout).send().await
+        }
+        res => res,
+    };
+    match res {
+        // Bot protection answers 403 and such even when the page is fine
+        Ok(res)
+            if res.status() == reqwest::StatusCode::NOT_FOUND
+                || res.status() == reqwest::StatusCode::GONE
+                || res.status().is_server_error() =>
+        {
+            Some(res.status().to_string())
+        }
+        Ok(_) => None,
+        Err(e) if e.is_timeout() => Some("Timed out".to_string()),
+        Err(e) if e.is_connect() => Some("Couldn't connect".to_string()),
+        Err(e) => Some(e.to_string()),
+    }
+}
+
+/// Checks each `(company id, careers URL)`, returning the broken ones by company id
+#[tracing::instrument(skip_all, fields(urls = urls.len()))]
+pub async fn check_careers_urls(
+    client: reqwest::Client,
+    urls: Vec<(i64, String)>,
+) -> BTreeMap<i64, String> {
+    let mut broken = BTreeMap::new();
+    for chunk in urls.chunks(MAX_CONCURRENT_URL_CHECKS) {
+        let mut tasks = tokio::task::JoinSet::new();
+        for (company_id, url) in chunk.iter().cloned() {
+            let client = client.clone();
+            tasks.spawn(async move { (company_id, check_careers_url(&client, &url).await) });
+        }
+        while let Some(res) = tasks.join_next().await {
+            if let Ok((company_id, Some(error))) = res {
+                broken.insert(company_id, error);
+            }
+        }
+    }
+    if !broken.is_empty() {
+        tracing::warn!("{} careers URLs look broken", broken.len());
+    }
+    broken
+}
+
+/* Rate limiting */
+
+const MAX_RATE_LIMIT_RETRIES: u32 = 2;
+const MAX_RETRY_AFTER_SECS: u64 = 30;
+
+/// Waits until at least `min_interval` has passed since the previous request.
+/// Holding the lock while sleeping queues concurrent requests behind each other.
+async fn wait_for_rate_limit(
+    last_request: &tokio::sync::Mutex<Option<tokio::time::Instant>>,
+    min_interval: std::time::Duration,
+) {
+    let mut last = last_request.lock().await;
+    if let Some(last_request) = *last {
+        let elapsed = last_request.elapsed();
+        if elapsed < min_interval {
+            tokio::time::sleep(min_interval - elapsed).await;
+        }
+    }
+    *last = Some(tokio::time::Instant::now());
+}
+
+fn retry_after_secs(resp: &reqwest::Response) -> u64 {
+    resp.headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(5)
+        .min(MAX_RETRY_AFTER_SECS)
+}
+
+pub fn start_of_month() -> SqliteDateTime {
+    let today = Utc::now().date_naive();
+    let first = today.with_day(1).expect("Failed to get first day of month");
+    SqliteDateTime(
+        first
+            .and_hms_opt(0, 0, 0)
+            .expect("Failed to get start of month")
+            .and_utc(),
+    )
+}
 
 /* APIJobs.dev */
 // https://apijobs.dev/documentation/api/openapi.html //
 
+pub const APIJOBS_PROVIDER: &str = "apijobs";
+
+static APIJOBS_LAST_REQUEST: tokio::sync::Mutex<Option<tokio::time::Instant>> =
+    tokio::sync::Mutex::const_new(None);
+
+pub async fn apijobs_requests_this_month(executor: &sqlx::SqlitePool) -> anyhow::Result<i64> {
+    ApiRequest::count_since(APIJOBS_PROVIDER, start_of_month(), executor).await
+}
+
 #[derive(Debug, Deserialize)]
 struct APIJobsJob {
     id: String,
     title: String,
-    employment_type: Option<String>,
     workplace_type: Option<String>,
     hiring_organization_name: String,
     // hiring_organization_url: Option<String>,
@@ -30,26 +215,11 @@ struct APIJobsJob {
     website: String,
     url: String,
     published_at: String,
+    description: Option<String>,
 }
 
 impl APIJobsJob {
-    pub async fn into_job_post(self, executor: &sqlx::SqlitePool) -> JobPost {
-        // Get or create company
-        let company_id = match Company::fetch_id_by_name(&self.hiring_organization_name, executor)
-            .await
-            .expect("Failed to fetch company")
-        {
-            Some(id) => id,
-            None => Company {
-                id: 0,
-                name: self.hiring_organization_name.clone(),
-                careers_url: Some(self.website),
-                hidden: SqliteBoolean(false),
-            }
-            .insert(executor)
-            .await
-            .expect("Failed to insert company"),
-        };
+    pub fn into_search_result(self) -> JobSearchResult {
         // Handle yoe
         let yoe = self
             .experience_requirements_months
@@ -80,27 +250,38 @@ impl APIJobsJob {
             Some(str) => str,
             None => "".to_string(),
         };
-        JobPost {
-            id: 0,
-            company_id: company_id,
-            location: format_location(&city, &region, &self.country),
-            location_type: JobPostLocationType::from(loc_type),
-            url: self.url,
-            min_yoe: yoe,
-            max_yoe: None,
-            min_pay_cents: min_pay,
-            max_pay_cents: max_pay,
-            date_posted: NullableSqliteDateTime::from_iso_str(&self.published_at),
-            date_retrieved: SqliteDateTime(Utc::now()),
-            job_title: self.title,
-            benefits: None,
-            skills: skills,
-            pay_unit: self.base_salary_unit,
-            currency: self.base_salary_currency,
-            apijobs_id: Some(self.id),
-            industry: None,     // TODO
-            notes: None,        // TODO
-            platform_url: None, // TODO
+        JobSearchResult {
+            company_name: self.hiring_organization_name,
+            company_url: Some(self.website),
+            // company_id is resolved on import
+            job_post: JobPost {
+                id: 0,
+                company_id: 0,
+                location: format_location(&city, &region, &self.country),
+                location_type: JobPostLocationType::from(loc_type),
+                url: self.url,
+                min_yoe: yoe,
+                max_yoe: None,
+                min_pay_cents: min_pay,
+                max_pay_cents: max_pay,
+                date_posted: NullableSqliteDateTime::from_iso_str(&self.published_at),
+                date_retrieved: SqliteDateTime(Utc::now()),
+                job_title: self.title,
+                benefits: None,
+                skills,
+                pay_unit: self.base_salary_unit,
+                currency: self.base_salary_currency,
+                apijobs_id: Some(self.id),
+                industry: None,     // TODO
+                notes: None,        // TODO
+                platform_url: None, // TODO
+                archived: SqliteBoolean(false),
+                favorite: SqliteBoolean(false),
+                expected_pay_cents: None,
+                tags: None,
+                description: self.description,
+            },
+            exists: false,
         }
     }
 }
@@ -108,106 +289,1495 @@ impl APIJobsJob {
 #[derive(Debug, Deserialize)]
 struct APIJobsJobSearchResponse {
     hits: Vec<APIJobsJob>,
+    #[serde(default)]
+    facets: Option<serde_json::Value>,
 }
 
-pub async fn apijobs_job_search(
-    api_key: String,
-    companies: String,
-    job_title: String,
-    location: String,
-    min_yoe: i64,
-    onsite: bool,
-    hybrid: bool,
-    remote: bool,
-    executor: sqlx::SqlitePool,
-) -> anyhow::Result<()> {
+pub const APIJOBS_FACETS: [&str; 3] = ["country", "employment_type", "workplace_type"];
+
+/// Facet buckets come back either as `[{"value": .., "count": ..}]` or as `{value: count}`
+fn parse_apijobs_facets(facets: &serde_json::Value) -> Vec<JobSearchFacet> {
+    APIJOBS_FACETS
+        .iter()
+        .filter_map(|name| {
+            let buckets = facets.get(name)?;
+            let mut values: Vec<(String, i64)> = match buckets {
+                serde_json::Value::Array(buckets) => buckets
+                    .iter()
+                    .filter_map(|bucket| {
+                        let value = ["value", "key", "name"]
+                            .iter()
+                            .find_map(|key| bucket.get(key)?.as_str())?;
+                        let count = ["count", "doc_count"]
+                            .iter()
+                            .find_map(|key| bucket.get(key)?.as_i64())?;
+                        Some((value.to_string(), count))
+                    })
+                    .collect(),
+                serde_json::Value::Object(buckets) => buckets
+                    .iter()
+                    .filter_map(|(value, count)| Some((value.clone(), count.as_i64()?)))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            values.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+            Some(JobSearchFacet {
+                name: name.to_string(),
+                values,
+            })
+        })
+        .collect()
+}
+
+fn capitalize_words(s: &str) -> String {
+    s.split_whitespace()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Builds the search request body without sending it (also used for previews)
+pub fn apijobs_request_body(params: &JobSearchParams) -> serde_json::Value {
+    let mut loc_types: Vec<&str> = Vec::new();
+    if params.onsite {
+        loc_types.push("on-site");
+    }
+    if params.hybrid {
+        loc_types.push("hybrid");
+    }
+    if params.remote {
+        loc_types.push("remote");
+    }
+
+    let mut body = json!({
+        "sort_by": "created_at",
+        "sort_order": "desc",
+        "title": params.job_title, // "q" is too broad compared to "title" see schema: https://www.apijobs.dev/documentation/api/openapi.html
+        "hiring_organization_name": params.companies,
+        "country": capitalize_words(&params.country), // it REALLY wants countries capitalized
+        "experience_requirements_months": params.min_yoe * 12,
+        "workplace_type": loc_types.join(","),
+        "facets": APIJOBS_FACETS,
+    });
+    if !params.location.trim().is_empty() {
+        body["city"] = json!(capitalize_words(&params.location));
+    }
+    body
+}
+
+async fn apijobs_job_search(
+    client: &reqwest::Client,
+    api_key: &str,
+    params: &JobSearchParams,
+    monthly_quota: i64,
+    min_request_interval_secs: u64,
+    executor: &sqlx::SqlitePool,
+) -> anyhow::Result<(Vec<JobSearchResult>, Vec<JobSearchFacet>)> {
+    if monthly_quota > 0 {
+        let used = apijobs_requests_this_month(executor).await?;
+        if used >= monthly_quota {
+            anyhow::bail!("APIJobs monthly quota reached ({used}/{monthly_quota} requests)");
+        }
+    }
+
     let mut headers = HeaderMap::new();
     headers.insert(
         HeaderName::from_static("apikey"),
-        HeaderValue::from_str(&api_key).expect("Invalid header value"),
+        HeaderValue::from_str(api_key).expect("Invalid header value"),
     );
     headers.insert(
         HeaderName::from_static("content-type"),
         HeaderValue::from_static("application/json"),
     );
 
-    let mut loc_types: Vec<&str> = Vec::new();
-    if onsite {
-        loc_types.push("on-site");
+    let body = apijobs_request_body(params);
+
+    tracing::debug!(body = %body, "APIJobs request");
+
+    let mut retries = 0;
+    let resp = loop {
+        wait_for_rate_limit(
+            &APIJOBS_LAST_REQUEST,
+            std::time::Duration::from_secs(min_request_interval_secs),
+        )
+        .await;
+        let resp = client
+            .post("https://api.apijobs.dev/v1/job/search")
+            .headers(headers.clone())
+            .json(&body)
+            .send()
+            .await?;
+        ApiRequest::record(
+            APIJOBS_PROVIDER,
+            Some(resp.status().as_u16() as i64),
+            executor,
+        )
+        .await?;
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+            && retries < MAX_RATE_LIMIT_RETRIES
+        {
+            retries += 1;
+            let wait = retry_after_secs(&resp);
+            tracing::warn!("APIJobs rate limited, retrying in {wait}s");
+            tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+            continue;
+        }
+        break resp;
+    };
+    if !resp.status().is_success() {
+        anyhow::bail!("APIJobs request failed: {}", resp.status());
     }
-    if hybrid {
-        loc_types.push("hybrid");
+
+    let json = resp.json().await?;
+    tracing::trace!(response = %json, "APIJobs response");
+
+    let parsed: APIJobsJobSearchResponse = serde_json::from_value(json)?;
+    tracing::debug!(hits = parsed.hits.len(), "APIJobs response parsed");
+
+    let facets = match &parsed.facets {
+        Some(facets) => parse_apijobs_facets(facets),
+        None => Vec::new(),
+    };
+
+    let mut results = Vec::new();
+    for job in parsed.hits {
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE apijobs_id = ?")
+            .bind(job.id.clone())
+            .fetch_optional(executor)
+            .await?;
+        let mut result = job.into_search_result();
+        result.exists = exists.is_some();
+        results.push(result);
     }
-    if remote {
-        loc_types.push("remote");
+
+    Ok((results, facets))
+}
+
+/* Adzuna */
+// https://developer.adzuna.com/docs/search //
+
+pub const ADZUNA_PROVIDER: &str = "adzuna";
+
+const ADZUNA_RESULTS_PER_PAGE: u32 = 50;
+
+/// Countries Adzuna has a site for: its code, names people type for it, and its currency
+const ADZUNA_COUNTRIES: [(&str, &[&str], &str); 19] = [
+    ("at", &["austria", "österreich"], "EUR"),
+    ("au", &["australia"], "AUD"),
+    ("be", &["belgium", "belgië", "belgique"], "EUR"),
+    ("br", &["brazil", "brasil"], "BRL"),
+    ("ca", &["canada"], "CAD"),
+    ("ch", &["switzerland", "schweiz", "suisse"], "CHF"),
+    ("de", &["germany", "deutschland"], "EUR"),
+    ("es", &["spain", "españa"], "EUR"),
+    ("fr", &["france"], "EUR"),
+    (
+        "gb",
+        &[
+            "united kingdom",
+            "uk",
+            "great britain",
+            "england",
+            "scotland",
+            "wales",
+        ],
+        "GBP",
+    ),
+    ("in", &["india"], "INR"),
+    ("it", &["italy", "italia"], "EUR"),
+    ("mx", &["mexico", "méxico"], "MXN"),
+    ("nl", &["netherlands", "the netherlands", "holland"], "EUR"),
+    ("nz", &["new zealand"], "NZD"),
+    ("pl", &["poland", "polska"], "PLN"),
+    ("sg", &["singapore"], "SGD"),
+    (
+        "us",
+        &[
+            "united states",
+            "united states of america",
+            "usa",
+            "america",
+        ],
+        "USD",
+    ),
+    ("za", &["south africa"], "ZAR"),
+];
+
+/// The code and currency of the Adzuna site for a country name or code
+fn adzuna_country(country: &str) -> Option<(&'static str, &'static str)> {
+    let country = country.trim().to_lowercase();
+    ADZUNA_COUNTRIES
+        .iter()
+        .find(|(code, names, _)| *code == country || names.contains(&country.as_str()))
+        .map(|(code, _, currency)| (*code, *currency))
+}
+
+static ADZUNA_LAST_REQUEST: tokio::sync::Mutex<Option<tokio::time::Instant>> =
+    tokio::sync::Mutex::const_new(None);
+
+#[derive(Debug, Deserialize)]
+struct AdzunaJob {
+    /// May have the search terms wrapped in `<strong>`
+    title: String,
+    /// The start of the ad, not all of it
+    #[serde(default)]
+    description: String,
+    created: String,
+    redirect_url: String,
+    company: Option<AdzunaName>,
+    location: Option<AdzunaName>,
+    salary_min: Option<f64>,
+    salary_max: Option<f64>,
+    /// "1" when Adzuna estimated the salary instead of the ad stating it
+    #[serde(default)]
+    salary_is_predicted: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdzunaName {
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AdzunaSearchResponse {
+    results: Vec<AdzunaJob>,
+}
+
+/// Plain text from a provider's HTML, with a line break after each paragraph, list item, or
+/// `<br>`
+fn strip_tags(html: &str) -> String {
+    let block =
+        regex::Regex::new(r"(?i)</p>|</li>|</h\d>|<br\s*/?>").expect("Failed to make regex");
+    let tag = regex::Regex::new(r"<[^>]*>").expect("Failed to make regex");
+    let blank_lines = regex::Regex::new(r"\n\s*\n\s*").expect("Failed to make regex");
+    let text = block.replace_all(html, "\n");
+    let text = tag
+        .replace_all(&text, "")
+        .replace("&nbsp;", " ")
+        .replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&");
+    blank_lines.replace_all(&text, "\n\n").trim().to_string()
+}
+
+impl AdzunaJob {
+    fn company_name(&self) -> String {
+        self.company
+            .as_ref()
+            .and_then(|company| company.display_name.clone())
+            .unwrap_or_default()
     }
 
-    let loc_capitalized = {
-        location
-            .split_whitespace()
-            .map(|word| {
-                let mut chars = word.chars();
-                match chars.next() {
-                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-                    None => String::new(),
-                }
+    /// Salaries are yearly, in the currency of the country searched
+    fn into_search_result(self, currency: &str) -> JobSearchResult {
+        let company_name = self.company_name();
+        let job_title = strip_tags(&self.title);
+        let description = strip_tags(&self.description);
+        // No workplace field, so it's whatever the title or description says
+        let text = format!("{job_title}\n{description}").to_lowercase();
+        let location_type = if text.contains("hybrid") {
+            JobPostLocationType::Hybrid
+        } else if text.contains("remote") {
+            JobPostLocationType::Remote
+        } else {
+            JobPostLocationType::Unknown
+        };
+        let predicted = self.salary_is_predicted == json!("1") || self.salary_is_predicted == 1;
+        let cents = |pay: Option<f64>| {
+            pay.filter(|_| !predicted)
+                .map(|pay| (pay * 100.0).round() as i64)
+        };
+        let (min_pay_cents, max_pay_cents) = (cents(self.salary_min), cents(self.salary_max));
+        let has_pay = min_pay_cents.is_some() || max_pay_cents.is_some();
+        let (min_yoe, max_yoe) = find_yoe_naive(&description);
+        JobSearchResult {
+            company_name,
+            company_url: None,
+            job_post: JobPost {
+                id: 0,
+                company_id: 0,
+                location: self
+                    .location
+                    .and_then(|location| location.display_name)
+                    .unwrap_or_default(),
+                location_type,
+                url: self.redirect_url,
+                min_yoe,
+                max_yoe,
+                min_pay_cents,
+                max_pay_cents,
+                date_posted: NullableSqliteDateTime(
+                    DateTime::parse_from_rfc3339(&self.created)
+                        .ok()
+                        .map(|created| created.with_timezone(&Utc).date_naive()),
+                ),
+                date_retrieved: SqliteDateTime(Utc::now()),
+                job_title,
+                benefits: None,
+                skills: None,
+                pay_unit: has_pay.then(|| "year".to_string()),
+                currency: has_pay.then(|| currency.to_string()),
+                apijobs_id: None,
+                industry: None,
+                notes: None,
+                platform_url: Some("https://www.adzuna.com".to_string()),
+                archived: SqliteBoolean(false),
+                favorite: SqliteBoolean(false),
+                expected_pay_cents: None,
+                tags: None,
+                description: (!description.is_empty()).then_some(description),
+            },
+            exists: false,
+        }
+    }
+}
+
+/// The search URL and its query, without the credentials. Adzuna has no experience or
+/// workplace filters, and searches one country per request.
+fn adzuna_request(params: &JobSearchParams) -> anyhow::Result<(String, Vec<(&str, String)>)> {
+    let Some((country, _)) = adzuna_country(&params.country) else {
+        anyhow::bail!(
+            "Adzuna searches one country at a time; enter one of: {}",
+            ADZUNA_COUNTRIES
+                .iter()
+                .map(|(_, names, _)| names[0])
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    };
+    let mut query = vec![
+        ("results_per_page", ADZUNA_RESULTS_PER_PAGE.to_string()),
+        ("sort_by", "date".to_string()),
+    ];
+    if !params.job_title.trim().is_empty() {
+        query.push(("title_only", params.job_title.trim().to_string()));
+    }
+    if !params.location.trim().is_empty() {
+        query.push(("where", params.location.trim().to_string()));
+    }
+    if params.min_salary > 0 {
+        query.push(("salary_min", params.min_salary.to_string()));
+    }
+    Ok((
+        format!("https://api.adzuna.com/v1/api/jobs/{country}/search/1"),
+        query,
+    ))
+}
+
+async fn adzuna_job_search(
+    client: &reqwest::Client,
+    app_id: &str,
+    app_key: &str,
+    params: &JobSearchParams,
+    min_request_interval_secs: u64,
+    executor: &sqlx::SqlitePool,
+) -> anyhow::Result<(Vec<JobSearchResult>, Vec<JobSearchFacet>)> {
+    let (url, query) = adzuna_request(params)?;
+    let (_, currency) = adzuna_country(&params.country).expect("Country checked above");
+
+    tracing::debug!(url = %url, query = ?query, "Adzuna request");
+
+    let mut retries = 0;
+    let resp = loop {
+        wait_for_rate_limit(
+            &ADZUNA_LAST_REQUEST,
+            std::time::Duration::from_secs(min_request_interval_secs),
+        )
+        .await;
+        let resp = client
+            .get(&url)
+            .query(&[("app_id", app_id), ("app_key", app_key)])
+            .query(&query)
+            .send()
+            .await?;
+        ApiRequest::record(
+            ADZUNA_PROVIDER,
+            Some(resp.status().as_u16() as i64),
+            executor,
+        )
+        .await?;
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+            && retries < MAX_RATE_LIMIT_RETRIES
+        {
+            retries += 1;
+            let wait = retry_after_secs(&resp);
+            tracing::warn!("Adzuna rate limited, retrying in {wait}s");
+            tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+            continue;
+        }
+        break resp;
+    };
+    if !resp.status().is_success() {
+        anyhow::bail!("Adzuna request failed: {}", resp.status());
+    }
+
+    let parsed: AdzunaSearchResponse = resp.json().await?;
+    tracing::debug!(results = parsed.results.len(), "Adzuna response parsed");
+
+    let mut results = Vec::new();
+    // Without a company there's nothing to file the post under
+    for job in parsed
+        .results
+        .into_iter()
+        .filter(|job| !job.company_name().trim().is_empty())
+    {
+        let mut result = job.into_search_result(currency);
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+            .bind(&result.job_post.url)
+            .fetch_optional(executor)
+            .await?;
+        result.exists = exists.is_some();
+        results.push(result);
+    }
+
+    // Adzuna's histograms don't map onto search fields
+    Ok((results, Vec::new()))
+}
+
+/* Remotive */
+// https://remotive.com/api-documentation //
+
+pub const REMOTIVE_PROVIDER: &str = "remotive";
+
+static REMOTIVE_LAST_REQUEST: tokio::sync::Mutex<Option<tokio::time::Instant>> =
+    tokio::sync::Mutex::const_new(None);
+
+const REMOTIVE_RESULTS_LIMIT: u32 = 100;
+
+/// Remotive's category slugs, by words in a job title that point to them. More specific ones
+/// come first, so "Data Engineer" is data and not software development.
+const REMOTIVE_CATEGORIES: [(&str, &[&str]); 12] = [
+    ("design", &["design", "ux", " ui "]),
+    ("data", &["data", "analyst", "machine learning", " ml "]),
+    (
+        "devops",
+        &[
+            "devops",
+            "sre",
+            "site reliability",
+            "sysadmin",
+            "infrastructure",
+        ],
+    ),
+    ("qa", &["qa", "quality", "test"]),
+    ("product", &["product manager", "product owner"]),
+    ("marketing", &["marketing", "seo", "growth"]),
+    (
+        "sales",
+        &["sales", "account executive", "business development"],
+    ),
+    ("customer-support", &["support", "customer success"]),
+    ("writing", &["writer", "editor", "copywriter"]),
+    ("hr", &["recruiter", "talent", "people"]),
+    (
+        "finance-legal",
+        &["finance", "accountant", "legal", "counsel"],
+    ),
+    ("software-dev", &["engineer", "developer", "programmer"]),
+];
+
+/// The Remotive category a job title falls in, if it's clear from the title
+fn remotive_category(job_title: &str) -> Option<&'static str> {
+    let title = format!(" {} ", job_title.trim().to_lowercase());
+    REMOTIVE_CATEGORIES
+        .iter()
+        .find(|(_, words)| words.iter().any(|word| title.contains(word)))
+        .map(|(category, _)| *category)
+}
+
+#[derive(Debug, Deserialize)]
+struct RemotiveJob {
+    url: String,
+    title: String,
+    company_name: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Local time without an offset, e.g. "2025-03-04T10:23:26"
+    publication_date: String,
+    /// Where applicants can be, e.g. "Worldwide" or "USA"
+    #[serde(default)]
+    candidate_required_location: String,
+    /// As the company wrote it, e.g. "$80k - $100k"
+    #[serde(default)]
+    salary: String,
+    /// HTML
+    #[serde(default)]
+    description: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemotiveSearchResponse {
+    jobs: Vec<RemotiveJob>,
+}
+
+/// Remotive salaries are free text, e.g. "$40,000 - $50,000", "€60k-€70k", or "USD 45/hour".
+/// Returns the min and max in cents, the pay unit, and the currency.
+fn parse_remotive_salary(
+    salary: &str,
+) -> (Option<i64>, Option<i64>, Option<String>, Option<String>) {
+    // Benefits like "+ 401(k)" aren't pay
+    let retirement_plan =
+        regex::Regex::new(r"(?i)\b40[13]\s*\(?[kb]\)?").expect("Failed to make regex");
+    let salary = retirement_plan.replace_all(salary, "");
+    let amount =
+        regex::Regex::new(r"(?i)(\d[\d,]*(?:\.\d+)?)\s*(k)?").expect("Failed to make regex");
+    let amounts: Vec<f64> = amount
+        .captures_iter(&salary)
+        .filter_map(|cap| {
+            let value: f64 = cap[1].replace(',', "").parse().ok()?;
+            Some(if cap.get(2).is_some() {
+                value * 1000.0
+            } else {
+                value
             })
-            .collect::<Vec<_>>()
-            .join(" ")
+        })
+        .take(2)
+        .collect();
+    let Some(&min) = amounts.first() else {
+        return (None, None, None, None);
+    };
+    let max = amounts.get(1).copied();
+    let upper = salary.to_uppercase();
+    let currency = if upper.contains('€') || upper.contains("EUR") {
+        "EUR"
+    } else if upper.contains('£') || upper.contains("GBP") {
+        "GBP"
+    } else if upper.contains("CAD") {
+        "CAD"
+    } else {
+        "USD"
+    };
+    let lower = salary.to_lowercase();
+    let yearly = ["year", "/yr", "annual", "annum"]
+        .iter()
+        .any(|marker| lower.contains(marker));
+    let unit = if lower.contains("hour") || lower.contains("/hr") {
+        "hour"
+    } else if lower.contains("month") {
+        "month"
+    } else if !yearly && amounts.iter().all(|&pay| pay < 1000.0) {
+        // Bare amounts this small are only plausible per hour
+        "hour"
+    } else {
+        "year"
     };
+    let cents = |pay: f64| (pay * 100.0).round() as i64;
+    (
+        Some(cents(min)),
+        max.map(cents),
+        Some(unit.to_string()),
+        Some(currency.to_string()),
+    )
+}
 
-    let body = json!({
-        "sort_by": "created_at",
-        "sort_order": "desc",
-        "title": job_title, // "q" is too broad compared to "title" see schema: https://www.apijobs.dev/documentation/api/openapi.html
-        "hiring_organization_name": companies,
-        // TODO: eventually, location on our side should be processed into subfields
-        "country": loc_capitalized, // it REALLY wants countries capitalized
-        // "region": location,
-        // "city": location,
-        "experience_requirements_months": min_yoe * 12,
-        "workplace_type": loc_types.join(","),
-        "facets": vec!["country", "employment_type", "workplace_type"],
-    });
+impl RemotiveJob {
+    fn into_search_result(self) -> JobSearchResult {
+        let description = strip_tags(&self.description);
+        let (min_yoe, max_yoe) = find_yoe_naive(&description);
+        let (min_pay_cents, max_pay_cents, pay_unit, currency) =
+            parse_remotive_salary(&self.salary);
+        let date_posted =
+            chrono::NaiveDateTime::parse_from_str(&self.publication_date, "%Y-%m-%dT%H:%M:%S")
+                .ok()
+                .map(|published| published.date());
+        JobSearchResult {
+            company_name: self.company_name.trim().to_string(),
+            company_url: None,
+            job_post: JobPost {
+                id: 0,
+                company_id: 0,
+                location: self.candidate_required_location,
+                // Every post on Remotive is remote
+                location_type: JobPostLocationType::Remote,
+                url: self.url,
+                min_yoe,
+                max_yoe,
+                min_pay_cents,
+                max_pay_cents,
+                date_posted: NullableSqliteDateTime(date_posted),
+                date_retrieved: SqliteDateTime(Utc::now()),
+                job_title: strip_tags(&self.title),
+                benefits: None,
+                skills: (!self.tags.is_empty()).then(|| self.tags.join(", ")),
+                pay_unit,
+                currency,
+                apijobs_id: None,
+                industry: None,
+                notes: None,
+                platform_url: Some("https://remotive.com".to_string()),
+                archived: SqliteBoolean(false),
+                favorite: SqliteBoolean(false),
+                expected_pay_cents: None,
+                tags: None,
+                description: (!description.is_empty()).then_some(description),
+            },
+            exists: false,
+        }
+    }
+}
 
-    println!(
-        "API REQUEST BODY:\n{}",
-        serde_json::to_string_pretty(&body)?
-    );
+/// The search's query: the job title as search words, and its category when the title makes
+/// it clear. Remotive has no location, experience, or salary filters.
+fn remotive_query(params: &JobSearchParams) -> Vec<(&'static str, String)> {
+    let mut query = vec![("limit", REMOTIVE_RESULTS_LIMIT.to_string())];
+    if let Some(category) = remotive_category(&params.job_title) {
+        query.push(("category", category.to_string()));
+    }
+    if !params.job_title.trim().is_empty() {
+        query.push(("search", params.job_title.trim().to_string()));
+    }
+    query
+}
 
-    let client = reqwest::Client::new();
-    let resp = client
-        .post("https://api.apijobs.dev/v1/job/search")
-        .headers(headers)
-        .json(&body)
-        .send()
+async fn remotive_job_search(
+    client: &reqwest::Client,
+    params: &JobSearchParams,
+    min_request_interval_secs: u64,
+    executor: &sqlx::SqlitePool,
+) -> anyhow::Result<(Vec<JobSearchResult>, Vec<JobSearchFacet>)> {
+    // Only remote posts to offer
+    if (params.onsite || params.hybrid) && !params.remote {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let query = remotive_query(params);
+
+    tracing::debug!(query = ?query, "Remotive request");
+
+    let mut retries = 0;
+    let resp = loop {
+        wait_for_rate_limit(
+            &REMOTIVE_LAST_REQUEST,
+            std::time::Duration::from_secs(min_request_interval_secs),
+        )
+        .await;
+        let resp = client
+            .get("https://remotive.com/api/remote-jobs")
+            .query(&query)
+            .send()
+            .await?;
+        ApiRequest::record(
+            REMOTIVE_PROVIDER,
+            Some(resp.status().as_u16() as i64),
+            executor,
+        )
         .await?;
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+            && retries < MAX_RATE_LIMIT_RETRIES
+        {
+            retries += 1;
+            let wait = retry_after_secs(&resp);
+            tracing::warn!("Remotive rate limited, retrying in {wait}s");
+            tokio::time::sleep(std::time::Duration::from_secs(wait)).await;
+            continue;
+        }
+        break resp;
+    };
+    if !resp.status().is_success() {
+        anyhow::bail!("Remotive request failed: {}", resp.status());
+    }
 
-    let json = resp.json().await?;
-    println!("API RESPONSE:\n{}", serde_json::to_string_pretty(&json)?);
-
-    let parsed: Result<APIJobsJobSearchResponse, _> = serde_json::from_value(json);
-    match parsed {
-        Ok(parsed) => {
-            println!("PARSED API RESPONSE: {:?}", parsed);
-            println!("HITS LEN: {}", parsed.hits.len());
-
-            for job in parsed.hits {
-                let exists: Option<(i64,)> =
-                    sqlx::query_as("SELECT id FROM job_post WHERE apijobs_id = ?")
-                        .bind(job.id.clone())
-                        .fetch_optional(&executor)
-                        .await?;
-                if exists.is_none() {
-                    let job_post = job.into_job_post(&executor).await;
-                    job_post.insert(&executor).await?;
+    let parsed: RemotiveSearchResponse = resp.json().await?;
+    tracing::debug!(jobs = parsed.jobs.len(), "Remotive response parsed");
+
+    // No company filter either, so tracked companies are matched here
+    let companies: Vec<String> = split_comma_separated(&params.companies)
+        .iter()
+        .map(|company| company.to_lowercase())
+        .collect();
+    let mut results = Vec::new();
+    for job in parsed.jobs.into_iter().filter(|job| {
+        companies.is_empty() || companies.contains(&job.company_name.trim().to_lowercase())
+    }) {
+        let mut result = job.into_search_result();
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+            .bind(&result.job_post.url)
+            .fetch_optional(executor)
+            .await?;
+        result.exists = exists.is_some();
+        results.push(result);
+    }
+
+    Ok((results, Vec::new()))
+}
+
+/* Provider-agnostic search */
+
+/// Credentials and limits for each provider, from Settings
+#[derive(Debug, Clone, Default)]
+pub struct ProviderSettings {
+    pub apijobs_key: String,
+    /// Requests allowed per calendar month (0 = unlimited)
+    pub apijobs_monthly_quota: i64,
+    /// Minimum delay between consecutive requests, to every provider
+    pub request_interval_secs: u64,
+    pub adzuna_app_id: String,
+    pub adzuna_app_key: String,
+    /// Sent by providers that turn away requests without one (empty = ours)
+    pub user_agent: String,
+    /// Ids of providers turned off in Settings
+    pub disabled: BTreeSet<String>,
+}
+
+impl ProviderSettings {
+    pub fn is_enabled(&self, provider: Provider) -> bool {
+        !self.disabled.contains(provider.id())
+    }
+
+    /// Whether the provider is turned on and its credentials are filled in
+    pub fn is_configured(&self, provider: Provider) -> bool {
+        self.is_enabled(provider)
+            && provider
+                .credentials(self)
+                .iter()
+                .all(|(_, value)| !value.is_empty())
+    }
+
+    pub fn configured(&self) -> Vec<Provider> {
+        PROVIDERS
+            .into_iter()
+            .filter(|provider| self.is_configured(*provider))
+            .collect()
+    }
+}
+
+type ProviderSearch<'a> = std::pin::Pin<
+    Box<
+        dyn std::future::Future<
+                Output = anyhow::Result<(Vec<JobSearchResult>, Vec<JobSearchFacet>)>,
+            > + Send
+            + 'a,
+    >,
+>;
+
+/// A job search API that Find Jobs and alert rules can search. Adding one means
+/// implementing this and listing it in `PROVIDERS`; alert rules find it again by `id`.
+pub trait JobSearchProvider: Sync {
+    /// Key used for the `provider` column of request, fetch, and alert rule rows
+    fn id(&self) -> &'static str;
+
+    /// Shown in Find Jobs and Settings
+    fn name(&self) -> &'static str;
+
+    /// The settings it can't search without, by label, with their values
+    fn credentials<'a>(&self, settings: &'a ProviderSettings) -> Vec<(&'static str, &'a str)>;
+
+    /// The request a search would send, shown before searching. Leaves out credentials.
+    fn request_preview(&self, params: &JobSearchParams) -> String;
+
+    /// One page of results, with `exists` set for posts already saved
+    fn search<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        settings: &'a ProviderSettings,
+        params: &'a JobSearchParams,
+        executor: &'a sqlx::SqlitePool,
+    ) -> ProviderSearch<'a>;
+}
+
+/// A registered provider, compared by id so it can be picked from a list
+#[derive(Clone, Copy)]
+pub struct Provider(&'static dyn JobSearchProvider);
+
+impl Provider {
+    /// The provider an alert rule or log row was saved with
+    pub fn from_id(id: &str) -> Option<Self> {
+        PROVIDERS.into_iter().find(|provider| provider.id() == id)
+    }
+}
+
+impl std::ops::Deref for Provider {
+    type Target = dyn JobSearchProvider;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl PartialEq for Provider {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Provider {}
+
+impl std::fmt::Debug for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Provider").field(&self.id()).finish()
+    }
+}
+
+impl std::fmt::Display for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Every provider, in the order Find Jobs offers them
+pub const PROVIDERS: [Provider; 4] = [
+    Provider(&APIJobsProvider),
+    Provider(&AdzunaProvider),
+    Provider(&RemotiveProvider),
+    Provider(&RemoteOkProvider),
+];
+
+fn format_query(query: &[(&str, String)]) -> String {
+    query
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct APIJobsProvider;
+
+impl JobSearchProvider for APIJobsProvider {
+    fn id(&self) -> &'static str {
+        APIJOBS_PROVIDER
+    }
+
+    fn name(&self) -> &'static str {
+        "APIJobs"
+    }
+
+    fn credentials<'a>(&self, settings: &'a ProviderSettings) -> Vec<(&'static str, &'a str)> {
+        vec![("APIJobs API Key", &settings.apijobs_key)]
+    }
+
+    fn request_preview(&self, params: &JobSearchParams) -> String {
+        format!(
+            "POST https://api.apijobs.dev/v1/job/search\n\n{}",
+            serde_json::to_string_pretty(&apijobs_request_body(params))
+                .expect("Failed to serialize request body")
+        )
+    }
+
+    fn search<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        settings: &'a ProviderSettings,
+        params: &'a JobSearchParams,
+        executor: &'a sqlx::SqlitePool,
+    ) -> ProviderSearch<'a> {
+        Box::pin(apijobs_job_search(
+            client,
+            &settings.apijobs_key,
+            params,
+            settings.apijobs_monthly_quota,
+            settings.request_interval_secs,
+            executor,
+        ))
+    }
+}
+
+pub struct AdzunaProvider;
+
+impl JobSearchProvider for AdzunaProvider {
+    fn id(&self) -> &'static str {
+        ADZUNA_PROVIDER
+    }
+
+    fn name(&self) -> &'static str {
+        "Adzuna"
+    }
+
+    fn credentials<'a>(&self, settings: &'a ProviderSettings) -> Vec<(&'static str, &'a str)> {
+        vec![
+            ("Adzuna App ID", &settings.adzuna_app_id),
+            ("Adzuna App Key", &settings.adzuna_app_key),
+        ]
+    }
+
+    fn request_preview(&self, params: &JobSearchParams) -> String {
+        match adzuna_request(params) {
+            Ok((url, query)) => format!(
+                "GET {url}?app_id=...&app_key=...\n\n{}",
+                format_query(&query)
+            ),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    fn search<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        settings: &'a ProviderSettings,
+        params: &'a JobSearchParams,
+        executor: &'a sqlx::SqlitePool,
+    ) -> ProviderSearch<'a> {
+        Box::pin(adzuna_job_search(
+            client,
+            &settings.adzuna_app_id,
+            &settings.adzuna_app_key,
+            params,
+            settings.request_interval_secs,
+            executor,
+        ))
+    }
+}
+
+/// Remote jobs only, no key needed
+pub struct RemotiveProvider;
+
+impl JobSearchProvider for RemotiveProvider {
+    fn id(&self) -> &'static str {
+        REMOTIVE_PROVIDER
+    }
+
+    fn name(&self) -> &'static str {
+        "Remotive"
+    }
+
+    fn credentials<'a>(&self, _settings: &'a ProviderSettings) -> Vec<(&'static str, &'a str)> {
+        Vec::new()
+    }
+
+    fn request_preview(&self, params: &JobSearchParams) -> String {
+        format!(
+            "GET https://remotive.com/api/remote-jobs\n\n{}",
+            format_query(&remotive_query(params))
+        )
+    }
+
+    fn search<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        settings: &'a ProviderSettings,
+        params: &'a JobSearchParams,
+        executor: &'a sqlx::SqlitePool,
+    ) -> ProviderSearch<'a> {
+        Box::pin(remotive_job_search(
+            client,
+            params,
+            settings.request_interval_secs,
+            executor,
+        ))
+    }
+}
+
+/// Search parameters entered in the Find Jobs modal, independent of the local filter bar
+#[derive(Debug, Clone, Default)]
+pub struct JobSearchParams {
+    pub companies: String,
+    pub job_title: String,
+    pub location: String,
+    pub country: String,
+    pub min_yoe: i64,
+    /// Whole currency units, 0 = any
+    pub min_salary: i64,
+    pub onsite: bool,
+    pub hybrid: bool,
+    pub remote: bool,
+}
+
+impl JobSearchParams {
+    /// Short human-readable description of the query for the fetch history
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.job_title.is_empty() {
+            parts.push(format!("title: {}", self.job_title));
+        }
+        if !self.location.is_empty() {
+            parts.push(format!("city: {}", self.location));
+        }
+        if !self.country.is_empty() {
+            parts.push(format!("country: {}", self.country));
+        }
+        if self.min_yoe > 0 {
+            parts.push(format!("{}+ yoe", self.min_yoe));
+        }
+        if self.min_salary > 0 {
+            parts.push(format!("${}+", self.min_salary));
+        }
+        let loc_types = [
+            (self.onsite, "onsite"),
+            (self.hybrid, "hybrid"),
+            (self.remote, "remote"),
+        ]
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>();
+        if !loc_types.is_empty() {
+            parts.push(loc_types.join("/"));
+        }
+        if !self.companies.is_empty() {
+            parts.push("tracked companies".to_string());
+        }
+        if parts.is_empty() {
+            "any".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// A fetched job post that has not been written to the database yet
+#[derive(Debug, Clone)]
+pub struct JobSearchResult {
+    pub company_name: String,
+    pub company_url: Option<String>,
+    pub job_post: JobPost,
+    /// Already imported by a previous search
+    pub exists: bool,
+}
+
+/// Result counts per value of a facet, most common first
+#[derive(Debug, Clone)]
+pub struct JobSearchFacet {
+    pub name: String,
+    pub values: Vec<(String, i64)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JobSearchResponse {
+    pub results: Vec<JobSearchResult>,
+    pub facets: Vec<JobSearchFacet>,
+    pub fetch_log_id: i64,
+}
+
+/// Whether the post's top pay, over a year, reaches `min_salary` whole currency units. Posts
+/// without pay are kept, since they may well pay enough.
+fn pays_at_least(job_post: &JobPost, min_salary: i64) -> bool {
+    min_salary == 0
+        || job_post
+            .max_pay_cents
+            .or(job_post.min_pay_cents)
+            .is_none_or(|pay| {
+                annualize_cents(pay, job_post.pay_unit.as_deref()) >= min_salary * 100
+            })
+}
+
+#[tracing::instrument(skip_all, fields(provider = %provider), err)]
+pub async fn job_search(
+    client: reqwest::Client,
+    provider: Provider,
+    settings: ProviderSettings,
+    params: JobSearchParams,
+    executor: sqlx::SqlitePool,
+) -> anyhow::Result<JobSearchResponse> {
+    let (results, facets) = provider
+        .search(&client, &settings, &params, &executor)
+        .await?;
+
+    // Salary isn't a search field, so posts known to pay less are dropped here
+    let results: Vec<JobSearchResult> = results
+        .into_iter()
+        .filter(|result| pays_at_least(&result.job_post, params.min_salary))
+        .collect();
+
+    let fetch_log_id = FetchLog::record(
+        provider.id(),
+        &params.summary(),
+        results.len() as i64,
+        &executor,
+    )
+    .await?;
+
+    Ok(JobSearchResponse {
+        results,
+        facets,
+        fetch_log_id,
+    })
+}
+
+/// Writes the selected search results, creating companies as needed
+#[tracing::instrument(skip_all, fields(results = results.len()), err)]
+pub async fn import_results(
+    results: Vec<JobSearchResult>,
+    fetch_log_id: Option<i64>,
+    executor: sqlx::SqlitePool,
+) -> anyhow::Result<InsertCounts> {
+    // All or nothing, so a failure partway through doesn't leave a half-imported batch
+    let mut tx = executor.begin().await?;
+    let mut job_posts = Vec::with_capacity(results.len());
+    let mut apijobs_ids = HashSet::new();
+    let mut urls = HashSet::new();
+    for result in results {
+        if let Some(apijobs_id) = &result.job_post.apijobs_id {
+            if !apijobs_ids.insert(apijobs_id.clone()) {
+                continue;
+            }
+            let exists: Option<(i64,)> =
+                sqlx::query_as("SELECT id FROM job_post WHERE apijobs_id = ?")
+                    .bind(apijobs_id)
+                    .fetch_optional(&mut *tx)
+                    .await?;
+            if exists.is_some() {
+                continue;
+            }
+        } else {
+            // Other providers' posts are known by their URL
+            if !urls.insert(result.job_post.url.clone()) {
+                continue;
+            }
+            let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+                .bind(&result.job_post.url)
+                .fetch_optional(&mut *tx)
+                .await?;
+            if exists.is_some() {
+                continue;
+            }
+        }
+        // Get or create company
+        let company_id = match Company::fetch_id_by_name(&result.company_name, &mut *tx).await? {
+            Some(id) => {
+                // Resolves directly next time, whichever way it was matched
+                CompanyAltName::insert(id, &result.company_name, &mut *tx).await?;
+                id
+            }
+            None => {
+                Company {
+                    id: 0,
+                    name: result.company_name.clone(),
+                    careers_url: result.company_url.clone(),
+                    hidden: SqliteBoolean(false),
+                    group_id: None,
                 }
+                .insert(&mut *tx)
+                .await?
             }
+        };
+        let mut job_post = result.job_post;
+        job_post.company_id = company_id;
+        job_posts.push(job_post);
+    }
+    let imported = JobPost::insert_many(&job_posts, &mut *tx).await?;
+
+    if let Some(fetch_log_id) = fetch_log_id {
+        FetchLog::add_inserted(fetch_log_id, imported.inserted as i64, &mut *tx).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(imported)
+}
+
+pub fn alert_rule_params(rule: &AlertRule, companies: &str) -> JobSearchParams {
+    JobSearchParams {
+        companies: if rule.tracked_companies.0 {
+            companies.to_string()
+        } else {
+            "".to_string()
+        },
+        job_title: rule.job_title.clone(),
+        location: rule.location.clone(),
+        country: rule.country.clone(),
+        min_yoe: rule.min_yoe,
+        min_salary: rule.min_salary,
+        onsite: rule.onsite.0,
+        hybrid: rule.hybrid.0,
+        remote: rule.remote.0,
+    }
+}
+
+/// Runs every due alert rule whose provider is set up and imports its new matches. A
+/// positive `interval_hours` replaces each rule's own. Returns the number of posts inserted
+/// per rule name; rules whose search fails are logged and left out.
+#[tracing::instrument(skip_all, err)]
+pub async fn run_due_alert_rules(
+    client: reqwest::Client,
+    settings: ProviderSettings,
+    companies: String,
+    interval_hours: i64,
+    executor: sqlx::SqlitePool,
+) -> anyhow::Result<Vec<(String, InsertCounts)>> {
+    let mut inserted = Vec::new();
+    for rule in AlertRule::fetch_due(interval_hours, &executor).await? {
+        let Some(provider) = Provider::from_id(&rule.provider) else {
+            continue;
+        };
+        if !settings.is_configured(provider) {
+            continue;
+        }
+        // Marked before running so a failing rule isn't retried every tick
+        AlertRule::set_last_run(rule.id, &executor).await?;
+
+        let res = async {
+            let response = job_search(
+                client.clone(),
+                provider,
+                settings.clone(),
+                alert_rule_params(&rule, &companies),
+                executor.clone(),
+            )
+            .await?;
+            let new_results = response
+                .results
+                .into_iter()
+                .filter(|result| !result.exists)
+                .collect();
+            import_results(new_results, Some(response.fetch_log_id), executor.clone()).await
         }
-        Err(e) => {
-            println!("Failed to deserialize response: {:?}", e);
+        .await;
+        match res {
+            Ok(count) => inserted.push((rule.name, count)),
+            // One failing search shouldn't keep the rest from running
+            Err(e) => tracing::warn!(rule = %rule.name, "Alert rule failed: {:?}", e),
         }
     }
 
-    Ok(())
+    Ok(inserted)
+}
+
+#[cfg(test)]
+// Cents are written apart from the dollars, e.g. 85_000_00
+#[allow(clippy::inconsistent_digit_grouping)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::db::test_support::JobPostBuilder;
+
+    #[test]
+    fn providers_are_registered_once_and_need_their_credentials() {
+        let ids: HashSet<&str> = PROVIDERS.iter().map(|provider| provider.id()).collect();
+        assert_eq!(ids.len(), PROVIDERS.len());
+        for provider in PROVIDERS {
+            assert_eq!(Provider::from_id(provider.id()), Some(provider));
+        }
+        assert_eq!(Provider::from_id("monster"), None);
+
+        let adzuna = Provider::from_id(ADZUNA_PROVIDER).unwrap();
+        let remotive = Provider::from_id(REMOTIVE_PROVIDER).unwrap();
+        let remoteok = Provider::from_id(remoteok::REMOTEOK_PROVIDER).unwrap();
+        let mut settings = ProviderSettings {
+            adzuna_app_id: "id".to_string(),
+            ..Default::default()
+        };
+        assert!(!settings.is_configured(adzuna));
+        assert_eq!(settings.configured(), vec![remotive, remoteok]);
+
+        settings.adzuna_app_key = "key".to_string();
+        settings.disabled.insert(REMOTIVE_PROVIDER.to_string());
+        assert_eq!(settings.configured(), vec![adzuna, remoteok]);
+        settings
+            .disabled
+            .insert(remoteok::REMOTEOK_PROVIDER.to_string());
+        assert_eq!(settings.configured(), vec![adzuna]);
+        assert_eq!(adzuna.to_string(), "Adzuna");
+    }
+
+    #[test]
+    fn min_salary_compares_yearly_pay() {
+        let post = |pay_cents: Option<i64>, unit: &str| {
+            JobPostBuilder::new(1, "Engineer")
+                .pay(None, pay_cents, unit)
+                .build()
+        };
+        // $60/hour is about $125k a year
+        assert!(pays_at_least(&post(Some(60_00), "hour"), 100_000));
+        assert!(!pays_at_least(&post(Some(90_000_00), "year"), 100_000));
+        assert!(pays_at_least(&post(Some(9_000_00), "month"), 100_000));
+        assert!(pays_at_least(&post(None, "year"), 100_000));
+        assert!(pays_at_least(&post(Some(1_00), "hour"), 0));
+    }
+
+    #[test]
+    fn adzuna_searches_one_country_by_name_or_code() {
+        assert_eq!(adzuna_country("United Kingdom"), Some(("gb", "GBP")));
+        assert_eq!(adzuna_country(" us "), Some(("us", "USD")));
+        assert_eq!(adzuna_country("Narnia"), None);
+
+        let params = JobSearchParams {
+            job_title: "rust engineer".to_string(),
+            location: "London".to_string(),
+            country: "uk".to_string(),
+            min_salary: 60000,
+            ..Default::default()
+        };
+        let (url, query) = adzuna_request(&params).unwrap();
+        assert_eq!(url, "https://api.adzuna.com/v1/api/jobs/gb/search/1");
+        assert!(query.contains(&("title_only", "rust engineer".to_string())));
+        assert!(query.contains(&("salary_min", "60000".to_string())));
+        assert!(adzuna_request(&JobSearchParams::default()).is_err());
+    }
+
+    #[test]
+    fn adzuna_results_map_onto_job_posts() {
+        let response: AdzunaSearchResponse = serde_json::from_str(
+            r#"{"results": [
+                {
+                    "title": "Senior <strong>Rust</strong> Engineer",
+                    "description": "Remote-first team. 5+ years of experience...",
+                    "created": "2025-03-04T10:00:00Z",
+                    "redirect_url": "https://www.adzuna.co.uk/jobs/land/ad/1",
+                    "company": {"display_name": "Acme"},
+                    "location": {"display_name": "London, UK"},
+                    "salary_min": 70000,
+                    "salary_max": 90000,
+                    "salary_is_predicted": "0"
+                },
+                {
+                    "title": "Analyst",
+                    "created": "2025-03-05T10:00:00Z",
+                    "redirect_url": "https://www.adzuna.co.uk/jobs/land/ad/2",
+                    "company": {"display_name": "Globex"},
+                    "salary_min": 40000,
+                    "salary_is_predicted": "1"
+                }
+            ]}"#,
+        )
+        .unwrap();
+        let mut results = response.results.into_iter();
+
+        let result = results.next().unwrap().into_search_result("GBP");
+        assert_eq!(result.company_name, "Acme");
+        let job = result.job_post;
+        assert_eq!(job.job_title, "Senior Rust Engineer");
+        assert_eq!(job.location, "London, UK");
+        assert_eq!(job.location_type, JobPostLocationType::Remote);
+        assert_eq!(job.min_yoe, Some(5));
+        assert_eq!(job.min_pay_cents, Some(7_000_000));
+        assert_eq!(job.max_pay_cents, Some(9_000_000));
+        assert_eq!(job.currency.as_deref(), Some("GBP"));
+        assert_eq!(job.pay_unit.as_deref(), Some("year"));
+        assert_eq!(job.date_posted.0, NaiveDate::from_ymd_opt(2025, 3, 4));
+
+        // Adzuna's own estimates aren't what the ad pays
+        let job = results.next().unwrap().into_search_result("GBP").job_post;
+        assert_eq!(job.min_pay_cents, None);
+        assert_eq!(job.currency, None);
+        assert_eq!(job.description, None);
+    }
+
+    #[test]
+    fn remotive_categories_come_from_job_titles() {
+        assert_eq!(
+            remotive_category("Senior Rust Engineer"),
+            Some("software-dev")
+        );
+        assert_eq!(remotive_category("Data Engineer"), Some("data"));
+        assert_eq!(remotive_category("UI Designer"), Some("design"));
+        assert_eq!(remotive_category("HTML Developer"), Some("software-dev"));
+        assert_eq!(
+            remotive_category("Site Reliability Engineer"),
+            Some("devops")
+        );
+        assert_eq!(remotive_category("Chief of Staff"), None);
+
+        let params = JobSearchParams {
+            job_title: " rust engineer ".to_string(),
+            ..Default::default()
+        };
+        let query = remotive_query(&params);
+        assert!(query.contains(&("category", "software-dev".to_string())));
+        assert!(query.contains(&("search", "rust engineer".to_string())));
+    }
+
+    #[test]
+    fn remotive_salaries_are_parsed_from_text() {
+        assert_eq!(
+            parse_remotive_salary("$80k - $100k"),
+            (
+                Some(8_000_000),
+                Some(10_000_000),
+                Some("year".to_string()),
+                Some("USD".to_string())
+            )
+        );
+        assert_eq!(
+            parse_remotive_salary("€60,000-€70,000"),
+            (
+                Some(6_000_000),
+                Some(7_000_000),
+                Some("year".to_string()),
+                Some("EUR".to_string())
+            )
+        );
+        assert_eq!(
+            parse_remotive_salary("GBP 45/hour"),
+            (
+                Some(4500),
+                None,
+                Some("hour".to_string()),
+                Some("GBP".to_string())
+            )
+        );
+        assert_eq!(
+            parse_remotive_salary("Up to 150k + 401(k)"),
+            (
+                Some(15_000_000),
+                None,
+                Some("year".to_string()),
+                Some("USD".to_string())
+            )
+        );
+        assert_eq!(
+            parse_remotive_salary("$40 - $60"),
+            (
+                Some(4000),
+                Some(6000),
+                Some("hour".to_string()),
+                Some("USD".to_string())
+            )
+        );
+        // Small, but explicitly yearly or monthly
+        assert_eq!(
+            parse_remotive_salary("USD 900 per year stipend"),
+            (
+                Some(90_000),
+                None,
+                Some("year".to_string()),
+                Some("USD".to_string())
+            )
+        );
+        assert_eq!(
+            parse_remotive_salary("€800/month"),
+            (
+                Some(80_000),
+                None,
+                Some("month".to_string()),
+                Some("EUR".to_string())
+            )
+        );
+        assert_eq!(parse_remotive_salary(""), (None, None, None, None));
+        assert_eq!(
+            parse_remotive_salary("Competitive"),
+            (None, None, None, None)
+        );
+    }
+
+    #[test]
+    fn remotive_jobs_map_onto_remote_job_posts() {
+        let response: RemotiveSearchResponse = serde_json::from_str(
+            r#"{"jobs": [{
+                "url": "https://remotive.com/remote-jobs/software-dev/rust-engineer-1",
+                "title": "Rust Engineer",
+                "company_name": " Acme ",
+                "tags": ["rust", "tokio"],
+                "publication_date": "2025-03-04T10:23:26",
+                "candidate_required_location": "Europe",
+                "salary": "$90k - $110k",
+                "description": "<p>We&#39;re hiring.</p><ul><li>3+ years of experience</li><li>Rust &amp; SQL</li></ul>"
+            }]}"#,
+        )
+        .unwrap();
+        let result = response
+            .jobs
+            .into_iter()
+            .next()
+            .unwrap()
+            .into_search_result();
+        assert_eq!(result.company_name, "Acme");
+        let job = result.job_post;
+        assert_eq!(job.location, "Europe");
+        assert_eq!(job.location_type, JobPostLocationType::Remote);
+        assert_eq!(job.skills.as_deref(), Some("rust, tokio"));
+        assert_eq!(job.min_yoe, Some(3));
+        assert_eq!(job.max_pay_cents, Some(11_000_000));
+        assert_eq!(job.date_posted.0, NaiveDate::from_ymd_opt(2025, 3, 4));
+        assert_eq!(
+            job.description.as_deref(),
+            Some("We're hiring.\n3+ years of experience\nRust & SQL")
+        );
+    }
 }
@@ -0,0 +1,315 @@
+/* RemoteOK */
+// https://remoteok.com/api //
+
+use super::{
+    import_results, strip_tags, wait_for_rate_limit, JobSearchFacet, JobSearchParams,
+    JobSearchProvider, JobSearchResult, ProviderSearch, ProviderSettings,
+};
+use crate::db::api_request::ApiRequest;
+use crate::db::fetch_log::FetchLog;
+use crate::db::job_post::{JobPost, JobPostLocationType};
+use crate::db::post_rule::InsertCounts;
+use crate::db::{NullableSqliteDateTime, SqliteBoolean, SqliteDateTime};
+use crate::utils::{find_yoe_naive, split_comma_separated};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+pub const REMOTEOK_PROVIDER: &str = "remoteok";
+
+const FEED_URL: &str = "https://remoteok.com/api";
+
+/// RemoteOK turns away requests that don't say who's asking
+const DEFAULT_USER_AGENT: &str = concat!("job-hunter/", env!("CARGO_PKG_VERSION"));
+
+static REMOTEOK_LAST_REQUEST: tokio::sync::Mutex<Option<tokio::time::Instant>> =
+    tokio::sync::Mutex::const_new(None);
+
+#[derive(Debug, Deserialize)]
+struct RemoteOkJob {
+    position: String,
+    company: String,
+    url: String,
+    /// e.g. "2025-03-04T10:00:17+00:00"
+    date: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Where applicants can be, often empty for anywhere
+    #[serde(default)]
+    location: String,
+    /// Yearly USD, 0 when not given
+    #[serde(default)]
+    salary_min: i64,
+    #[serde(default)]
+    salary_max: i64,
+    /// HTML
+    #[serde(default)]
+    description: String,
+}
+
+/// The feed is an array of jobs after a legal notice, which doesn't parse as one and is
+/// skipped along with anything else that isn't a job
+fn parse_feed(json: &str) -> anyhow::Result<Vec<RemoteOkJob>> {
+    let entries: Vec<serde_json::Value> = serde_json::from_str(json)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| serde_json::from_value(entry).ok())
+        .collect())
+}
+
+impl RemoteOkJob {
+    /// Like the job title filter: the title or any tag contains it
+    fn matches(&self, title: &str) -> bool {
+        let title = title.trim().to_lowercase();
+        title.is_empty()
+            || self.position.to_lowercase().contains(&title)
+            || self
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&title))
+    }
+
+    fn into_search_result(self) -> JobSearchResult {
+        let description = strip_tags(&self.description);
+        let (min_yoe, max_yoe) = find_yoe_naive(&description);
+        let pay_cents = |pay: i64| (pay > 0).then_some(pay * 100);
+        let (min_pay_cents, max_pay_cents) =
+            (pay_cents(self.salary_min), pay_cents(self.salary_max));
+        let has_pay = min_pay_cents.is_some() || max_pay_cents.is_some();
+        let date_posted = DateTime::parse_from_rfc3339(&self.date)
+            .ok()
+            .map(|date| date.date_naive());
+        JobSearchResult {
+            company_name: self.company.trim().to_string(),
+            company_url: None,
+            job_post: JobPost {
+                id: 0,
+                company_id: 0,
+                location: self.location.trim().to_string(),
+                location_type: JobPostLocationType::Remote,
+                url: self.url,
+                min_yoe,
+                max_yoe,
+                min_pay_cents,
+                max_pay_cents,
+                date_posted: NullableSqliteDateTime(date_posted),
+                date_retrieved: SqliteDateTime(Utc::now()),
+                job_title: strip_tags(&self.position),
+                benefits: None,
+                skills: (!self.tags.is_empty()).then(|| self.tags.join(", ")),
+                pay_unit: has_pay.then(|| "year".to_string()),
+                currency: has_pay.then(|| "USD".to_string()),
+                apijobs_id: None,
+                industry: None,
+                notes: None,
+                platform_url: Some("https://remoteok.com".to_string()),
+                archived: SqliteBoolean(false),
+                favorite: SqliteBoolean(false),
+                expected_pay_cents: None,
+                tags: None,
+                description: (!description.is_empty()).then_some(description),
+            },
+            exists: false,
+        }
+    }
+}
+
+/// The whole feed, sent with the configured user agent or ours
+async fn fetch_feed(
+    client: &reqwest::Client,
+    user_agent: &str,
+    min_request_interval_secs: u64,
+    executor: &sqlx::SqlitePool,
+) -> anyhow::Result<Vec<RemoteOkJob>> {
+    let user_agent = match user_agent.trim() {
+        "" => DEFAULT_USER_AGENT,
+        user_agent => user_agent,
+    };
+    wait_for_rate_limit(
+        &REMOTEOK_LAST_REQUEST,
+        std::time::Duration::from_secs(min_request_interval_secs),
+    )
+    .await;
+    let resp = client
+        .get(FEED_URL)
+        .header(reqwest::header::USER_AGENT, user_agent)
+        .send()
+        .await?;
+    ApiRequest::record(
+        REMOTEOK_PROVIDER,
+        Some(resp.status().as_u16() as i64),
+        executor,
+    )
+    .await?;
+    if !resp.status().is_success() {
+        anyhow::bail!("RemoteOK request failed: {}", resp.status());
+    }
+
+    let jobs = parse_feed(&resp.text().await?)?;
+    tracing::debug!(jobs = jobs.len(), "RemoteOK feed parsed");
+    // Without a company there's nothing to file the post under
+    Ok(jobs
+        .into_iter()
+        .filter(|job| !job.company.trim().is_empty())
+        .collect())
+}
+
+/// Fetches the feed and imports new posts whose title or tags contain `title` (every post
+/// when it's empty). Returns the number of posts inserted, and archived by post rules.
+#[tracing::instrument(skip_all, err)]
+pub async fn sync_feed(
+    client: reqwest::Client,
+    user_agent: String,
+    title: String,
+    executor: sqlx::SqlitePool,
+) -> anyhow::Result<InsertCounts> {
+    let results: Vec<JobSearchResult> = fetch_feed(&client, &user_agent, 0, &executor)
+        .await?
+        .into_iter()
+        .filter(|job| job.matches(&title))
+        .map(RemoteOkJob::into_search_result)
+        .collect();
+    let query = match title.trim() {
+        "" => "everything".to_string(),
+        title => format!("title: {title}"),
+    };
+    let fetch_log_id =
+        FetchLog::record(REMOTEOK_PROVIDER, &query, results.len() as i64, &executor).await?;
+
+    import_results(results, Some(fetch_log_id), executor).await
+}
+
+async fn remoteok_job_search(
+    client: &reqwest::Client,
+    settings: &ProviderSettings,
+    params: &JobSearchParams,
+    executor: &sqlx::SqlitePool,
+) -> anyhow::Result<(Vec<JobSearchResult>, Vec<JobSearchFacet>)> {
+    // Only remote posts to offer
+    if (params.onsite || params.hybrid) && !params.remote {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let jobs = fetch_feed(
+        client,
+        &settings.user_agent,
+        settings.request_interval_secs,
+        executor,
+    )
+    .await?;
+
+    // The feed can't be searched, so the title and tracked companies are matched here
+    let companies: Vec<String> = split_comma_separated(&params.companies)
+        .iter()
+        .map(|company| company.to_lowercase())
+        .collect();
+    let mut results = Vec::new();
+    for job in jobs.into_iter().filter(|job| {
+        job.matches(&params.job_title)
+            && (companies.is_empty() || companies.contains(&job.company.trim().to_lowercase()))
+    }) {
+        let mut result = job.into_search_result();
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+            .bind(&result.job_post.url)
+            .fetch_optional(executor)
+            .await?;
+        result.exists = exists.is_some();
+        results.push(result);
+    }
+
+    Ok((results, Vec::new()))
+}
+
+pub struct RemoteOkProvider;
+
+impl JobSearchProvider for RemoteOkProvider {
+    fn id(&self) -> &'static str {
+        REMOTEOK_PROVIDER
+    }
+
+    fn name(&self) -> &'static str {
+        "RemoteOK"
+    }
+
+    fn credentials<'a>(&self, _settings: &'a ProviderSettings) -> Vec<(&'static str, &'a str)> {
+        Vec::new()
+    }
+
+    fn request_preview(&self, params: &JobSearchParams) -> String {
+        match params.job_title.trim() {
+            "" => format!("GET {FEED_URL}"),
+            title => {
+                format!("GET {FEED_URL}\n\nKeeping posts whose title or tags contain: {title}")
+            }
+        }
+    }
+
+    fn search<'a>(
+        &'a self,
+        client: &'a reqwest::Client,
+        settings: &'a ProviderSettings,
+        params: &'a JobSearchParams,
+        executor: &'a sqlx::SqlitePool,
+    ) -> ProviderSearch<'a> {
+        Box::pin(remoteok_job_search(client, settings, params, executor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    const FEED: &str = r#"[
+        {"last_updated": 1741082417, "legal": "API Terms of Service: ..."},
+        {
+            "id": "1092345",
+            "date": "2025-03-04T10:00:17+00:00",
+            "company": "Acme ",
+            "position": "Senior Backend Engineer",
+            "tags": ["rust", "postgres"],
+            "description": "<p>5+ years of experience</p>",
+            "location": "Europe",
+            "salary_min": 90000,
+            "salary_max": 0,
+            "url": "https://remoteok.com/remote-jobs/1092345"
+        },
+        {
+            "id": "1092346",
+            "date": "2025-03-03T09:00:00+00:00",
+            "company": "Globex",
+            "position": "Marketing Lead",
+            "url": "https://remoteok.com/remote-jobs/1092346"
+        }
+    ]"#;
+
+    #[test]
+    fn feed_skips_the_legal_notice_and_filters_by_title_or_tag() {
+        let jobs = parse_feed(FEED).unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs[0].matches("backend"));
+        assert!(jobs[0].matches(" Rust "));
+        assert!(!jobs[0].matches("marketing"));
+        assert!(jobs[1].matches(""));
+    }
+
+    #[test]
+    fn feed_jobs_map_onto_remote_job_posts() {
+        let mut jobs = parse_feed(FEED).unwrap().into_iter();
+
+        let result = jobs.next().unwrap().into_search_result();
+        assert_eq!(result.company_name, "Acme");
+        let job = result.job_post;
+        assert_eq!(job.location_type, JobPostLocationType::Remote);
+        assert_eq!(job.skills.as_deref(), Some("rust, postgres"));
+        assert_eq!(job.min_yoe, Some(5));
+        assert_eq!(job.min_pay_cents, Some(9_000_000));
+        assert_eq!(job.max_pay_cents, None);
+        assert_eq!(job.currency.as_deref(), Some("USD"));
+        assert_eq!(job.date_posted.0, NaiveDate::from_ymd_opt(2025, 3, 4));
+
+        let job = jobs.next().unwrap().into_search_result().job_post;
+        assert_eq!(job.pay_unit, None);
+        assert_eq!(job.skills, None);
+        assert_eq!(job.description, None);
+    }
+}
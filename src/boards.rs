@@ -0,0 +1,785 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+use crate::db::{
+    company::Company,
+    fetch_log::FetchLog,
+    job_post::{JobPost, JobPostLocationType},
+    post_rule::InsertCounts,
+    NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+use crate::utils::format_location;
+
+/* ATS public job boards */
+// These boards expose their postings as JSON, so no WebDriver is needed //
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardPlatform {
+    /// https://developers.greenhouse.io/job-board.html
+    Greenhouse(String),
+    /// https://github.com/lever/postings-api
+    Lever(String),
+    /// https://developers.ashbyhq.com/docs/public-job-posting-api
+    Ashby(String),
+    /// https://workable.readme.io/reference/jobs-1
+    Workable(String),
+    /// https://developers.smartrecruiters.com/docs/posting-api
+    SmartRecruiters(String),
+}
+
+impl BoardPlatform {
+    /// Detects the platform and board token from a company's careers URL
+    pub fn detect(careers_url: &str) -> Option<Self> {
+        let url = reqwest::Url::parse(careers_url.trim()).ok()?;
+        let host = url.host_str()?.to_lowercase();
+        // Workable also hosts boards on a per-account subdomain
+        if let Some(account) = host.strip_suffix(".workable.com") {
+            if !["apply", "www"].contains(&account) {
+                return Some(Self::Workable(account.to_string()));
+            }
+        }
+        let token = url
+            .path_segments()?
+            .find(|segment| !segment.is_empty())?
+            .to_string();
+
+        match host.as_str() {
+            "boards.greenhouse.io" | "job-boards.greenhouse.io" => Some(Self::Greenhouse(token)),
+            "jobs.lever.co" => Some(Self::Lever(token)),
+            "jobs.ashbyhq.com" => Some(Self::Ashby(token)),
+            "apply.workable.com" => Some(Self::Workable(token)),
+            "careers.smartrecruiters.com" | "jobs.smartrecruiters.com" => {
+                Some(Self::SmartRecruiters(token))
+            }
+            _ => None,
+        }
+    }
+
+    /// Key used for the `provider` column of the fetch log
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Greenhouse(_) => "greenhouse",
+            Self::Lever(_) => "lever",
+            Self::Ashby(_) => "ashby",
+            Self::Workable(_) => "workable",
+            Self::SmartRecruiters(_) => "smartrecruiters",
+        }
+    }
+
+    async fn fetch_jobs(&self, client: &reqwest::Client) -> anyhow::Result<Vec<BoardJob>> {
+        match self {
+            Self::Greenhouse(token) => {
+                let resp: GreenhouseResponse = client
+                    .get(format!(
+                        "https://boards-api.greenhouse.io/v1/boards/{token}/jobs"
+                    ))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(resp.jobs.into_iter().map(BoardJob::from).collect())
+            }
+            Self::Lever(token) => {
+                let resp: Vec<LeverPosting> = client
+                    .get(format!(
+                        "https://api.lever.co/v0/postings/{token}?mode=json"
+                    ))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(resp.into_iter().map(BoardJob::from).collect())
+            }
+            Self::Ashby(token) => {
+                let resp: AshbyResponse = client
+                    .get(format!(
+                        "https://api.ashbyhq.com/posting-api/job-board/{token}"
+                    ))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(resp
+                    .jobs
+                    .into_iter()
+                    .filter(|job| job.is_listed.unwrap_or(true))
+                    .map(BoardJob::from)
+                    .collect())
+            }
+            Self::Workable(token) => {
+                let resp: WorkableResponse = client
+                    .get(format!(
+                        "https://apply.workable.com/api/v1/widget/accounts/{token}"
+                    ))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(resp.jobs.into_iter().map(BoardJob::from).collect())
+            }
+            Self::SmartRecruiters(token) => fetch_smartrecruiters(client, token).await,
+        }
+    }
+}
+
+/// A posting normalized from any board platform
+#[derive(Debug, Clone)]
+struct BoardJob {
+    title: String,
+    url: String,
+    location: String,
+    location_type: JobPostLocationType,
+    date_posted: NullableSqliteDateTime,
+    notes: Option<String>,
+}
+
+impl BoardJob {
+    fn into_job_post(self, company_id: i64) -> JobPost {
+        JobPost {
+            id: 0,
+            company_id,
+            location: self.location,
+            location_type: self.location_type,
+            url: self.url,
+            min_yoe: None,
+            max_yoe: None,
+            min_pay_cents: None,
+            max_pay_cents: None,
+            date_posted: self.date_posted,
+            date_retrieved: SqliteDateTime(Utc::now()),
+            job_title: self.title,
+            benefits: None,
+            skills: None,
+            pay_unit: None,
+            currency: None,
+            apijobs_id: None,
+            industry: None,
+            notes: self.notes,
+            platform_url: None,
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            description: None,
+        }
+    }
+}
+
+fn location_type_from_text(text: &str) -> JobPostLocationType {
+    let text = text.to_lowercase();
+    if text.contains("hybrid") {
+        JobPostLocationType::Hybrid
+    } else if text.contains("remote") {
+        JobPostLocationType::Remote
+    } else if text.is_empty() {
+        JobPostLocationType::Unknown
+    } else {
+        JobPostLocationType::Onsite
+    }
+}
+
+/// JobPost has no department column, so it's kept in the notes
+fn department_notes(department: Option<String>) -> Option<String> {
+    department
+        .filter(|department| !department.trim().is_empty())
+        .map(|department| format!("Department: {}", department.trim()))
+}
+
+fn date_from_rfc3339(s: Option<&str>) -> NullableSqliteDateTime {
+    NullableSqliteDateTime(
+        s.and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.with_timezone(&Utc).date_naive()),
+    )
+}
+
+/* Greenhouse */
+
+#[derive(Debug, Deserialize)]
+struct GreenhouseResponse {
+    jobs: Vec<GreenhouseJob>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GreenhouseJob {
+    title: String,
+    absolute_url: String,
+    location: Option<GreenhouseLocation>,
+    first_published: Option<String>,
+    updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GreenhouseLocation {
+    name: String,
+}
+
+impl From<GreenhouseJob> for BoardJob {
+    fn from(job: GreenhouseJob) -> Self {
+        let location = job.location.map(|loc| loc.name).unwrap_or_default();
+        Self {
+            title: job.title,
+            url: job.absolute_url,
+            location_type: location_type_from_text(&location),
+            location,
+            date_posted: date_from_rfc3339(
+                job.first_published.as_deref().or(job.updated_at.as_deref()),
+            ),
+            notes: None,
+        }
+    }
+}
+
+/* Lever */
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeverPosting {
+    text: String,
+    hosted_url: String,
+    categories: Option<LeverCategories>,
+    workplace_type: Option<String>,
+    /// Milliseconds since the epoch
+    created_at: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeverCategories {
+    location: Option<String>,
+}
+
+impl From<LeverPosting> for BoardJob {
+    fn from(posting: LeverPosting) -> Self {
+        let location = posting
+            .categories
+            .and_then(|categories| categories.location)
+            .unwrap_or_default();
+        let location_type = match posting.workplace_type.as_deref() {
+            Some("onsite") => JobPostLocationType::Onsite,
+            Some("hybrid") => JobPostLocationType::Hybrid,
+            Some("remote") => JobPostLocationType::Remote,
+            _ => location_type_from_text(&location),
+        };
+        Self {
+            title: posting.text,
+            url: posting.hosted_url,
+            location,
+            location_type,
+            date_posted: NullableSqliteDateTime::from(posting.created_at.map(|ms| ms / 1000)),
+            notes: None,
+        }
+    }
+}
+
+/* Ashby */
+
+#[derive(Debug, Deserialize)]
+struct AshbyResponse {
+    jobs: Vec<AshbyJob>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AshbyJob {
+    title: String,
+    job_url: String,
+    location: Option<String>,
+    department: Option<String>,
+    is_remote: Option<bool>,
+    workplace_type: Option<String>,
+    published_at: Option<String>,
+    is_listed: Option<bool>,
+}
+
+impl From<AshbyJob> for BoardJob {
+    fn from(job: AshbyJob) -> Self {
+        let location = job.location.unwrap_or_default();
+        let location_type = match job.workplace_type.as_deref() {
+            Some("OnSite") => JobPostLocationType::Onsite,
+            Some("Hybrid") => JobPostLocationType::Hybrid,
+            Some("Remote") => JobPostLocationType::Remote,
+            _ if job.is_remote == Some(true) => JobPostLocationType::Remote,
+            _ => location_type_from_text(&location),
+        };
+        Self {
+            title: job.title,
+            url: job.job_url,
+            location,
+            location_type,
+            date_posted: date_from_rfc3339(job.published_at.as_deref()),
+            notes: department_notes(job.department),
+        }
+    }
+}
+
+/* Workable */
+
+#[derive(Debug, Deserialize)]
+struct WorkableResponse {
+    jobs: Vec<WorkableJob>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkableJob {
+    title: String,
+    url: String,
+    city: Option<String>,
+    state: Option<String>,
+    country: Option<String>,
+    department: Option<String>,
+    #[serde(default)]
+    telecommuting: bool,
+    /// `YYYY-MM-DD`
+    published_on: Option<String>,
+}
+
+impl From<WorkableJob> for BoardJob {
+    fn from(job: WorkableJob) -> Self {
+        let location = format_location(
+            job.city.as_deref().unwrap_or_default(),
+            job.state.as_deref().unwrap_or_default(),
+            job.country.as_deref().unwrap_or_default(),
+        );
+        let location_type = if job.telecommuting {
+            JobPostLocationType::Remote
+        } else {
+            location_type_from_text(&location)
+        };
+        Self {
+            title: job.title,
+            url: job.url,
+            location,
+            location_type,
+            date_posted: NullableSqliteDateTime(
+                job.published_on
+                    .and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()),
+            ),
+            notes: department_notes(job.department),
+        }
+    }
+}
+
+/* SmartRecruiters */
+
+const SMARTRECRUITERS_PAGE_SIZE: i64 = 100;
+// Guards against paging forever if totalFound is wrong
+const SMARTRECRUITERS_MAX_PAGES: i64 = 20;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SmartRecruitersResponse {
+    total_found: i64,
+    content: Vec<SmartRecruitersPosting>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SmartRecruitersPosting {
+    id: String,
+    name: String,
+    released_date: Option<String>,
+    location: Option<SmartRecruitersLocation>,
+    department: Option<SmartRecruitersDepartment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SmartRecruitersLocation {
+    city: Option<String>,
+    region: Option<String>,
+    country: Option<String>,
+    #[serde(default)]
+    remote: bool,
+    #[serde(default)]
+    hybrid: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SmartRecruitersDepartment {
+    label: Option<String>,
+}
+
+impl SmartRecruitersPosting {
+    /// Postings don't carry their URL, so it's built from the company's board token
+    fn into_board_job(self, company: &str) -> BoardJob {
+        let location = self.location;
+        let location_text = location
+            .as_ref()
+            .map(|loc| {
+                format_location(
+                    loc.city.as_deref().unwrap_or_default(),
+                    loc.region.as_deref().unwrap_or_default(),
+                    loc.country.as_deref().unwrap_or_default(),
+                )
+            })
+            .unwrap_or_default();
+        let location_type = match &location {
+            Some(loc) if loc.hybrid => JobPostLocationType::Hybrid,
+            Some(loc) if loc.remote => JobPostLocationType::Remote,
+            _ => location_type_from_text(&location_text),
+        };
+        BoardJob {
+            title: self.name,
+            url: format!("https://jobs.smartrecruiters.com/{company}/{}", self.id),
+            location: location_text,
+            location_type,
+            date_posted: date_from_rfc3339(self.released_date.as_deref()),
+            notes: department_notes(self.department.and_then(|dept| dept.label)),
+        }
+    }
+}
+
+async fn fetch_smartrecruiters(
+    client: &reqwest::Client,
+    company: &str,
+) -> anyhow::Result<Vec<BoardJob>> {
+    let mut jobs = Vec::new();
+    for page in 0..SMARTRECRUITERS_MAX_PAGES {
+        let offset = page * SMARTRECRUITERS_PAGE_SIZE;
+        let resp: SmartRecruitersResponse = client
+            .get(format!(
+                "https://api.smartrecruiters.com/v1/companies/{company}/postings"
+            ))
+            .query(&[("limit", SMARTRECRUITERS_PAGE_SIZE), ("offset", offset)])
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let page_len = resp.content.len() as i64;
+        jobs.extend(
+            resp.content
+                .into_iter()
+                .map(|posting| posting.into_board_job(company)),
+        );
+        if page_len < SMARTRECRUITERS_PAGE_SIZE || offset + page_len >= resp.total_found {
+            break;
+        }
+    }
+
+    Ok(jobs)
+}
+
+/* Sync */
+
+fn matches_keywords(title: &str, keywords: &[String]) -> bool {
+    let title = title.to_lowercase();
+    keywords.is_empty() || keywords.iter().any(|keyword| title.contains(keyword))
+}
+
+/// Fetches every shown company's board and imports new postings whose title matches
+/// any of the comma-separated keywords (all postings if none are given).
+/// Returns the number of posts inserted, and archived by post rules.
+#[tracing::instrument(skip_all, err)]
+pub async fn sync_boards(
+    client: reqwest::Client,
+    keywords: String,
+    executor: sqlx::SqlitePool,
+) -> anyhow::Result<InsertCounts> {
+    let keywords: Vec<String> = keywords
+        .split(',')
+        .map(|keyword| keyword.trim().to_lowercase())
+        .filter(|keyword| !keyword.is_empty())
+        .collect();
+
+    let mut inserted = InsertCounts::default();
+    for company in Company::fetch_shown(&executor).await? {
+        let Some(platform) = company
+            .careers_url
+            .as_deref()
+            .and_then(BoardPlatform::detect)
+        else {
+            continue;
+        };
+        let jobs = match platform.fetch_jobs(&client).await {
+            Ok(jobs) => jobs,
+            Err(e) => {
+                // One broken board shouldn't stop the rest from syncing
+                tracing::warn!(company = %company.name, "Failed to fetch board: {:?}", e);
+                continue;
+            }
+        };
+
+        let jobs: Vec<BoardJob> = jobs
+            .into_iter()
+            .filter(|job| matches_keywords(&job.title, &keywords))
+            .collect();
+        let fetch_log_id =
+            FetchLog::record(platform.id(), &company.name, jobs.len() as i64, &executor).await?;
+
+        let mut job_posts = Vec::new();
+        let mut urls = HashSet::new();
+        for job in jobs {
+            if !urls.insert(job.url.clone()) {
+                continue;
+            }
+            let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+                .bind(&job.url)
+                .fetch_optional(&executor)
+                .await?;
+            if exists.is_none() {
+                job_posts.push(job.into_job_post(company.id));
+            }
+        }
+        let company_inserted = JobPost::insert_many(&job_posts, &executor).await?;
+        FetchLog::add_inserted(fetch_log_id, company_inserted.inserted as i64, &executor).await?;
+        inserted += company_inserted;
+    }
+
+    Ok(inserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    #[test]
+    fn platforms_are_detected_from_careers_urls() {
+        let cases = [
+            (
+                "https://boards.greenhouse.io/acme",
+                Some(BoardPlatform::Greenhouse("acme".to_string())),
+            ),
+            (
+                "https://job-boards.greenhouse.io/acme/jobs/123",
+                Some(BoardPlatform::Greenhouse("acme".to_string())),
+            ),
+            (
+                " https://jobs.lever.co/globex/ ",
+                Some(BoardPlatform::Lever("globex".to_string())),
+            ),
+            (
+                "https://jobs.ashbyhq.com/Initech",
+                Some(BoardPlatform::Ashby("Initech".to_string())),
+            ),
+            (
+                "https://apply.workable.com/umbrella/",
+                Some(BoardPlatform::Workable("umbrella".to_string())),
+            ),
+            (
+                "https://careers.smartrecruiters.com/Hooli",
+                Some(BoardPlatform::SmartRecruiters("Hooli".to_string())),
+            ),
+            (
+                "https://jobs.smartrecruiters.com/Hooli/744000012345",
+                Some(BoardPlatform::SmartRecruiters("Hooli".to_string())),
+            ),
+            ("https://acme.com/careers", None),
+            ("https://boards.greenhouse.io/", None),
+            ("not a url", None),
+        ];
+        for (url, platform) in cases {
+            assert_eq!(BoardPlatform::detect(url), platform, "{url}");
+        }
+    }
+
+    #[test]
+    fn workable_subdomains_are_their_account() {
+        assert_eq!(
+            BoardPlatform::detect("https://Vandelay.workable.com/"),
+            Some(BoardPlatform::Workable("vandelay".to_string()))
+        );
+        // Workable's own hosts need the account in the path
+        assert_eq!(BoardPlatform::detect("https://www.workable.com/"), None);
+        assert_eq!(BoardPlatform::detect("https://apply.workable.com/"), None);
+    }
+
+    #[test]
+    fn location_type_is_read_from_location_text() {
+        assert_eq!(
+            location_type_from_text("Remote - US"),
+            JobPostLocationType::Remote
+        );
+        assert_eq!(
+            location_type_from_text("New York (Hybrid, remote Fridays)"),
+            JobPostLocationType::Hybrid
+        );
+        assert_eq!(
+            location_type_from_text("Berlin, Germany"),
+            JobPostLocationType::Onsite
+        );
+        assert_eq!(location_type_from_text(""), JobPostLocationType::Unknown);
+    }
+
+    #[test]
+    fn greenhouse_jobs_map_onto_board_jobs() {
+        let resp: GreenhouseResponse = serde_json::from_str(
+            r#"{"jobs": [
+                {
+                    "id": 4012345,
+                    "title": "Backend Engineer",
+                    "absolute_url": "https://boards.greenhouse.io/acme/jobs/4012345",
+                    "location": {"name": "Remote, US"},
+                    "first_published": "2025-03-01T09:30:00-05:00",
+                    "updated_at": "2025-03-05T12:00:00-05:00"
+                },
+                {
+                    "title": "Office Manager",
+                    "absolute_url": "https://boards.greenhouse.io/acme/jobs/4012346",
+                    "location": null,
+                    "updated_at": "2025-02-20T08:00:00Z"
+                }
+            ]}"#,
+        )
+        .unwrap();
+        let jobs: Vec<BoardJob> = resp.jobs.into_iter().map(BoardJob::from).collect();
+
+        assert_eq!(jobs[0].title, "Backend Engineer");
+        assert_eq!(jobs[0].location, "Remote, US");
+        assert_eq!(jobs[0].location_type, JobPostLocationType::Remote);
+        assert_eq!(jobs[0].date_posted.0, date(2025, 3, 1));
+        // Falls back to when it was last updated
+        assert_eq!(jobs[1].location_type, JobPostLocationType::Unknown);
+        assert_eq!(jobs[1].date_posted.0, date(2025, 2, 20));
+    }
+
+    #[test]
+    fn lever_postings_map_onto_board_jobs() {
+        let postings: Vec<LeverPosting> = serde_json::from_str(
+            r#"[
+                {
+                    "id": "5a1b",
+                    "text": "Site Reliability Engineer",
+                    "hostedUrl": "https://jobs.lever.co/globex/5a1b",
+                    "categories": {"location": "Toronto", "team": "Infrastructure"},
+                    "workplaceType": "hybrid",
+                    "createdAt": 1740787200000
+                },
+                {
+                    "text": "Designer",
+                    "hostedUrl": "https://jobs.lever.co/globex/5a1c",
+                    "categories": {"location": "Remote"},
+                    "workplaceType": "unspecified"
+                }
+            ]"#,
+        )
+        .unwrap();
+        let jobs: Vec<BoardJob> = postings.into_iter().map(BoardJob::from).collect();
+
+        assert_eq!(jobs[0].location, "Toronto");
+        assert_eq!(jobs[0].location_type, JobPostLocationType::Hybrid);
+        assert_eq!(jobs[0].date_posted.0, date(2025, 3, 1));
+        assert_eq!(jobs[1].location_type, JobPostLocationType::Remote);
+        assert_eq!(jobs[1].date_posted.0, None);
+    }
+
+    #[test]
+    fn ashby_jobs_map_onto_board_jobs() {
+        let resp: AshbyResponse = serde_json::from_str(
+            r#"{"jobs": [
+                {
+                    "title": "Data Engineer",
+                    "jobUrl": "https://jobs.ashbyhq.com/initech/d1",
+                    "location": "Austin, TX",
+                    "department": " Data ",
+                    "isRemote": false,
+                    "workplaceType": "OnSite",
+                    "publishedAt": "2025-03-02T15:00:00.000+00:00",
+                    "isListed": true
+                },
+                {
+                    "title": "Support Engineer",
+                    "jobUrl": "https://jobs.ashbyhq.com/initech/d2",
+                    "location": "Anywhere",
+                    "isRemote": true
+                }
+            ]}"#,
+        )
+        .unwrap();
+        let jobs: Vec<BoardJob> = resp.jobs.into_iter().map(BoardJob::from).collect();
+
+        assert_eq!(jobs[0].location_type, JobPostLocationType::Onsite);
+        assert_eq!(jobs[0].notes.as_deref(), Some("Department: Data"));
+        assert_eq!(jobs[0].date_posted.0, date(2025, 3, 2));
+        assert_eq!(jobs[1].location_type, JobPostLocationType::Remote);
+        assert_eq!(jobs[1].notes, None);
+    }
+
+    #[test]
+    fn workable_jobs_map_onto_board_jobs() {
+        let resp: WorkableResponse = serde_json::from_str(
+            r#"{"name": "Umbrella", "jobs": [
+                {
+                    "title": "QA Engineer",
+                    "shortcode": "A1B2C3",
+                    "url": "https://apply.workable.com/j/A1B2C3",
+                    "city": "Lisbon",
+                    "state": "",
+                    "country": "Portugal",
+                    "department": "Engineering",
+                    "telecommuting": false,
+                    "published_on": "2025-03-03"
+                },
+                {
+                    "title": "Recruiter",
+                    "url": "https://apply.workable.com/j/D4E5F6",
+                    "country": "Portugal",
+                    "telecommuting": true
+                }
+            ]}"#,
+        )
+        .unwrap();
+        let jobs: Vec<BoardJob> = resp.jobs.into_iter().map(BoardJob::from).collect();
+
+        assert_eq!(jobs[0].location, "Lisbon, Portugal");
+        assert_eq!(jobs[0].location_type, JobPostLocationType::Onsite);
+        assert_eq!(jobs[0].notes.as_deref(), Some("Department: Engineering"));
+        assert_eq!(jobs[0].date_posted.0, date(2025, 3, 3));
+        assert_eq!(jobs[1].location_type, JobPostLocationType::Remote);
+        assert_eq!(jobs[1].date_posted.0, None);
+    }
+
+    #[test]
+    fn smartrecruiters_postings_map_onto_board_jobs() {
+        let resp: SmartRecruitersResponse = serde_json::from_str(
+            r#"{"offset": 0, "limit": 100, "totalFound": 2, "content": [
+                {
+                    "id": "744000012345",
+                    "name": "Platform Engineer",
+                    "releasedDate": "2025-03-04T10:15:00.000Z",
+                    "location": {"city": "Dublin", "region": "", "country": "ie", "remote": false, "hybrid": true},
+                    "department": {"id": "1", "label": "Platform"}
+                },
+                {
+                    "id": "744000012346",
+                    "name": "Account Executive",
+                    "location": {"city": "Chicago", "country": "us", "remote": true}
+                }
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(resp.total_found, 2);
+        let jobs: Vec<BoardJob> = resp
+            .content
+            .into_iter()
+            .map(|posting| posting.into_board_job("Hooli"))
+            .collect();
+
+        assert_eq!(
+            jobs[0].url,
+            "https://jobs.smartrecruiters.com/Hooli/744000012345"
+        );
+        assert_eq!(jobs[0].location, "Dublin, ie");
+        assert_eq!(jobs[0].location_type, JobPostLocationType::Hybrid);
+        assert_eq!(jobs[0].notes.as_deref(), Some("Department: Platform"));
+        assert_eq!(jobs[0].date_posted.0, date(2025, 3, 4));
+        assert_eq!(jobs[1].location_type, JobPostLocationType::Remote);
+        assert_eq!(jobs[1].notes, None);
+    }
+
+    #[test]
+    fn titles_match_any_keyword() {
+        let keywords = ["rust".to_string(), "platform".to_string()];
+        assert!(matches_keywords("Senior Rust Engineer", &keywords));
+        assert!(!matches_keywords("Designer", &keywords));
+        assert!(matches_keywords("Designer", &[]));
+    }
+}
@@ -0,0 +1,92 @@
+use iced::widget::{button, column, row, text, text_input, Column, Row};
+use iced::{Alignment, Element, Padding};
+
+/// Tag-style input: chips with remove buttons, a text field that adds a chip on Enter,
+/// and suggestions that start or contain what's been typed
+pub struct ChipInput<'a, Message> {
+    chips: Vec<String>,
+    input: &'a str,
+    suggestions: &'a [String],
+    on_input: fn(String) -> Message,
+    on_add: fn(String) -> Message,
+    on_remove: fn(usize) -> Message,
+}
+
+impl<'a, Message: Clone + 'a> ChipInput<'a, Message> {
+    pub fn new(
+        chips: Vec<String>,
+        input: &'a str,
+        on_input: fn(String) -> Message,
+        on_add: fn(String) -> Message,
+        on_remove: fn(usize) -> Message,
+    ) -> Self {
+        Self {
+            chips,
+            input,
+            suggestions: &[],
+            on_input,
+            on_add,
+            on_remove,
+        }
+    }
+
+    pub fn suggestions(mut self, suggestions: &'a [String]) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    pub fn view(self) -> Element<'a, Message> {
+        let on_remove = self.on_remove;
+        let chips = Row::with_children(self.chips.iter().enumerate().map(|(index, chip)| {
+            button(
+                row![text(chip.clone()).size(12), text("×").size(12)]
+                    .spacing(5)
+                    .align_y(Alignment::Center),
+            )
+            .padding(Padding::from([2, 5]))
+            .style(button::secondary)
+            .on_press(on_remove(index))
+            .into()
+        }))
+        .spacing(5)
+        .wrap();
+
+        let typed = self.input.trim().to_lowercase();
+        let suggestions: Element<'a, Message> = if typed.is_empty() {
+            column![].into()
+        } else {
+            let on_add = self.on_add;
+            let existing = &self.chips;
+            Column::with_children(
+                self.suggestions
+                    .iter()
+                    .filter(|suggestion| {
+                        let lowered = suggestion.to_lowercase();
+                        lowered.contains(&typed)
+                            && !existing.iter().any(|chip| chip.to_lowercase() == lowered)
+                    })
+                    .take(5)
+                    .map(|suggestion| {
+                        button(text(suggestion.clone()).size(12))
+                            .padding(Padding::from([2, 5]))
+                            .style(button::text)
+                            .on_press(on_add(suggestion.clone()))
+                            .into()
+                    }),
+            )
+            .spacing(2)
+            .into()
+        };
+
+        column![
+            chips,
+            text_input("Type and press Enter", self.input)
+                .on_input(self.on_input)
+                .on_submit((self.on_add)(self.input.to_string()))
+                .padding(5),
+            suggestions,
+        ]
+        .spacing(5)
+        .into()
+    }
+}
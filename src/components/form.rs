@@ -0,0 +1,18 @@
+use iced::widget::{button, row, text};
+use iced::Element;
+
+/// Field label, with a red asterisk when the field is required
+pub fn label<'a, Message: 'a>(label: &'a str, required: bool) -> Element<'a, Message> {
+    if !required {
+        return text(label).size(12).into();
+    }
+    row![text(label).size(12), text("*").size(12).style(text::danger)].into()
+}
+
+/// Save button that's disabled until the form is valid
+pub fn save_button<'a, Message: Clone + 'a>(
+    submit_message: Message,
+    valid: bool,
+) -> button::Button<'a, Message> {
+    button(text("Save")).on_press_maybe(valid.then_some(submit_message))
+}
@@ -1,7 +1,7 @@
 use iced::{
     color,
     widget::{button, row, text},
-    Alignment, Element,
+    Alignment, Color, Element,
 };
 use iced_font_awesome::{fa_icon, fa_icon_solid};
 
@@ -14,6 +14,7 @@ pub struct IconButton<'a> {
     pub icon_name: &'a str,
     pub label: Option<&'a str>,
     pub solid: bool,
+    pub color: Color,
 }
 
 impl<'a> IconButton<'a> {
@@ -22,16 +23,18 @@ impl<'a> IconButton<'a> {
             icon_name,
             label: None,
             solid: false,
+            color: color!(255, 255, 255),
         }
     }
 
-    pub fn label(mut self, label: &'a str) -> Self {
-        self.label = Some(label);
+    pub fn solid(mut self) -> Self {
+        self.solid = true;
         self
     }
 
-    pub fn solid(mut self) -> Self {
-        self.solid = true;
+    /// The icon's color, white unless set
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
         self
     }
 
@@ -47,7 +50,7 @@ impl<'a> IconButton<'a> {
             fa_icon(self.icon_name)
         };
 
-        content = content.push(icon.color(color!(255, 255, 255)).size(15.0));
+        content = content.push(icon.color(self.color).size(15.0));
 
         button(content.spacing(5).align_y(Alignment::Center))
             .on_press(IconButtonMessage::Pressed)
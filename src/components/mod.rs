@@ -1,3 +1,8 @@
+pub mod chip_input;
+pub mod form;
 pub mod icon_button;
+pub mod money_input;
 
+pub use chip_input::ChipInput;
 pub use icon_button::{IconButton, IconButtonMessage};
+pub use money_input::MoneyInput;
@@ -0,0 +1,284 @@
+use iced::widget::{row, text, text_input};
+use iced::{Alignment, Element, Length, Theme};
+
+use crate::utils::{get_pay_i64, get_pay_str};
+use crate::validation::input_style;
+
+pub const CURRENCIES: [&str; 8] = ["USD", "EUR", "GBP", "CAD", "AUD", "JPY", "INR", "CHF"];
+
+pub fn currency_symbol(currency: &str) -> &str {
+    match currency {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "CAD" => "CA$",
+        "AUD" => "A$",
+        "JPY" => "¥",
+        "INR" => "₹",
+        _ => currency,
+    }
+}
+
+/// How amounts in a currency are usually written where it's used
+struct CurrencyFormat {
+    thousands: &'static str,
+    decimal: &'static str,
+    symbol_after: bool,
+    /// Whether the currency has cents at all
+    minor_units: bool,
+    /// Groups by hundreds past the first thousand, e.g. 12,34,567
+    lakh_grouping: bool,
+}
+
+fn currency_format(currency: &str) -> CurrencyFormat {
+    let format = CurrencyFormat {
+        thousands: ",",
+        decimal: ".",
+        symbol_after: false,
+        minor_units: true,
+        lakh_grouping: false,
+    };
+    match currency {
+        "EUR" => CurrencyFormat {
+            thousands: ".",
+            decimal: ",",
+            symbol_after: true,
+            ..format
+        },
+        "CHF" => CurrencyFormat {
+            thousands: "'",
+            ..format
+        },
+        "JPY" => CurrencyFormat {
+            minor_units: false,
+            ..format
+        },
+        "INR" => CurrencyFormat {
+            lakh_grouping: true,
+            ..format
+        },
+        _ => format,
+    }
+}
+
+fn group_digits(whole: &str, separator: &str, lakh_grouping: bool) -> String {
+    let mut groups = Vec::new();
+    let mut rest = whole;
+    let mut size = 3;
+    while rest.len() > size {
+        let (head, tail) = rest.split_at(rest.len() - size);
+        groups.push(tail);
+        rest = head;
+        if lakh_grouping {
+            size = 2;
+        }
+    }
+    groups.push(rest);
+    groups.reverse();
+    groups.join(separator)
+}
+
+/// Groups the whole part of a plain amount the way `format_pay` writes the currency, e.g.
+/// "85000.5" -> "85,000.5" in dollars or "85.000,5" in euros
+pub fn format_money(amount: &str, currency: &str) -> String {
+    let format = currency_format(currency);
+    let group = |whole: &str| group_digits(whole, format.thousands, format.lakh_grouping);
+    match amount.split_once('.') {
+        Some((whole, fraction)) => format!("{}{}{}", group(whole), format.decimal, fraction),
+        None => group(amount),
+    }
+}
+
+/// Cents as a display amount written the way the currency usually is, e.g. "$85,000",
+/// "85.000,50 €", or "₹12,34,567". Cents are left off whole amounts
+pub fn format_pay(cents: i64, currency: Option<&str>) -> String {
+    let currency = currency.unwrap_or("USD");
+    let format = currency_format(currency);
+    let sign = if cents < 0 { "-" } else { "" };
+    let cents = cents.abs();
+    let (whole, fraction) = if format.minor_units {
+        (cents / 100, cents % 100)
+    } else {
+        ((cents + 50) / 100, 0)
+    };
+    let mut amount = group_digits(&whole.to_string(), format.thousands, format.lakh_grouping);
+    if fraction != 0 {
+        amount = format!("{}{}{:02}", amount, format.decimal, fraction);
+    }
+    let symbol = currency_symbol(currency);
+    if format.symbol_after {
+        format!("{sign}{amount} {symbol}")
+    } else if symbol.ends_with(|c: char| c.is_ascii_alphabetic()) {
+        format!("{sign}{symbol} {amount}")
+    } else {
+        format!("{sign}{symbol}{amount}")
+    }
+}
+
+/// Cents as the plain amount a `MoneyInput` edits, e.g. "85000.50", or "5000000" for a
+/// currency without cents
+pub fn money_value(cents: Option<i64>, currency: &str) -> String {
+    match cents {
+        Some(cents) if !currency_format(currency).minor_units => {
+            (cents as f64 / 100.0).round().to_string()
+        }
+        _ => get_pay_str(cents),
+    }
+}
+
+/// Rewrites a typed amount for a newly picked currency, rounding off cents it doesn't have
+pub fn convert_money_value(amount: &str, currency: &str) -> String {
+    if currency_format(currency).minor_units {
+        return amount.to_string();
+    }
+    match get_pay_i64(amount) {
+        Ok(cents) => money_value(Some(cents), currency),
+        Err(_) => amount.to_string(),
+    }
+}
+
+/// Strips the currency's separators and returns the plain amount, or `None` if it isn't one
+fn parse_money(input: &str, currency: &str) -> Option<String> {
+    let format = currency_format(currency);
+    let amount: String = input
+        .chars()
+        .filter(|c| !format.thousands.contains(*c) && *c != ' ')
+        .map(|c| if format.decimal.contains(c) { '.' } else { c })
+        .collect();
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (amount.as_str(), None),
+    };
+    if !whole.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    match fraction {
+        Some(_) if !format.minor_units => None,
+        Some(fraction) if fraction.len() > 2 || !fraction.chars().all(|c| c.is_ascii_digit()) => {
+            None
+        }
+        _ => Some(amount),
+    }
+}
+
+/// Pay input that shows the currency's symbol and separators.
+/// Keystrokes that wouldn't leave a valid amount are ignored.
+pub struct MoneyInput<'a, Message> {
+    value: &'a str,
+    currency: &'a str,
+    on_input: fn(String) -> Message,
+    on_submit: Option<Message>,
+    invalid: bool,
+}
+
+impl<'a, Message: Clone + 'a> MoneyInput<'a, Message> {
+    pub fn new(value: &'a str, currency: &'a str, on_input: fn(String) -> Message) -> Self {
+        Self {
+            value,
+            currency,
+            on_input,
+            on_submit: None,
+            invalid: false,
+        }
+    }
+
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.on_submit = Some(message);
+        self
+    }
+
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn view(self) -> Element<'a, Message, Theme> {
+        let previous = self.value.to_string();
+        let on_input = self.on_input;
+        let currency = self.currency;
+        let mut input = text_input("", &format_money(self.value, currency))
+            .on_input(move |input| match parse_money(&input, currency) {
+                Some(amount) => on_input(amount),
+                None => on_input(previous.clone()),
+            })
+            .padding(5)
+            .style(input_style(self.invalid));
+        if let Some(message) = self.on_submit {
+            input = input.on_submit(message);
+        }
+        row![
+            text(currency_symbol(self.currency).to_string()).size(12),
+            input.width(Length::Fill),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .into()
+    }
+}
+
+#[cfg(test)]
+// Cents are written apart from the dollars, e.g. 85_000_00
+#[allow(clippy::inconsistent_digit_grouping)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pay_is_written_the_way_its_currency_is() {
+        assert_eq!(format_pay(85_000_00, None), "$85,000");
+        assert_eq!(format_pay(85_000_50, Some("USD")), "$85,000.50");
+        assert_eq!(format_pay(1_234_567_00, Some("EUR")), "1.234.567 €");
+        assert_eq!(format_pay(85_000_05, Some("EUR")), "85.000,05 €");
+        assert_eq!(format_pay(120_000_00, Some("CHF")), "CHF 120'000");
+        assert_eq!(format_pay(5_000_000_00, Some("JPY")), "¥5,000,000");
+        assert_eq!(format_pay(12_34_567_00, Some("INR")), "₹12,34,567");
+        assert_eq!(format_pay(-500_00, Some("GBP")), "-£500");
+        assert_eq!(format_pay(0, Some("CAD")), "CA$0");
+    }
+
+    #[test]
+    fn typed_amounts_keep_their_fraction() {
+        assert_eq!(format_money("85000.5", "USD"), "85,000.5");
+        assert_eq!(format_money("100", "USD"), "100");
+        assert_eq!(format_money("", "USD"), "");
+    }
+
+    #[test]
+    fn typed_amounts_use_the_currency_separators() {
+        assert_eq!(format_money("85000.5", "EUR"), "85.000,5");
+        assert_eq!(format_money("120000", "CHF"), "120'000");
+        assert_eq!(format_money("1234567", "INR"), "12,34,567");
+
+        assert_eq!(parse_money("85,000.5", "USD").as_deref(), Some("85000.5"));
+        assert_eq!(parse_money("85.000,5", "EUR").as_deref(), Some("85000.5"));
+        assert_eq!(parse_money("120'000", "CHF").as_deref(), Some("120000"));
+        assert_eq!(parse_money("5,000,000", "JPY").as_deref(), Some("5000000"));
+        assert_eq!(parse_money("500.5", "JPY"), None);
+        assert_eq!(parse_money("85,123", "EUR"), None);
+        assert_eq!(parse_money("12a", "USD"), None);
+    }
+
+    #[test]
+    fn loaded_amounts_stay_editable_in_every_currency() {
+        for currency in CURRENCIES {
+            for cents in [0, 1_50, 85_000_00, 1_234_567_89] {
+                let value = money_value(Some(cents), currency);
+                let shown = format_money(&value, currency);
+                assert_eq!(
+                    parse_money(&shown, currency).as_deref(),
+                    Some(value.as_str()),
+                    "{cents} in {currency}"
+                );
+            }
+        }
+        assert_eq!(money_value(Some(5_000_000_00), "JPY"), "5000000");
+        assert_eq!(money_value(Some(85_000_50), "USD"), "85000.50");
+        assert_eq!(money_value(None, "JPY"), "");
+    }
+
+    #[test]
+    fn switching_currency_drops_cents_it_doesnt_have() {
+        assert_eq!(convert_money_value("85000.5", "JPY"), "85001");
+        assert_eq!(convert_money_value("85000.5", "EUR"), "85000.5");
+        assert_eq!(convert_money_value("", "JPY"), "");
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{Duration, Utc};
+
+use super::{SqliteBoolean, SqliteDateTime};
+
+/// A saved search that is re-run on a schedule
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AlertRule {
+    pub id: i64,
+    pub name: String,
+    pub provider: String,
+    pub job_title: String,
+    pub location: String,
+    pub country: String,
+    pub min_yoe: i64,
+    pub min_salary: i64,
+    pub onsite: SqliteBoolean,
+    pub hybrid: SqliteBoolean,
+    pub remote: SqliteBoolean,
+    pub tracked_companies: SqliteBoolean,
+    pub interval_hours: i64,
+    pub last_run: Option<SqliteDateTime>,
+    pub enabled: SqliteBoolean,
+}
+
+impl AlertRule {
+    pub const DEFAULT_ORDER: &str = "name ASC";
+
+    pub async fn fetch_all(executor: &sqlx::SqlitePool) -> anyhow::Result<Vec<Self>> {
+        let mut query = sqlx::QueryBuilder::new("SELECT * FROM alert_rule ORDER BY ");
+        query.push(Self::DEFAULT_ORDER);
+        query
+            .build_query_as()
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Enabled rules that have never run or whose interval has elapsed. A positive
+    /// `interval_hours` replaces every rule's own.
+    pub async fn fetch_due(
+        interval_hours: i64,
+        executor: &sqlx::SqlitePool,
+    ) -> anyhow::Result<Vec<Self>> {
+        let rules = sqlx::query_as::<_, Self>("SELECT * FROM alert_rule WHERE enabled = 1")
+            .fetch_all(executor)
+            .await?;
+        let now = Utc::now();
+
+        Ok(rules
+            .into_iter()
+            .filter(|rule| {
+                let interval_hours = if interval_hours > 0 {
+                    interval_hours
+                } else {
+                    rule.interval_hours
+                };
+                match rule.last_run {
+                    Some(last_run) => now - last_run.0 >= Duration::hours(interval_hours),
+                    None => true,
+                }
+            })
+            .collect())
+    }
+
+    pub async fn insert(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<i64> {
+        let res = sqlx::query(
+            r#"INSERT INTO alert_rule (
+                name, provider, job_title, location, country, min_yoe, min_salary,
+                onsite, hybrid, remote, tracked_companies, interval_hours, enabled
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&self.name)
+        .bind(&self.provider)
+        .bind(&self.job_title)
+        .bind(&self.location)
+        .bind(&self.country)
+        .bind(self.min_yoe)
+        .bind(self.min_salary)
+        .bind(self.onsite)
+        .bind(self.hybrid)
+        .bind(self.remote)
+        .bind(self.tracked_companies)
+        .bind(self.interval_hours)
+        .bind(self.enabled)
+        .execute(executor)
+        .await?;
+
+        Ok(res.last_insert_rowid())
+    }
+
+    pub async fn set_last_run(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+        sqlx::query("UPDATE alert_rule SET last_run = ? WHERE id = ?")
+            .bind(SqliteDateTime(Utc::now()))
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn set_enabled(
+        id: i64,
+        enabled: bool,
+        executor: &sqlx::SqlitePool,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE alert_rule SET enabled = ? WHERE id = ?")
+            .bind(SqliteBoolean(enabled))
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM alert_rule WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::memory_pool;
+
+    #[tokio::test]
+    async fn settings_interval_replaces_each_rules_own() {
+        let pool = memory_pool().await;
+        let id = AlertRule {
+            id: 0,
+            name: "Rust".to_string(),
+            provider: "apijobs".to_string(),
+            job_title: "Rust Engineer".to_string(),
+            location: "".to_string(),
+            country: "".to_string(),
+            min_yoe: 0,
+            min_salary: 0,
+            onsite: SqliteBoolean(false),
+            hybrid: SqliteBoolean(false),
+            remote: SqliteBoolean(true),
+            tracked_companies: SqliteBoolean(false),
+            interval_hours: 24,
+            last_run: None,
+            enabled: SqliteBoolean(true),
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        assert_eq!(AlertRule::fetch_due(0, &pool).await.unwrap().len(), 1);
+
+        sqlx::query("UPDATE alert_rule SET last_run = ? WHERE id = ?")
+            .bind(SqliteDateTime(Utc::now() - Duration::hours(6)))
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(AlertRule::fetch_due(0, &pool).await.unwrap().is_empty());
+        assert_eq!(AlertRule::fetch_due(4, &pool).await.unwrap().len(), 1);
+
+        AlertRule::set_enabled(id, false, &pool).await.unwrap();
+        assert!(AlertRule::fetch_due(4, &pool).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::Utc;
+
+use super::SqliteDateTime;
+
+/// Requests made to each job search provider, counted for its rate limit and quota
+pub struct ApiRequest;
+
+impl ApiRequest {
+    pub async fn record(
+        provider: &str,
+        status: Option<i64>,
+        executor: &sqlx::SqlitePool,
+    ) -> anyhow::Result<()> {
+        sqlx::query("INSERT INTO api_request (provider, requested_at, status) VALUES (?, ?, ?)")
+            .bind(provider)
+            .bind(SqliteDateTime(Utc::now()))
+            .bind(status)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn count_since(
+        provider: &str,
+        since: SqliteDateTime,
+        executor: &sqlx::SqlitePool,
+    ) -> anyhow::Result<i64> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM api_request WHERE provider = ? AND requested_at >= ?",
+        )
+        .bind(provider)
+        .bind(since)
+        .fetch_one(executor)
+        .await
+        .map_err(Into::into)
+    }
+}
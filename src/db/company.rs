@@ -1,3 +1,4 @@
+use super::company_alt_name::CompanyAltName;
 use super::SqliteBoolean;
 use sqlx::QueryBuilder;
 
@@ -7,14 +8,22 @@ pub struct Company {
     pub name: String,
     pub careers_url: Option<String>,
     pub hidden: SqliteBoolean,
+    pub group_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::FromRow)]
+pub struct CompanyJobCount {
+    pub company_id: i64,
+    pub job_count: i64,
+    pub active_application_count: i64,
 }
 
 impl Company {
     pub const DEFAULT_ORDER: &str = "name ASC";
 
-    pub async fn fetch_shown(executor: &sqlx::SqlitePool) -> anyhow::Result<Vec<Self>> {
+    pub async fn fetch_shown(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
         let mut query = QueryBuilder::new(
-            "SELECT id, name, careers_url, hidden FROM company WHERE hidden = 0 ORDER BY ",
+            "SELECT id, name, careers_url, hidden, group_id FROM company WHERE hidden = 0 ORDER BY ",
         );
         query.push(Self::DEFAULT_ORDER);
         query
@@ -24,7 +33,10 @@ impl Company {
             .map_err(Into::into)
     }
 
-    pub async fn fetch_one(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<Option<Self>> {
+    pub async fn fetch_one(
+        id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Option<Self>> {
         sqlx::query_as!(Self, "SELECT * FROM company WHERE id = $1", id)
             .fetch_optional(executor)
             .await
@@ -34,7 +46,7 @@ impl Company {
     pub async fn fetch_by_name(
         name: &str,
         include_hidden: bool,
-        executor: &sqlx::SqlitePool,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<Vec<Self>> {
         let mut query = QueryBuilder::new("SELECT * FROM company WHERE name LIKE ");
         query.push_bind(format!("%{}%", name));
@@ -50,29 +62,64 @@ impl Company {
             .map_err(Into::into)
     }
 
+    pub async fn fetch_job_counts(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<CompanyJobCount>> {
+        sqlx::query_as::<_, CompanyJobCount>(
+            r#"SELECT
+                    job_post.company_id AS company_id,
+                    COUNT(job_post.id) AS job_count,
+                    COUNT(
+                        CASE WHEN job_application.status IN ('Applied', 'Interview', 'Offer')
+                        THEN 1 END
+                    ) AS active_application_count
+                FROM job_post
+                LEFT JOIN job_application ON job_post.id = job_application.job_post_id
+                WHERE job_post.archived = 0
+                GROUP BY job_post.company_id
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Resolves a company by its name or one of its alt names, ignoring case. Failing that,
+    /// a company whose name only differs by a legal suffix matches, so "Google LLC" finds
+    /// "Google" and the other way around
     pub async fn fetch_id_by_name(
         name: &str,
-        executor: &sqlx::SqlitePool,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<Option<i64>> {
-        let res = sqlx::query!(
-            r#"SELECT id FROM company WHERE name = $1
-                OR id = (
-                    SELECT company_id FROM company_alt_name WHERE name = $2 LIMIT 1
-                )"#,
-            name,
-            name
+        let base_name = CompanyAltName::base_name(name);
+        let first_word = base_name.split(' ').next().unwrap_or_default();
+        let candidates: Vec<(i64, String, i64)> = sqlx::query_as(
+            r#"SELECT id, name, 0 AS rank FROM company WHERE name = ?1 COLLATE NOCASE
+                UNION ALL
+                SELECT company_id, name, 1 FROM company_alt_name WHERE name = ?1
+                UNION ALL
+                SELECT id, name, 2 FROM company WHERE ?2 != '' AND name LIKE ?2 || '%'
+                ORDER BY rank, id"#,
         )
-        .fetch_optional(executor)
+        .bind(name.trim())
+        .bind(first_word)
+        .fetch_all(executor)
         .await?;
-        Ok(res.map(|r| r.id))
+        Ok(candidates
+            .into_iter()
+            .find(|(_, candidate, rank)| {
+                *rank < 2 || CompanyAltName::base_name(candidate) == base_name
+            })
+            .map(|(id, _, _)| id))
     }
 
-    pub async fn insert(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<i64> {
+    pub async fn insert(&self, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<i64> {
         let res = sqlx::query!(
-            "INSERT INTO company (name, careers_url, hidden) VALUES ($1, $2, $3)",
+            "INSERT INTO company (name, careers_url, hidden, group_id) VALUES ($1, $2, $3, $4)",
             self.name,
             self.careers_url,
             self.hidden,
+            self.group_id,
         )
         .execute(executor)
         .await?;
@@ -80,12 +127,13 @@ impl Company {
         Ok(res.last_insert_rowid())
     }
 
-    pub async fn update(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+    pub async fn update(&self, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
         sqlx::query!(
-            "UPDATE company SET name = $1, careers_url = $2, hidden = $3 WHERE id = $4",
+            "UPDATE company SET name = $1, careers_url = $2, hidden = $3, group_id = $4 WHERE id = $5",
             self.name,
             self.careers_url,
             self.hidden,
+            self.group_id,
             self.id
         )
         .execute(executor)
@@ -94,7 +142,7 @@ impl Company {
         Ok(())
     }
 
-    pub async fn hide(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+    pub async fn hide(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
         sqlx::query!("UPDATE company SET hidden = 1 WHERE id = $1", id)
             .execute(executor)
             .await?;
@@ -102,23 +150,34 @@ impl Company {
         Ok(())
     }
 
-    pub async fn show_all(executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
-        sqlx::query!("UPDATE company SET hidden = 0")
-            .execute(executor)
-            .await?;
+    pub async fn set_group(
+        id: i64,
+        group_id: Option<i64>,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query!(
+            "UPDATE company SET group_id = $1 WHERE id = $2",
+            group_id,
+            id
+        )
+        .execute(executor)
+        .await?;
 
         Ok(())
     }
 
-    pub async fn solo(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
-        sqlx::query!("UPDATE company SET hidden = 1 WHERE id != $1", id)
+    pub async fn show_all(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query!("UPDATE company SET hidden = 0")
             .execute(executor)
             .await?;
 
         Ok(())
     }
 
-    pub async fn delete(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+    pub async fn delete(
+        id: i64,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<()> {
         let mut tx = executor.begin().await?;
 
         sqlx::query!(
@@ -151,3 +210,53 @@ impl std::fmt::Display for Company {
         write!(f, "{}", self.name)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::job_application::JobApplicationStatus;
+    use crate::db::test_support::{
+        count_rows, memory_pool, CompanyBuilder, JobApplicationBuilder, JobPostBuilder,
+    };
+
+    #[tokio::test]
+    async fn delete_cascades_to_posts_and_applications() {
+        let pool = memory_pool().await;
+        let deleted = CompanyBuilder::new("Deleted").insert(&pool).await;
+        let kept = CompanyBuilder::new("Kept").insert(&pool).await;
+        for company_id in [deleted.id, deleted.id, kept.id] {
+            let post = JobPostBuilder::new(company_id, "Engineer")
+                .insert(&pool)
+                .await;
+            JobApplicationBuilder::new(post.id, JobApplicationStatus::Applied)
+                .insert(&pool)
+                .await;
+        }
+
+        Company::delete(deleted.id, &pool).await.unwrap();
+
+        assert!(Company::fetch_one(deleted.id, &pool)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(Company::fetch_one(kept.id, &pool).await.unwrap().is_some());
+        assert_eq!(count_rows("job_post", &pool).await, 1);
+        assert_eq!(count_rows("job_application", &pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn delete_can_share_a_transaction() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        JobPostBuilder::new(company.id, "Engineer")
+            .insert(&pool)
+            .await;
+
+        let mut tx = pool.begin().await.unwrap();
+        Company::delete(company.id, &mut *tx).await.unwrap();
+        tx.rollback().await.unwrap();
+
+        assert_eq!(count_rows("company", &pool).await, 1);
+        assert_eq!(count_rows("job_post", &pool).await, 1);
+    }
+}
@@ -0,0 +1,190 @@
+/// Another name a company goes by, e.g. "Google LLC" for "Google". Names are unique ignoring
+/// case, so each one resolves to a single company
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct CompanyAltName {
+    pub id: i64,
+    pub company_id: i64,
+    pub name: String,
+}
+
+/// Legal suffixes left off when comparing company names, lowercase
+const LEGAL_SUFFIXES: [&str; 12] = [
+    "inc",
+    "llc",
+    "ltd",
+    "limited",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "gmbh",
+    "plc",
+    "sa",
+    "ag",
+];
+
+impl CompanyAltName {
+    pub async fn fetch_for_company(
+        company_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(
+            "SELECT id, company_id, name FROM company_alt_name WHERE company_id = ? ORDER BY name",
+        )
+        .bind(company_id)
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Records `name` for the company, unless it's already the company's name or another
+    /// alt name
+    pub async fn insert(
+        company_id: i64,
+        name: &str,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"INSERT INTO company_alt_name (name, company_id)
+                SELECT ?1, ?2 WHERE NOT EXISTS (
+                    SELECT 1 FROM company WHERE id = ?2 AND name = ?1 COLLATE NOCASE
+                )
+                ON CONFLICT (name) DO NOTHING"#,
+        )
+        .bind(name.trim())
+        .bind(company_id)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM company_alt_name WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The name without punctuation or a trailing legal suffix, lowercase, e.g. "acme" for
+    /// "Acme, Inc."
+    pub fn base_name(name: &str) -> String {
+        let mut words: Vec<String> = name
+            .split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '&')
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.len() > 1 && LEGAL_SUFFIXES.contains(&words[words.len() - 1].as_str()) {
+            words.pop();
+        }
+        words.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{import_results, JobSearchResult};
+    use crate::db::company::Company;
+    use crate::db::test_support::{count_rows, memory_pool, CompanyBuilder, JobPostBuilder};
+
+    #[test]
+    fn base_names_leave_off_legal_suffixes() {
+        assert_eq!(CompanyAltName::base_name("Acme, Inc."), "acme");
+        assert_eq!(CompanyAltName::base_name("Google LLC"), "google");
+        assert_eq!(
+            CompanyAltName::base_name("  Procter & Gamble Co "),
+            "procter & gamble"
+        );
+        // A suffix that's the whole name is kept
+        assert_eq!(CompanyAltName::base_name("Limited"), "limited");
+    }
+
+    #[tokio::test]
+    async fn alt_names_resolve_to_their_company() {
+        let pool = memory_pool().await;
+        let google = CompanyBuilder::new("Google").insert(&pool).await;
+        CompanyBuilder::new("Alphabet").insert(&pool).await;
+
+        CompanyAltName::insert(google.id, "YouTube", &pool)
+            .await
+            .unwrap();
+        // Already the company's name, and already known
+        CompanyAltName::insert(google.id, "google", &pool)
+            .await
+            .unwrap();
+        CompanyAltName::insert(google.id, "youtube", &pool)
+            .await
+            .unwrap();
+        let alt_names = CompanyAltName::fetch_for_company(google.id, &pool)
+            .await
+            .unwrap();
+        assert_eq!(alt_names.len(), 1);
+        assert_eq!(
+            Company::fetch_id_by_name("YOUTUBE", &pool).await.unwrap(),
+            Some(google.id)
+        );
+
+        CompanyAltName::delete(alt_names[0].id, &pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            Company::fetch_id_by_name("YouTube", &pool).await.unwrap(),
+            None
+        );
+        CompanyAltName::insert(google.id, "Google LLC", &pool)
+            .await
+            .unwrap();
+        Company::delete(google.id, &pool).await.unwrap();
+        assert_eq!(count_rows("company_alt_name", &pool).await, 0);
+    }
+
+    #[tokio::test]
+    async fn names_differing_by_a_suffix_resolve_to_one_company() {
+        let pool = memory_pool().await;
+        let google = CompanyBuilder::new("Google").insert(&pool).await;
+        let acme = CompanyBuilder::new("Acme, Inc.").insert(&pool).await;
+        CompanyBuilder::new("Google Cloud").insert(&pool).await;
+
+        assert_eq!(
+            Company::fetch_id_by_name("Google LLC", &pool)
+                .await
+                .unwrap(),
+            Some(google.id)
+        );
+        assert_eq!(
+            Company::fetch_id_by_name("ACME", &pool).await.unwrap(),
+            Some(acme.id)
+        );
+        assert_eq!(
+            Company::fetch_id_by_name("Googler", &pool).await.unwrap(),
+            None
+        );
+
+        let result = |company_name: &str, title: &str| JobSearchResult {
+            company_name: company_name.to_string(),
+            company_url: None,
+            job_post: JobPostBuilder::new(0, title).build(),
+            exists: false,
+        };
+        import_results(
+            vec![result("Google LLC", "SRE"), result("Acme Corp", "Engineer")],
+            None,
+            pool.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(count_rows("company", &pool).await, 3);
+        let alt_names = CompanyAltName::fetch_for_company(google.id, &pool)
+            .await
+            .unwrap();
+        assert_eq!(alt_names[0].name, "Google LLC");
+    }
+}
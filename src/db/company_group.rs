@@ -0,0 +1,79 @@
+use sqlx::QueryBuilder;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, sqlx::FromRow)]
+pub struct CompanyGroup {
+    pub id: i64,
+    pub name: String,
+}
+
+impl CompanyGroup {
+    pub const DEFAULT_ORDER: &str = "id ASC";
+
+    pub async fn fetch_all(executor: &sqlx::SqlitePool) -> anyhow::Result<Vec<Self>> {
+        let mut query = QueryBuilder::new("SELECT id, name FROM company_group ORDER BY ");
+        query.push(Self::DEFAULT_ORDER);
+        query
+            .build_query_as()
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn insert(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<i64> {
+        let res = sqlx::query!("INSERT INTO company_group (name) VALUES ($1)", self.name)
+            .execute(executor)
+            .await?;
+
+        Ok(res.last_insert_rowid())
+    }
+
+    pub async fn hide(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+        sqlx::query!("UPDATE company SET hidden = 1 WHERE group_id = $1", id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn solo(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+        let mut tx = executor.begin().await?;
+
+        sqlx::query!("UPDATE company SET hidden = 0 WHERE group_id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query!(
+            "UPDATE company SET hidden = 1 WHERE group_id IS NULL OR group_id != $1",
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+        let mut tx = executor.begin().await?;
+
+        // Companies fall back to ungrouped rather than being deleted
+        sqlx::query!("UPDATE company SET group_id = NULL WHERE group_id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query!("DELETE FROM company_group WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for CompanyGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
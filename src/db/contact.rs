@@ -0,0 +1,191 @@
+/// Someone I deal with about jobs, like a recruiter or hiring manager, linked to the posts
+/// they were involved in
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct Contact {
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    pub company_id: Option<i64>,
+    /// E.g. "Recruiter"
+    pub role: String,
+    pub linkedin_url: String,
+    pub notes: String,
+    /// Filled in by the fetches; not written
+    #[sqlx(default)]
+    pub company_name: Option<String>,
+}
+
+impl std::fmt::Display for Contact {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.company_name {
+            Some(company_name) => write!(f, "{} ({})", self.name, company_name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl Contact {
+    const SELECT: &str = r#"SELECT contact.*, company.name AS company_name FROM contact
+        LEFT JOIN company ON contact.company_id = company.id"#;
+
+    pub async fn fetch_all(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(&format!(
+            "{} ORDER BY contact.name COLLATE NOCASE",
+            Self::SELECT
+        ))
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Contacts linked to the post, in the order they were linked
+    pub async fn fetch_for_job_post(
+        job_post_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(&format!(
+            r#"{} JOIN job_post_contact ON job_post_contact.contact_id = contact.id
+                WHERE job_post_contact.job_post_id = ?
+                ORDER BY job_post_contact.rowid"#,
+            Self::SELECT
+        ))
+        .bind(job_post_id)
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    pub async fn insert(&self, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<i64> {
+        let res = sqlx::query(
+            r#"INSERT INTO contact (name, email, company_id, role, linkedin_url, notes)
+                VALUES (?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&self.name)
+        .bind(&self.email)
+        .bind(self.company_id)
+        .bind(&self.role)
+        .bind(&self.linkedin_url)
+        .bind(&self.notes)
+        .execute(executor)
+        .await?;
+
+        Ok(res.last_insert_rowid())
+    }
+
+    pub async fn update(&self, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"UPDATE contact
+                SET name = ?, email = ?, company_id = ?, role = ?, linkedin_url = ?, notes = ?
+                WHERE id = ?"#,
+        )
+        .bind(&self.name)
+        .bind(&self.email)
+        .bind(self.company_id)
+        .bind(&self.role)
+        .bind(&self.linkedin_url)
+        .bind(&self.notes)
+        .bind(self.id)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Deletes the contact and its links to posts
+    pub async fn delete(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM contact WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn link(
+        id: i64,
+        job_post_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO job_post_contact (job_post_id, contact_id) VALUES (?, ?)",
+        )
+        .bind(job_post_id)
+        .bind(id)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn unlink(
+        id: i64,
+        job_post_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM job_post_contact WHERE job_post_id = ? AND contact_id = ?")
+            .bind(job_post_id)
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{count_rows, memory_pool, CompanyBuilder, JobPostBuilder};
+
+    fn contact(name: &str, company_id: Option<i64>) -> Contact {
+        Contact {
+            id: 0,
+            name: name.to_string(),
+            email: format!("{}@example.com", name.to_lowercase()),
+            company_id,
+            role: "Recruiter".to_string(),
+            linkedin_url: "".to_string(),
+            notes: "".to_string(),
+            company_name: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn contacts_are_linked_to_posts() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let post = JobPostBuilder::new(company.id, "Engineer")
+            .insert(&pool)
+            .await;
+        let sam = contact("Sam", Some(company.id))
+            .insert(&pool)
+            .await
+            .unwrap();
+        let alex = contact("Alex", None).insert(&pool).await.unwrap();
+
+        Contact::link(sam, post.id, &pool).await.unwrap();
+        Contact::link(alex, post.id, &pool).await.unwrap();
+        Contact::link(sam, post.id, &pool).await.unwrap();
+        let linked = Contact::fetch_for_job_post(post.id, &pool).await.unwrap();
+        let names: Vec<String> = linked.iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["Sam (Acme)", "Alex"]);
+
+        Contact::unlink(alex, post.id, &pool).await.unwrap();
+        assert_eq!(
+            Contact::fetch_for_job_post(post.id, &pool)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let mut updated = linked[0].clone();
+        updated.role = "Hiring Manager".to_string();
+        updated.update(&pool).await.unwrap();
+        let all = Contact::fetch_all(&pool).await.unwrap();
+        assert_eq!(all[1].role, "Hiring Manager");
+
+        Contact::delete(sam, &pool).await.unwrap();
+        assert_eq!(count_rows("job_post_contact", &pool).await, 0);
+    }
+}
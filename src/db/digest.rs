@@ -0,0 +1,207 @@
+use chrono::{DateTime, Duration, Utc};
+
+use super::job_application::JobApplicationStatus;
+use super::job_post::JobPost;
+use super::SqliteDateTime;
+
+/// Applications without a reply after this many days are due a follow-up
+pub const FOLLOW_UP_AFTER_DAYS: i64 = 7;
+/// Interviews this close are listed in the digest
+pub const UPCOMING_INTERVIEW_DAYS: i64 = 2;
+/// How long before an interview it's brought up again, by notification or calendar alarm
+pub const INTERVIEW_REMINDER_MINUTES: i64 = 60;
+
+/// A post waiting in the review queue
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReviewPost {
+    #[sqlx(flatten)]
+    pub job_post: JobPost,
+    pub company_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct UpcomingInterview {
+    pub job_title: String,
+    pub company_name: String,
+    /// UTC timestamp
+    pub interview_at: i64,
+    pub interview_timezone: Option<String>,
+}
+
+impl UpcomingInterview {
+    /// Interviews at or after `from` and before `to`, soonest first
+    pub async fn fetch_between(
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(
+            r#"SELECT job_post.job_title, company.name AS company_name,
+                job_application.interview_at, job_application.interview_timezone
+            FROM job_application
+            JOIN job_post ON job_application.job_post_id = job_post.id
+            JOIN company ON job_post.company_id = company.id
+            WHERE job_application.interview_at >= ? AND job_application.interview_at < ?
+            ORDER BY job_application.interview_at ASC"#,
+        )
+        .bind(SqliteDateTime(from))
+        .bind(SqliteDateTime(to))
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+}
+
+/// What changed since the last session, shown on launch
+#[derive(Debug, Clone, Default)]
+pub struct Digest {
+    /// Posts retrieved since then, or whose snooze ran out since then, oldest first. Posts
+    /// that were applied to or archived are left out
+    pub new_posts: Vec<ReviewPost>,
+    pub upcoming_interviews: Vec<UpcomingInterview>,
+    /// Applications still waiting on a reply `FOLLOW_UP_AFTER_DAYS` after they were sent
+    pub awaiting_follow_up: i64,
+    pub open_offers: i64,
+}
+
+impl Digest {
+    pub fn is_empty(&self) -> bool {
+        self.new_posts.is_empty()
+            && self.upcoming_interviews.is_empty()
+            && self.awaiting_follow_up == 0
+            && self.open_offers == 0
+    }
+
+    pub async fn fetch(
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<Self> {
+        let mut conn = executor.acquire().await?;
+
+        let new_posts = sqlx::query_as(
+            r#"SELECT job_post.*, company.name AS company_name FROM job_post
+                JOIN company ON job_post.company_id = company.id
+                WHERE company.hidden = 0 AND job_post.archived = 0
+                AND job_post.id NOT IN (SELECT job_post_id FROM job_application)
+                AND CASE WHEN job_post.snoozed_until IS NULL
+                    THEN job_post.date_retrieved > ?
+                    ELSE job_post.snoozed_until > ? AND job_post.snoozed_until <= ?
+                END
+                ORDER BY COALESCE(job_post.snoozed_until, job_post.date_retrieved) ASC"#,
+        )
+        .bind(SqliteDateTime(since))
+        .bind(SqliteDateTime(since))
+        .bind(SqliteDateTime(now))
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let upcoming_interviews = UpcomingInterview::fetch_between(
+            now,
+            now + Duration::days(UPCOMING_INTERVIEW_DAYS),
+            &mut *conn,
+        )
+        .await?;
+
+        let awaiting_follow_up = sqlx::query_scalar(
+            r#"SELECT COUNT(*) FROM job_application
+                WHERE status = ? AND date_applied <= ? AND date_responded IS NULL"#,
+        )
+        .bind(JobApplicationStatus::Applied.name())
+        .bind(SqliteDateTime(now - Duration::days(FOLLOW_UP_AFTER_DAYS)))
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let open_offers =
+            sqlx::query_scalar("SELECT COUNT(*) FROM job_application WHERE status = ?")
+                .bind(JobApplicationStatus::Offer.name())
+                .fetch_one(&mut *conn)
+                .await?;
+
+        Ok(Self {
+            new_posts,
+            upcoming_interviews,
+            awaiting_follow_up,
+            open_offers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::db::test_support::{
+        memory_pool, CompanyBuilder, JobApplicationBuilder, JobPostBuilder,
+    };
+
+    #[tokio::test]
+    async fn digest_covers_what_changed_since_last_session() {
+        let pool = memory_pool().await;
+        let now = Utc::now();
+        let last_session = now - Duration::days(1);
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+
+        let old = JobPostBuilder::new(company.id, "Old")
+            .retrieved(now - Duration::days(3))
+            .insert(&pool)
+            .await;
+        let new = JobPostBuilder::new(company.id, "New")
+            .retrieved(now - Duration::hours(2))
+            .insert(&pool)
+            .await;
+        let applied = JobPostBuilder::new(company.id, "Applied")
+            .retrieved(now - Duration::hours(1))
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(applied.id, JobApplicationStatus::Applied)
+            .applied(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+            .insert(&pool)
+            .await;
+        let snoozed = JobPostBuilder::new(company.id, "Snoozed")
+            .retrieved(now - Duration::hours(1))
+            .insert(&pool)
+            .await;
+        JobPost::snooze(snoozed.id, now + Duration::days(1), &pool)
+            .await
+            .unwrap();
+        // Snoozed last session, and due again now
+        JobPost::snooze(old.id, now - Duration::hours(3), &pool)
+            .await
+            .unwrap();
+
+        let offer = JobPostBuilder::new(company.id, "Offer").insert(&pool).await;
+        let offer = JobApplicationBuilder::new(offer.id, JobApplicationStatus::Offer)
+            .insert(&pool)
+            .await;
+        sqlx::query("UPDATE job_application SET interview_at = ? WHERE id = ?")
+            .bind((now + Duration::hours(5)).timestamp())
+            .bind(offer.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE job_post SET archived = 1 WHERE job_title = 'Offer'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let digest = Digest::fetch(last_session, now, &pool).await.unwrap();
+        let titles: Vec<&str> = digest
+            .new_posts
+            .iter()
+            .map(|post| post.job_post.job_title.as_str())
+            .collect();
+        assert_eq!(titles, ["Old", "New"]);
+        assert_eq!(digest.new_posts[1].job_post.id, new.id);
+        assert_eq!(digest.upcoming_interviews.len(), 1);
+        assert_eq!(digest.upcoming_interviews[0].job_title, "Offer");
+        assert_eq!(digest.awaiting_follow_up, 1);
+        assert_eq!(digest.open_offers, 1);
+
+        JobPost::archive(new.id, &pool).await.unwrap();
+        let digest = Digest::fetch(last_session, now, &pool).await.unwrap();
+        assert_eq!(digest.new_posts.len(), 1);
+        assert!(!digest.is_empty());
+    }
+}
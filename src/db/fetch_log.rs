@@ -0,0 +1,67 @@
+use chrono::Utc;
+
+use super::SqliteDateTime;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct FetchLog {
+    pub provider: String,
+    pub fetched_at: SqliteDateTime,
+    pub query: String,
+    pub results: i64,
+    pub inserted: i64,
+}
+
+impl FetchLog {
+    pub const DEFAULT_ORDER: &str = "fetched_at DESC";
+
+    pub async fn record(
+        provider: &str,
+        query: &str,
+        results: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<i64> {
+        let res = sqlx::query(
+            "INSERT INTO fetch_log (provider, fetched_at, query, results) VALUES (?, ?, ?, ?)",
+        )
+        .bind(provider)
+        .bind(SqliteDateTime(Utc::now()))
+        .bind(query)
+        .bind(results)
+        .execute(executor)
+        .await?;
+
+        Ok(res.last_insert_rowid())
+    }
+
+    /// Posts are inserted after the fetch is logged (e.g. on import), so the count is added later
+    pub async fn add_inserted(
+        id: i64,
+        inserted: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE fetch_log SET inserted = inserted + ? WHERE id = ?")
+            .bind(inserted)
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn fetch_recent(
+        limit: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        let mut query = sqlx::QueryBuilder::new(
+            "SELECT provider, fetched_at, query, results, inserted FROM fetch_log ORDER BY ",
+        );
+        query.push(Self::DEFAULT_ORDER);
+        query.push(" LIMIT ");
+        query.push_bind(limit);
+        query
+            .build_query_as()
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -1,6 +1,4 @@
-use iced::advanced::clipboard::Null;
-
-use super::{NullableSqliteDateTime, SqliteBoolean};
+use super::{NullableSqliteDateTime, SqliteBoolean, SqliteDateTime};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, sqlx::Type)]
 #[sqlx(type_name = "job_application_status")]
@@ -69,6 +67,120 @@ impl std::fmt::Display for JobApplicationStatus {
     }
 }
 
+/// Where an application was sent through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, sqlx::Type)]
+#[sqlx(type_name = "application_channel")]
+pub enum ApplicationChannel {
+    CompanySite,
+    EasyApply,
+    Referral,
+    Recruiter,
+    JobFair,
+}
+
+impl ApplicationChannel {
+    pub const ALL: [ApplicationChannel; 5] = [
+        ApplicationChannel::CompanySite,
+        ApplicationChannel::EasyApply,
+        ApplicationChannel::Referral,
+        ApplicationChannel::Recruiter,
+        ApplicationChannel::JobFair,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ApplicationChannel::CompanySite => "Company Site",
+            ApplicationChannel::EasyApply => "LinkedIn Easy Apply",
+            ApplicationChannel::Referral => "Referral",
+            ApplicationChannel::Recruiter => "Recruiter Outreach",
+            ApplicationChannel::JobFair => "Job Fair",
+        }
+    }
+}
+
+impl std::str::FromStr for ApplicationChannel {
+    type Err = ();
+
+    /// Parses the stored variant name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CompanySite" => Ok(ApplicationChannel::CompanySite),
+            "EasyApply" => Ok(ApplicationChannel::EasyApply),
+            "Referral" => Ok(ApplicationChannel::Referral),
+            "Recruiter" => Ok(ApplicationChannel::Recruiter),
+            "JobFair" => Ok(ApplicationChannel::JobFair),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for ApplicationChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Filters over the (optional) application joined to each job post
+#[derive(Debug, Clone, Default)]
+pub struct JobApplicationFilter {
+    pub applied_from: NullableSqliteDateTime,
+    pub applied_to: NullableSqliteDateTime,
+    pub responded_from: NullableSqliteDateTime,
+    pub responded_to: NullableSqliteDateTime,
+    pub interviewed: bool,
+    pub no_response: bool,
+    pub awaiting_response: bool,
+}
+
+impl JobApplicationFilter {
+    /// Longest wait first
+    pub const AWAITING_RESPONSE_ORDER: &str = "job_application.date_applied ASC";
+
+    pub fn add_filters<'a>(
+        &self,
+        mut query: sqlx::QueryBuilder<'a, sqlx::Sqlite>,
+    ) -> sqlx::QueryBuilder<'a, sqlx::Sqlite> {
+        // date applied range (inclusive)
+        if self.applied_from.0.is_some() {
+            query
+                .push(" AND job_application.date_applied >= ")
+                .push_bind(self.applied_from);
+        }
+        if self.applied_to.0.is_some() {
+            query
+                .push(" AND job_application.date_applied <= ")
+                .push_bind(self.applied_to);
+        }
+        // date responded range (inclusive)
+        if self.responded_from.0.is_some() {
+            query
+                .push(" AND job_application.date_responded >= ")
+                .push_bind(self.responded_from);
+        }
+        if self.responded_to.0.is_some() {
+            query
+                .push(" AND job_application.date_responded <= ")
+                .push_bind(self.responded_to);
+        }
+        if self.interviewed {
+            query.push(" AND job_application.interviewed = 1");
+        }
+        if self.awaiting_response {
+            query.push(" AND job_application.status = ");
+            query.push_bind(JobApplicationStatus::Applied.name());
+            query.push(
+                " AND job_application.date_applied IS NOT NULL AND job_application.date_responded IS NULL",
+            );
+        }
+        if self.no_response {
+            query.push(
+                " AND job_application.date_applied IS NOT NULL AND job_application.date_responded IS NULL",
+            );
+        }
+        query
+    }
+}
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct JobApplication {
     pub id: i64,
@@ -77,6 +189,17 @@ pub struct JobApplication {
     pub date_applied: NullableSqliteDateTime,
     pub date_responded: NullableSqliteDateTime,
     pub interviewed: SqliteBoolean,
+    /// Which resume was sent, free-form (e.g. "v2-backend")
+    pub resume_version: Option<String>,
+    pub channel: Option<ApplicationChannel>,
+    /// Next interview, as a UTC timestamp
+    pub interview_at: Option<i64>,
+    /// IANA name of the company's timezone the interview was scheduled in
+    pub interview_timezone: Option<String>,
+    /// The cover letter file that was sent
+    pub cover_letter_path: Option<String>,
+    /// The cover letter's text, for ones that weren't kept as a file
+    pub cover_letter: Option<String>,
 }
 
 impl JobApplication {
@@ -89,22 +212,28 @@ impl JobApplication {
         interviewed: bool,
     ) -> Self {
         Self {
-            id: app_id as i64,
-            job_post_id: job_post_id as i64,
+            id: app_id,
+            job_post_id,
             status,
             date_applied: NullableSqliteDateTime::from(date_applied),
             date_responded: NullableSqliteDateTime::from(date_responded),
             interviewed: SqliteBoolean(interviewed),
+            resume_version: None,
+            channel: None,
+            interview_at: None,
+            interview_timezone: None,
+            cover_letter_path: None,
+            cover_letter: None,
         }
     }
 
     pub async fn fetch_one(
         application_id: i64,
-        executor: &sqlx::SqlitePool,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<Option<Self>> {
         let ret = sqlx::query_as!(
             Self,
-            r#"SELECT * FROM job_application WHERE id = $1"#,
+            r#"SELECT id, job_post_id, status, date_applied, date_responded, interviewed, resume_version, channel AS "channel: ApplicationChannel", interview_at, interview_timezone, cover_letter_path, cover_letter FROM job_application WHERE id = $1"#,
             application_id,
         )
         .fetch_optional(executor)
@@ -115,11 +244,11 @@ impl JobApplication {
 
     pub async fn fetch_one_by_job_post_id(
         job_post_id: i64,
-        executor: &sqlx::SqlitePool,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<Option<Self>> {
         let ret = sqlx::query_as!(
             Self,
-            r#"SELECT * FROM job_application WHERE job_post_id = $1"#,
+            r#"SELECT id AS "id!", job_post_id, status, date_applied, date_responded, interviewed, resume_version, channel AS "channel: ApplicationChannel", interview_at, interview_timezone, cover_letter_path, cover_letter FROM job_application WHERE job_post_id = $1"#,
             job_post_id,
         )
         .fetch_optional(executor)
@@ -128,33 +257,454 @@ impl JobApplication {
         Ok(ret)
     }
 
-    pub async fn insert(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
-        sqlx::query!(
-            r#"INSERT INTO job_application (status, date_applied, date_responded, job_post_id, interviewed) VALUES ($1, $2, $3, $4, $5)"#,
+    /// Inserts the application, recording its status as the first event
+    pub async fn insert(
+        &self,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<()> {
+        let mut tx = executor.begin().await?;
+        let id = sqlx::query!(
+            r#"INSERT INTO job_application (status, date_applied, date_responded, job_post_id, interviewed, resume_version, channel, interview_at, interview_timezone, cover_letter_path, cover_letter) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"#,
             self.status,
             self.date_applied,
             self.date_responded,
             self.job_post_id,
             self.interviewed,
+            self.resume_version,
+            self.channel,
+            self.interview_at,
+            self.interview_timezone,
+            self.cover_letter_path,
+            self.cover_letter,
         )
-        .execute(executor)
-        .await?;
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+        JobApplicationEvent::record(id, None, &self.status, &mut *tx).await?;
+        tx.commit().await?;
 
         Ok(())
     }
 
-    pub async fn update(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+    /// Saves the application, recording a status change as an event
+    pub async fn update(
+        &self,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<()> {
+        let mut tx = executor.begin().await?;
+        let previous = Self::fetch_status(self.id, &mut *tx).await?;
         sqlx::query!(
-            r#"UPDATE job_application SET status = $1, date_applied = $2, date_responded = $3, interviewed = $4 WHERE id = $5"#,
+            r#"UPDATE job_application SET status = $1, date_applied = $2, date_responded = $3, interviewed = $4, resume_version = $5, channel = $6, interview_at = $7, interview_timezone = $8, cover_letter_path = $9, cover_letter = $10 WHERE id = $11"#,
             self.status,
             self.date_applied,
             self.date_responded,
             self.interviewed,
+            self.resume_version,
+            self.channel,
+            self.interview_at,
+            self.interview_timezone,
+            self.cover_letter_path,
+            self.cover_letter,
             self.id,
         )
+        .execute(&mut *tx)
+        .await?;
+        if previous.as_ref() != Some(&self.status) {
+            JobApplicationEvent::record(self.id, previous.as_ref(), &self.status, &mut *tx).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn fetch_status(
+        id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Option<JobApplicationStatus>> {
+        sqlx::query_scalar("SELECT status FROM job_application WHERE id = ?")
+            .bind(id)
+            .fetch_optional(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Moves an application to another column of the board. Dates the status implies are
+    /// filled in with today when they're missing
+    pub async fn set_status(
+        id: i64,
+        status: JobApplicationStatus,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<()> {
+        let mut tx = executor.begin().await?;
+        let previous = Self::fetch_status(id, &mut *tx).await?;
+        let today = NullableSqliteDateTime(Some(chrono::Utc::now().date_naive()));
+        let applied = status != JobApplicationStatus::New;
+        let responded = matches!(
+            status,
+            JobApplicationStatus::Interview
+                | JobApplicationStatus::Offer
+                | JobApplicationStatus::Rejected
+        );
+        sqlx::query(
+            r#"UPDATE job_application SET status = ?,
+                date_applied = CASE WHEN ? THEN COALESCE(date_applied, ?) ELSE date_applied END,
+                date_responded = CASE WHEN ? THEN COALESCE(date_responded, ?) ELSE date_responded END
+            WHERE id = ?"#,
+        )
+        .bind(&status)
+        .bind(applied)
+        .bind(today)
+        .bind(responded)
+        .bind(today)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+        if previous.as_ref() != Some(&status) {
+            JobApplicationEvent::record(id, previous.as_ref(), &status, &mut *tx).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Resume versions used so far, most recent first, for suggestions
+    pub async fn fetch_resume_versions(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<String>> {
+        sqlx::query_scalar(
+            r#"SELECT resume_version FROM job_application
+                WHERE TRIM(COALESCE(resume_version, '')) != ''
+                GROUP BY resume_version
+                ORDER BY MAX(id) DESC
+            "#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+}
+
+/// A status change, or the status an application started with when `from_status` is `None`
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct JobApplicationEvent {
+    pub id: i64,
+    pub job_application_id: i64,
+    pub from_status: Option<JobApplicationStatus>,
+    pub to_status: JobApplicationStatus,
+    pub occurred_at: SqliteDateTime,
+}
+
+impl JobApplicationEvent {
+    async fn record(
+        job_application_id: i64,
+        from_status: Option<&JobApplicationStatus>,
+        to_status: &JobApplicationStatus,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"INSERT INTO job_application_event (
+                job_application_id, from_status, to_status, occurred_at
+            ) VALUES (?, ?, ?, ?)"#,
+        )
+        .bind(job_application_id)
+        .bind(from_status)
+        .bind(to_status)
+        .bind(SqliteDateTime(chrono::Utc::now()))
         .execute(executor)
         .await?;
 
         Ok(())
     }
+
+    /// The application's history, oldest first
+    pub async fn fetch_for_application(
+        job_application_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(
+            r#"SELECT * FROM job_application_event
+                WHERE job_application_id = ?
+                ORDER BY occurred_at ASC, id ASC"#,
+        )
+        .bind(job_application_id)
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// E.g. "Applied → Interview", or just "Applied" for where it started
+    pub fn summary(&self) -> String {
+        match &self.from_status {
+            Some(from_status) => format!("{} → {}", from_status, self.to_status),
+            None => self.to_status.to_string(),
+        }
+    }
+}
+
+/// An application as a card on the board
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct BoardCard {
+    pub application_id: i64,
+    pub job_post_id: i64,
+    pub job_title: String,
+    pub company_name: String,
+    pub status: JobApplicationStatus,
+    pub date_applied: NullableSqliteDateTime,
+}
+
+impl BoardCard {
+    /// Applications to posts that are shown in the list, most recently applied first
+    pub async fn fetch_all(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(
+            r#"SELECT job_application.id AS application_id, job_post.id AS job_post_id,
+                job_post.job_title, company.name AS company_name, job_application.status,
+                job_application.date_applied
+            FROM job_application
+            JOIN job_post ON job_application.job_post_id = job_post.id
+            JOIN company ON job_post.company_id = company.id
+            WHERE company.hidden = 0 AND job_post.archived = 0
+            ORDER BY job_application.date_applied DESC NULLS FIRST, job_application.id DESC"#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Groups cards by status, with a (possibly empty) column for every status
+    pub fn columns(cards: &[Self]) -> Vec<(JobApplicationStatus, Vec<&Self>)> {
+        JobApplicationStatus::ALL
+            .iter()
+            .map(|status| {
+                let column = cards.iter().filter(|card| &card.status == status).collect();
+                (status.clone(), column)
+            })
+            .collect()
+    }
+}
+
+/// How applications sharing a resume version or channel went
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct ApplicationStats {
+    /// The resume version or stored channel name, empty when none was recorded
+    pub group: String,
+    pub applications: i64,
+    pub responses: i64,
+    pub interviews: i64,
+}
+
+impl ApplicationStats {
+    /// Below this many applications a group's rates are mostly noise
+    pub const MIN_SAMPLE: i64 = 10;
+
+    pub async fn by_resume_version(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        Self::fetch_grouped("TRIM(COALESCE(resume_version, ''))", executor).await
+    }
+
+    pub async fn by_channel(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
+        Self::fetch_grouped("COALESCE(channel, '')", executor).await
+    }
+
+    /// Applications made (anything past New, or with a date applied), grouped by `group`.
+    /// Any reply, interview, or rejection counts as a response
+    async fn fetch_grouped(
+        group: &'static str,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        let mut query = sqlx::QueryBuilder::new("SELECT ");
+        query.push(group);
+        query.push(
+            r#" AS "group",
+                    COUNT(*) AS applications,
+                    SUM(
+                        date_responded IS NOT NULL
+                        OR interviewed = 1
+                        OR status IN ('Interview', 'Offer', 'Rejected')
+                    ) AS responses,
+                    SUM(interviewed = 1 OR status IN ('Interview', 'Offer')) AS interviews
+                FROM job_application
+                WHERE status != 'New' OR date_applied IS NOT NULL
+                GROUP BY 1
+                ORDER BY applications DESC, 1 ASC
+            "#,
+        );
+        query
+            .build_query_as()
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub fn response_rate(&self) -> f64 {
+        Self::rate(self.responses, self.applications)
+    }
+
+    pub fn interview_rate(&self) -> f64 {
+        Self::rate(self.interviews, self.applications)
+    }
+
+    pub fn small_sample(&self) -> bool {
+        self.applications < Self::MIN_SAMPLE
+    }
+
+    fn rate(count: i64, applications: i64) -> f64 {
+        if applications == 0 {
+            0.0
+        } else {
+            count as f64 / applications as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{
+        memory_pool, CompanyBuilder, JobApplicationBuilder, JobPostBuilder,
+    };
+
+    #[tokio::test]
+    async fn stats_are_grouped_by_resume_version_and_channel() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let applications = [
+            ("a", Some("v1"), JobApplicationStatus::Applied, false),
+            ("b", Some("v1"), JobApplicationStatus::Rejected, false),
+            ("c", Some("v2"), JobApplicationStatus::Interview, true),
+            ("d", None, JobApplicationStatus::Applied, false),
+            ("e", Some("v2"), JobApplicationStatus::New, false),
+        ];
+        for (title, version, status, interviewed) in applications {
+            let post = JobPostBuilder::new(company.id, title).insert(&pool).await;
+            let mut builder = JobApplicationBuilder::new(post.id, status);
+            if let Some(version) = version {
+                builder = builder.resume_version(version);
+            }
+            if title == "c" {
+                builder = builder.channel(ApplicationChannel::Referral);
+            }
+            if interviewed {
+                builder = builder.interviewed();
+            }
+            builder.insert(&pool).await;
+        }
+
+        let summary = |stats: &[ApplicationStats]| -> Vec<(String, i64, i64, i64)> {
+            stats
+                .iter()
+                .map(|s| (s.group.clone(), s.applications, s.responses, s.interviews))
+                .collect()
+        };
+        let stats = ApplicationStats::by_resume_version(&pool).await.unwrap();
+        assert_eq!(
+            summary(&stats),
+            [
+                ("v1".to_string(), 2, 1, 0),
+                ("".to_string(), 1, 0, 0),
+                ("v2".to_string(), 1, 1, 1)
+            ]
+        );
+        assert_eq!(stats[0].response_rate(), 0.5);
+        assert!(stats[0].small_sample());
+
+        let stats = ApplicationStats::by_channel(&pool).await.unwrap();
+        assert_eq!(
+            summary(&stats),
+            [("".to_string(), 3, 1, 0), ("Referral".to_string(), 1, 1, 1)]
+        );
+        assert_eq!(stats[1].group.parse(), Ok(ApplicationChannel::Referral));
+
+        let versions = JobApplication::fetch_resume_versions(&pool).await.unwrap();
+        assert_eq!(versions, ["v2", "v1"]);
+    }
+
+    #[tokio::test]
+    async fn moving_cards_updates_status_and_dates() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let post = JobPostBuilder::new(company.id, "Engineer")
+            .insert(&pool)
+            .await;
+        let application = JobApplicationBuilder::new(post.id, JobApplicationStatus::New)
+            .insert(&pool)
+            .await;
+        let hidden = CompanyBuilder::new("Initech").hidden().insert(&pool).await;
+        let hidden_post = JobPostBuilder::new(hidden.id, "Engineer")
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(hidden_post.id, JobApplicationStatus::Applied)
+            .insert(&pool)
+            .await;
+
+        let cards = BoardCard::fetch_all(&pool).await.unwrap();
+        assert_eq!(cards.len(), 1);
+        let columns = BoardCard::columns(&cards);
+        assert_eq!(columns.len(), JobApplicationStatus::ALL.len());
+        assert_eq!(columns[0].0, JobApplicationStatus::New);
+        assert_eq!(columns[0].1.len(), 1);
+
+        JobApplication::set_status(application.id, JobApplicationStatus::Interview, &pool)
+            .await
+            .unwrap();
+        let moved = JobApplication::fetch_one(application.id, &pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(moved.status, JobApplicationStatus::Interview);
+        assert!(moved.date_applied.0.is_some());
+        assert!(moved.date_responded.0.is_some());
+    }
+
+    #[tokio::test]
+    async fn status_changes_are_recorded_as_events() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let post = JobPostBuilder::new(company.id, "Engineer")
+            .insert(&pool)
+            .await;
+        let mut application = JobApplicationBuilder::new(post.id, JobApplicationStatus::Applied)
+            .insert(&pool)
+            .await;
+
+        application.status = JobApplicationStatus::Interview;
+        application.update(&pool).await.unwrap();
+        // Saving other fields leaves no event
+        application.resume_version = Some("v2".to_string());
+        application.update(&pool).await.unwrap();
+        JobApplication::set_status(application.id, JobApplicationStatus::Offer, &pool)
+            .await
+            .unwrap();
+
+        let events = JobApplicationEvent::fetch_for_application(application.id, &pool)
+            .await
+            .unwrap();
+        let summaries: Vec<String> = events.iter().map(JobApplicationEvent::summary).collect();
+        assert_eq!(
+            summaries,
+            ["Applied", "Applied → Interview", "Interview → Offer"]
+        );
+    }
+
+    #[tokio::test]
+    async fn cover_letters_are_saved_with_applications() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let post = JobPostBuilder::new(company.id, "Engineer")
+            .insert(&pool)
+            .await;
+        let mut application = JobApplicationBuilder::new(post.id, JobApplicationStatus::Applied)
+            .insert(&pool)
+            .await;
+
+        application.cover_letter_path = Some("letters/acme.pdf".to_string());
+        application.cover_letter = Some("Dear Acme,".to_string());
+        application.update(&pool).await.unwrap();
+        let saved = JobApplication::fetch_one_by_job_post_id(post.id, &pool)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved.cover_letter_path.as_deref(), Some("letters/acme.pdf"));
+        assert_eq!(saved.cover_letter.as_deref(), Some("Dear Acme,"));
+    }
 }
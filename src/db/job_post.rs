@@ -1,4 +1,14 @@
-use super::{NullableSqliteDateTime, SqliteDateTime};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::company::Company;
+use super::job_application::{JobApplicationFilter, JobApplicationStatus};
+use super::post_rule::{InsertCounts, PostRule};
+use super::tag::Tag;
+use super::{NullableSqliteDateTime, SqliteBoolean, SqliteDateTime};
+use crate::industry::Taxonomy;
+use crate::utils::{compensation, split_comma_separated};
 
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Hash, sqlx::Type, serde::Deserialize, serde::Serialize,
@@ -46,9 +56,8 @@ impl std::str::FromStr for JobPostLocationType {
 impl From<String> for JobPostLocationType {
     fn from(value: String) -> Self {
         use std::str::FromStr;
-        Self::from_str(value.as_str()).expect(&format!(
-            "Expected JobPostLocationType, got {value} instead"
-        ))
+        Self::from_str(value.as_str())
+            .unwrap_or_else(|_| panic!("Expected JobPostLocationType, got {value} instead"))
     }
 }
 
@@ -63,6 +72,276 @@ impl std::fmt::Display for JobPostLocationType {
     }
 }
 
+/// What the job list is sorted by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SortBy {
+    /// Recent application activity first, then the newest posts
+    #[default]
+    Activity,
+    DatePosted,
+    DateRetrieved,
+    /// By yearly pay, taking the top of the range
+    Pay,
+    CompanyName,
+    /// By pipeline stage, posts without an application last
+    Status,
+}
+
+impl SortBy {
+    pub const ALL: [SortBy; 6] = [
+        SortBy::Activity,
+        SortBy::DatePosted,
+        SortBy::DateRetrieved,
+        SortBy::Pay,
+        SortBy::CompanyName,
+        SortBy::Status,
+    ];
+
+    /// The value sorted on; `Activity` has its own fixed order
+    fn sql(&self) -> String {
+        match self {
+            SortBy::Activity => JobPost::DEFAULT_ORDER.to_string(),
+            SortBy::DatePosted => "job_post.date_posted".to_string(),
+            SortBy::DateRetrieved => "job_post.date_retrieved".to_string(),
+            SortBy::Pay => format!(
+                "COALESCE(job_post.max_pay_cents, job_post.min_pay_cents) * ({})",
+                JobPost::yearly_factor_sql()
+            ),
+            SortBy::CompanyName => "company.name COLLATE NOCASE".to_string(),
+            SortBy::Status => {
+                let mut sql = "CASE job_application.status".to_string();
+                for (position, status) in JobApplicationStatus::ALL.iter().enumerate() {
+                    sql.push_str(&format!(" WHEN '{}' THEN {}", status.name(), position));
+                }
+                sql.push_str(" END");
+                sql
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SortBy::Activity => write!(f, "Recent Activity"),
+            SortBy::DatePosted => write!(f, "Date Posted"),
+            SortBy::DateRetrieved => write!(f, "Date Retrieved"),
+            SortBy::Pay => write!(f, "Pay"),
+            SortBy::CompanyName => write!(f, "Company"),
+            SortBy::Status => write!(f, "Status"),
+        }
+    }
+}
+
+/// Filters over job posts, with the application filters nested
+#[derive(Debug, Clone, Default)]
+pub struct JobPostFilter {
+    /// Words to find across titles, skills, benefits, notes, and company names
+    pub search: String,
+    pub title: String,
+    pub location: String,
+    pub min_yoe: i64,
+    pub max_yoe: i64,
+    pub onsite: bool,
+    pub hybrid: bool,
+    pub remote: bool,
+    pub company_ids: Vec<i64>,
+    pub retrieved_within_days: i64,
+    /// Yearly pay the top of a post's range has to reach (0 = any), in `DEFAULT_CURRENCY`; a
+    /// post's own expectation overrides it. Open-ended minimums always pass, and posts in
+    /// another currency without an expectation are left out
+    pub meets_target_cents: i64,
+    /// Yearly pay the top of a post's range, or its minimum when open-ended, has to reach
+    /// (0 = any), in `DEFAULT_CURRENCY`. Posts without pay or in another currency are left out
+    pub min_annual_pay_cents: i64,
+    /// Exact industry name (empty = any)
+    pub industry: String,
+    /// Tag name, ignoring case (empty = any)
+    pub tag: String,
+    pub sort_by: SortBy,
+    pub sort_descending: bool,
+    /// Archived posts instead of the rest
+    pub archived: bool,
+    /// Only starred posts
+    pub favorites_only: bool,
+    pub application: JobApplicationFilter,
+}
+
+impl JobPostFilter {
+    /// The search as an FTS5 query: every word has to appear, as a prefix of a word in the
+    /// post. `None` when there's nothing to search for
+    fn search_query(&self) -> Option<String> {
+        let terms: Vec<String> = self
+            .search
+            .split_whitespace()
+            .map(|word| word.replace('"', ""))
+            .filter(|word| !word.is_empty())
+            .map(|word| format!("\"{word}\"*"))
+            .collect();
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+
+    pub fn add_filters<'a>(
+        &self,
+        mut query: sqlx::QueryBuilder<'a, sqlx::Sqlite>,
+    ) -> sqlx::QueryBuilder<'a, sqlx::Sqlite> {
+        // company.id
+        if !self.company_ids.is_empty() {
+            query.push(" AND company.id IN (");
+            for (i, company_id) in self.company_ids.iter().enumerate() {
+                if i > 0 {
+                    query.push(", ");
+                }
+                query.push_bind(*company_id);
+            }
+            query.push(")");
+        }
+        // years of experience
+        if !(self.min_yoe == self.max_yoe && self.max_yoe == 0) {
+            query.push(" AND min_yoe = ").push_bind(self.min_yoe);
+            if let Some(max_yoe) =
+                (self.max_yoe > 0 && self.max_yoe > self.min_yoe).then_some(self.max_yoe)
+            {
+                query.push(" AND max_yoe <= ").push_bind(max_yoe);
+            }
+        }
+        // full-text search
+        if let Some(search) = self.search_query() {
+            query
+                .push(" AND job_post.id IN (SELECT rowid FROM job_post_search WHERE job_post_search MATCH ")
+                .push_bind(search)
+                .push(")");
+        }
+        // job title or skills
+        if !self.title.is_empty() {
+            let keyword = format!("%{}%", self.title.clone()); // push_bind does the quoting
+            query
+                .push(" AND (job_title LIKE ")
+                .push_bind(keyword.clone())
+                .push(" OR skills LIKE ")
+                .push_bind(keyword)
+                .push(")");
+        }
+        // location
+        if !self.location.is_empty() {
+            query
+                .push(" AND location LIKE ")
+                .push_bind(format!("%{}%", self.location.clone()));
+        }
+
+        // loc types
+        let mut job_loc_types = Vec::with_capacity(3);
+        if self.onsite {
+            job_loc_types.push(JobPostLocationType::Onsite.name());
+        }
+        if self.hybrid {
+            job_loc_types.push(JobPostLocationType::Hybrid.name());
+        }
+        if self.remote {
+            job_loc_types.push(JobPostLocationType::Remote.name());
+        }
+        if !job_loc_types.is_empty() {
+            query.push(" AND location_type IN (");
+            for (i, loc_type) in job_loc_types.iter().enumerate() {
+                if i > 0 {
+                    query.push(", ");
+                }
+                query.push_bind(loc_type.clone());
+            }
+            query.push(")");
+        }
+        // date retrieved
+        if self.retrieved_within_days > 0 {
+            let cutoff = SqliteDateTime(Utc::now() - Duration::days(self.retrieved_within_days));
+            query.push(" AND date_retrieved >= ").push_bind(cutoff);
+        }
+        // pay
+        if self.meets_target_cents > 0 {
+            // The target is only compared with posts in its currency
+            query
+                .push(" AND COALESCE(job_post.max_pay_cents, job_post.min_pay_cents) IS NOT NULL")
+                .push(
+                    " AND (job_post.expected_pay_cents IS NOT NULL OR COALESCE(job_post.currency, ",
+                )
+                .push_bind(DEFAULT_CURRENCY)
+                .push(") = ")
+                .push_bind(DEFAULT_CURRENCY)
+                .push(")")
+                .push(" AND (job_post.max_pay_cents IS NULL OR job_post.max_pay_cents * ")
+                .push(JobPost::yearly_factor_sql())
+                .push(" >= COALESCE(job_post.expected_pay_cents, ")
+                .push_bind(self.meets_target_cents)
+                .push("))");
+        }
+        if self.min_annual_pay_cents > 0 {
+            query
+                .push(" AND COALESCE(job_post.currency, ")
+                .push_bind(DEFAULT_CURRENCY)
+                .push(") = ")
+                .push_bind(DEFAULT_CURRENCY)
+                .push(" AND COALESCE(job_post.max_pay_cents, job_post.min_pay_cents) * ")
+                .push(JobPost::yearly_factor_sql())
+                .push(" >= ")
+                .push_bind(self.min_annual_pay_cents);
+        }
+        // industry
+        if !self.industry.is_empty() {
+            query
+                .push(" AND job_post.industry = ")
+                .push_bind(self.industry.clone());
+        }
+        // tag
+        if !self.tag.is_empty() {
+            query
+                .push(" AND job_post.id IN (SELECT job_post_id FROM job_post_tag JOIN tag ON job_post_tag.tag_id = tag.id WHERE tag.name = ")
+                .push_bind(self.tag.clone())
+                .push(")");
+        }
+        // favorite
+        if self.favorites_only {
+            query.push(" AND job_post.favorite = 1");
+        }
+        // archived
+        query
+            .push(" AND job_post.archived = ")
+            .push_bind(SqliteBoolean(self.archived));
+        // job application
+        query = self.application.add_filters(query);
+        query
+    }
+
+    /// Sorts by `sort_by`, missing values last either way. Otherwise, with a keyword, exact
+    /// title matches come first, then titles containing it, then skills
+    pub fn add_order<'a>(
+        &self,
+        mut query: sqlx::QueryBuilder<'a, sqlx::Sqlite>,
+    ) -> sqlx::QueryBuilder<'a, sqlx::Sqlite> {
+        query.push(" ORDER BY ");
+        if self.sort_by != SortBy::Activity {
+            let value = self.sort_by.sql();
+            let direction = if self.sort_descending { "DESC" } else { "ASC" };
+            query.push(format!(
+                "({value}) IS NULL, {value} {direction}, job_post.id {direction}"
+            ));
+            return query;
+        }
+        if !self.title.is_empty() {
+            query
+                .push("CASE WHEN job_title = ")
+                .push_bind(self.title.clone())
+                .push(" COLLATE NOCASE THEN 0 WHEN job_title LIKE ")
+                .push_bind(format!("%{}%", self.title.clone()))
+                .push(" THEN 1 ELSE 2 END, ");
+        }
+        if self.application.awaiting_response {
+            query.push(JobApplicationFilter::AWAITING_RESPONSE_ORDER);
+        } else {
+            query.push(JobPost::DEFAULT_ORDER);
+        }
+        query
+    }
+}
+
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct JobPost {
     pub id: i64,
@@ -85,17 +364,181 @@ pub struct JobPost {
     pub platform_url: Option<String>,
     pub notes: Option<String>,
     pub industry: Option<String>,
+    pub archived: SqliteBoolean,
+    /// Starred as high priority
+    pub favorite: SqliteBoolean,
+    /// What I'd want from this post specifically, yearly; overrides the target salary setting
+    pub expected_pay_cents: Option<i64>,
+    /// Comma-separated copy of the post's `Tag`s. Saving the post replaces its tags with
+    /// these; post rules add theirs to new posts
+    pub tags: Option<String>,
+    /// The full text of the post as scraped or imported
+    pub description: Option<String>,
+}
+
+/// Posts without a currency are in this one, and so are the target salary and the minimum
+/// pay filter, which only match posts in it
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+/// Where a post's pay range falls against what I'm after
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayGap {
+    /// Even the top of the range is short, by this many cents a year
+    Below(i64),
+    /// The target is somewhere in the range, or above an open-ended minimum
+    InRange,
+    /// Even the bottom of the range reaches it
+    Meets,
+}
+
+/// Seniority and filler words that don't change what a job is
+const TITLE_FILLER_WORDS: [&str; 16] = [
+    "senior",
+    "sr",
+    "junior",
+    "jr",
+    "lead",
+    "staff",
+    "principal",
+    "i",
+    "ii",
+    "iii",
+    "iv",
+    "of",
+    "and",
+    "the",
+    "a",
+    "for",
+];
+
+/// The words that say what a job is, ignoring case, punctuation, and seniority, so
+/// "Sr. Rust Engineer II" and "rust engineer" share theirs
+pub fn title_keywords(title: &str) -> BTreeSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| !word.is_empty() && !TITLE_FILLER_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// A post's yearly pay midpoint, for ranking posts with the same title keywords and currency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaySample {
+    pub title_keywords: BTreeSet<String>,
+    pub currency: String,
+    pub midpoint_cents: i64,
+}
+
+impl PaySample {
+    /// Fewer similar posts than this are too few to rank against
+    pub const MIN_SAMPLE: usize = 5;
 }
 
 impl JobPost {
+    pub fn annual_pay_cents(&self, cents: Option<i64>) -> Option<i64> {
+        cents.map(|cents| compensation::annualize_cents(cents, self.pay_unit.as_deref()))
+    }
+
+    /// Against the post's own expectation, else `target_cents` (yearly, in
+    /// `DEFAULT_CURRENCY`); `None` without a target in the post's currency or any posted pay
+    pub fn pay_gap(&self, target_cents: i64) -> Option<PayGap> {
+        let target = match self.expected_pay_cents {
+            Some(expected) => expected,
+            None if self.currency_or_default() == DEFAULT_CURRENCY => target_cents,
+            None => return None,
+        };
+        if target <= 0 {
+            return None;
+        }
+        let min = self.annual_pay_cents(self.min_pay_cents);
+        let max = self.annual_pay_cents(self.max_pay_cents);
+        if min.is_none() && max.is_none() {
+            return None;
+        }
+        Some(match max {
+            Some(max) if max < target => PayGap::Below(target - max),
+            _ if min.is_some_and(|min| min >= target) => PayGap::Meets,
+            _ => PayGap::InRange,
+        })
+    }
+
+    /// Yearly, halfway between the min and max pay, or whichever one is posted
+    pub fn midpoint_pay_cents(&self) -> Option<i64> {
+        let min = self.annual_pay_cents(self.min_pay_cents);
+        let max = self.annual_pay_cents(self.max_pay_cents);
+        match (min, max) {
+            (Some(min), Some(max)) => Some((min + max) / 2),
+            _ => min.or(max),
+        }
+    }
+
+    pub fn currency_or_default(&self) -> &str {
+        self.currency.as_deref().unwrap_or(DEFAULT_CURRENCY)
+    }
+
+    pub fn pay_sample(&self) -> Option<PaySample> {
+        Some(PaySample {
+            title_keywords: title_keywords(&self.job_title),
+            currency: self.currency_or_default().to_string(),
+            midpoint_cents: self.midpoint_pay_cents()?,
+        })
+    }
+
+    /// Where the post's midpoint falls among similar posts paid in the same currency, as a
+    /// percentile from 0 (lowest) to 100, along with how many similar posts there are. `None`
+    /// without pay or enough similar posts to say
+    pub fn pay_percentile(&self, samples: &[PaySample]) -> Option<(i64, usize)> {
+        let own = self.pay_sample()?;
+        let similar: Vec<i64> = samples
+            .iter()
+            .filter(|sample| {
+                sample.title_keywords == own.title_keywords && sample.currency == own.currency
+            })
+            .map(|sample| sample.midpoint_cents)
+            .collect();
+        if similar.len() < PaySample::MIN_SAMPLE {
+            return None;
+        }
+        let below = similar
+            .iter()
+            .filter(|&&mid| mid < own.midpoint_cents)
+            .count();
+        let equal = similar
+            .iter()
+            .filter(|&&mid| mid == own.midpoint_cents)
+            .count();
+        // Ties count half, so the same pay everywhere lands in the middle
+        let percentile = (below * 200 + equal * 100) / (similar.len() * 2);
+        Some((percentile as i64, similar.len()))
+    }
+
+    /// Pay samples from every stored post with pay, archived and hidden ones included
+    pub async fn fetch_pay_samples(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<PaySample>> {
+        let posts: Vec<Self> = sqlx::query_as(
+            "SELECT * FROM job_post WHERE COALESCE(max_pay_cents, min_pay_cents) IS NOT NULL",
+        )
+        .fetch_all(executor)
+        .await?;
+        Ok(posts.iter().filter_map(Self::pay_sample).collect())
+    }
+
+    /// The pay unit's yearly factor as SQL, so filters agree with `annual_pay_cents`
+    fn yearly_factor_sql() -> String {
+        compensation::yearly_factor_sql("job_post.pay_unit")
+    }
+
     pub const DEFAULT_JOINS: &str = "JOIN company ON job_post.company_id = company.id LEFT JOIN job_application ON job_post.id = job_application.job_post_id";
-    pub const DEFAULT_WHERE: &str = "company.hidden = 0";
+    pub const DEFAULT_WHERE: &str = "company.hidden = 0 AND job_post.archived = 0";
+    /// `DEFAULT_WHERE` without the archived check, which `JobPostFilter` adds either way
+    const FILTER_WHERE: &str = "company.hidden = 0";
     pub const DEFAULT_ORDER: &str = "job_application.date_applied DESC NULLS FIRST, job_application.date_responded DESC, date_posted DESC, date_retrieved DESC";
 
     pub async fn fetch_all(
         page: i64,
         page_size: i64,
-        executor: &sqlx::SqlitePool,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<Vec<Self>> {
         // println!("fetch all");
         let offset = (page - 1) * page_size;
@@ -117,7 +560,7 @@ impl JobPost {
             .map_err(Into::into)
     }
 
-    pub async fn fetch_all_count(executor: &sqlx::SqlitePool) -> anyhow::Result<i64> {
+    pub async fn fetch_all_count(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<i64> {
         let mut query = sqlx::QueryBuilder::new("SELECT COUNT(*) FROM job_post");
         query.push(" ");
         query.push(Self::DEFAULT_JOINS);
@@ -130,78 +573,46 @@ impl JobPost {
             .map_err(Into::into)
     }
 
-    pub fn add_filters(
-        mut query: sqlx::QueryBuilder<'_, sqlx::Sqlite>,
-        title: String,
-        location: String,
-        min_yoe: i64,
-        max_yoe: i64,
-        onsite: bool,
-        hybrid: bool,
-        remote: bool,
-        company_name: String,
-    ) -> sqlx::QueryBuilder<'_, sqlx::Sqlite> {
-        // company.name
-        if !(company_name).is_empty() {
-            query.push(" AND company.name LIKE ");
-            query.push_bind(format!("%{}%", company_name.clone()));
-        }
-        // years of experience
-        if !(min_yoe == max_yoe && max_yoe == 0) {
-            query.push(" AND min_yoe = ").push_bind(min_yoe);
-            if let Some(max_yoe) = (max_yoe > 0 && max_yoe > min_yoe).then_some(max_yoe) {
-                query.push(" AND max_yoe <= ").push_bind(max_yoe);
-            }
-        }
-        // job title
-        if !title.is_empty() {
-            query
-                .push(" AND job_title LIKE ")
-                .push_bind(format!("%{}%", title.clone())); // push_bind does the quoting
-        }
-        // location
-        if !location.is_empty() {
-            query
-                .push(" AND location LIKE ")
-                .push_bind(format!("%{}%", location.clone()));
-        }
+    /// Location strings already in use, most common first
+    pub async fn distinct_locations(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<String>> {
+        sqlx::query_scalar(
+            "SELECT location FROM job_post WHERE location != '' GROUP BY location ORDER BY COUNT(*) DESC, location",
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
 
-        // loc types
-        let mut job_loc_types = Vec::with_capacity(3);
-        if onsite {
-            job_loc_types.push(JobPostLocationType::Onsite.name());
-        }
-        if hybrid {
-            job_loc_types.push(JobPostLocationType::Hybrid.name());
-        }
-        if remote {
-            job_loc_types.push(JobPostLocationType::Remote.name());
-        }
-        if !job_loc_types.is_empty() {
-            query.push(" AND location_type IN (");
-            for (i, loc_type) in job_loc_types.iter().enumerate() {
-                if i > 0 {
-                    query.push(", ");
-                }
-                query.push_bind(loc_type.clone());
-            }
-            query.push(")");
+    /// Skills from every post's comma-separated `skills`, most common first
+    pub async fn distinct_skills(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<String>> {
+        let rows: Vec<String> = sqlx::query_scalar(
+            "SELECT skills FROM job_post WHERE skills IS NOT NULL AND skills != ''",
+        )
+        .fetch_all(executor)
+        .await?;
+        // Counted case-insensitively, keeping the first spelling seen
+        let mut counts: std::collections::HashMap<String, (String, usize)> =
+            std::collections::HashMap::new();
+        for skill in rows.iter().flat_map(|skills| split_comma_separated(skills)) {
+            counts
+                .entry(skill.to_lowercase())
+                .or_insert_with(|| (skill.clone(), 0))
+                .1 += 1;
         }
-        query
+        let mut skills: Vec<(String, usize)> = counts.into_values().collect();
+        skills.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(skills.into_iter().map(|(skill, _)| skill).collect())
     }
 
     pub async fn filter(
         page: i64,
         page_size: i64,
-        title: String,
-        location: String,
-        min_yoe: i64,
-        max_yoe: i64,
-        onsite: bool,
-        hybrid: bool,
-        remote: bool,
-        company_name: String,
-        executor: &sqlx::SqlitePool,
+        filter: JobPostFilter,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<Vec<JobPost>> {
         let offset = (page - 1) * page_size;
         let mut query = sqlx::QueryBuilder::new("SELECT job_post.* FROM job_post");
@@ -210,21 +621,10 @@ impl JobPost {
         // WHERE
         query.push(" WHERE ");
         // company.hidden
-        query.push(Self::DEFAULT_WHERE);
-        query = Self::add_filters(
-            query,
-            title,
-            location,
-            min_yoe,
-            max_yoe,
-            onsite,
-            hybrid,
-            remote,
-            company_name,
-        );
+        query.push(Self::FILTER_WHERE);
+        query = filter.add_filters(query);
         // ORDER BY
-        query.push(" ORDER BY ");
-        query.push(Self::DEFAULT_ORDER);
+        query = filter.add_order(query);
         query.push(" LIMIT ");
         query.push_bind(page_size);
         query.push(" OFFSET ");
@@ -239,32 +639,15 @@ impl JobPost {
     }
 
     pub async fn filter_count(
-        title: String,
-        location: String,
-        min_yoe: i64,
-        max_yoe: i64,
-        onsite: bool,
-        hybrid: bool,
-        remote: bool,
-        company_name: String,
-        executor: &sqlx::SqlitePool,
+        filter: JobPostFilter,
+        executor: impl sqlx::SqliteExecutor<'_>,
     ) -> anyhow::Result<i64> {
         let mut query = sqlx::QueryBuilder::new("SELECT COUNT(*) from job_post");
         query.push(" ");
         query.push(Self::DEFAULT_JOINS);
         query.push(" WHERE ");
-        query.push(Self::DEFAULT_WHERE);
-        query = Self::add_filters(
-            query,
-            title,
-            location,
-            min_yoe,
-            max_yoe,
-            onsite,
-            hybrid,
-            remote,
-            company_name,
-        );
+        query.push(Self::FILTER_WHERE);
+        query = filter.add_filters(query);
         query
             .build_query_scalar()
             .fetch_one(executor)
@@ -272,9 +655,14 @@ impl JobPost {
             .map_err(Into::into)
     }
 
-    pub async fn update(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<Self> {
+    /// Saves the post, replacing its tags with `tags`
+    pub async fn update(
+        &self,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<Self> {
+        let mut tx = executor.begin().await?;
         let posted = self.date_posted.timestamp();
-        let updated = sqlx::query_as::<_, Self>(
+        sqlx::query(
             r#"UPDATE job_post
                 SET
                     location = ?,
@@ -290,9 +678,14 @@ impl JobPost {
                     skills = ?,
                     date_retrieved = ?,
                     company_id = ?,
-                    apijobs_id = ?
+                    apijobs_id = ?,
+                    pay_unit = ?,
+                    currency = ?,
+                    expected_pay_cents = ?,
+                    notes = ?,
+                    description = ?,
+                    platform_url = ?
                 WHERE id = ?
-                RETURNING *
             "#,
         )
         .bind(self.location.clone())
@@ -309,14 +702,138 @@ impl JobPost {
         .bind(self.date_retrieved)
         .bind(self.company_id)
         .bind(self.apijobs_id.clone())
+        .bind(self.pay_unit.clone())
+        .bind(self.currency.clone())
+        .bind(self.expected_pay_cents)
+        .bind(self.notes.clone())
+        .bind(self.description.clone())
+        .bind(self.platform_url.clone())
         .bind(self.id)
-        .fetch_one(executor)
+        .execute(&mut *tx)
         .await?;
+        Tag::set_for_post(self.id, self.tags.as_deref().unwrap_or_default(), &mut *tx).await?;
+        let updated = sqlx::query_as("SELECT * FROM job_post WHERE id = ?")
+            .bind(self.id)
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
 
         Ok(updated)
     }
 
-    pub async fn delete(id: i64, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
+    /// Archives posts older than `max_age_days` that were never applied to
+    pub async fn archive_stale(
+        max_age_days: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<u64> {
+        let cutoff = SqliteDateTime(Utc::now() - Duration::days(max_age_days));
+        let res = sqlx::query(
+            r#"UPDATE job_post
+                SET archived = 1
+                WHERE archived = 0
+                AND COALESCE(date_posted, date_retrieved) < ?
+                AND id NOT IN (SELECT job_post_id FROM job_application)
+            "#,
+        )
+        .bind(cutoff)
+        .execute(executor)
+        .await?;
+
+        Ok(res.rows_affected())
+    }
+
+    pub async fn archive(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query("UPDATE job_post SET archived = 1 WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Brings an archived post back to the main list
+    pub async fn restore(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query("UPDATE job_post SET archived = 0 WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stars or unstars a post
+    pub async fn set_favorite(
+        id: i64,
+        favorite: bool,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE job_post SET favorite = ? WHERE id = ?")
+            .bind(SqliteBoolean(favorite))
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Puts off reviewing a post; it comes back in the first digest after `until`
+    pub async fn snooze(
+        id: i64,
+        until: DateTime<Utc>,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE job_post SET snoozed_until = ? WHERE id = ?")
+            .bind(SqliteDateTime(until))
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes every post (and its application) matching the filters in one transaction
+    pub async fn delete_where(
+        filter: JobPostFilter,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<u64> {
+        let mut tx = executor.begin().await?;
+
+        let mut query = sqlx::QueryBuilder::new("SELECT job_post.id FROM job_post");
+        query.push(" ");
+        query.push(Self::DEFAULT_JOINS);
+        query.push(" WHERE ");
+        query.push(Self::FILTER_WHERE);
+        query = filter.add_filters(query);
+        let ids: Vec<i64> = query.build_query_scalar().fetch_all(&mut *tx).await?;
+
+        // Stay well under SQLite's bound parameter limit
+        for chunk in ids.chunks(500) {
+            let mut delete_apps =
+                sqlx::QueryBuilder::new("DELETE FROM job_application WHERE job_post_id IN (");
+            let mut delete_posts = sqlx::QueryBuilder::new("DELETE FROM job_post WHERE id IN (");
+            for (i, id) in chunk.iter().enumerate() {
+                if i > 0 {
+                    delete_apps.push(", ");
+                    delete_posts.push(", ");
+                }
+                delete_apps.push_bind(*id);
+                delete_posts.push_bind(*id);
+            }
+            delete_apps.push(")");
+            delete_posts.push(")");
+            delete_apps.build().execute(&mut *tx).await?;
+            delete_posts.build().execute(&mut *tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(ids.len() as u64)
+    }
+
+    pub async fn delete(
+        id: i64,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<()> {
         // println!("id: {}", id);
         let mut tx = executor.begin().await?;
 
@@ -324,7 +841,7 @@ impl JobPost {
             .execute(&mut *tx)
             .await
             .map_err(|e| {
-                eprintln!("Failed to delete job_application: {}", e);
+                tracing::error!("Failed to delete job_application: {}", e);
                 e
             })?;
 
@@ -332,7 +849,7 @@ impl JobPost {
             .execute(&mut *tx)
             .await
             .map_err(|e| {
-                eprintln!("Failed to delete job_post: {}", e);
+                tracing::error!("Failed to delete job_post: {}", e);
                 e
             })?;
 
@@ -341,34 +858,1097 @@ impl JobPost {
         Ok(())
     }
 
-    pub async fn insert(&self, executor: &sqlx::SqlitePool) -> anyhow::Result<()> {
-        sqlx::query!(
+    /// Classifies posts that haven't been yet, by company name, title, skills, benefits, and
+    /// description. Posts no industry matches get an empty one so they aren't checked again.
+    /// Returns the number of posts given an industry
+    pub async fn classify_industries(
+        taxonomy: &Taxonomy,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<u64> {
+        let mut tx = executor.begin().await?;
+        // Id, company name, title, skills, benefits, and description
+        type Post = (
+            i64,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+        );
+        let posts: Vec<Post> = sqlx::query_as(
+            r#"SELECT job_post.id, company.name, job_post.job_title, job_post.skills,
+                job_post.benefits, job_post.description
+            FROM job_post JOIN company ON job_post.company_id = company.id
+            WHERE job_post.industry IS NULL"#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut classified = 0;
+        for (id, company_name, job_title, skills, benefits, description) in posts {
+            let text = format!(
+                "{} {} {} {} {}",
+                company_name,
+                job_title,
+                skills.unwrap_or_default(),
+                benefits.unwrap_or_default(),
+                description.unwrap_or_default()
+            );
+            let industry = taxonomy.classify(&text);
+            classified += industry.is_some() as u64;
+            sqlx::query("UPDATE job_post SET industry = ? WHERE id = ?")
+                .bind(industry.unwrap_or_default())
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(classified)
+    }
+
+    /// Forgets every post's industry, including that none matched, so the next
+    /// `classify_industries` goes over them all with an edited taxonomy
+    pub async fn reset_industries(
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<u64> {
+        let mut conn = executor.acquire().await?;
+        let res = sqlx::query("UPDATE job_post SET industry = NULL")
+            .execute(&mut *conn)
+            .await?;
+        Ok(res.rows_affected())
+    }
+
+    /// The name post rules match against, empty for a missing company
+    async fn company_name(
+        company_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<String> {
+        Ok(Company::fetch_one(company_id, executor)
+            .await?
+            .map(|company| company.name)
+            .unwrap_or_default())
+    }
+
+    /// Inserts the post, tagged or archived by the enabled post rules
+    pub async fn insert(
+        &self,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<(i64, InsertCounts)> {
+        let mut tx = executor.begin().await?;
+        let rules = PostRule::fetch_enabled(&mut *tx).await?;
+        let company_name = Self::company_name(self.company_id, &mut *tx).await?;
+        let mut job_post = self.clone();
+        PostRule::apply_all(&rules, &mut job_post, &company_name);
+        let job_post = &job_post;
+
+        let id = sqlx::query!(
             r#"INSERT INTO job_post (
                 location, location_type, url,
                 min_yoe, max_yoe, min_pay_cents,
                 max_pay_cents, date_posted, job_title,
-                benefits, skills, date_retrieved, company_id, apijobs_id
+                benefits, skills, date_retrieved, company_id, apijobs_id,
+                currency, pay_unit, expected_pay_cents, notes, archived, description,
+                platform_url
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+                $19, $20, $21
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             "#,
-            self.location,
-            self.location_type,
-            self.url,
-            self.min_yoe,
-            self.max_yoe,
-            self.min_pay_cents,
-            self.max_pay_cents,
-            self.date_posted,
-            self.job_title,
-            self.benefits,
-            self.skills,
-            self.date_retrieved,
-            self.company_id,
-            self.apijobs_id,
+            job_post.location,
+            job_post.location_type,
+            job_post.url,
+            job_post.min_yoe,
+            job_post.max_yoe,
+            job_post.min_pay_cents,
+            job_post.max_pay_cents,
+            job_post.date_posted,
+            job_post.job_title,
+            job_post.benefits,
+            job_post.skills,
+            job_post.date_retrieved,
+            job_post.company_id,
+            job_post.apijobs_id,
+            job_post.currency,
+            job_post.pay_unit,
+            job_post.expected_pay_cents,
+            job_post.notes,
+            job_post.archived,
+            job_post.description,
+            job_post.platform_url,
         )
-        .execute(executor)
-        .await?;
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+        Tag::set_for_post(id, job_post.tags.as_deref().unwrap_or_default(), &mut *tx).await?;
 
-        Ok(())
+        tx.commit().await?;
+
+        Ok((
+            id,
+            InsertCounts {
+                inserted: 1,
+                archived: job_post.archived.0 as usize,
+            },
+        ))
+    }
+
+    /// Inserts the posts in one transaction, many rows per statement, tagged or archived by
+    /// the enabled post rules
+    pub async fn insert_many(
+        job_posts: &[JobPost],
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<InsertCounts> {
+        let mut tx = executor.begin().await?;
+        let rules = PostRule::fetch_enabled(&mut *tx).await?;
+        let mut company_names = std::collections::HashMap::new();
+        let mut ruled_posts = Vec::with_capacity(job_posts.len());
+        for job_post in job_posts {
+            let company_name = match company_names.get(&job_post.company_id) {
+                Some(name) => name,
+                None => {
+                    let name = Self::company_name(job_post.company_id, &mut *tx).await?;
+                    company_names.entry(job_post.company_id).or_insert(name)
+                }
+            };
+            let mut job_post = job_post.clone();
+            PostRule::apply_all(&rules, &mut job_post, company_name);
+            ruled_posts.push(job_post);
+        }
+
+        let mut inserted = 0;
+        // 21 columns per row keeps each statement under SQLite's default 999 bound parameters
+        for chunk in ruled_posts.chunks(47) {
+            let mut query = sqlx::QueryBuilder::new(
+                r#"INSERT INTO job_post (
+                    location, location_type, url,
+                    min_yoe, max_yoe, min_pay_cents,
+                    max_pay_cents, date_posted, job_title,
+                    benefits, skills, date_retrieved, company_id, apijobs_id,
+                    pay_unit, currency, expected_pay_cents, notes, archived, description,
+                    platform_url
+                ) "#,
+            );
+            query.push_values(chunk, |mut row, job_post| {
+                row.push_bind(job_post.location.clone())
+                    .push_bind(job_post.location_type)
+                    .push_bind(job_post.url.clone())
+                    .push_bind(job_post.min_yoe)
+                    .push_bind(job_post.max_yoe)
+                    .push_bind(job_post.min_pay_cents)
+                    .push_bind(job_post.max_pay_cents)
+                    .push_bind(job_post.date_posted)
+                    .push_bind(job_post.job_title.clone())
+                    .push_bind(job_post.benefits.clone())
+                    .push_bind(job_post.skills.clone())
+                    .push_bind(job_post.date_retrieved)
+                    .push_bind(job_post.company_id)
+                    .push_bind(job_post.apijobs_id.clone())
+                    .push_bind(job_post.pay_unit.clone())
+                    .push_bind(job_post.currency.clone())
+                    .push_bind(job_post.expected_pay_cents)
+                    .push_bind(job_post.notes.clone())
+                    .push_bind(job_post.archived)
+                    .push_bind(job_post.description.clone())
+                    .push_bind(job_post.platform_url.clone());
+            });
+            query.push(" RETURNING id");
+            // Rowids go up in the order the rows were listed
+            let mut ids: Vec<i64> = query.build_query_scalar().fetch_all(&mut *tx).await?;
+            ids.sort_unstable();
+            inserted += ids.len() as u64;
+            for (id, job_post) in ids.into_iter().zip(chunk) {
+                if let Some(tags) = job_post.tags.as_deref() {
+                    Tag::set_for_post(id, tags, &mut *tx).await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(InsertCounts {
+            inserted: inserted as usize,
+            archived: ruled_posts.iter().filter(|post| post.archived.0).count(),
+        })
+    }
+}
+
+#[cfg(test)]
+// Cents are written apart from the dollars, e.g. 85_000_00
+#[allow(clippy::inconsistent_digit_grouping)]
+mod tests {
+    use chrono::{Duration, NaiveDate, Utc};
+
+    use super::*;
+    use crate::db::job_application::JobApplicationStatus;
+    use crate::db::test_support::{
+        count_rows, memory_pool, CompanyBuilder, JobApplicationBuilder, JobPostBuilder,
+    };
+
+    fn titles(job_posts: &[JobPost]) -> Vec<&str> {
+        job_posts
+            .iter()
+            .map(|post| post.job_title.as_str())
+            .collect()
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn filter_matches_title_location_and_type() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        JobPostBuilder::new(company.id, "Rust Engineer")
+            .location("Berlin", JobPostLocationType::Remote)
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Senior Rust Engineer")
+            .location("Austin", JobPostLocationType::Onsite)
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Go Engineer")
+            .location("Berlin", JobPostLocationType::Remote)
+            .insert(&pool)
+            .await;
+
+        let filter = JobPostFilter {
+            title: "rust".to_string(),
+            location: "berlin".to_string(),
+            remote: true,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter.clone(), &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Rust Engineer"]);
+        assert_eq!(JobPost::filter_count(filter, &pool).await.unwrap(), 1);
+
+        let filter = JobPostFilter {
+            onsite: true,
+            hybrid: true,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Senior Rust Engineer"]);
+    }
+
+    #[tokio::test]
+    async fn filter_ranks_keyword_matches_by_relevance() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        JobPostBuilder::new(company.id, "Backend Engineer")
+            .skills("Rust, SQL")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Senior Rust Engineer")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "rust").insert(&pool).await;
+        JobPostBuilder::new(company.id, "Frontend Engineer")
+            .skills("TypeScript")
+            .insert(&pool)
+            .await;
+
+        let filter = JobPostFilter {
+            title: "Rust".to_string(),
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter.clone(), &pool).await.unwrap();
+        assert_eq!(
+            titles(&posts),
+            ["rust", "Senior Rust Engineer", "Backend Engineer"]
+        );
+        assert_eq!(JobPost::filter_count(filter, &pool).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn filter_skips_hidden_companies_and_matches_company_ids() {
+        let pool = memory_pool().await;
+        let shown = CompanyBuilder::new("Shown").insert(&pool).await;
+        let other = CompanyBuilder::new("Other").insert(&pool).await;
+        let hidden = CompanyBuilder::new("Hidden").hidden().insert(&pool).await;
+        JobPostBuilder::new(shown.id, "Shown Post")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(other.id, "Other Post")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(hidden.id, "Hidden Post")
+            .insert(&pool)
+            .await;
+
+        let all = JobPostFilter::default();
+        assert_eq!(JobPost::filter_count(all, &pool).await.unwrap(), 2);
+
+        let filter = JobPostFilter {
+            company_ids: vec![shown.id, hidden.id],
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Shown Post"]);
+    }
+
+    #[tokio::test]
+    async fn filter_shows_archived_posts_only_when_asked() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        JobPostBuilder::new(company.id, "Open").insert(&pool).await;
+        let closed = JobPostBuilder::new(company.id, "Closed")
+            .insert(&pool)
+            .await;
+        JobPost::archive(closed.id, &pool).await.unwrap();
+
+        let posts = JobPost::filter(1, 10, JobPostFilter::default(), &pool)
+            .await
+            .unwrap();
+        assert_eq!(titles(&posts), ["Open"]);
+        let archived = JobPostFilter {
+            archived: true,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, archived.clone(), &pool)
+            .await
+            .unwrap();
+        assert_eq!(titles(&posts), ["Closed"]);
+
+        JobPost::restore(closed.id, &pool).await.unwrap();
+        assert_eq!(JobPost::filter_count(archived, &pool).await.unwrap(), 0);
+        let all = JobPostFilter::default();
+        assert_eq!(JobPost::filter_count(all, &pool).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn filter_keeps_favorites_when_asked() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let starred = JobPostBuilder::new(company.id, "Starred")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Plain").insert(&pool).await;
+        JobPost::set_favorite(starred.id, true, &pool)
+            .await
+            .unwrap();
+
+        let filter = JobPostFilter {
+            favorites_only: true,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter.clone(), &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Starred"]);
+        assert!(posts[0].favorite.0);
+
+        JobPost::set_favorite(starred.id, false, &pool)
+            .await
+            .unwrap();
+        assert_eq!(JobPost::filter_count(filter, &pool).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn filter_matches_yoe_and_retrieved_days() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        JobPostBuilder::new(company.id, "Junior")
+            .yoe(1, Some(3))
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Mid")
+            .yoe(3, Some(5))
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Old Mid")
+            .yoe(3, Some(5))
+            .retrieved(Utc::now() - Duration::days(30))
+            .insert(&pool)
+            .await;
+
+        let filter = JobPostFilter {
+            min_yoe: 3,
+            max_yoe: 5,
+            retrieved_within_days: 7,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter.clone(), &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Mid"]);
+        assert_eq!(JobPost::filter_count(filter, &pool).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn filter_pages_while_count_covers_every_match() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        for i in 0..5 {
+            JobPostBuilder::new(company.id, &format!("Engineer {i}"))
+                .insert(&pool)
+                .await;
+        }
+
+        let filter = JobPostFilter::default();
+        let first = JobPost::filter(1, 2, filter.clone(), &pool).await.unwrap();
+        let last = JobPost::filter(3, 2, filter.clone(), &pool).await.unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(last.len(), 1);
+        assert_eq!(JobPost::filter_count(filter, &pool).await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn filter_awaiting_response_orders_longest_wait_first() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let recent = JobPostBuilder::new(company.id, "Recent")
+            .insert(&pool)
+            .await;
+        let oldest = JobPostBuilder::new(company.id, "Oldest")
+            .insert(&pool)
+            .await;
+        let answered = JobPostBuilder::new(company.id, "Answered")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(company.id, "Not Applied")
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(recent.id, JobApplicationStatus::Applied)
+            .applied(date(10))
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(oldest.id, JobApplicationStatus::Applied)
+            .applied(date(1))
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(answered.id, JobApplicationStatus::Interview)
+            .applied(date(2))
+            .responded(date(5))
+            .interviewed()
+            .insert(&pool)
+            .await;
+
+        let filter = JobPostFilter {
+            application: JobApplicationFilter {
+                awaiting_response: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Oldest", "Recent"]);
+
+        let filter = JobPostFilter {
+            application: JobApplicationFilter {
+                interviewed: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Answered"]);
+    }
+
+    #[tokio::test]
+    async fn insert_many_spans_several_statements() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let job_posts: Vec<JobPost> = (0..150)
+            .map(|i| JobPostBuilder::new(company.id, &format!("Engineer {i}")).build())
+            .collect();
+
+        assert_eq!(
+            JobPost::insert_many(&job_posts, &pool)
+                .await
+                .unwrap()
+                .inserted,
+            150
+        );
+        assert_eq!(count_rows("job_post", &pool).await, 150);
+        assert_eq!(JobPost::insert_many(&[], &pool).await.unwrap().inserted, 0);
+    }
+
+    /// Times the filter and count queries on a large database, with and without the filter
+    /// indexes. Run with `cargo test --release filter_index_benchmark -- --ignored --nocapture`.
+    #[tokio::test]
+    #[ignore]
+    async fn filter_index_benchmark() {
+        const FILTER_INDEXES: [&str; 5] = [
+            "idx_job_post_company_id",
+            "idx_job_post_location_type",
+            "idx_job_post_date_posted",
+            "idx_job_application_job_post_id",
+            "idx_company_name",
+        ];
+
+        async fn time_queries(
+            filter: &JobPostFilter,
+            pool: &sqlx::SqlitePool,
+        ) -> std::time::Duration {
+            let start = std::time::Instant::now();
+            for page in 1..=20 {
+                JobPost::filter(page, 10, filter.clone(), pool)
+                    .await
+                    .unwrap();
+                JobPost::filter_count(filter.clone(), pool).await.unwrap();
+            }
+            start.elapsed()
+        }
+
+        let pool = memory_pool().await;
+        let mut company_ids = Vec::new();
+        for i in 0..200 {
+            let company = CompanyBuilder::new(&format!("Company {i}"))
+                .insert(&pool)
+                .await;
+            company_ids.push(company.id);
+        }
+        let job_posts: Vec<JobPost> = (0..50_000)
+            .map(|i| {
+                let location_type = if i % 3 == 0 {
+                    JobPostLocationType::Remote
+                } else {
+                    JobPostLocationType::Onsite
+                };
+                JobPostBuilder::new(company_ids[i % company_ids.len()], &format!("Engineer {i}"))
+                    .location("Anywhere", location_type)
+                    .build()
+            })
+            .collect();
+        JobPost::insert_many(&job_posts, &pool).await.unwrap();
+        sqlx::query(
+            "INSERT INTO job_application (status, job_post_id, interviewed)
+            SELECT 'Applied', id, 0 FROM job_post WHERE id % 10 = 0",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let filter = JobPostFilter {
+            company_ids: company_ids[..5].to_vec(),
+            remote: true,
+            ..Default::default()
+        };
+        crate::db::optimize(&pool).await.unwrap();
+        // Warms the cache so the first timing doesn't pay for it
+        time_queries(&filter, &pool).await;
+        let indexed = time_queries(&filter, &pool).await;
+        for index in FILTER_INDEXES {
+            sqlx::query(&format!("DROP INDEX {index}"))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        let unindexed = time_queries(&filter, &pool).await;
+
+        println!(
+            "20 pages of filter + count over 50,000 posts: {indexed:?} with indexes, {unindexed:?} without"
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_takes_the_application_with_it() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let post = JobPostBuilder::new(company.id, "Engineer")
+            .insert(&pool)
+            .await;
+        let kept = JobPostBuilder::new(company.id, "Kept").insert(&pool).await;
+        JobApplicationBuilder::new(post.id, JobApplicationStatus::Applied)
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(kept.id, JobApplicationStatus::Applied)
+            .insert(&pool)
+            .await;
+
+        JobPost::delete(post.id, &pool).await.unwrap();
+
+        assert_eq!(count_rows("job_post", &pool).await, 1);
+        assert_eq!(count_rows("job_application", &pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn delete_where_only_removes_matching_posts() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let rust = JobPostBuilder::new(company.id, "Rust Engineer")
+            .insert(&pool)
+            .await;
+        let go = JobPostBuilder::new(company.id, "Go Engineer")
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(rust.id, JobApplicationStatus::New)
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(go.id, JobApplicationStatus::New)
+            .insert(&pool)
+            .await;
+
+        let filter = JobPostFilter {
+            title: "Rust".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(JobPost::delete_where(filter, &pool).await.unwrap(), 1);
+
+        let remaining = JobPost::filter(1, 10, JobPostFilter::default(), &pool)
+            .await
+            .unwrap();
+        assert_eq!(titles(&remaining), ["Go Engineer"]);
+        assert_eq!(count_rows("job_application", &pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn pay_is_compared_against_the_target_per_year() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let target = 100_000_00;
+        let posts = [
+            ("Low", None, Some(80_000_00), "year", None),
+            ("Hourly", Some(50_00), Some(60_00), "hour", None),
+            ("Range", Some(90_000_00), Some(120_000_00), "year", None),
+            ("Open", Some(70_000_00), None, "year", None),
+            ("Unpaid", None, None, "year", None),
+            ("Modest", None, Some(80_000_00), "year", Some(70_000_00)),
+        ];
+        let mut gaps = Vec::new();
+        for (title, min, max, unit, expected) in posts {
+            let mut builder = JobPostBuilder::new(company.id, title).pay(min, max, unit);
+            if let Some(expected) = expected {
+                builder = builder.expected_pay(expected);
+            }
+            gaps.push(builder.insert(&pool).await.pay_gap(target));
+        }
+        // The target is in dollars, so yen can't be held to it
+        let yen = JobPostBuilder::new(company.id, "Yen")
+            .pay(Some(15_000_000_00), None, "year")
+            .currency("JPY")
+            .insert(&pool)
+            .await;
+        assert_eq!(yen.pay_gap(target), None);
+        assert_eq!(
+            gaps,
+            [
+                Some(PayGap::Below(20_000_00)),
+                Some(PayGap::Meets),
+                Some(PayGap::InRange),
+                Some(PayGap::InRange),
+                None,
+                Some(PayGap::InRange),
+            ]
+        );
+
+        let filter = JobPostFilter {
+            meets_target_cents: target,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        let mut titles = titles(&posts);
+        titles.sort();
+        assert_eq!(titles, ["Hourly", "Modest", "Open", "Range"]);
+    }
+
+    #[tokio::test]
+    async fn pay_is_ranked_against_similar_titles() {
+        assert_eq!(
+            title_keywords("Sr. Rust Engineer II"),
+            title_keywords("rust engineer")
+        );
+        assert_ne!(
+            title_keywords("Rust Engineer"),
+            title_keywords("Go Engineer")
+        );
+
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let mut posts = Vec::new();
+        for (title, min, max) in [
+            ("Rust Engineer", 80_000_00, 100_000_00),
+            ("Senior Rust Engineer", 140_000_00, 160_000_00),
+            ("Rust Engineer", 100_000_00, 120_000_00),
+            ("Rust Engineer II", 110_000_00, 130_000_00),
+            ("Lead Rust Engineer", 120_000_00, 140_000_00),
+            ("Go Engineer", 300_000_00, 400_000_00),
+        ] {
+            let post = JobPostBuilder::new(company.id, title)
+                .pay(Some(min), Some(max), "year")
+                .insert(&pool)
+                .await;
+            posts.push(post);
+        }
+        JobPostBuilder::new(company.id, "Rust Engineer")
+            .insert(&pool)
+            .await;
+        // Not ranked against dollars, nor they against it
+        let euros = JobPostBuilder::new(company.id, "Rust Engineer")
+            .pay(Some(1_000_00), Some(2_000_00), "year")
+            .currency("EUR")
+            .insert(&pool)
+            .await;
+
+        let samples = JobPost::fetch_pay_samples(&pool).await.unwrap();
+        assert_eq!(samples.len(), 7);
+        assert_eq!(euros.pay_percentile(&samples), None);
+        assert_eq!(posts[1].pay_percentile(&samples), Some((90, 5)));
+        assert_eq!(posts[0].pay_percentile(&samples), Some((10, 5)));
+        assert_eq!(posts[3].pay_percentile(&samples), Some((50, 5)));
+        // Too few Go posts to rank
+        assert_eq!(posts[5].pay_percentile(&samples), None);
+    }
+
+    #[tokio::test]
+    async fn posts_are_classified_by_industry_once() {
+        let pool = memory_pool().await;
+        let bank = CompanyBuilder::new("Acme Payments").insert(&pool).await;
+        let clinic = CompanyBuilder::new("Northwind").insert(&pool).await;
+        JobPostBuilder::new(bank.id, "Backend Engineer")
+            .skills("Rust, banking APIs")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(clinic.id, "Clinical Data Engineer")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(clinic.id, "Engineer")
+            .insert(&pool)
+            .await;
+        // Only the description says what it's for
+        JobPostBuilder::new(clinic.id, "Platform Engineer")
+            .description("Build the tools our patient-facing medical apps run on")
+            .insert(&pool)
+            .await;
+
+        let taxonomy = Taxonomy::default();
+        assert_eq!(
+            JobPost::classify_industries(&taxonomy, &pool)
+                .await
+                .unwrap(),
+            3
+        );
+        // Already checked, including the post nothing matched
+        assert_eq!(
+            JobPost::classify_industries(&taxonomy, &pool)
+                .await
+                .unwrap(),
+            0
+        );
+
+        let filter = JobPostFilter {
+            industry: "Fintech".to_string(),
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].job_title, "Backend Engineer");
+        let filter = JobPostFilter {
+            industry: "Healthcare".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(JobPost::filter_count(filter, &pool).await.unwrap(), 2);
+
+        // An edited taxonomy goes over every post again, the unmatched one too
+        let taxonomy = Taxonomy::parse(
+            r#"
+            [industries]
+            Infrastructure = ["engineer"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(JobPost::reset_industries(&pool).await.unwrap(), 4);
+        assert_eq!(
+            JobPost::classify_industries(&taxonomy, &pool)
+                .await
+                .unwrap(),
+            4
+        );
+    }
+
+    #[tokio::test]
+    async fn filter_sorts_by_the_chosen_column() {
+        let pool = memory_pool().await;
+        let acme = CompanyBuilder::new("acme").insert(&pool).await;
+        let zeta = CompanyBuilder::new("Zeta").insert(&pool).await;
+        JobPostBuilder::new(zeta.id, "Hourly")
+            .pay(Some(5_000), Some(6_000), "hour")
+            .insert(&pool)
+            .await;
+        JobPostBuilder::new(acme.id, "Unpaid").insert(&pool).await;
+        JobPostBuilder::new(acme.id, "Salaried")
+            .pay(Some(9_000_000), Some(10_000_000), "year")
+            .insert(&pool)
+            .await;
+
+        let titles = |posts: Vec<JobPost>| -> Vec<String> {
+            posts.into_iter().map(|post| post.job_title).collect()
+        };
+        let filter = JobPostFilter {
+            sort_by: SortBy::Pay,
+            sort_descending: true,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(posts), ["Hourly", "Salaried", "Unpaid"]);
+
+        // Posts without pay stay last either way
+        let filter = JobPostFilter {
+            sort_by: SortBy::Pay,
+            sort_descending: false,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(posts), ["Salaried", "Hourly", "Unpaid"]);
+
+        let filter = JobPostFilter {
+            sort_by: SortBy::CompanyName,
+            sort_descending: false,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(posts), ["Unpaid", "Salaried", "Hourly"]);
+    }
+
+    #[tokio::test]
+    async fn search_matches_words_across_fields() {
+        let pool = memory_pool().await;
+        let acme = CompanyBuilder::new("Acme Robotics").insert(&pool).await;
+        let globex = CompanyBuilder::new("Globex").insert(&pool).await;
+        JobPostBuilder::new(acme.id, "Firmware Engineer")
+            .skills("C, embedded Linux")
+            .insert(&pool)
+            .await;
+        let analyst = JobPostBuilder::new(globex.id, "Data Analyst")
+            .skills("SQL, Python")
+            .insert(&pool)
+            .await;
+
+        let count = |search: &str| {
+            let filter = JobPostFilter {
+                search: search.to_string(),
+                ..Default::default()
+            };
+            JobPost::filter_count(filter, &pool)
+        };
+        assert_eq!(count("robot").await.unwrap(), 1);
+        assert_eq!(count("embed engineer").await.unwrap(), 1);
+        assert_eq!(count("python firmware").await.unwrap(), 0);
+        assert_eq!(count("\"").await.unwrap(), 2);
+
+        // Kept in step with edits to posts and companies
+        sqlx::query("UPDATE job_post SET notes = 'Referral from Sam' WHERE id = ?")
+            .bind(analyst.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count("referral").await.unwrap(), 1);
+        sqlx::query("UPDATE company SET name = 'Initech' WHERE id = ?")
+            .bind(globex.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count("globex").await.unwrap(), 0);
+        assert_eq!(count("initech").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn descriptions_are_saved_and_searchable() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let scraped = JobPostBuilder::new(company.id, "Backend Engineer")
+            .description("You'll own our Kubernetes clusters.")
+            .insert(&pool)
+            .await;
+        let imported = [JobPostBuilder::new(company.id, "Data Engineer")
+            .description("Build pipelines in Airflow.")
+            .build()];
+        JobPost::insert_many(&imported, &pool).await.unwrap();
+
+        let count = |search: &str| {
+            let filter = JobPostFilter {
+                search: search.to_string(),
+                ..Default::default()
+            };
+            JobPost::filter_count(filter, &pool)
+        };
+        assert_eq!(count("kubernetes").await.unwrap(), 1);
+        assert_eq!(count("airflow").await.unwrap(), 1);
+
+        let mut post = scraped;
+        assert_eq!(
+            post.description.as_deref(),
+            Some("You'll own our Kubernetes clusters.")
+        );
+        post.description = Some("You'll own our Terraform modules.".to_string());
+        post.update(&pool).await.unwrap();
+        assert_eq!(count("kubernetes").await.unwrap(), 0);
+        assert_eq!(count("terraform").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn tags_are_saved_with_posts_and_filterable() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let job_posts = [
+            JobPostBuilder::new(company.id, "Referred")
+                .tags("referral, dream job")
+                .build(),
+            JobPostBuilder::new(company.id, "Untagged").build(),
+            JobPostBuilder::new(company.id, "Backup")
+                .tags("Low Priority")
+                .build(),
+        ];
+        JobPost::insert_many(&job_posts, &pool).await.unwrap();
+
+        let tagged = |tag: &str| {
+            let filter = JobPostFilter {
+                tag: tag.to_string(),
+                ..Default::default()
+            };
+            JobPost::filter(1, 10, filter, &pool)
+        };
+        let posts = tagged("Dream Job").await.unwrap();
+        assert_eq!(titles(&posts), ["Referred"]);
+        assert_eq!(posts[0].tags.as_deref(), Some("referral, dream job"));
+        assert_eq!(titles(&tagged("low priority").await.unwrap()), ["Backup"]);
+
+        let mut post = posts[0].clone();
+        post.tags = Some("referral".to_string());
+        let updated = post.update(&pool).await.unwrap();
+        assert_eq!(updated.tags.as_deref(), Some("referral"));
+        assert!(tagged("dream job").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn notes_are_saved_with_posts() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let mut post = JobPostBuilder::new(company.id, "Engineer").build();
+        post.notes = Some("Recruiter: Sam\nAsk about on-call".to_string());
+        post.insert(&pool).await.unwrap();
+        JobPost::insert_many(&[post.clone()], &pool).await.unwrap();
+        let notes: Vec<Option<String>> =
+            sqlx::query_scalar("SELECT notes FROM job_post ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(notes, vec![post.notes.clone(); 2]);
+
+        post.id = 1;
+        post.notes = None;
+        assert_eq!(post.update(&pool).await.unwrap().notes, None);
+    }
+
+    #[tokio::test]
+    async fn batch_inserts_keep_pay_units_and_expectations() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let posts = [
+            JobPostBuilder::new(company.id, "Contractor")
+                .pay(Some(60_00), Some(80_00), "hour")
+                .build(),
+            JobPostBuilder::new(company.id, "Engineer")
+                .pay(Some(10_000_00), None, "month")
+                .expected_pay(130_000_00)
+                .build(),
+        ];
+        JobPost::insert_many(&posts, &pool).await.unwrap();
+
+        let saved: Vec<(Option<String>, Option<i64>)> =
+            sqlx::query_as("SELECT pay_unit, expected_pay_cents FROM job_post ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            saved,
+            [
+                (Some("hour".to_string()), None),
+                (Some("month".to_string()), Some(130_000_00)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn pay_edits_are_saved() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let mut post = JobPostBuilder::new(company.id, "Engineer")
+            .pay(Some(120_000_00), None, "year")
+            .insert(&pool)
+            .await;
+        post.min_pay_cents = Some(60_00);
+        post.pay_unit = Some("hour".to_string());
+        post.update(&pool).await.unwrap();
+
+        let saved: JobPost = sqlx::query_as("SELECT * FROM job_post WHERE id = ?")
+            .bind(post.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(saved.min_pay_cents, Some(60_00));
+        assert_eq!(saved.pay_unit.as_deref(), Some("hour"));
+    }
+
+    #[tokio::test]
+    async fn platform_urls_are_saved() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let platform_url = |title: &str| {
+            let mut post = JobPostBuilder::new(company.id, title).build();
+            post.url = format!("https://acme.example/{title}");
+            post.platform_url = Some(format!("https://board.example/{title}"));
+            post
+        };
+        let (id, _) = platform_url("one").insert(&pool).await.unwrap();
+        JobPost::insert_many(&[platform_url("two")], &pool)
+            .await
+            .unwrap();
+
+        let saved: Vec<Option<String>> =
+            sqlx::query_scalar("SELECT platform_url FROM job_post ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            saved,
+            [
+                Some("https://board.example/one".to_string()),
+                Some("https://board.example/two".to_string()),
+            ]
+        );
+
+        let mut post: JobPost = sqlx::query_as("SELECT * FROM job_post WHERE id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        post.platform_url = None;
+        let updated = post.update(&pool).await.unwrap();
+        assert_eq!(updated.platform_url, None);
+    }
+
+    #[tokio::test]
+    async fn min_pay_filter_compares_yearly_pay() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let posts = [
+            ("Hourly", Some(45_00), Some(55_00), "HOUR"),
+            ("Monthly", Some(6_000_00), None, "month"),
+            ("Yearly", Some(80_000_00), Some(95_000_00), "year"),
+            ("Unpaid", None, None, "year"),
+        ];
+        for (title, min, max, unit) in posts {
+            JobPostBuilder::new(company.id, title)
+                .pay(min, max, unit)
+                .insert(&pool)
+                .await;
+        }
+        JobPostBuilder::new(company.id, "Rupees")
+            .pay(Some(20_00_000_00), None, "year")
+            .currency("INR")
+            .insert(&pool)
+            .await;
+
+        let filter = JobPostFilter {
+            min_annual_pay_cents: 100_000_00,
+            ..Default::default()
+        };
+        let posts = JobPost::filter(1, 10, filter, &pool).await.unwrap();
+        assert_eq!(titles(&posts), ["Hourly"]);
+        assert_eq!(
+            posts[0].annual_pay_cents(posts[0].min_pay_cents),
+            Some(93_600_00)
+        );
     }
 }
@@ -0,0 +1,97 @@
+use super::job_post::JobPostLocationType;
+
+/// Saved job post fields for prefilling manual posts. Empty or missing fields are left alone
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct JobPostTemplate {
+    pub id: i64,
+    pub name: String,
+    pub job_title: String,
+    pub location: String,
+    pub location_type: Option<JobPostLocationType>,
+    pub skills: String,
+    pub benefits: String,
+    pub min_yoe: Option<i64>,
+    pub max_yoe: Option<i64>,
+    pub currency: Option<String>,
+}
+
+impl JobPostTemplate {
+    pub const DEFAULT_ORDER: &str = "name ASC";
+
+    pub async fn fetch_all(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
+        let mut query = sqlx::QueryBuilder::new("SELECT * FROM job_post_template ORDER BY ");
+        query.push(Self::DEFAULT_ORDER);
+        query
+            .build_query_as()
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn insert(&self, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<i64> {
+        let res = sqlx::query(
+            r#"INSERT INTO job_post_template (
+                name, job_title, location, location_type, skills, benefits,
+                min_yoe, max_yoe, currency
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&self.name)
+        .bind(&self.job_title)
+        .bind(&self.location)
+        .bind(self.location_type)
+        .bind(&self.skills)
+        .bind(&self.benefits)
+        .bind(self.min_yoe)
+        .bind(self.max_yoe)
+        .bind(&self.currency)
+        .execute(executor)
+        .await?;
+
+        Ok(res.last_insert_rowid())
+    }
+
+    pub async fn delete(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM job_post_template WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for JobPostTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::memory_pool;
+
+    #[tokio::test]
+    async fn templates_round_trip() {
+        let pool = memory_pool().await;
+        let template = JobPostTemplate {
+            id: 0,
+            name: "Backend".to_string(),
+            job_title: "Backend Engineer".to_string(),
+            location: "".to_string(),
+            location_type: Some(JobPostLocationType::Remote),
+            skills: "Rust, SQL".to_string(),
+            benefits: "".to_string(),
+            min_yoe: Some(3),
+            max_yoe: None,
+            currency: None,
+        };
+        let id = template.insert(&pool).await.unwrap();
+
+        let templates = JobPostTemplate::fetch_all(&pool).await.unwrap();
+        assert_eq!(templates, [JobPostTemplate { id, ..template }]);
+
+        JobPostTemplate::delete(id, &pool).await.unwrap();
+        assert!(JobPostTemplate::fetch_all(&pool).await.unwrap().is_empty());
+    }
+}
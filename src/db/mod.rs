@@ -6,31 +6,56 @@ use sqlx::{
     Database, Decode, Encode, Sqlite, SqlitePool, Type,
 };
 
+pub mod alert_rule;
+pub mod api_request;
 pub mod company;
+pub mod company_alt_name;
+pub mod company_group;
+pub mod contact;
+pub mod digest;
+pub mod fetch_log;
 pub mod job_application;
 pub mod job_post;
+pub mod job_post_template;
+pub mod post_rule;
+pub mod tag;
+#[cfg(test)]
+pub mod test_support;
 
 /* Database */
 
 static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
 static LAST_RUSQL_MIGRATION: i64 = 9;
 
-pub async fn create(url: &str) -> SqlitePool {
-    SqlitePool::connect_with(
-        SqliteConnectOptions::new()
-            .filename(url)
-            .create_if_missing(true),
-    )
-    .await
-    .expect("Failed to create database")
-}
-
-pub async fn connect(url: &str) -> SqlitePool {
+/// Connects on first use, creating the file if needed, so opening the window doesn't wait on it.
+/// Must be called inside a Tokio runtime.
+pub fn connect_lazy(url: &str) -> SqlitePool {
     SqlitePoolOptions::new()
         .max_connections(100)
-        .connect(url)
-        .await
-        .expect("Failed to open database")
+        .connect_lazy_with(
+            SqliteConnectOptions::new()
+                .filename(url)
+                .create_if_missing(true),
+        )
+}
+
+/// Brings the database up to date; `existed` is whether the file was there before startup
+pub async fn prepare(pool: &SqlitePool, existed: bool) -> anyhow::Result<()> {
+    if existed {
+        bootstrap_sqlx_migrations(pool).await;
+    }
+    migrate(pool).await?;
+    optimize(pool).await
+}
+
+/// Gathers the statistics the query planner needs to pick the filter indexes. Without them
+/// it can prefer a worse plan, e.g. scanning by date instead of looking up by company.
+pub async fn optimize(pool: &SqlitePool) -> anyhow::Result<()> {
+    // 0x10000 checks every table, not only ones this connection has queried
+    sqlx::query("PRAGMA optimize = 0x10002")
+        .execute(pool)
+        .await?;
+    Ok(())
 }
 
 pub async fn bootstrap_sqlx_migrations(pool: &sqlx::SqlitePool) {
@@ -42,7 +67,7 @@ pub async fn bootstrap_sqlx_migrations(pool: &sqlx::SqlitePool) {
     .unwrap_or(None);
 
     if table_exists.is_none() {
-        sqlx::query(
+        if let Err(e) = sqlx::query(
             r#"
             CREATE TABLE _sqlx_migrations (
                 version BIGINT PRIMARY KEY,
@@ -55,9 +80,13 @@ pub async fn bootstrap_sqlx_migrations(pool: &sqlx::SqlitePool) {
             "#,
         )
         .execute(pool)
-        .await;
+        .await
+        {
+            tracing::warn!("Failed to create _sqlx_migrations: {:?}", e);
+            return;
+        }
 
-        println!("_sqlx_migrations table created");
+        tracing::info!("_sqlx_migrations table created");
     }
 
     let row_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_migrations")
@@ -68,27 +97,29 @@ pub async fn bootstrap_sqlx_migrations(pool: &sqlx::SqlitePool) {
     if row_count == 0 {
         for migration in MIGRATOR.iter() {
             let migration = migration.clone();
-            sqlx::query(
+            if let Err(e) = sqlx::query(
                     "INSERT INTO _sqlx_migrations (version, description, installed_on, success, checksum, execution_time) VALUES (?, ?, CURRENT_TIMESTAMP, 1, ?, 0)"
                 )
                 .bind(migration.version)
                 .bind(migration.description)
                 .bind(migration.checksum.to_vec())
                 .execute(pool)
-                .await;
+                .await
+            {
+                tracing::warn!("Failed to record migration {}: {:?}", migration.version, e);
+            }
             if migration.version >= LAST_RUSQL_MIGRATION {
                 break;
             }
         }
-        println!("_sqlx_migrations legacy rows populated");
+        tracing::info!("_sqlx_migrations legacy rows populated");
     }
 }
 
-pub async fn migrate(acquirable: impl sqlx::Acquire<'_, Database = sqlx::sqlite::Sqlite>) {
-    MIGRATOR
-        .run(acquirable)
-        .await
-        .expect("Failed to run migrations")
+pub async fn migrate(
+    acquirable: impl sqlx::Acquire<'_, Database = sqlx::sqlite::Sqlite>,
+) -> anyhow::Result<()> {
+    MIGRATOR.run(acquirable).await.map_err(Into::into)
 }
 
 pub async fn shutdown(pool: sqlx::SqlitePool) {
@@ -142,16 +173,10 @@ impl<'q> Encode<'q, Sqlite> for SqliteDateTime {
 }
 
 /* NullableSqliteDateTime */
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct NullableSqliteDateTime(pub Option<NaiveDate>);
 
-impl Default for NullableSqliteDateTime {
-    fn default() -> Self {
-        Self(None)
-    }
-}
-
 impl NullableSqliteDateTime {
     pub const fn timestamp(&self) -> Option<i64> {
         let Some(date) = self.0 else {
@@ -164,7 +189,7 @@ impl NullableSqliteDateTime {
         )
     }
 
-    pub fn format<'a>(&self, fmt: &'a str) -> String {
+    pub fn format(&self, fmt: &str) -> String {
         let Some(date) = self.0 else {
             return "".to_string();
         };
@@ -0,0 +1,340 @@
+use super::job_post::JobPost;
+use super::SqliteBoolean;
+use crate::utils::split_comma_separated;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "post_rule_action")]
+pub enum PostRuleAction {
+    Tag,
+    /// Stores the post archived, so it stays out of the list but isn't imported again
+    Archive,
+}
+
+impl PostRuleAction {
+    pub const ALL: [PostRuleAction; 2] = [PostRuleAction::Tag, PostRuleAction::Archive];
+}
+
+impl std::fmt::Display for PostRuleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PostRuleAction::Tag => write!(f, "Tag"),
+            PostRuleAction::Archive => write!(f, "Archive"),
+        }
+    }
+}
+
+/// Tags or archives incoming job posts that meet every condition it sets. Text conditions
+/// are comma-separated alternatives matched anywhere, ignoring case; empty ones are ignored
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct PostRule {
+    pub id: i64,
+    pub name: String,
+    pub title_contains: String,
+    pub skills_contains: String,
+    pub location_contains: String,
+    pub company_contains: String,
+    /// Yearly, in whole currency units; the top of the posted range must reach it (0 = any)
+    pub min_pay: i64,
+    /// Yearly, in whole currency units; the top of the posted range must fall short (0 = any)
+    pub pay_below: i64,
+    pub action: PostRuleAction,
+    /// Only used by `PostRuleAction::Tag`
+    pub tag: String,
+    pub enabled: SqliteBoolean,
+}
+
+/// Posts written by an insert, and how many of them rules archived on the way in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InsertCounts {
+    pub inserted: usize,
+    pub archived: usize,
+}
+
+impl std::ops::AddAssign for InsertCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.inserted += other.inserted;
+        self.archived += other.archived;
+    }
+}
+
+/// E.g. "12 job posts (3 archived by rules)"
+impl std::fmt::Display for InsertCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} job posts", self.inserted)?;
+        if self.archived > 0 {
+            write!(f, " ({} archived by rules)", self.archived)?;
+        }
+        Ok(())
+    }
+}
+
+fn contains_any(value: &str, terms: &str) -> bool {
+    let value = value.to_lowercase();
+    split_comma_separated(terms)
+        .iter()
+        .any(|term| value.contains(&term.to_lowercase()))
+}
+
+impl PostRule {
+    pub const DEFAULT_ORDER: &str = "name ASC";
+
+    /// Whether the rule sets any condition at all; one that doesn't never matches
+    pub fn has_conditions(&self) -> bool {
+        !self.title_contains.trim().is_empty()
+            || !self.skills_contains.trim().is_empty()
+            || !self.location_contains.trim().is_empty()
+            || !self.company_contains.trim().is_empty()
+            || self.min_pay > 0
+            || self.pay_below > 0
+    }
+
+    pub fn matches(&self, job_post: &JobPost, company_name: &str) -> bool {
+        if !self.has_conditions() {
+            return false;
+        }
+        let text_matches = |value: &str, terms: &str| {
+            split_comma_separated(terms).is_empty() || contains_any(value, terms)
+        };
+        let top_pay = job_post.annual_pay_cents(job_post.max_pay_cents.or(job_post.min_pay_cents));
+        let pay_matches = (self.min_pay <= 0
+            || top_pay.is_some_and(|pay| pay >= self.min_pay * 100))
+            && (self.pay_below <= 0 || top_pay.is_some_and(|pay| pay < self.pay_below * 100));
+        text_matches(&job_post.job_title, &self.title_contains)
+            && text_matches(
+                job_post.skills.as_deref().unwrap_or_default(),
+                &self.skills_contains,
+            )
+            && text_matches(&job_post.location, &self.location_contains)
+            && text_matches(company_name, &self.company_contains)
+            && pay_matches
+    }
+
+    /// Runs every enabled rule the post matches: tags are added to the ones it has, and any
+    /// archiving rule archives it
+    pub fn apply_all(rules: &[Self], job_post: &mut JobPost, company_name: &str) {
+        let mut tags = split_comma_separated(job_post.tags.as_deref().unwrap_or_default());
+        for rule in rules.iter().filter(|rule| rule.enabled.0) {
+            if !rule.matches(job_post, company_name) {
+                continue;
+            }
+            match rule.action {
+                PostRuleAction::Tag => {
+                    let tag = rule.tag.trim();
+                    if !tag.is_empty() && !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                        tags.push(tag.to_string());
+                    }
+                }
+                PostRuleAction::Archive => job_post.archived = SqliteBoolean(true),
+            }
+        }
+        if !tags.is_empty() {
+            job_post.tags = Some(tags.join(", "));
+        }
+    }
+
+    pub async fn fetch_all(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
+        let mut query = sqlx::QueryBuilder::new("SELECT * FROM post_rule ORDER BY ");
+        query.push(Self::DEFAULT_ORDER);
+        query
+            .build_query_as()
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn fetch_enabled(
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as("SELECT * FROM post_rule WHERE enabled = 1")
+            .fetch_all(executor)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn insert(&self, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<i64> {
+        let res = sqlx::query(
+            r#"INSERT INTO post_rule (
+                name, title_contains, skills_contains, location_contains, company_contains,
+                min_pay, pay_below, action, tag, enabled
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+        )
+        .bind(&self.name)
+        .bind(&self.title_contains)
+        .bind(&self.skills_contains)
+        .bind(&self.location_contains)
+        .bind(&self.company_contains)
+        .bind(self.min_pay)
+        .bind(self.pay_below)
+        .bind(self.action)
+        .bind(&self.tag)
+        .bind(self.enabled)
+        .execute(executor)
+        .await?;
+
+        Ok(res.last_insert_rowid())
+    }
+
+    pub async fn set_enabled(
+        id: i64,
+        enabled: bool,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        sqlx::query("UPDATE post_rule SET enabled = ? WHERE id = ?")
+            .bind(SqliteBoolean(enabled))
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(id: i64, executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<()> {
+        sqlx::query("DELETE FROM post_rule WHERE id = ?")
+            .bind(id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+// Cents are written apart from the dollars, e.g. 85_000_00
+#[allow(clippy::inconsistent_digit_grouping)]
+mod tests {
+    use super::*;
+    use crate::db::job_post::JobPostLocationType;
+    use crate::db::test_support::{memory_pool, CompanyBuilder, JobPostBuilder};
+
+    fn rule(name: &str, tag: &str) -> PostRule {
+        PostRule {
+            id: 0,
+            name: name.to_string(),
+            title_contains: "".to_string(),
+            skills_contains: "".to_string(),
+            location_contains: "".to_string(),
+            company_contains: "".to_string(),
+            min_pay: 0,
+            pay_below: 0,
+            action: PostRuleAction::Tag,
+            tag: tag.to_string(),
+            enabled: SqliteBoolean(true),
+        }
+    }
+
+    #[tokio::test]
+    async fn rules_tag_posts_on_insert() {
+        let pool = memory_pool().await;
+        PostRule {
+            title_contains: "rust, systems".to_string(),
+            ..rule("Rust", "rust")
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        PostRule {
+            location_contains: "berlin".to_string(),
+            min_pay: 100_000,
+            ..rule("Well-paid Berlin", "berlin-top")
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        let disabled = rule("Everything", "all");
+        let disabled_id = PostRule {
+            skills_contains: "sql".to_string(),
+            ..disabled
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        PostRule::set_enabled(disabled_id, false, &pool)
+            .await
+            .unwrap();
+        // No conditions, so it never matches
+        rule("Empty", "empty").insert(&pool).await.unwrap();
+
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let post = JobPostBuilder::new(company.id, "Senior Rust Engineer")
+            .location("Berlin", JobPostLocationType::Remote)
+            .skills("Rust, SQL")
+            .pay(None, Some(60_00), "hour")
+            .insert(&pool)
+            .await;
+        assert_eq!(post.tags.as_deref(), Some("rust, berlin-top"));
+
+        let post = JobPostBuilder::new(company.id, "Systems Engineer")
+            .location("Berlin", JobPostLocationType::Remote)
+            .pay(Some(50_000_00), Some(90_000_00), "year")
+            .insert(&pool)
+            .await;
+        assert_eq!(post.tags.as_deref(), Some("rust"));
+
+        let post = JobPostBuilder::new(company.id, "Go Engineer")
+            .insert(&pool)
+            .await;
+        assert_eq!(post.tags, None);
+
+        let rules = PostRule::fetch_all(&pool).await.unwrap();
+        assert_eq!(rules.len(), 4);
+        PostRule::delete(disabled_id, &pool).await.unwrap();
+        assert_eq!(PostRule::fetch_enabled(&pool).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn archiving_rules_are_counted_on_insert() {
+        let pool = memory_pool().await;
+        PostRule {
+            title_contains: "staff".to_string(),
+            action: PostRuleAction::Archive,
+            ..rule("Too senior", "")
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        PostRule {
+            company_contains: "initech".to_string(),
+            action: PostRuleAction::Archive,
+            ..rule("Blocklist", "")
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        PostRule {
+            pay_below: 80_000,
+            action: PostRuleAction::Archive,
+            ..rule("Underpaid", "")
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+
+        let acme = CompanyBuilder::new("Acme").insert(&pool).await;
+        let initech = CompanyBuilder::new("Initech").insert(&pool).await;
+        let job_posts = [
+            JobPostBuilder::new(acme.id, "Staff Engineer").build(),
+            JobPostBuilder::new(initech.id, "Engineer").build(),
+            JobPostBuilder::new(acme.id, "Engineer")
+                .pay(Some(30_00), Some(35_00), "hour")
+                .build(),
+            JobPostBuilder::new(acme.id, "Engineer")
+                .pay(Some(90_000_00), None, "year")
+                .build(),
+            JobPostBuilder::new(acme.id, "Engineer").build(),
+        ];
+        let counts = JobPost::insert_many(&job_posts, &pool).await.unwrap();
+        assert_eq!(
+            counts,
+            InsertCounts {
+                inserted: 5,
+                archived: 3
+            }
+        );
+        assert_eq!(counts.to_string(), "5 job posts (3 archived by rules)");
+        let archived: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM job_post WHERE archived = 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(archived, 3);
+    }
+}
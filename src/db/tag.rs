@@ -0,0 +1,126 @@
+use crate::utils::split_comma_separated;
+
+/// A label on job posts, e.g. "dream job" or "referral". Names are unique ignoring case.
+/// `job_post.tags` mirrors a post's tags as a comma-separated list for reading
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct Tag {
+    pub id: i64,
+    pub name: String,
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Tag {
+    /// Tags on at least one post, by name
+    pub async fn fetch_all(executor: impl sqlx::SqliteExecutor<'_>) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(
+            r#"SELECT * FROM tag
+                WHERE id IN (SELECT tag_id FROM job_post_tag)
+                ORDER BY name COLLATE NOCASE"#,
+        )
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// The post's tags, in the order they were added
+    pub async fn fetch_for_post(
+        job_post_id: i64,
+        executor: impl sqlx::SqliteExecutor<'_>,
+    ) -> anyhow::Result<Vec<Self>> {
+        sqlx::query_as(
+            r#"SELECT tag.* FROM job_post_tag
+                JOIN tag ON job_post_tag.tag_id = tag.id
+                WHERE job_post_tag.job_post_id = ?
+                ORDER BY job_post_tag.rowid"#,
+        )
+        .bind(job_post_id)
+        .fetch_all(executor)
+        .await
+        .map_err(Into::into)
+    }
+
+    /// Replaces the post's tags with the comma-separated `names`, creating any that don't
+    /// exist yet
+    pub async fn set_for_post(
+        job_post_id: i64,
+        names: &str,
+        executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+    ) -> anyhow::Result<()> {
+        let mut tx = executor.begin().await?;
+        sqlx::query("DELETE FROM job_post_tag WHERE job_post_id = ?")
+            .bind(job_post_id)
+            .execute(&mut *tx)
+            .await?;
+        for name in split_comma_separated(names) {
+            sqlx::query("INSERT INTO tag (name) VALUES (?) ON CONFLICT (name) DO NOTHING")
+                .bind(&name)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query(
+                r#"INSERT OR IGNORE INTO job_post_tag (job_post_id, tag_id)
+                    SELECT ?, id FROM tag WHERE name = ?"#,
+            )
+            .bind(job_post_id)
+            .bind(&name)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{count_rows, memory_pool, CompanyBuilder, JobPostBuilder};
+
+    #[tokio::test]
+    async fn tags_are_shared_between_posts_and_mirrored_on_them() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme").insert(&pool).await;
+        let first = JobPostBuilder::new(company.id, "First").insert(&pool).await;
+        let second = JobPostBuilder::new(company.id, "Second")
+            .insert(&pool)
+            .await;
+
+        Tag::set_for_post(first.id, "Referral, dream job", &pool)
+            .await
+            .unwrap();
+        Tag::set_for_post(second.id, "referral, Referral", &pool)
+            .await
+            .unwrap();
+        assert_eq!(count_rows("tag", &pool).await, 2);
+        let names: Vec<String> = Tag::fetch_for_post(first.id, &pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        assert_eq!(names, ["Referral", "dream job"]);
+        let tags: Option<String> = sqlx::query_scalar("SELECT tags FROM job_post WHERE id = ?")
+            .bind(second.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(tags.as_deref(), Some("Referral"));
+
+        // Untagging a post leaves only the tags still in use to pick from
+        Tag::set_for_post(first.id, "", &pool).await.unwrap();
+        let all = Tag::fetch_all(&pool).await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].name, "Referral");
+        let tags: Option<String> = sqlx::query_scalar("SELECT tags FROM job_post WHERE id = ?")
+            .bind(first.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(tags, None);
+    }
+}
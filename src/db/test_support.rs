@@ -0,0 +1,215 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+
+use super::{
+    company::Company,
+    job_application::{ApplicationChannel, JobApplication, JobApplicationStatus},
+    job_post::{JobPost, JobPostLocationType},
+    migrate, NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+
+/// A fresh, fully migrated database that only lives as long as the pool
+pub async fn memory_pool() -> SqlitePool {
+    // Every connection to `:memory:` is its own database, so keep exactly one open for good
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("Failed to open in-memory database");
+    migrate(&pool).await.expect("Failed to run migrations");
+    pool
+}
+
+pub struct CompanyBuilder(Company);
+
+impl CompanyBuilder {
+    pub fn new(name: &str) -> Self {
+        Self(Company {
+            id: 0,
+            name: name.to_string(),
+            careers_url: None,
+            hidden: SqliteBoolean(false),
+            group_id: None,
+        })
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.0.hidden = SqliteBoolean(true);
+        self
+    }
+
+    pub async fn insert(self, pool: &SqlitePool) -> Company {
+        let id = self.0.insert(pool).await.expect("Failed to insert company");
+        Company { id, ..self.0 }
+    }
+}
+
+pub struct JobPostBuilder(JobPost);
+
+impl JobPostBuilder {
+    pub fn new(company_id: i64, job_title: &str) -> Self {
+        Self(JobPost {
+            id: 0,
+            company_id,
+            location: "".to_string(),
+            location_type: JobPostLocationType::Onsite,
+            url: format!("https://example.com/jobs/{}", job_title.replace(' ', "-")),
+            min_yoe: None,
+            max_yoe: None,
+            min_pay_cents: None,
+            max_pay_cents: None,
+            date_posted: NullableSqliteDateTime(None),
+            date_retrieved: SqliteDateTime(Utc::now()),
+            job_title: job_title.to_string(),
+            benefits: None,
+            skills: None,
+            pay_unit: None,
+            currency: None,
+            apijobs_id: None,
+            platform_url: None,
+            notes: None,
+            industry: None,
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            description: None,
+        })
+    }
+
+    pub fn location(mut self, location: &str, location_type: JobPostLocationType) -> Self {
+        self.0.location = location.to_string();
+        self.0.location_type = location_type;
+        self
+    }
+
+    pub fn yoe(mut self, min_yoe: i64, max_yoe: Option<i64>) -> Self {
+        self.0.min_yoe = Some(min_yoe);
+        self.0.max_yoe = max_yoe;
+        self
+    }
+
+    pub fn pay(
+        mut self,
+        min_pay_cents: Option<i64>,
+        max_pay_cents: Option<i64>,
+        unit: &str,
+    ) -> Self {
+        self.0.min_pay_cents = min_pay_cents;
+        self.0.max_pay_cents = max_pay_cents;
+        self.0.pay_unit = Some(unit.to_string());
+        self
+    }
+
+    pub fn currency(mut self, currency: &str) -> Self {
+        self.0.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn expected_pay(mut self, cents: i64) -> Self {
+        self.0.expected_pay_cents = Some(cents);
+        self
+    }
+
+    pub fn skills(mut self, skills: &str) -> Self {
+        self.0.skills = Some(skills.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.0.description = Some(description.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: &str) -> Self {
+        self.0.tags = Some(tags.to_string());
+        self
+    }
+
+    pub fn retrieved(mut self, date_retrieved: DateTime<Utc>) -> Self {
+        self.0.date_retrieved = SqliteDateTime(date_retrieved);
+        self
+    }
+
+    pub fn build(self) -> JobPost {
+        self.0
+    }
+
+    pub async fn insert(self, pool: &SqlitePool) -> JobPost {
+        self.0
+            .insert(pool)
+            .await
+            .expect("Failed to insert job post");
+        sqlx::query_as("SELECT * FROM job_post ORDER BY id DESC LIMIT 1")
+            .fetch_one(pool)
+            .await
+            .expect("Failed to fetch inserted job post")
+    }
+}
+
+pub struct JobApplicationBuilder(JobApplication);
+
+impl JobApplicationBuilder {
+    pub fn new(job_post_id: i64, status: JobApplicationStatus) -> Self {
+        Self(JobApplication {
+            id: 0,
+            job_post_id,
+            status,
+            date_applied: NullableSqliteDateTime(None),
+            date_responded: NullableSqliteDateTime(None),
+            interviewed: SqliteBoolean(false),
+            resume_version: None,
+            channel: None,
+            interview_at: None,
+            interview_timezone: None,
+            cover_letter_path: None,
+            cover_letter: None,
+        })
+    }
+
+    pub fn applied(mut self, date: NaiveDate) -> Self {
+        self.0.date_applied = NullableSqliteDateTime(Some(date));
+        self
+    }
+
+    pub fn responded(mut self, date: NaiveDate) -> Self {
+        self.0.date_responded = NullableSqliteDateTime(Some(date));
+        self
+    }
+
+    pub fn interviewed(mut self) -> Self {
+        self.0.interviewed = SqliteBoolean(true);
+        self
+    }
+
+    pub fn resume_version(mut self, version: &str) -> Self {
+        self.0.resume_version = Some(version.to_string());
+        self
+    }
+
+    pub fn channel(mut self, channel: ApplicationChannel) -> Self {
+        self.0.channel = Some(channel);
+        self
+    }
+
+    pub async fn insert(self, pool: &SqlitePool) -> JobApplication {
+        self.0
+            .insert(pool)
+            .await
+            .expect("Failed to insert job application");
+        JobApplication::fetch_one_by_job_post_id(self.0.job_post_id, pool)
+            .await
+            .expect("Failed to fetch inserted job application")
+            .expect("Inserted job application is missing")
+    }
+}
+
+/// Rows left in `table`, for checking what a delete took with it
+pub async fn count_rows(table: &str, pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {table}"))
+        .fetch_one(pool)
+        .await
+        .expect("Failed to count rows")
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::db::job_post::JobPostLocationType;
+use crate::paths;
+
+/// Under the data directory
+pub const JOB_POST_DRAFT_FILE: &str = "job_post_draft.toml";
+
+/// Unsaved job post modal fields, kept on disk so they survive a crash or an accidental close
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct JobPostDraft {
+    /// The post being edited, `None` for a new post
+    pub job_post_id: Option<i64>,
+    pub company_id: Option<i64>,
+    pub job_title: String,
+    pub location: String,
+    pub location_type: Option<JobPostLocationType>,
+    pub min_yoe: Option<i64>,
+    pub max_yoe: Option<i64>,
+    pub min_pay: String,
+    pub max_pay: String,
+    #[serde(default)]
+    pub expected_pay: String,
+    #[serde(default)]
+    pub currency: String,
+    /// `PayUnit` name (empty = yearly)
+    #[serde(default)]
+    pub pay_unit: String,
+    pub benefits: String,
+    pub skills: String,
+    #[serde(default)]
+    pub notes: String,
+    pub url: String,
+    pub date_posted: Option<NaiveDate>,
+    #[serde(default)]
+    pub saved_at: Option<DateTime<Local>>,
+}
+
+impl JobPostDraft {
+    /// Whether there's anything worth restoring
+    pub fn is_empty(&self) -> bool {
+        self.job_title.is_empty()
+            && self.location.is_empty()
+            && self.location_type.is_none()
+            && self.min_yoe.is_none()
+            && self.max_yoe.is_none()
+            && self.min_pay.is_empty()
+            && self.max_pay.is_empty()
+            && self.expected_pay.is_empty()
+            && self.benefits.is_empty()
+            && self.skills.is_empty()
+            && self.notes.is_empty()
+            && self.url.is_empty()
+            && self.date_posted.is_none()
+    }
+
+    /// Loads the saved draft, if any. A draft that can't be read is treated as missing.
+    pub fn load() -> Option<Self> {
+        let content = std::fs::read_to_string(paths::data_file(JOB_POST_DRAFT_FILE)).ok()?;
+        toml::from_str(&content)
+            .map_err(|e| tracing::warn!("Ignoring unreadable job post draft: {:?}", e))
+            .ok()
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let draft = JobPostDraft {
+            saved_at: Some(Local::now()),
+            ..self.clone()
+        };
+        let path = paths::data_file(JOB_POST_DRAFT_FILE);
+        paths::ensure_parent(&path)?;
+        std::fs::write(path, toml::to_string_pretty(&draft)?)?;
+        Ok(())
+    }
+
+    pub fn discard() {
+        if let Err(e) = std::fs::remove_file(paths::data_file(JOB_POST_DRAFT_FILE)) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("Failed to remove job post draft: {:?}", e);
+            }
+        }
+    }
+}
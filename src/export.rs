@@ -0,0 +1,512 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+
+use crate::db::{
+    digest::INTERVIEW_REMINDER_MINUTES, job_application::JobApplicationStatus,
+    job_post::JobPostLocationType, NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+use crate::{interview, paths};
+
+/// Under the data directory
+const EXPORT_DIR: &str = "exports";
+
+/* Work search log */
+
+/// One application as unemployment work search logs ask for it
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WorkSearchEntry {
+    pub date_applied: NullableSqliteDateTime,
+    pub employer: String,
+    pub position: String,
+    pub location: String,
+    pub url: String,
+    pub status: JobApplicationStatus,
+}
+
+/// The Sunday on or before `date`; work search weeks run Sunday through Saturday
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_sunday() as i64)
+}
+
+/// Applications made in the week starting on `week_start`, oldest first
+pub async fn work_search_log(
+    week_start: NaiveDate,
+    executor: impl sqlx::SqliteExecutor<'_>,
+) -> anyhow::Result<Vec<WorkSearchEntry>> {
+    let from = NullableSqliteDateTime(Some(week_start));
+    let to = NullableSqliteDateTime(Some(week_start + Duration::days(7)));
+    sqlx::query_as(
+        r#"SELECT
+                job_application.date_applied AS date_applied,
+                company.name AS employer,
+                job_post.job_title AS position,
+                job_post.location AS location,
+                job_post.url AS url,
+                job_application.status AS status
+            FROM job_application
+            JOIN job_post ON job_post.id = job_application.job_post_id
+            JOIN company ON company.id = job_post.company_id
+            WHERE job_application.date_applied >= ? AND job_application.date_applied < ?
+            ORDER BY job_application.date_applied ASC, company.name ASC
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+    .map_err(Into::into)
+}
+
+pub fn work_search_csv(entries: &[WorkSearchEntry]) -> String {
+    let mut csv = csv_row(&[
+        "Date",
+        "Employer",
+        "Position",
+        "Location",
+        "Contact Method",
+        "Contact",
+        "Result",
+    ]);
+    for entry in entries {
+        csv.push_str(&csv_row(&[
+            &entry.date_applied.format("%m/%d/%Y"),
+            &entry.employer,
+            &entry.position,
+            &entry.location,
+            "Online application",
+            &entry.url,
+            entry.status.name(),
+        ]));
+    }
+    csv
+}
+
+/// Writes the week's log to the data directory's `exports/` and returns where it went
+pub fn save_work_search_log(
+    week_start: NaiveDate,
+    entries: &[WorkSearchEntry],
+) -> anyhow::Result<PathBuf> {
+    let dir = paths::data_file(EXPORT_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("work-search-{}.csv", week_start.format("%Y-%m-%d")));
+    std::fs::write(&path, work_search_csv(entries))?;
+    Ok(path)
+}
+
+/* Anonymized funnel data */
+
+/// A job post and how its application went, minus anything naming where it was
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AnonymizedEntry {
+    pub company_id: i64,
+    pub job_title: String,
+    pub location: String,
+    pub location_type: JobPostLocationType,
+    pub industry: Option<String>,
+    pub skills: Option<String>,
+    pub min_yoe: Option<i64>,
+    pub max_yoe: Option<i64>,
+    pub min_pay_cents: Option<i64>,
+    pub max_pay_cents: Option<i64>,
+    pub pay_unit: Option<String>,
+    pub currency: Option<String>,
+    pub date_posted: NullableSqliteDateTime,
+    pub date_retrieved: SqliteDateTime,
+    pub status: Option<JobApplicationStatus>,
+    pub date_applied: NullableSqliteDateTime,
+    pub date_responded: NullableSqliteDateTime,
+    pub interviewed: SqliteBoolean,
+}
+
+/// Every job post with its application, if any. Company names, URLs, notes, and
+/// benefits are never selected, so they can't end up in the export
+pub async fn anonymized_data(
+    executor: impl sqlx::SqliteExecutor<'_>,
+) -> anyhow::Result<Vec<AnonymizedEntry>> {
+    sqlx::query_as(
+        r#"SELECT
+                job_post.company_id AS company_id,
+                job_post.job_title AS job_title,
+                job_post.location AS location,
+                job_post.location_type AS location_type,
+                job_post.industry AS industry,
+                job_post.skills AS skills,
+                job_post.min_yoe AS min_yoe,
+                job_post.max_yoe AS max_yoe,
+                job_post.min_pay_cents AS min_pay_cents,
+                job_post.max_pay_cents AS max_pay_cents,
+                job_post.pay_unit AS pay_unit,
+                job_post.currency AS currency,
+                job_post.date_posted AS date_posted,
+                job_post.date_retrieved AS date_retrieved,
+                job_application.status AS status,
+                job_application.date_applied AS date_applied,
+                job_application.date_responded AS date_responded,
+                COALESCE(job_application.interviewed, 0) AS interviewed
+            FROM job_post
+            LEFT JOIN job_application ON job_application.job_post_id = job_post.id
+            ORDER BY job_post.company_id ASC, job_post.id ASC
+        "#,
+    )
+    .fetch_all(executor)
+    .await
+    .map_err(Into::into)
+}
+
+/// Stands in for a company's name; ids never change, so neither does the pseudonym
+pub fn company_pseudonym(company_id: i64) -> String {
+    format!("Company {company_id}")
+}
+
+pub fn anonymized_csv(entries: &[AnonymizedEntry]) -> String {
+    let mut csv = csv_row(&[
+        "Company",
+        "Job Title",
+        "Location",
+        "Location Type",
+        "Industry",
+        "Skills",
+        "Min YOE",
+        "Max YOE",
+        "Min Pay",
+        "Max Pay",
+        "Pay Unit",
+        "Currency",
+        "Date Posted",
+        "Date Retrieved",
+        "Status",
+        "Date Applied",
+        "Date Responded",
+        "Interviewed",
+    ]);
+    let optional = |value: Option<i64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let pay = |cents: Option<i64>| {
+        cents
+            .map(|cents| format!("{:.2}", cents as f64 / 100.0))
+            .unwrap_or_default()
+    };
+    for entry in entries {
+        csv.push_str(&csv_row(&[
+            &company_pseudonym(entry.company_id),
+            &entry.job_title,
+            &entry.location,
+            &entry.location_type.name(),
+            entry.industry.as_deref().unwrap_or_default(),
+            entry.skills.as_deref().unwrap_or_default(),
+            &optional(entry.min_yoe),
+            &optional(entry.max_yoe),
+            &pay(entry.min_pay_cents),
+            &pay(entry.max_pay_cents),
+            entry.pay_unit.as_deref().unwrap_or_default(),
+            entry.currency.as_deref().unwrap_or_default(),
+            &entry.date_posted.format("%Y-%m-%d"),
+            &entry.date_retrieved.0.format("%Y-%m-%d").to_string(),
+            entry.status.as_ref().map(|s| s.name()).unwrap_or_default(),
+            &entry.date_applied.format("%Y-%m-%d"),
+            &entry.date_responded.format("%Y-%m-%d"),
+            if entry.interviewed.0 { "Yes" } else { "No" },
+        ]));
+    }
+    csv
+}
+
+/// Writes the anonymized data to the data directory's `exports/` and returns where it went
+pub fn save_anonymized_data(entries: &[AnonymizedEntry]) -> anyhow::Result<PathBuf> {
+    let dir = paths::data_file(EXPORT_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "job-hunt-anonymized-{}.csv",
+        chrono::Utc::now().format("%Y-%m-%d")
+    ));
+    std::fs::write(&path, anonymized_csv(entries))?;
+    Ok(path)
+}
+
+/* Interview calendar */
+
+/// Interviews aren't given a length, so calendar events get this one
+const INTERVIEW_EVENT_MINUTES: i64 = 60;
+
+/// A scheduled interview as a calendar event
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct InterviewEntry {
+    pub application_id: i64,
+    pub job_title: String,
+    pub company_name: String,
+    pub url: String,
+    /// UTC timestamp
+    pub interview_at: i64,
+    pub interview_timezone: Option<String>,
+}
+
+/// Interviews from `now` on, soonest first
+pub async fn interview_schedule(
+    now: DateTime<Utc>,
+    executor: impl sqlx::SqliteExecutor<'_>,
+) -> anyhow::Result<Vec<InterviewEntry>> {
+    sqlx::query_as(
+        r#"SELECT
+                job_application.id AS application_id,
+                job_post.job_title AS job_title,
+                company.name AS company_name,
+                job_post.url AS url,
+                job_application.interview_at AS interview_at,
+                job_application.interview_timezone AS interview_timezone
+            FROM job_application
+            JOIN job_post ON job_post.id = job_application.job_post_id
+            JOIN company ON company.id = job_post.company_id
+            WHERE job_application.interview_at >= ?
+            ORDER BY job_application.interview_at ASC
+        "#,
+    )
+    .bind(now.timestamp())
+    .fetch_all(executor)
+    .await
+    .map_err(Into::into)
+}
+
+/// An iCalendar file with a VEVENT per interview, each with a reminder alarm. Event UIDs
+/// come from the application, so importing a newer export updates events instead of
+/// duplicating them
+pub fn interview_ics(entries: &[InterviewEntry], now: DateTime<Utc>) -> String {
+    let mut ics = ics_lines(&[
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        "PRODID:-//Job Hunter//Interviews//EN",
+        "CALSCALE:GREGORIAN",
+    ]);
+    for entry in entries {
+        let start = DateTime::from_timestamp(entry.interview_at, 0).unwrap_or_default();
+        let end = start + Duration::minutes(INTERVIEW_EVENT_MINUTES);
+        let description = format!(
+            "{}\\n{}",
+            ics_text(&interview::format_interview(
+                start,
+                entry.interview_timezone.as_deref()
+            )),
+            ics_text(&entry.url)
+        );
+        ics.push_str(&ics_lines(&[
+            "BEGIN:VEVENT",
+            &format!("UID:interview-{}@job-hunter", entry.application_id),
+            &format!("DTSTAMP:{}", ics_time(now)),
+            &format!("DTSTART:{}", ics_time(start)),
+            &format!("DTEND:{}", ics_time(end)),
+            &format!(
+                "SUMMARY:{}",
+                ics_text(&format!(
+                    "Interview: {} at {}",
+                    entry.job_title, entry.company_name
+                ))
+            ),
+            &format!("DESCRIPTION:{description}"),
+            &format!("URL:{}", entry.url),
+            "BEGIN:VALARM",
+            "ACTION:DISPLAY",
+            "DESCRIPTION:Interview reminder",
+            &format!("TRIGGER:-PT{}M", INTERVIEW_REMINDER_MINUTES),
+            "END:VALARM",
+            "END:VEVENT",
+        ]));
+    }
+    ics.push_str(&ics_lines(&["END:VCALENDAR"]));
+    ics
+}
+
+/// Writes the interview calendar to the data directory's `exports/` and returns where it went
+pub fn save_interview_calendar(entries: &[InterviewEntry]) -> anyhow::Result<PathBuf> {
+    let dir = paths::data_file(EXPORT_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let now = Utc::now();
+    let path = dir.join(format!("interviews-{}.ics", now.format("%Y-%m-%d")));
+    std::fs::write(&path, interview_ics(entries, now))?;
+    Ok(path)
+}
+
+/* iCalendar */
+
+fn ics_time(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes the characters TEXT values reserve
+fn ics_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Content lines, folded so none is longer than 75 octets
+fn ics_lines(lines: &[&str]) -> String {
+    let mut ics = String::new();
+    for line in lines {
+        let mut length = 0;
+        for c in line.chars() {
+            if length + c.len_utf8() > 75 {
+                ics.push_str("\r\n ");
+                // The leading space counts towards the continuation line
+                length = 1;
+            }
+            ics.push(c);
+            length += c.len_utf8();
+        }
+        ics.push_str("\r\n");
+    }
+    ics
+}
+
+/* CSV */
+
+fn csv_row(fields: &[&str]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| csv_field(field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// Quotes the field when it contains a delimiter, quote, or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{
+        memory_pool, CompanyBuilder, JobApplicationBuilder, JobPostBuilder,
+    };
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    #[test]
+    fn week_starts_on_sunday() {
+        // March 2, 2025 was a Sunday
+        assert_eq!(week_start(date(2)), date(2));
+        assert_eq!(week_start(date(5)), date(2));
+        assert_eq!(week_start(date(8)), date(2));
+        assert_eq!(week_start(date(9)), date(9));
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("Acme"), "Acme");
+        assert_eq!(csv_field("Acme, Inc."), "\"Acme, Inc.\"");
+        assert_eq!(csv_field("The \"Best\" Co"), "\"The \"\"Best\"\" Co\"");
+    }
+
+    #[tokio::test]
+    async fn anonymized_data_leaves_out_identifying_fields() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Secret Startup").insert(&pool).await;
+        let mut post = JobPostBuilder::new(company.id, "Engineer").build();
+        post.notes = Some("Referred by a friend".to_string());
+        post.insert(&pool).await.unwrap();
+        let post = JobPostBuilder::new(company.id, "Manager")
+            .insert(&pool)
+            .await;
+        JobApplicationBuilder::new(post.id, JobApplicationStatus::Rejected)
+            .applied(date(3))
+            .interviewed()
+            .insert(&pool)
+            .await;
+
+        let entries = anonymized_data(&pool).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].status, None);
+        assert_eq!(entries[1].status, Some(JobApplicationStatus::Rejected));
+
+        let csv = anonymized_csv(&entries);
+        assert!(!csv.contains("Secret Startup"));
+        assert!(!csv.contains("example.com"));
+        assert!(!csv.contains("Referred"));
+        assert!(csv.contains(&company_pseudonym(company.id)));
+        assert!(csv.contains(",Rejected,2025-03-03,,Yes\r\n"));
+    }
+
+    #[test]
+    fn ics_text_is_escaped_and_long_lines_folded() {
+        assert_eq!(
+            ics_text("Acme, Inc.; R&D\nRemote"),
+            "Acme\\, Inc.\\; R&D\\nRemote"
+        );
+        let folded = ics_lines(&[&"a".repeat(100)]);
+        let lines: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(lines[1], format!(" {}", "a".repeat(25)));
+    }
+
+    #[tokio::test]
+    async fn interview_calendar_has_upcoming_interviews() {
+        let pool = memory_pool().await;
+        let now = Utc::now();
+        let company = CompanyBuilder::new("Acme, Inc.").insert(&pool).await;
+        for (title, hours) in [("Past", -2), ("Onsite", 26), ("Phone Screen", 3)] {
+            let post = JobPostBuilder::new(company.id, title).insert(&pool).await;
+            let application = JobApplicationBuilder::new(post.id, JobApplicationStatus::Interview)
+                .insert(&pool)
+                .await;
+            sqlx::query("UPDATE job_application SET interview_at = ? WHERE id = ?")
+                .bind((now + Duration::hours(hours)).timestamp())
+                .bind(application.id)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let entries = interview_schedule(now, &pool).await.unwrap();
+        let titles: Vec<&str> = entries.iter().map(|e| e.job_title.as_str()).collect();
+        assert_eq!(titles, ["Phone Screen", "Onsite"]);
+
+        let ics = interview_ics(&entries, now);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("SUMMARY:Interview: Phone Screen at Acme\\, Inc.\r\n"));
+        assert!(ics.contains(&format!(
+            "UID:interview-{}@job-hunter\r\n",
+            entries[0].application_id
+        )));
+        assert!(ics.contains("TRIGGER:-PT60M\r\n"));
+    }
+
+    #[tokio::test]
+    async fn work_search_log_covers_one_week() {
+        let pool = memory_pool().await;
+        let company = CompanyBuilder::new("Acme, Inc.").insert(&pool).await;
+        for (title, day) in [("Before", 1), ("Sunday", 2), ("Saturday", 8), ("After", 9)] {
+            let post = JobPostBuilder::new(company.id, title).insert(&pool).await;
+            JobApplicationBuilder::new(post.id, JobApplicationStatus::Applied)
+                .applied(date(day))
+                .insert(&pool)
+                .await;
+        }
+
+        let entries = work_search_log(date(2), &pool).await.unwrap();
+        let positions: Vec<&str> = entries.iter().map(|e| e.position.as_str()).collect();
+        assert_eq!(positions, ["Sunday", "Saturday"]);
+
+        let csv = work_search_csv(&entries);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("Date,Employer,Position,Location,Contact Method,Contact,Result")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("03/02/2025,\"Acme, Inc.\",Sunday,,Online application,https://example.com/jobs/Sunday,Applied")
+        );
+    }
+}
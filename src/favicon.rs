@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use crate::paths;
+
+/* Company favicons, cached on disk by host */
+
+/// Under the cache directory
+const CACHE_DIR: &str = "favicons";
+
+/// The host a careers URL is on, which favicons are fetched from and cached by. URLs
+/// without a scheme are taken as HTTPS
+pub fn host(careers_url: &str) -> Option<String> {
+    let url = careers_url.trim();
+    if url.is_empty() {
+        return None;
+    }
+    let url = if url.contains("://") {
+        reqwest::Url::parse(url)
+    } else {
+        reqwest::Url::parse(&format!("https://{url}"))
+    };
+    url.ok()?.host_str().map(|host| host.to_lowercase())
+}
+
+fn cache_path(host: &str) -> PathBuf {
+    paths::cache_dir(CACHE_DIR).join(host)
+}
+
+/// The cached favicon, if the host was fetched before. Hosts without one are cached as empty
+pub fn cached(host: &str) -> Option<Vec<u8>> {
+    std::fs::read(cache_path(host)).ok()
+}
+
+/// Fetches the host's `/favicon.ico` and caches it. A missing favicon is cached too (as
+/// empty), so it isn't asked for again on every launch
+pub async fn fetch(client: reqwest::Client, host: String) -> anyhow::Result<Vec<u8>> {
+    let res = client
+        .get(format!("https://{host}/favicon.ico"))
+        .timeout(std::time::Duration::from_secs(10))
+        .send()
+        .await?;
+    let bytes = if res.status().is_success() {
+        res.bytes().await?.to_vec()
+    } else {
+        tracing::debug!("No favicon for {}: {}", host, res.status());
+        Vec::new()
+    };
+    std::fs::create_dir_all(paths::cache_dir(CACHE_DIR))?;
+    std::fs::write(cache_path(&host), &bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hosts_come_from_careers_urls() {
+        assert_eq!(
+            host("https://Careers.Acme.com/jobs?team=eng").as_deref(),
+            Some("careers.acme.com")
+        );
+        assert_eq!(host("acme.com/careers").as_deref(), Some("acme.com"));
+        assert_eq!(host("  "), None);
+    }
+}
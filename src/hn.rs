@@ -0,0 +1,316 @@
+use chrono::Utc;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::api::JobSearchResult;
+use crate::db::{
+    job_post::{JobPost, JobPostLocationType},
+    NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+use crate::utils::find_yoe_naive;
+
+/* Hacker News "Who's Hiring" */
+// https://github.com/HackerNews/API //
+
+const HN_API_URL: &str = "https://hacker-news.firebaseio.com/v0";
+const HN_ITEM_URL: &str = "https://news.ycombinator.com/item?id=";
+const WHOISHIRING_USER: &str = "whoishiring";
+// Comments are fetched one request each, so keep a bounded number in flight
+const MAX_CONCURRENT_REQUESTS: usize = 20;
+
+const ROLE_KEYWORDS: [&str; 14] = [
+    "engineer",
+    "developer",
+    "designer",
+    "manager",
+    "scientist",
+    "analyst",
+    "architect",
+    "lead",
+    "sre",
+    "devops",
+    "product",
+    "programmer",
+    "researcher",
+    "intern",
+];
+
+#[derive(Debug, Deserialize)]
+struct HNUser {
+    submitted: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HNItem {
+    id: i64,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    time: Option<i64>,
+    #[serde(default)]
+    kids: Vec<i64>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    dead: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct HNThread {
+    pub title: String,
+    pub results: Vec<JobSearchResult>,
+}
+
+async fn fetch_item(client: &reqwest::Client, id: i64) -> anyhow::Result<Option<HNItem>> {
+    client
+        .get(format!("{HN_API_URL}/item/{id}.json"))
+        .send()
+        .await?
+        .json()
+        .await
+        .map_err(Into::into)
+}
+
+/// Finds the most recent "Ask HN: Who is hiring?" thread
+async fn latest_thread(client: &reqwest::Client) -> anyhow::Result<HNItem> {
+    let user: HNUser = client
+        .get(format!("{HN_API_URL}/user/{WHOISHIRING_USER}.json"))
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    // The account also posts "Who wants to be hired?" and "Freelancer?" threads each month
+    for id in user.submitted.iter().take(10) {
+        if let Some(item) = fetch_item(client, *id).await? {
+            let is_hiring = item
+                .title
+                .as_deref()
+                .is_some_and(|title| title.to_lowercase().contains("who is hiring"));
+            if is_hiring {
+                return Ok(item);
+            }
+        }
+    }
+
+    anyhow::bail!("Could not find a recent Who's Hiring thread")
+}
+
+/// Fetches the latest Who's Hiring thread and parses its top-level comments.
+/// Nothing is written to the database; results are imported with `api::import_results`.
+#[tracing::instrument(skip_all, err)]
+pub async fn fetch_whos_hiring(
+    client: reqwest::Client,
+    executor: sqlx::SqlitePool,
+) -> anyhow::Result<HNThread> {
+    let thread = latest_thread(&client).await?;
+
+    let mut comments = Vec::new();
+    for chunk in thread.kids.chunks(MAX_CONCURRENT_REQUESTS) {
+        let mut tasks = tokio::task::JoinSet::new();
+        for id in chunk.iter().copied() {
+            let client = client.clone();
+            tasks.spawn(async move { fetch_item(&client, id).await });
+        }
+        while let Some(res) = tasks.join_next().await {
+            if let Some(item) = res?? {
+                comments.push(item);
+            }
+        }
+    }
+    // JoinSet yields in completion order, so sort newest first
+    comments.sort_by_key(|comment| std::cmp::Reverse(comment.time));
+
+    let mut results = Vec::new();
+    for comment in comments {
+        if comment.deleted || comment.dead {
+            continue;
+        }
+        let Some(mut result) = parse_comment(&comment) else {
+            continue;
+        };
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+            .bind(&result.job_post.url)
+            .fetch_optional(&executor)
+            .await?;
+        result.exists = exists.is_some();
+        results.push(result);
+    }
+
+    Ok(HNThread {
+        title: thread.title.unwrap_or_default(),
+        results,
+    })
+}
+
+fn decode_html(html: &str) -> String {
+    let tags = Regex::new(r"<[^>]*>").expect("Failed to make regex");
+    let text = html.replace("<p>", "\n");
+    tags.replace_all(&text, "")
+        .replace("&#x2F;", "/")
+        .replace("&#x27;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Top-level comments conventionally start with `Company | Title | Location | REMOTE | ...`
+fn parse_comment(comment: &HNItem) -> Option<JobSearchResult> {
+    let html = comment.text.as_deref()?;
+    let text = decode_html(html);
+    let header = text.lines().next()?.trim();
+    let parts: Vec<&str> = header
+        .split('|')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    // Comments without the pipe-separated header are usually replies or meta discussion
+    if parts.len() < 2 {
+        return None;
+    }
+
+    let company_name = parts[0].to_string();
+    let rest = &parts[1..];
+    let lowered: Vec<String> = rest.iter().map(|part| part.to_lowercase()).collect();
+
+    let job_title = lowered
+        .iter()
+        .position(|part| ROLE_KEYWORDS.iter().any(|keyword| part.contains(keyword)))
+        .map_or(rest[0], |index| rest[index])
+        .to_string();
+
+    let remote = lowered.iter().any(|part| part.contains("remote"));
+    let hybrid = lowered.iter().any(|part| part.contains("hybrid"));
+    let onsite = lowered
+        .iter()
+        .any(|part| part.contains("onsite") || part.contains("on-site"));
+    let location_type = if hybrid {
+        JobPostLocationType::Hybrid
+    } else if remote {
+        JobPostLocationType::Remote
+    } else if onsite {
+        JobPostLocationType::Onsite
+    } else {
+        JobPostLocationType::Unknown
+    };
+
+    // Location is the first part that isn't the title, a workplace type, pay, or a link
+    let location = rest
+        .iter()
+        .zip(lowered.iter())
+        .find(|(part, lower)| {
+            **part != job_title
+                && !ROLE_KEYWORDS.iter().any(|keyword| lower.contains(keyword))
+                && ![
+                    "remote",
+                    "hybrid",
+                    "onsite",
+                    "on-site",
+                    "full-time",
+                    "full time",
+                ]
+                .iter()
+                .any(|keyword| lower.trim() == *keyword)
+                && !lower.contains('$')
+                && !lower.contains("http")
+        })
+        .map(|(part, _)| part.to_string())
+        .unwrap_or_default();
+
+    let link = Regex::new(r#"href="([^"]+)""#)
+        .expect("Failed to make regex")
+        .captures(html)
+        .and_then(|cap| cap.get(1))
+        .map(|m| decode_html(m.as_str()));
+    let item_url = format!("{HN_ITEM_URL}{}", comment.id);
+    let description = text.trim().to_string();
+    let (min_yoe, max_yoe) = find_yoe_naive(&description);
+
+    Some(JobSearchResult {
+        company_name,
+        company_url: link,
+        job_post: JobPost {
+            id: 0,
+            company_id: 0,
+            location,
+            location_type,
+            // The comment itself is the posting; the first link is usually the company or careers page
+            url: item_url.clone(),
+            min_yoe,
+            max_yoe,
+            min_pay_cents: None,
+            max_pay_cents: None,
+            date_posted: NullableSqliteDateTime::from(comment.time),
+            date_retrieved: SqliteDateTime(Utc::now()),
+            job_title,
+            benefits: None,
+            skills: None,
+            pay_unit: None,
+            currency: None,
+            apijobs_id: None,
+            industry: None,
+            notes: None,
+            platform_url: Some(item_url),
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            // The whole comment, searchable and shown in the post's details
+            description: Some(description),
+        },
+        exists: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(text: &str) -> HNItem {
+        HNItem {
+            id: 42,
+            title: None,
+            text: Some(text.to_string()),
+            time: Some(1_740_000_000),
+            kids: Vec::new(),
+            deleted: false,
+            dead: false,
+        }
+    }
+
+    #[test]
+    fn comments_with_a_header_become_job_posts() {
+        let result = parse_comment(&comment(
+            "Acme | Senior Rust Engineer | Berlin | REMOTE | $150k<p>3+ years of Rust. \
+             Apply at <a href=\"https:&#x2F;&#x2F;acme.dev&#x2F;jobs\">acme.dev</a>",
+        ))
+        .unwrap();
+        assert_eq!(result.company_name, "Acme");
+        assert_eq!(result.company_url.as_deref(), Some("https://acme.dev/jobs"));
+        let job = result.job_post;
+        assert_eq!(job.job_title, "Senior Rust Engineer");
+        assert_eq!(job.location, "Berlin");
+        assert_eq!(job.location_type, JobPostLocationType::Remote);
+        assert_eq!(job.url, "https://news.ycombinator.com/item?id=42");
+        assert_eq!(job.min_yoe, Some(3));
+        assert!(job
+            .description
+            .is_some_and(|description| description.ends_with("Apply at acme.dev")));
+        assert_eq!(job.notes, None);
+    }
+
+    #[test]
+    fn comments_without_a_header_are_skipped() {
+        assert!(parse_comment(&comment("Is anyone hiring juniors this month?")).is_none());
+
+        let job = parse_comment(&comment("Globex | Hybrid | London | Product Designer"))
+            .unwrap()
+            .job_post;
+        assert_eq!(job.job_title, "Product Designer");
+        assert_eq!(job.location, "London");
+        assert_eq!(job.location_type, JobPostLocationType::Hybrid);
+    }
+}
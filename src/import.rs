@@ -0,0 +1,653 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::db::{
+    company::Company,
+    job_application::{JobApplication, JobApplicationStatus},
+    job_post::{JobPost, JobPostLocationType},
+    post_rule::InsertCounts,
+    NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+
+/* Job tracker exports (Huntr, Teal) */
+
+/// One saved job from another tracker's export
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackerRecord {
+    pub company: String,
+    pub job_title: String,
+    pub url: String,
+    pub location: String,
+    pub stage: String,
+    pub date_added: Option<NaiveDate>,
+    pub date_applied: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+impl TrackerRecord {
+    /// Picks out the known columns; each tracker names and cases them a little differently
+    fn from_fields(fields: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut record = Self::default();
+        for (key, value) in fields {
+            let value = value.trim().to_string();
+            if value.is_empty() {
+                continue;
+            }
+            match normalize_key(&key).as_str() {
+                "company" | "company name" | "employer" | "organization" => record.company = value,
+                "job title" | "title" | "position" | "role" => record.job_title = value,
+                "url" | "job url" | "job posting url" | "job link" | "link" | "post url" => {
+                    record.url = value
+                }
+                "location" | "job location" => record.location = value,
+                "status" | "stage" | "list" | "list name" | "column" => record.stage = value,
+                "date added" | "date saved" | "created" | "created at" | "date created" => {
+                    record.date_added = parse_date(&value)
+                }
+                "date applied" | "applied at" | "applied on" | "application date" => {
+                    record.date_applied = parse_date(&value)
+                }
+                "notes" | "note" => record.notes = Some(value),
+                _ => {}
+            }
+        }
+        record
+    }
+
+    pub fn status(&self) -> JobApplicationStatus {
+        tracker_status(&self.stage)
+    }
+
+    fn location_type(&self) -> JobPostLocationType {
+        let location = self.location.to_lowercase();
+        if location.contains("remote") {
+            JobPostLocationType::Remote
+        } else if location.contains("hybrid") {
+            JobPostLocationType::Hybrid
+        } else {
+            JobPostLocationType::Unknown
+        }
+    }
+}
+
+/// Maps a Huntr list or Teal status onto ours. Custom stages fall back to `New`
+pub fn tracker_status(stage: &str) -> JobApplicationStatus {
+    let stage = stage.to_lowercase();
+    let has = |words: &[&str]| words.iter().any(|word| stage.contains(word));
+    if has(&["withdr"]) {
+        JobApplicationStatus::Withdrawn
+    } else if has(&["reject", "not selected", "declined"]) {
+        JobApplicationStatus::Rejected
+    } else if has(&["offer", "negotiat", "accepted"]) {
+        JobApplicationStatus::Offer
+    } else if has(&["interview", "screen"]) {
+        JobApplicationStatus::Interview
+    } else if has(&["no response", "archived", "closed", "ghosted"]) {
+        JobApplicationStatus::Closed
+    } else if stage.trim() == "applied" {
+        JobApplicationStatus::Applied
+    } else {
+        // Wishlist, Bookmarked, Applying, ...
+        JobApplicationStatus::New
+    }
+}
+
+/// "Job_Title", "jobTitle", and "Job Title" all become "job title"
+fn normalize_key(key: &str) -> String {
+    let mut normalized = String::with_capacity(key.len());
+    let mut prev_lower = false;
+    for c in key.trim().trim_start_matches('\u{feff}').chars() {
+        if c.is_uppercase() && prev_lower {
+            normalized.push(' ');
+        }
+        prev_lower = c.is_lowercase();
+        match c {
+            '_' | '-' | '.' => normalized.push(' '),
+            c => normalized.extend(c.to_lowercase()),
+        }
+    }
+    normalized.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%m/%d/%Y") {
+        return Some(date);
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Utc).date_naive());
+    }
+    // Unix timestamps, in milliseconds from JSON exports
+    let timestamp: i64 = value.parse().ok()?;
+    let datetime = if timestamp > 100_000_000_000 {
+        DateTime::from_timestamp_millis(timestamp)
+    } else {
+        DateTime::from_timestamp(timestamp, 0)
+    };
+    datetime.map(|datetime| datetime.date_naive())
+}
+
+/* Parsing */
+
+/// Reads a CSV or JSON export, skipping entries without a company or title
+pub fn read_tracker_export(path: &Path) -> anyhow::Result<Vec<TrackerRecord>> {
+    let contents = std::fs::read_to_string(path)?;
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+    let records = if is_json {
+        parse_json(&contents)?
+    } else {
+        parse_csv_records(&contents)
+    };
+    Ok(records
+        .into_iter()
+        .filter(|record| !record.company.is_empty() && !record.job_title.is_empty())
+        .collect())
+}
+
+fn parse_csv_records(contents: &str) -> Vec<TrackerRecord> {
+    let mut rows = parse_csv(contents).into_iter();
+    let Some(header) = rows.next() else {
+        return Vec::new();
+    };
+    rows.map(|row| TrackerRecord::from_fields(header.iter().cloned().zip(row)))
+        .collect()
+}
+
+/// Splits CSV text into rows of fields, honoring quoted commas, quotes, and line breaks
+fn parse_csv(contents: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = contents.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => in_quotes = !in_quotes,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|row| row.iter().any(|field| !field.is_empty()));
+    rows
+}
+
+fn parse_json(contents: &str) -> anyhow::Result<Vec<TrackerRecord>> {
+    let value: serde_json::Value = serde_json::from_str(contents)?;
+    // Either a bare list of jobs or an object holding one, e.g. `{ "jobs": [...] }`
+    let jobs = match value {
+        serde_json::Value::Array(jobs) => jobs,
+        serde_json::Value::Object(object) => object
+            .into_iter()
+            .find_map(|(_, value)| match value {
+                serde_json::Value::Array(jobs) if jobs.iter().all(|job| job.is_object()) => {
+                    Some(jobs)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| anyhow::anyhow!("No list of jobs found"))?,
+        _ => anyhow::bail!("Expected a list of jobs"),
+    };
+    Ok(jobs
+        .iter()
+        .map(|job| {
+            let mut fields = Vec::new();
+            flatten_json("", job, &mut fields);
+            TrackerRecord::from_fields(fields)
+        })
+        .collect())
+}
+
+/// Nested objects become space-joined keys, so `{ "company": { "name": .. } }` is "company name"
+fn flatten_json(prefix: &str, value: &serde_json::Value, fields: &mut Vec<(String, String)>) {
+    let key = |name: &str| {
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{prefix} {name}")
+        }
+    };
+    match value {
+        serde_json::Value::Object(object) => {
+            for (name, value) in object {
+                flatten_json(&key(name), value, fields);
+            }
+        }
+        serde_json::Value::String(s) => fields.push((prefix.to_string(), s.clone())),
+        serde_json::Value::Number(n) => fields.push((prefix.to_string(), n.to_string())),
+        serde_json::Value::Bool(b) => fields.push((prefix.to_string(), b.to_string())),
+        serde_json::Value::Array(_) | serde_json::Value::Null => {}
+    }
+}
+
+/* Import */
+
+/// Rows written by an import, and rows left out because their URL was already tracked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrackerImportCounts {
+    pub imported: InsertCounts,
+    pub duplicates: usize,
+}
+
+/// E.g. "3 job posts (1 archived by rules), skipped 2 duplicates"
+impl std::fmt::Display for TrackerImportCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.imported)?;
+        if self.duplicates > 0 {
+            write!(f, ", skipped {} duplicates", self.duplicates)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the records as job posts with applications, creating companies as needed.
+/// Posts whose URL is already tracked, or repeated in the export, are skipped.
+#[tracing::instrument(skip_all, fields(records = records.len()), err)]
+pub async fn import_tracker_records(
+    records: Vec<TrackerRecord>,
+    executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+) -> anyhow::Result<TrackerImportCounts> {
+    let mut tx = executor.begin().await?;
+    let mut urls = HashSet::new();
+    let mut counts = TrackerImportCounts::default();
+    for record in records {
+        if !record.url.is_empty() {
+            if !urls.insert(record.url.clone()) {
+                counts.duplicates += 1;
+                continue;
+            }
+            let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+                .bind(&record.url)
+                .fetch_optional(&mut *tx)
+                .await?;
+            if exists.is_some() {
+                counts.duplicates += 1;
+                continue;
+            }
+        }
+        let company_id = match Company::fetch_id_by_name(&record.company, &mut *tx).await? {
+            Some(id) => id,
+            None => {
+                Company {
+                    id: 0,
+                    name: record.company.clone(),
+                    careers_url: None,
+                    hidden: SqliteBoolean(false),
+                    group_id: None,
+                }
+                .insert(&mut *tx)
+                .await?
+            }
+        };
+        let date_retrieved = record
+            .date_added
+            .map(|date| date.and_time(chrono::NaiveTime::MIN).and_utc())
+            .unwrap_or_else(Utc::now);
+        let (job_post_id, inserted) = JobPost {
+            id: 0,
+            company_id,
+            location: record.location.clone(),
+            location_type: record.location_type(),
+            url: record.url.clone(),
+            min_yoe: None,
+            max_yoe: None,
+            min_pay_cents: None,
+            max_pay_cents: None,
+            date_posted: NullableSqliteDateTime(None),
+            date_retrieved: SqliteDateTime(date_retrieved),
+            job_title: record.job_title.clone(),
+            benefits: None,
+            skills: None,
+            pay_unit: None,
+            currency: None,
+            apijobs_id: None,
+            platform_url: None,
+            notes: record.notes.clone(),
+            industry: None,
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            description: None,
+        }
+        .insert(&mut *tx)
+        .await?;
+        counts.imported += inserted;
+
+        let status = record.status();
+        if status != JobApplicationStatus::New || record.date_applied.is_some() {
+            let interviewed = matches!(
+                status,
+                JobApplicationStatus::Interview | JobApplicationStatus::Offer
+            );
+            JobApplication {
+                id: 0,
+                job_post_id,
+                status,
+                date_applied: NullableSqliteDateTime(record.date_applied),
+                date_responded: NullableSqliteDateTime(None),
+                interviewed: SqliteBoolean(interviewed),
+                resume_version: None,
+                channel: None,
+                interview_at: None,
+                interview_timezone: None,
+                cover_letter_path: None,
+                cover_letter: None,
+            }
+            .insert(&mut *tx)
+            .await?;
+        }
+    }
+    tx.commit().await?;
+
+    Ok(counts)
+}
+
+/* Job URLs */
+
+/// The http(s) links in pasted text, one per line or separated by spaces or commas, in
+/// order without repeats
+pub fn parse_url_list(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .map(|url| url.trim_matches(['<', '>', '"', '\'']))
+        .filter(|url| url.starts_with("https://") || url.starts_with("http://"))
+        .filter(|url| seen.insert(url.to_string()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Which of `urls` a job post already has
+pub async fn tracked_urls(
+    urls: &[String],
+    executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+) -> anyhow::Result<HashSet<String>> {
+    let mut conn = executor.acquire().await?;
+    let mut tracked = HashSet::new();
+    for url in urls {
+        let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+            .bind(url)
+            .fetch_optional(&mut *conn)
+            .await?;
+        if exists.is_some() {
+            tracked.insert(url.clone());
+        }
+    }
+    Ok(tracked)
+}
+
+/// Writes a scraped post under `company_name`, creating the company if it's new. `None` when
+/// the post's URL is already tracked, e.g. a link that redirected to one imported before
+#[tracing::instrument(skip(job_post, executor), err)]
+pub async fn import_scraped_post(
+    company_name: &str,
+    mut job_post: JobPost,
+    executor: impl sqlx::Acquire<'_, Database = sqlx::Sqlite>,
+) -> anyhow::Result<Option<InsertCounts>> {
+    let mut tx = executor.begin().await?;
+    let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM job_post WHERE url = ?")
+        .bind(&job_post.url)
+        .fetch_optional(&mut *tx)
+        .await?;
+    if exists.is_some() {
+        return Ok(None);
+    }
+    job_post.company_id = match Company::fetch_id_by_name(company_name, &mut *tx).await? {
+        Some(id) => id,
+        None => {
+            Company {
+                id: 0,
+                name: company_name.trim().to_string(),
+                careers_url: None,
+                hidden: SqliteBoolean(false),
+                group_id: None,
+            }
+            .insert(&mut *tx)
+            .await?
+        }
+    };
+    let (_, inserted) = job_post.insert(&mut *tx).await?;
+    tx.commit().await?;
+
+    Ok(Some(inserted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::post_rule::{PostRule, PostRuleAction};
+    use crate::db::test_support::{count_rows, memory_pool, CompanyBuilder, JobPostBuilder};
+
+    const HUNTR_CSV: &str =
+        "\u{feff}Company Name,Job Title,List Name,Job URL,Job Location,Applied At\r\n\
+        \"Acme, Inc.\",Engineer,Applied,https://acme.example/1,\"Remote, US\",2025-03-04\r\n\
+        Globex,Designer,Wishlist,https://globex.example/2,Springfield,\r\n\
+        ,Missing Company,Applied,,,\r\n";
+
+    const TEAL_JSON: &str = r#"{
+        "jobs": [
+            {
+                "company": { "name": "Initech" },
+                "jobTitle": "Analyst",
+                "status": "Interviewing",
+                "jobPostingUrl": "https://initech.example/3",
+                "dateApplied": "2025-03-05T12:00:00Z",
+                "notes": "Spoke with the hiring manager"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn csv_handles_quotes_and_line_breaks() {
+        let rows = parse_csv("a,\"b, c\",\"say \"\"hi\"\"\"\r\n\"multi\nline\",,\r\n");
+        assert_eq!(
+            rows,
+            [vec!["a", "b, c", "say \"hi\""], vec!["multi\nline", "", ""],]
+        );
+    }
+
+    #[test]
+    fn keys_are_normalized() {
+        assert_eq!(normalize_key("jobPostingUrl"), "job posting url");
+        assert_eq!(normalize_key("Job_Title"), "job title");
+        assert_eq!(normalize_key(" Date  Applied "), "date applied");
+    }
+
+    #[test]
+    fn stages_map_onto_statuses() {
+        assert_eq!(tracker_status("Wishlist"), JobApplicationStatus::New);
+        assert_eq!(tracker_status("Bookmarked"), JobApplicationStatus::New);
+        assert_eq!(tracker_status("Applying"), JobApplicationStatus::New);
+        assert_eq!(tracker_status("Applied"), JobApplicationStatus::Applied);
+        assert_eq!(
+            tracker_status("Interviewing"),
+            JobApplicationStatus::Interview
+        );
+        assert_eq!(tracker_status("Negotiating"), JobApplicationStatus::Offer);
+        assert_eq!(
+            tracker_status("Not Selected"),
+            JobApplicationStatus::Rejected
+        );
+        assert_eq!(
+            tracker_status("I Withdrew"),
+            JobApplicationStatus::Withdrawn
+        );
+        assert_eq!(tracker_status("No Response"), JobApplicationStatus::Closed);
+    }
+
+    #[test]
+    fn huntr_csv_records() {
+        let records: Vec<_> = parse_csv_records(HUNTR_CSV)
+            .into_iter()
+            .filter(|record| !record.company.is_empty())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].company, "Acme, Inc.");
+        assert_eq!(records[0].location_type(), JobPostLocationType::Remote);
+        assert_eq!(records[0].date_applied, NaiveDate::from_ymd_opt(2025, 3, 4));
+        assert_eq!(records[1].status(), JobApplicationStatus::New);
+        assert_eq!(records[1].date_applied, None);
+    }
+
+    #[test]
+    fn teal_json_records() {
+        let records = parse_json(TEAL_JSON).unwrap();
+        assert_eq!(
+            records,
+            [TrackerRecord {
+                company: "Initech".to_string(),
+                job_title: "Analyst".to_string(),
+                url: "https://initech.example/3".to_string(),
+                location: "".to_string(),
+                stage: "Interviewing".to_string(),
+                date_added: None,
+                date_applied: NaiveDate::from_ymd_opt(2025, 3, 5),
+                notes: Some("Spoke with the hiring manager".to_string()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn import_creates_applications_and_skips_tracked_urls() {
+        let pool = memory_pool().await;
+        let globex = CompanyBuilder::new("Globex").insert(&pool).await;
+        let mut tracked = JobPostBuilder::new(globex.id, "Designer").build();
+        tracked.url = "https://globex.example/2".to_string();
+        tracked.insert(&pool).await.unwrap();
+
+        let mut records = parse_csv_records(HUNTR_CSV);
+        records.retain(|record| !record.company.is_empty());
+        records.extend(parse_json(TEAL_JSON).unwrap());
+        let counts = import_tracker_records(records, &pool).await.unwrap();
+
+        assert_eq!(counts.imported.inserted, 2);
+        assert_eq!(counts.duplicates, 1);
+        assert_eq!(counts.to_string(), "2 job posts, skipped 1 duplicates");
+        assert_eq!(count_rows("company", &pool).await, 3);
+        assert_eq!(count_rows("job_post", &pool).await, 3);
+        let statuses: Vec<(JobApplicationStatus, SqliteBoolean)> =
+            sqlx::query_as("SELECT status, interviewed FROM job_application ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            statuses,
+            [
+                (JobApplicationStatus::Applied, SqliteBoolean(false)),
+                (JobApplicationStatus::Interview, SqliteBoolean(true)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn imports_attach_notes_and_applications_to_tagged_posts() {
+        let pool = memory_pool().await;
+        PostRule {
+            id: 0,
+            name: "Analysts".to_string(),
+            title_contains: "analyst".to_string(),
+            skills_contains: "".to_string(),
+            location_contains: "".to_string(),
+            company_contains: "".to_string(),
+            min_pay: 0,
+            pay_below: 0,
+            action: PostRuleAction::Tag,
+            tag: "analysis".to_string(),
+            enabled: SqliteBoolean(true),
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        // So the new post's id isn't the tag row's too
+        let globex = CompanyBuilder::new("Globex").insert(&pool).await;
+        for url in ["https://globex.example/1", "https://globex.example/2"] {
+            let mut post = JobPostBuilder::new(globex.id, "Designer").build();
+            post.url = url.to_string();
+            post.insert(&pool).await.unwrap();
+        }
+
+        let counts = import_tracker_records(parse_json(TEAL_JSON).unwrap(), &pool)
+            .await
+            .unwrap();
+        assert_eq!(counts.imported.inserted, 1);
+        let (job_post_id, notes): (i64, Option<String>) = sqlx::query_as(
+            "SELECT id, notes FROM job_post WHERE url = 'https://initech.example/3'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(notes.as_deref(), Some("Spoke with the hiring manager"));
+        let application_post_id: i64 =
+            sqlx::query_scalar("SELECT job_post_id FROM job_application")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(application_post_id, job_post_id);
+        assert_eq!(count_rows("job_post_tag", &pool).await, 1);
+    }
+
+    #[test]
+    fn url_lists_keep_links_once_in_order() {
+        let text = "https://jobs.lever.co/acme/1\n\n  https://example.com/a, <https://example.com/b>\nnot a link\nhttps://jobs.lever.co/acme/1\n";
+        assert_eq!(
+            parse_url_list(text),
+            [
+                "https://jobs.lever.co/acme/1",
+                "https://example.com/a",
+                "https://example.com/b",
+            ]
+        );
+        assert!(parse_url_list("").is_empty());
+    }
+
+    #[tokio::test]
+    async fn scraped_posts_are_imported_once() {
+        let pool = memory_pool().await;
+        let acme = CompanyBuilder::new("Acme").insert(&pool).await;
+        let mut job_post = JobPostBuilder::new(-1, "Engineer").build();
+        job_post.url = "https://jobs.lever.co/acme/1".to_string();
+
+        let urls = vec![job_post.url.clone(), "https://example.com/2".to_string()];
+        assert!(tracked_urls(&urls, &pool).await.unwrap().is_empty());
+        let counts = import_scraped_post("acme", job_post.clone(), &pool)
+            .await
+            .unwrap();
+        assert_eq!(counts.map(|counts| counts.inserted), Some(1));
+        let company_id: i64 = sqlx::query_scalar("SELECT company_id FROM job_post")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(company_id, acme.id);
+        assert_eq!(
+            tracked_urls(&urls, &pool).await.unwrap(),
+            HashSet::from([job_post.url.clone()])
+        );
+
+        assert_eq!(
+            import_scraped_post("Globex", job_post, &pool)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(count_rows("company", &pool).await, 1);
+        assert_eq!(count_rows("job_post", &pool).await, 1);
+    }
+}
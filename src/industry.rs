@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use crate::paths;
+
+/// Under the config directory
+pub const INDUSTRIES_FILE: &str = "industries.toml";
+
+/// Single-word keywords per industry, read from `industries.toml` so the taxonomy can be
+/// edited. Posts are given the industry whose keywords they mention most
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct Taxonomy {
+    pub industries: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for Taxonomy {
+    fn default() -> Self {
+        let industries: [(&str, &[&str]); 7] = [
+            (
+                "Defense",
+                &["defense", "military", "clearance", "dod", "aerospace"],
+            ),
+            (
+                "E-commerce",
+                &[
+                    "e-commerce",
+                    "ecommerce",
+                    "retail",
+                    "marketplace",
+                    "shopping",
+                ],
+            ),
+            (
+                "Education",
+                &["education", "edtech", "learning", "students", "university"],
+            ),
+            (
+                "Fintech",
+                &[
+                    "fintech", "payments", "banking", "trading", "lending", "crypto",
+                ],
+            ),
+            ("Gaming", &["gaming", "game", "games", "unity", "unreal"]),
+            (
+                "Healthcare",
+                &[
+                    "healthcare",
+                    "health",
+                    "medical",
+                    "clinical",
+                    "hipaa",
+                    "patient",
+                ],
+            ),
+            (
+                "Security",
+                &["cybersecurity", "security", "threat", "soc", "siem"],
+            ),
+        ];
+        Self {
+            industries: industries
+                .iter()
+                .map(|(name, keywords)| {
+                    (
+                        name.to_string(),
+                        keywords.iter().map(|keyword| keyword.to_string()).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Taxonomy {
+    /// Reads `industries.toml`, writing out the defaults first if it doesn't exist yet
+    pub fn load() -> anyhow::Result<Self> {
+        let path = paths::config_file(INDUSTRIES_FILE);
+        if !path.exists() {
+            let taxonomy = Self::default();
+            let content = toml::to_string_pretty(&taxonomy)?;
+            let res = paths::ensure_parent(&path).and_then(|_| std::fs::write(&path, content));
+            if let Err(e) = res {
+                tracing::warn!("Failed to write default industries: {:?}", e);
+            }
+            return Ok(taxonomy);
+        }
+        Self::parse(&std::fs::read_to_string(&path)?)
+    }
+
+    pub fn parse(content: &str) -> anyhow::Result<Self> {
+        toml::from_str(content)
+            .map_err(|e| anyhow::anyhow!("Invalid {INDUSTRIES_FILE}: {}", e.message()))
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.industries.keys().cloned().collect()
+    }
+
+    /// The industry with the most distinct keywords in `text` as whole words, ignoring case.
+    /// Ties go to the first industry alphabetically
+    pub fn classify(&self, text: &str) -> Option<String> {
+        let words: Vec<String> = text
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric() && c != '-')
+            .filter(|word| !word.is_empty())
+            .map(String::from)
+            .collect();
+        let mut best: Option<(&String, usize)> = None;
+        for (industry, keywords) in &self.industries {
+            let hits = keywords
+                .iter()
+                .filter(|keyword| words.contains(&keyword.to_lowercase()))
+                .count();
+            if hits > best.map_or(0, |(_, best_hits)| best_hits) {
+                best = Some((industry, hits));
+            }
+        }
+        best.map(|(industry, _)| industry.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posts_get_the_industry_they_mention_most() {
+        let taxonomy = Taxonomy::default();
+        assert_eq!(
+            taxonomy.classify("Backend Engineer, Payments - we build banking APIs"),
+            Some("Fintech".to_string())
+        );
+        assert_eq!(
+            taxonomy.classify("HIPAA-aware engineer for clinical health records"),
+            Some("Healthcare".to_string())
+        );
+        // "gameday" isn't "game"
+        assert_eq!(taxonomy.classify("Rust Engineer, gameday on-call"), None);
+    }
+
+    #[test]
+    fn edited_taxonomies_replace_the_defaults() {
+        let taxonomy = Taxonomy::parse(
+            r#"
+            [industries]
+            Climate = ["solar", "carbon"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(taxonomy.names(), ["Climate"]);
+        assert_eq!(
+            taxonomy.classify("Carbon accounting platform"),
+            Some("Climate".to_string())
+        );
+        assert_eq!(taxonomy.classify("Payments engineer"), None);
+    }
+}
@@ -0,0 +1,130 @@
+use chrono::{DateTime, LocalResult, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::utils::parse_date_input;
+
+/* Interview times are stored as UTC timestamps next to the company's timezone name,
+so they can be shown in both the company's time and the user's */
+
+/// An IANA timezone name like "America/New_York"
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.trim().parse().map_err(|_| {
+        format!(
+            "Unknown timezone \"{}\" (try America/New_York)",
+            name.trim()
+        )
+    })
+}
+
+/// Parses a typed time such as "14:30", "2:30 PM", or "2pm"
+pub fn parse_time_input(input: &str) -> Option<NaiveTime> {
+    let input = input.trim().to_uppercase();
+    ["%H:%M", "%I:%M %p", "%I:%M%p"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(&input, format).ok())
+        .or_else(|| {
+            // chrono can't parse a time without minutes, so "2 PM" becomes "2:00 PM"
+            let (hour, meridiem) = input.split_at(input.find(['A', 'P'])?);
+            let padded = format!("{}:00 {}", hour.trim(), meridiem);
+            NaiveTime::parse_from_str(&padded, "%I:%M %p").ok()
+        })
+}
+
+/// The moment a wall-clock time happens in `timezone` (the user's own when empty). When
+/// clocks fall back the earlier of the two times is used; times skipped when clocks spring
+/// forward are rejected
+pub fn to_utc(date: &str, time: &str, timezone: &str) -> Result<DateTime<Utc>, String> {
+    let date = parse_date_input(date).ok_or("Enter a date like 2025-03-01 or 3/1/25")?;
+    let time = parse_time_input(time).ok_or("Enter a time like 14:30 or 2:30 PM")?;
+    let naive = NaiveDateTime::new(date, time);
+    if timezone.trim().is_empty() {
+        resolve(chrono::Local.from_local_datetime(&naive))
+    } else {
+        resolve(parse_timezone(timezone)?.from_local_datetime(&naive))
+    }
+}
+
+fn resolve<T: TimeZone>(result: LocalResult<DateTime<T>>) -> Result<DateTime<Utc>, String> {
+    match result {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Ok(dt.with_timezone(&Utc)),
+        LocalResult::None => Err("That time is skipped by a daylight saving change".to_string()),
+    }
+}
+
+/// The date and time inputs for editing a stored interview, in its own timezone
+pub fn to_inputs(at: DateTime<Utc>, timezone: Option<&str>) -> (String, String) {
+    let (date, time) = match timezone.map(parse_timezone) {
+        Some(Ok(tz)) => {
+            let at = at.with_timezone(&tz);
+            (at.date_naive(), at.time())
+        }
+        _ => {
+            let at = at.with_timezone(&chrono::Local);
+            (at.date_naive(), at.time())
+        }
+    };
+    (
+        date.format("%Y-%m-%d").to_string(),
+        time.format("%H:%M").to_string(),
+    )
+}
+
+/// E.g. "Tue 03/04 2:00 PM EST (11:00 AM your time)"; just the user's time without a timezone
+pub fn format_interview(at: DateTime<Utc>, timezone: Option<&str>) -> String {
+    let local = at.with_timezone(&chrono::Local);
+    let Some(Ok(tz)) = timezone.map(parse_timezone) else {
+        return local.format("%a %m/%d %-I:%M %p").to_string();
+    };
+    let zoned = at.with_timezone(&tz);
+    let local_format = if local.date_naive() == zoned.date_naive() {
+        "%-I:%M %p"
+    } else {
+        "%a %m/%d %-I:%M %p"
+    };
+    format!(
+        "{} ({} your time)",
+        zoned.format("%a %m/%d %-I:%M %p %Z"),
+        local.format(local_format)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times_parse_in_common_formats() {
+        let two_thirty = NaiveTime::from_hms_opt(14, 30, 0);
+        assert_eq!(parse_time_input("14:30"), two_thirty);
+        assert_eq!(parse_time_input("2:30 pm"), two_thirty);
+        assert_eq!(parse_time_input("2:30PM"), two_thirty);
+        assert_eq!(parse_time_input("2pm"), NaiveTime::from_hms_opt(14, 0, 0));
+        assert_eq!(parse_time_input("2"), None);
+    }
+
+    #[test]
+    fn company_time_converts_across_dst() {
+        // EDT is UTC-4, EST is UTC-5
+        let summer = to_utc("2025-07-01", "2:00 PM", "America/New_York").unwrap();
+        assert_eq!(summer.to_rfc3339(), "2025-07-01T18:00:00+00:00");
+        let winter = to_utc("2025-12-01", "2:00 PM", "America/New_York").unwrap();
+        assert_eq!(winter.to_rfc3339(), "2025-12-01T19:00:00+00:00");
+        // 1:30 AM happens twice on Nov 2; the first one is still EDT
+        let fall_back = to_utc("2025-11-02", "1:30 AM", "America/New_York").unwrap();
+        assert_eq!(fall_back.to_rfc3339(), "2025-11-02T05:30:00+00:00");
+        // 2:30 AM never happens on Mar 9
+        assert!(to_utc("2025-03-09", "2:30 AM", "America/New_York").is_err());
+        assert!(to_utc("2025-07-01", "2:00 PM", "Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn stored_times_round_trip_to_inputs() {
+        let at = to_utc("2025-07-01", "09:15", "Europe/Berlin").unwrap();
+        assert_eq!(
+            to_inputs(at, Some("Europe/Berlin")),
+            ("2025-07-01".to_string(), "09:15".to_string())
+        );
+        let formatted = format_interview(at, Some("Europe/Berlin"));
+        assert!(formatted.starts_with("Tue 07/01 9:15 AM CEST ("));
+    }
+}
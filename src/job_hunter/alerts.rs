@@ -0,0 +1,248 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum AlertMessage {
+    ShowAlertRulesModal,
+    AlertRuleNameChanged(String),
+    AlertRuleIntervalChanged(i64),
+    SaveAlertRule,
+    ToggleAlertRule(i64, bool),
+    DeleteAlertRule(i64),
+    RunDueAlertRules,
+    AlertRulesRan(Vec<(String, InsertCounts)>),
+    AlertRulesFailed(String),
+}
+
+impl JobHunter {
+    pub(super) fn update_alerts(&mut self, message: AlertMessage) -> Task<Message> {
+        match message {
+            AlertMessage::ShowAlertRulesModal => {
+                self.set_alert_rules();
+                self.push_modal(Modal::AlertRulesModal);
+                Task::none()
+            }
+            AlertMessage::AlertRuleNameChanged(name) => {
+                self.alert_rule_name = name;
+                Task::none()
+            }
+            AlertMessage::AlertRuleIntervalChanged(hours) => {
+                self.alert_rule_interval_hours = hours;
+                Task::none()
+            }
+            AlertMessage::SaveAlertRule => {
+                let Some(provider) = self.find_jobs_provider else {
+                    return Task::none();
+                };
+                if self.alert_rule_name.is_empty() {
+                    return Task::none();
+                }
+                let rule = AlertRule {
+                    id: 0,
+                    name: self.alert_rule_name.clone(),
+                    provider: provider.id().to_string(),
+                    job_title: self.find_jobs_title.clone(),
+                    location: self.find_jobs_location.clone(),
+                    country: self.find_jobs_country.clone(),
+                    min_yoe: self.find_jobs_min_yoe,
+                    min_salary: self.find_jobs_min_salary,
+                    onsite: SqliteBoolean(self.find_jobs_onsite),
+                    hybrid: SqliteBoolean(self.find_jobs_hybrid),
+                    remote: SqliteBoolean(self.find_jobs_remote),
+                    tracked_companies: SqliteBoolean(self.find_jobs_tracked_companies),
+                    interval_hours: self.alert_rule_interval_hours,
+                    last_run: None,
+                    enabled: SqliteBoolean(true),
+                };
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = rule.insert(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to insert alert rule");
+                }
+                self.find_jobs_status = Some(format!("Saved alert \"{}\"", self.alert_rule_name));
+                self.alert_rule_name = "".to_string();
+                Task::none()
+            }
+            AlertMessage::ToggleAlertRule(id, enabled) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = AlertRule::set_enabled(id, enabled, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to update alert rule");
+                }
+                self.set_alert_rules();
+                Task::none()
+            }
+            AlertMessage::DeleteAlertRule(id) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = AlertRule::delete(id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to delete alert rule");
+                }
+                self.set_alert_rules();
+                Task::none()
+            }
+            AlertMessage::RunDueAlertRules => {
+                if self.alert_rules_running
+                    || self.config.provider_settings().configured().is_empty()
+                    || self.is_offline()
+                {
+                    return Task::none();
+                }
+                self.alert_rules_running = true;
+                Task::perform(
+                    api::run_due_alert_rules(
+                        self.http_client(),
+                        self.config.provider_settings(),
+                        self.companies
+                            .iter()
+                            .map(|c| c.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(","),
+                        self.config.alert_interval_hours,
+                        self.db.clone(),
+                    ),
+                    |res| match res {
+                        Ok(inserted) => Message::Alert(AlertMessage::AlertRulesRan(inserted)),
+                        Err(e) => Message::Alert(AlertMessage::AlertRulesFailed(e.to_string())),
+                    },
+                )
+            }
+            AlertMessage::AlertRulesRan(inserted) => {
+                self.alert_rules_running = false;
+                let matches = inserted
+                    .iter()
+                    .filter(|(_, counts)| counts.inserted > 0)
+                    .map(|(name, counts)| format!("{} ({})", name, counts.inserted))
+                    .collect::<Vec<_>>();
+                if matches.is_empty() {
+                    return Task::none();
+                }
+                let mut total = InsertCounts::default();
+                for (_, counts) in &inserted {
+                    total += *counts;
+                }
+                self.status_message =
+                    Some(format!("Alerts imported {}: {}", total, matches.join(", ")));
+                self.notify(&format!("Alerts imported {total}"), &matches.join(", "));
+                self.get_filter_task()
+            }
+            AlertMessage::AlertRulesFailed(error) => {
+                self.alert_rules_running = false;
+                self.status_message = Some(format!("Job alert failed: {error}"));
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn alert_rules_modal<'a>(&'a self) -> Element<'a, Message> {
+        let rules = Column::with_children(self.alert_rules.iter().map(|rule| {
+            let id = rule.id;
+            let last_run = match rule.last_run {
+                Some(last_run) => format!(
+                    "Last run {}",
+                    last_run
+                        .0
+                        .with_timezone(&chrono::Local)
+                        .format("%m/%d/%Y %H:%M")
+                ),
+                None => "Never run".to_string(),
+            };
+            // Company names aren't needed for the summary, only whether they're included
+            let summary = api::alert_rule_params(rule, "tracked").summary();
+            let confirm = format!("Delete the alert {}?", rule.name);
+            row![
+                checkbox("", rule.enabled.0).on_toggle(move |enabled| Message::Alert(
+                    AlertMessage::ToggleAlertRule(id, enabled)
+                )),
+                column![
+                    text(&rule.name).size(14),
+                    text(format!("{} | {}", rule.provider, summary)).size(12),
+                    text(format!("Every {}h | {}", rule.interval_hours, last_run)).size(12),
+                ]
+                .width(Fill),
+                IconButton::new("trash")
+                    .color(self.icon_color())
+                    .solid()
+                    .view()
+                    .map(move |msg| match msg {
+                        IconButtonMessage::Pressed => Message::Confirm(
+                            confirm.clone(),
+                            Box::new(Message::Alert(AlertMessage::DeleteAlertRule(id))),
+                        ),
+                    }),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .into()
+        }))
+        .spacing(10);
+        let rules: Element<'a, Message> = if self.alert_rules.is_empty() {
+            text("No alerts yet. Save one from Find Jobs.")
+                .size(12)
+                .into()
+        } else {
+            scrollable(rules)
+                .height(Length::Fixed(350.0))
+                .width(Fill)
+                .into()
+        };
+
+        container(
+            column![
+                text("Job Alerts").size(24),
+                column![
+                    rules,
+                    row![
+                        container(button(text("Close")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                    ]
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(500)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    fn set_alert_rules(&mut self) {
+        self.alert_rules = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = AlertRule::fetch_all(&pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive alert rules")
+                .expect("Failed to get alert rules")
+        };
+    }
+}
@@ -0,0 +1,131 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum AnalyticsMessage {
+    ShowApplicationStatsModal,
+}
+
+impl JobHunter {
+    pub(super) fn update_analytics(&mut self, message: AnalyticsMessage) -> Task<Message> {
+        match message {
+            AnalyticsMessage::ShowApplicationStatsModal => {
+                let (resume_stats, channel_stats) = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let resume_res = ApplicationStats::by_resume_version(&pool).await;
+                        let channel_res = ApplicationStats::by_channel(&pool).await;
+                        _ = sender.send((resume_res, channel_res));
+                    });
+                    let (resume_res, channel_res) = receiver
+                        .recv()
+                        .expect("Failed to receive application stats");
+                    (
+                        resume_res.expect("Failed to get resume stats"),
+                        channel_res.expect("Failed to get channel stats"),
+                    )
+                };
+                self.resume_stats = resume_stats;
+                self.channel_stats = channel_stats;
+                self.push_modal(Modal::ApplicationStatsModal);
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn application_stats_modal<'a>(&'a self) -> Element<'a, Message> {
+        let channel_name = |group: &str| {
+            group
+                .parse::<ApplicationChannel>()
+                .map(|channel| channel.name().to_string())
+                .unwrap_or_else(|_| group.to_string())
+        };
+        let small_sample_note: Element<'a, Message> = if self
+            .resume_stats
+            .iter()
+            .chain(&self.channel_stats)
+            .any(|stats| stats.small_sample())
+        {
+            text(format!(
+                "* Fewer than {} applications; too few to compare reliably",
+                ApplicationStats::MIN_SAMPLE
+            ))
+            .size(12)
+            .style(text::danger)
+            .into()
+        } else {
+            column![].into()
+        };
+
+        container(
+            column![
+                text("Application Stats").size(24),
+                column![
+                    text("Response and interview rates per group").size(12),
+                    text("By Resume Version").size(16),
+                    stats_table("Version", &self.resume_stats, |group| group.to_string()),
+                    text("By Channel").size(16),
+                    stats_table("Channel", &self.channel_stats, channel_name),
+                    small_sample_note,
+                    row![
+                        container(button(text("Close")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End)
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+            ]
+            .spacing(20),
+        )
+        .width(500)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
+
+/// One row per group; groups with too few applications are starred
+fn stats_table<'a>(
+    label: &'a str,
+    stats: &[ApplicationStats],
+    group_name: impl Fn(&str) -> String,
+) -> Element<'a, Message> {
+    if stats.is_empty() {
+        return text("No applications yet").size(12).into();
+    }
+    let cell = |content: String| container(text(content).size(12)).width(Length::FillPortion(1));
+    let rate = |count: i64, rate: f64| format!("{} ({:.0}%)", count, rate * 100.0);
+
+    let mut rows = column![row![
+        cell(label.to_string()),
+        cell("Sent".to_string()),
+        cell("Responses".to_string()),
+        cell("Interviews".to_string()),
+    ]
+    .spacing(5)]
+    .spacing(5);
+    for stats in stats {
+        let name = match stats.group.as_str() {
+            "" => "Untracked".to_string(),
+            group => group_name(group),
+        };
+        let name = if stats.small_sample() {
+            format!("{name} *")
+        } else {
+            name
+        };
+        rows = rows.push(
+            row![
+                cell(name),
+                cell(stats.applications.to_string()),
+                cell(rate(stats.responses, stats.response_rate())),
+                cell(rate(stats.interviews, stats.interview_rate())),
+            ]
+            .spacing(5),
+        );
+    }
+    rows.into()
+}
@@ -0,0 +1,590 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum ApplicationMessage {
+    CreateApplication,
+    EditApplication,
+    ShowCreateApplicationModal(i64),
+    MarkApplied(i64),
+    ShowEditApplicationModal(i64),
+    JobApplicationStatusChanged(usize, JobApplicationStatus),
+    JobApplicationAppliedChanged(Date),
+    JobApplicationRespondedChanged(Date),
+    JobApplicationAppliedTyped(String),
+    JobApplicationRespondedTyped(String),
+    JobApplicationResumeVersionChanged(String),
+    JobApplicationChannelChanged(ApplicationChannel),
+    JobApplicationInterviewDateChanged(String),
+    JobApplicationInterviewTimeChanged(String),
+    JobApplicationInterviewTimezoneChanged(String),
+    JobApplicationCoverLetterPathChanged(String),
+    JobApplicationCoverLetterEdited(text_editor::Action),
+    OpenCoverLetter,
+    PickJobApplicationApplied,
+    PickJobApplicationResponded,
+    CancelJobApplicationPickers,
+}
+
+impl JobHunter {
+    pub(super) fn update_applications(&mut self, message: ApplicationMessage) -> Task<Message> {
+        match message {
+            ApplicationMessage::CreateApplication => {
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                let interviewed = self.job_app_status == Some(JobApplicationStatus::Interview);
+                self.job_app_interviewed = interviewed;
+                let mut new_app = JobApplication::new(
+                    0,
+                    self.job_post_id.unwrap(),
+                    self.job_app_status.clone().unwrap(),
+                    self.job_app_applied,
+                    self.job_app_responded,
+                    self.job_app_interviewed,
+                );
+                self.set_job_app_details(&mut new_app);
+                // let _ = JobApplication::create(&self.db, new_app);
+                // let _ = new_app.insert(&mut self.db);
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = new_app.insert(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive app insert res")
+                        .expect("Failed to create application")
+                }
+                // self.filter_results();
+                self.hide_modal();
+                // Task::none()
+                self.get_filter_task()
+            }
+            ApplicationMessage::EditApplication => {
+                let app_id = match self.job_app_id {
+                    Some(id) => id,
+                    None => return Task::none(),
+                };
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                // Only modify "interviewed" if the "Interview" status matches, else persist previous value
+                let interviewed = if self.job_app_status == Some(JobApplicationStatus::Interview) {
+                    true
+                } else {
+                    self.job_app_interviewed
+                };
+                self.job_app_interviewed = interviewed;
+                let mut app = JobApplication::new(
+                    app_id,
+                    self.job_post_id.unwrap(),
+                    self.job_app_status.clone().unwrap(),
+                    self.job_app_applied,
+                    self.job_app_responded,
+                    self.job_app_interviewed,
+                );
+                self.set_job_app_details(&mut app);
+                // let _ =
+                //     JobApplication::update(&self.db, app).expect("Failed to update application");
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = app.update(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive app update res")
+                        .expect("Failed to update application")
+                }
+                // self.filter_results();
+                self.hide_modal();
+                // Task::none()
+                self.get_filter_task()
+            }
+            ApplicationMessage::MarkApplied(job_post_id) => {
+                // The common case of the create modal, without the modal
+                let mut new_app = JobApplication::new(
+                    0,
+                    job_post_id,
+                    JobApplicationStatus::Applied,
+                    Some(Date::today()),
+                    None,
+                    false,
+                );
+                new_app.resume_version = self.last_resume_version();
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = new_app.insert(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive app insert res")
+                        .expect("Failed to create application")
+                }
+                self.get_filter_task()
+            }
+            ApplicationMessage::ShowCreateApplicationModal(job_post_id) => {
+                // println!("job_post_id: {}", job_post_id);
+                self.job_app_status_index = JobApplicationStatus::ALL
+                    .iter()
+                    .position(|x| x == &JobApplicationStatus::Applied);
+                self.job_app_status = Some(JobApplicationStatus::Applied);
+                self.job_post_id = Some(job_post_id);
+                self.job_app_applied = Some(Date::today());
+                self.job_app_interviewed = false;
+                // Most applications go out with whatever resume was sent last
+                self.job_app_resume_version_input = self.last_resume_version().unwrap_or_default();
+                self.set_linked_contacts();
+                self.push_modal(Modal::CreateApplicationModal);
+                Task::none()
+            }
+            ApplicationMessage::ShowEditApplicationModal(application_id) => {
+                // let application = JobApplication::get(&self.db, application_id).unwrap();
+                let application = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let application_res =
+                            JobApplication::fetch_one(application_id, &pool).await;
+                        _ = sender.send(application_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive application_res")
+                        .expect("Failed to get application")
+                        .expect("Failed to get application")
+                };
+                self.job_post_id = Some(application.job_post_id);
+                self.job_app_id = Some(application.id);
+                self.job_app_status_index = JobApplicationStatus::ALL
+                    .iter()
+                    .position(|x| x == &application.status);
+                self.job_app_status = Some(application.status);
+                self.job_app_applied = application.date_applied.into();
+                self.job_app_responded = application.date_responded.into();
+                self.job_app_interviewed = application.interviewed.0;
+                self.job_app_resume_version_input = application.resume_version.unwrap_or_default();
+                self.job_app_channel = application.channel;
+                if let Some(at) = application
+                    .interview_at
+                    .and_then(|at| DateTime::from_timestamp(at, 0))
+                {
+                    (self.job_app_interview_date, self.job_app_interview_time) =
+                        interview::to_inputs(at, application.interview_timezone.as_deref());
+                }
+                self.job_app_interview_timezone =
+                    application.interview_timezone.unwrap_or_default();
+                self.job_app_cover_letter_path = application.cover_letter_path.unwrap_or_default();
+                self.job_app_cover_letter = text_editor::Content::with_text(
+                    application.cover_letter.as_deref().unwrap_or_default(),
+                );
+                self.job_app_events = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res =
+                            JobApplicationEvent::fetch_for_application(application.id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to get application history")
+                };
+                self.set_linked_contacts();
+                self.push_modal(Modal::EditApplicationModal);
+                Task::none()
+            }
+            ApplicationMessage::PickJobApplicationApplied => {
+                self.pick_job_app_applied = true;
+                Task::none()
+            }
+            ApplicationMessage::PickJobApplicationResponded => {
+                self.pick_job_app_responded = true;
+                Task::none()
+            }
+            ApplicationMessage::CancelJobApplicationPickers => {
+                self.pick_job_app_applied = false;
+                self.pick_job_app_responded = false;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationStatusChanged(index, status) => {
+                self.job_app_status = Some(status);
+                self.job_app_status_index = Some(index);
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationAppliedChanged(date) => {
+                self.job_app_applied = Some(date);
+                self.pick_job_app_applied = false;
+                self.job_app_applied_input = None;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationRespondedChanged(date) => {
+                self.job_app_responded = Some(date);
+                self.pick_job_app_responded = false;
+                self.job_app_responded_input = None;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationAppliedTyped(input) => {
+                if let Some(date) = typed_date(&input) {
+                    self.job_app_applied = date;
+                }
+                self.job_app_applied_input = Some(input);
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationRespondedTyped(input) => {
+                if let Some(date) = typed_date(&input) {
+                    self.job_app_responded = date;
+                }
+                self.job_app_responded_input = Some(input);
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationResumeVersionChanged(version) => {
+                self.job_app_resume_version_input = version;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationChannelChanged(channel) => {
+                self.job_app_channel = Some(channel);
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationInterviewDateChanged(date) => {
+                self.job_app_interview_date = date;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationInterviewTimeChanged(time) => {
+                self.job_app_interview_time = time;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationInterviewTimezoneChanged(timezone) => {
+                self.job_app_interview_timezone = timezone;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationCoverLetterPathChanged(path) => {
+                self.job_app_cover_letter_path = path;
+                Task::none()
+            }
+            ApplicationMessage::JobApplicationCoverLetterEdited(action) => {
+                self.job_app_cover_letter.perform(action);
+                Task::none()
+            }
+            ApplicationMessage::OpenCoverLetter => {
+                let path = self.job_app_cover_letter_path.trim();
+                if let Err(e) = open::that(path) {
+                    tracing::warn!("Failed to open cover letter {}: {:?}", path, e);
+                    self.status_message = Some(format!("Couldn't open {path}: {e}"));
+                }
+                Task::none()
+            }
+        }
+    }
+
+    /// Copies the modal's optional fields onto an application being saved
+    fn set_job_app_details(&self, app: &mut JobApplication) {
+        app.resume_version =
+            Some(self.job_app_resume_version_input.trim().to_string()).filter(|v| !v.is_empty());
+        app.channel = self.job_app_channel;
+        app.cover_letter_path =
+            Some(self.job_app_cover_letter_path.trim().to_string()).filter(|p| !p.is_empty());
+        app.cover_letter = Some(self.job_app_cover_letter.text().trim().to_string())
+            .filter(|text| !text.is_empty());
+        // Validated before saving
+        if let Ok(Some(at)) = self.job_app_interview() {
+            app.interview_at = Some(at.timestamp());
+            app.interview_timezone = Some(self.job_app_interview_timezone.trim().to_string())
+                .filter(|tz| !tz.is_empty());
+        }
+    }
+
+    /// The interview time entered in the modal; `None` when the date and time are both empty
+    pub(super) fn job_app_interview(&self) -> Result<Option<DateTime<Utc>>, String> {
+        if self.job_app_interview_date.trim().is_empty()
+            && self.job_app_interview_time.trim().is_empty()
+        {
+            return Ok(None);
+        }
+        interview::to_utc(
+            &self.job_app_interview_date,
+            &self.job_app_interview_time,
+            &self.job_app_interview_timezone,
+        )
+        .map(Some)
+    }
+
+    fn last_resume_version(&self) -> Option<String> {
+        let pool = self.db.clone();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.tokio_handle.spawn(async move {
+            let res = JobApplication::fetch_resume_versions(&pool).await;
+            _ = sender.send(res);
+        });
+        receiver
+            .recv()
+            .expect("Failed to receive resume versions")
+            .expect("Failed to get resume versions")
+            .into_iter()
+            .next()
+    }
+
+    pub(super) fn job_app_modal<'a>(&'a self, submit_message: Message) -> Element<'a, Message> {
+        let title = match &self.job_app_id {
+            Some(_) => "Edit Application",
+            None => "New Application",
+        };
+
+        let job_status_select: SelectionList<
+            '_,
+            JobApplicationStatus,
+            Message,
+            Theme,
+            iced::Renderer,
+        > = SelectionList::new_with(
+            &JobApplicationStatus::ALL,
+            |index, value| {
+                Message::Application(ApplicationMessage::JobApplicationStatusChanged(
+                    index, value,
+                ))
+            },
+            12.0,
+            5.0,
+            style::selection_list::primary,
+            self.job_app_status_index,
+            Font::default(),
+        )
+        // .width(Length::Shrink)
+        .height(Length::Fixed(135.0));
+
+        let applied_btn: iced::widget::Button<'_, Message, Theme, iced::Renderer> =
+            button(text("Pick")).on_press(Message::Application(
+                ApplicationMessage::PickJobApplicationApplied,
+            ));
+        let date_applied_picker = date_picker(
+            self.pick_job_app_applied,
+            self.job_app_applied.unwrap_or(Date::today()),
+            applied_btn,
+            Message::Application(ApplicationMessage::CancelJobApplicationPickers),
+            |value| Message::Application(ApplicationMessage::JobApplicationAppliedChanged(value)),
+        );
+
+        let responded_btn: iced::widget::Button<'_, Message, Theme, iced::Renderer> =
+            button(text("Pick")).on_press(Message::Application(
+                ApplicationMessage::PickJobApplicationResponded,
+            ));
+        let date_responded_picker = date_picker(
+            self.pick_job_app_responded,
+            self.job_app_responded.unwrap_or(Date::today()),
+            responded_btn,
+            Message::Application(ApplicationMessage::CancelJobApplicationPickers),
+            |value| Message::Application(ApplicationMessage::JobApplicationRespondedChanged(value)),
+        );
+        let valid = self.form_errors().is_empty();
+        let errors = self.visible_form_errors();
+        let history: Element<'a, Message> = if self.job_app_events.is_empty() {
+            column![].into()
+        } else {
+            column![
+                text("History").size(12),
+                Column::with_children(self.job_app_events.iter().map(|event| {
+                    row![
+                        text(
+                            event
+                                .occurred_at
+                                .0
+                                .with_timezone(&chrono::Local)
+                                .format("%m/%d/%Y")
+                                .to_string()
+                        )
+                        .size(11)
+                        .width(70),
+                        text(event.summary()).size(11),
+                    ]
+                    .spacing(10)
+                    .into()
+                }))
+                .spacing(3),
+            ]
+            .spacing(5)
+            .into()
+        };
+
+        container(
+            column![
+                text(title).size(24),
+                column![
+                    row![
+                        column![
+                            text("Date Applied").size(12),
+                            row![
+                                text_input(
+                                    "YYYY-MM-DD",
+                                    &date_input_value(
+                                        &self.job_app_applied_input,
+                                        self.job_app_applied
+                                    )
+                                )
+                                .on_input(|value| Message::Application(
+                                    ApplicationMessage::JobApplicationAppliedTyped(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::DateApplied))),
+                                date_applied_picker,
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center),
+                            field_error(&errors, FormField::DateApplied),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Date Responded").size(12),
+                            row![
+                                text_input(
+                                    "YYYY-MM-DD",
+                                    &date_input_value(
+                                        &self.job_app_responded_input,
+                                        self.job_app_responded
+                                    )
+                                )
+                                .on_input(|value| Message::Application(
+                                    ApplicationMessage::JobApplicationRespondedTyped(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::DateResponded))),
+                                date_responded_picker,
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center),
+                            field_error(&errors, FormField::DateResponded),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(15)
+                    .width(Fill),
+                    column![
+                        form::label("Status", true),
+                        job_status_select,
+                        field_error(&errors, FormField::ApplicationStatus),
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Resume Version").size(12),
+                        text("Compared in Stats").size(10),
+                        text_input("e.g. v2-backend", &self.job_app_resume_version_input)
+                            .on_input(|value| Message::Application(
+                                ApplicationMessage::JobApplicationResumeVersionChanged(value)
+                            ))
+                            .on_submit(submit_message.clone())
+                            .padding(5),
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Channel").size(12),
+                        pick_list(ApplicationChannel::ALL, self.job_app_channel, |value| {
+                            Message::Application(ApplicationMessage::JobApplicationChannelChanged(
+                                value,
+                            ))
+                        })
+                        .placeholder("Not recorded")
+                        .text_size(12)
+                        .width(Fill),
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Next Interview").size(12),
+                        text("In the company's timezone (empty = yours)").size(10),
+                        row![
+                            text_input("YYYY-MM-DD", &self.job_app_interview_date)
+                                .on_input(|value| Message::Application(
+                                    ApplicationMessage::JobApplicationInterviewDateChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::InterviewTime))),
+                            text_input("2:30 PM", &self.job_app_interview_time)
+                                .on_input(|value| Message::Application(
+                                    ApplicationMessage::JobApplicationInterviewTimeChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::InterviewTime))),
+                        ]
+                        .spacing(5),
+                        text_input("America/New_York", &self.job_app_interview_timezone)
+                            .on_input(|value| Message::Application(
+                                ApplicationMessage::JobApplicationInterviewTimezoneChanged(value)
+                            ))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                            .style(input_style(errors.contains(FormField::InterviewTime))),
+                        match self.job_app_interview() {
+                            Ok(Some(at)) => text(interview::format_interview(
+                                at,
+                                Some(self.job_app_interview_timezone.as_str())
+                                    .filter(|tz| !tz.trim().is_empty())
+                            ))
+                            .size(10),
+                            _ => text("").size(10),
+                        },
+                        field_error(&errors, FormField::InterviewTime),
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Cover Letter").size(12),
+                        text("The file that was sent, or its text pasted below").size(10),
+                        row![
+                            text_input("Path to file", &self.job_app_cover_letter_path)
+                                .on_input(|value| Message::Application(
+                                    ApplicationMessage::JobApplicationCoverLetterPathChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5),
+                            button(text("Open"))
+                                .style(button::secondary)
+                                .on_press_maybe(
+                                    (!self.job_app_cover_letter_path.trim().is_empty()).then_some(
+                                        Message::Application(ApplicationMessage::OpenCoverLetter)
+                                    )
+                                ),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center),
+                        text_editor(&self.job_app_cover_letter)
+                            .on_action(|action| Message::Application(
+                                ApplicationMessage::JobApplicationCoverLetterEdited(action)
+                            ))
+                            .height(Length::Fixed(80.0))
+                            .padding(5),
+                    ]
+                    .spacing(5),
+                    self.linked_contacts_field(),
+                    history,
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(form::save_button(submit_message.clone(), valid))
+                    ]
+                    .spacing(10)
+                    .width(Fill),
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(300)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
@@ -0,0 +1,132 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum BoardMessage {
+    ToggleBoardView,
+    MoveApplication(i64, JobApplicationStatus),
+}
+
+impl JobHunter {
+    pub(super) fn update_board(&mut self, message: BoardMessage) -> Task<Message> {
+        match message {
+            BoardMessage::ToggleBoardView => {
+                self.config.board_view = !self.config.board_view;
+                self.save_config();
+                if self.config.board_view {
+                    self.set_board_cards();
+                }
+                Task::none()
+            }
+            BoardMessage::MoveApplication(application_id, status) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobApplication::set_status(application_id, status, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to move application");
+                }
+                self.set_board_cards();
+                // Keeps the list view in step for when it's switched back to
+                self.get_filter_task()
+            }
+        }
+    }
+
+    pub(super) fn set_board_cards(&mut self) {
+        self.board_cards = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = BoardCard::fetch_all(&pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get board cards")
+        };
+    }
+
+    /// Applications in a column per status, with buttons moving each to its neighbours
+    pub(super) fn application_board<'a>(&'a self) -> Element<'a, Message> {
+        let statuses = JobApplicationStatus::ALL;
+        let icon_color = theme::on_secondary(&self.app_theme);
+        let columns = BoardCard::columns(&self.board_cards)
+            .into_iter()
+            .enumerate()
+            .map(|(index, (status, cards))| {
+                let previous = index.checked_sub(1).map(|i| statuses[i].clone());
+                let next = statuses.get(index + 1).cloned();
+                let count = cards.len();
+                let cards = Column::with_children(
+                    cards
+                        .into_iter()
+                        .map(|card| board_card(card, previous.clone(), next.clone(), icon_color)),
+                )
+                .spacing(10);
+                container(
+                    column![
+                        row![
+                            text(status.name()).size(14).width(Fill),
+                            badge(text(count).size(12)).style(style::badge::secondary),
+                        ]
+                        .align_y(Alignment::Center),
+                        scrollable(cards).height(Fill),
+                    ]
+                    .spacing(10),
+                )
+                .width(Length::FillPortion(1))
+                .height(Fill)
+                .padding(10)
+                .style(theme::card)
+                .into()
+            });
+        iced::widget::Row::with_children(columns)
+            .spacing(10)
+            .height(Fill)
+            .padding(Padding::from([0, 30]).bottom(20))
+            .into()
+    }
+}
+
+fn board_card<'a>(
+    card: &'a BoardCard,
+    previous: Option<JobApplicationStatus>,
+    next: Option<JobApplicationStatus>,
+    icon_color: iced::Color,
+) -> Element<'a, Message> {
+    let id = card.application_id;
+    let move_button = |icon: &'static str, status: Option<JobApplicationStatus>| {
+        button(fa_icon_solid(icon).size(12.0).color(icon_color))
+            .padding(Padding::from([2, 5]))
+            .style(button::secondary)
+            .on_press_maybe(
+                status.map(|status| Message::Board(BoardMessage::MoveApplication(id, status))),
+            )
+    };
+    container(
+        column![
+            text(&card.job_title).size(13),
+            text(&card.company_name).size(11),
+            text(card.date_applied.format("%m/%d/%Y")).size(11),
+            row![
+                move_button("angle-left", previous),
+                container(move_button("angle-right", next))
+                    .width(Fill)
+                    .align_x(Alignment::End),
+            ],
+        ]
+        .spacing(5),
+    )
+    .width(Fill)
+    .padding(8)
+    .style(container::rounded_box)
+    .into()
+}
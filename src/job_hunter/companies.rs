@@ -0,0 +1,900 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum CompanyMessage {
+    DeleteCompany(i64),
+    TrackNewCompany,
+    EditCompany,
+    ShowAllCompanies,
+    HideCompany(i64),
+    CompanyScroll(iced::widget::scrollable::Viewport),
+    SetCompanyGroup(i64, Option<i64>),
+    CreateCompanyGroup,
+    ToggleCompanyGroup(i64),
+    ToggleCompanyGroupDropdown(i64),
+    HideCompanyGroup(i64),
+    SoloCompanyGroup(i64),
+    DeleteCompanyGroup(i64),
+    ToggleCompanyDropdown(i64),
+    ShowCreateCompanyModal,
+    ShowEditCompanyModal(i64),
+    CompanyNameChanged(String),
+    CareersURLChanged(String),
+    AltNameChanged(String),
+    AddAltName,
+    DeleteAltName(i64),
+    ShowCreateCompanyGroupModal,
+    CompanyGroupNameChanged(String),
+    CheckCareersUrls,
+    CareersUrlsChecked(BTreeMap<i64, String>),
+    FaviconFetched(String, Option<Vec<u8>>),
+}
+
+pub(super) const CAREERS_URL_CHECK_INTERVAL_HOURS: u64 = 24;
+
+impl JobHunter {
+    pub(super) fn update_companies(&mut self, message: CompanyMessage) -> Task<Message> {
+        match message {
+            CompanyMessage::TrackNewCompany => {
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                // let _ = Company::create(
+                //     &self.db,
+                //     self.company_name.clone(),
+                //     self.careers_url.clone(),
+                //     false,
+                // );
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    let company_name = self.company_name.clone();
+                    let careers_url = self.careers_url.clone();
+                    let company = Company {
+                        id: 0,
+                        name: company_name,
+                        careers_url: Some(careers_url),
+                        hidden: SqliteBoolean(false),
+                        group_id: None,
+                    };
+                    self.tokio_handle.spawn(async move {
+                        Company::insert(&company, &pool).await.unwrap();
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                // self.companies = Company::get_all(&self.db).expect("Failed to get companies");
+                self.companies = companies;
+                self.hide_modal();
+                Task::none()
+            }
+            CompanyMessage::CheckCareersUrls => {
+                if self.careers_urls_checking || self.is_offline() {
+                    return Task::none();
+                }
+                self.careers_urls_checking = true;
+                self.last_careers_url_check = Some(std::time::Instant::now());
+                let urls = self
+                    .companies
+                    .iter()
+                    .filter_map(|company| {
+                        let url = company.careers_url.as_deref()?.trim();
+                        (!url.is_empty()).then(|| (company.id, url.to_string()))
+                    })
+                    .collect();
+                Task::perform(
+                    api::check_careers_urls(self.http_client(), urls),
+                    |broken| Message::Company(CompanyMessage::CareersUrlsChecked(broken)),
+                )
+            }
+            CompanyMessage::CareersUrlsChecked(broken) => {
+                self.careers_urls_checking = false;
+                self.broken_careers_urls = broken;
+                Task::none()
+            }
+            CompanyMessage::FaviconFetched(host, bytes) => {
+                self.favicons.insert(host, bytes.and_then(favicon_handle));
+                Task::none()
+            }
+            CompanyMessage::DeleteCompany(id) => {
+                // let _ = Company::delete(&self.db, id);
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        Company::delete(id, &pool)
+                            .await
+                            .expect("Failed to delete company");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                // self.companies = Company::get_all(&self.db).expect("Failed to get companies");
+                self.job_posts.retain(|job_post| job_post.company_id != id); // Update companies before job_posts = ERROR
+                self.filter.company_ids.remove(&id);
+                self.companies = companies;
+                // Task::none()
+                self.get_filter_task()
+            }
+            CompanyMessage::ToggleCompanyDropdown(id) => {
+                let current_val = match self.company_dropdowns.get(&id) {
+                    Some(&status) => status,
+                    None => false,
+                };
+                self.company_dropdowns.insert(id, !current_val);
+                Task::none()
+            }
+            CompanyMessage::EditCompany => {
+                let company_id = match self.company_id {
+                    Some(id) => id,
+                    None => return Task::none(),
+                };
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                // Rechecked with the rest on the next run
+                self.broken_careers_urls.remove(&company_id);
+                let company = Company {
+                    id: company_id,
+                    name: self.company_name.clone(),
+                    careers_url: Some(self.careers_url.clone()),
+                    hidden: SqliteBoolean(false), // TODO ?
+                    group_id: self
+                        .companies
+                        .iter()
+                        .find(|company| company.id == company_id)
+                        .and_then(|company| company.group_id),
+                };
+                // let _ = Company::update(&self.db, company).expect("Failed to update company");
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        company
+                            .update(&pool)
+                            .await
+                            .expect("Failed to update company");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                // self.companies = Company::get_all(&self.db).expect("Failed to get companies");
+                self.companies = companies;
+                self.hide_modal();
+                Task::none()
+            }
+            CompanyMessage::HideCompany(id) => {
+                // let mut company = Company::get(&self.db, id).expect("Failed to get company");
+                // company.hidden = true;
+                // let id_to_remove = company.id;
+                // // let _ = Company::update(&self.db, company).expect("Failed to update company");
+                // if let Some(pos) = self.companies.iter().position(|company| company.id == id) {
+                //     self.companies.remove(pos);
+                // };
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        Company::hide(id, &pool)
+                            .await
+                            .expect("Failed to hide company");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies;
+                self.company_dropdowns.remove(&id);
+                // self.filter_results();
+                // Task::none()
+                self.get_filter_task()
+            }
+            CompanyMessage::ShowAllCompanies => {
+                // let _ = Company::show_all(&self.db).expect("Failed to show companies");
+                // self.companies = Company::get_all(&self.db).expect("Failed to get companies");
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        Company::show_all(&pool)
+                            .await
+                            .expect("Failed to show companies");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies;
+                self.filter.company_name = "".to_string();
+                // self.filter_results();
+                // Task::none()
+                self.get_filter_task()
+            }
+            // https://github.com/iced-rs/iced_aw/issues/300#issuecomment-2563377964
+            CompanyMessage::CompanyScroll(viewport) => {
+                self.company_scroll = viewport.absolute_offset().y;
+                Task::none()
+            }
+            CompanyMessage::SetCompanyGroup(id, group_id) => {
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        Company::set_group(id, group_id, &pool)
+                            .await
+                            .expect("Failed to set company group");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies;
+                self.company_dropdowns.insert(id, false);
+                Task::none()
+            }
+            CompanyMessage::CreateCompanyGroup => {
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                let company_groups = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    let group = CompanyGroup {
+                        id: 0,
+                        name: self.company_group_name.clone(),
+                    };
+                    self.tokio_handle.spawn(async move {
+                        group
+                            .insert(&pool)
+                            .await
+                            .expect("Failed to insert company group");
+                        let groups_res = CompanyGroup::fetch_all(&pool).await;
+                        _ = sender.send(groups_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive groups_res")
+                        .expect("Failed to get company groups")
+                };
+                self.company_groups = company_groups;
+                self.hide_modal();
+                Task::none()
+            }
+            CompanyMessage::ToggleCompanyGroup(id) => {
+                let current_val = match self.company_group_collapsed.get(&id) {
+                    Some(&status) => status,
+                    None => false,
+                };
+                self.company_group_collapsed.insert(id, !current_val);
+                Task::none()
+            }
+            CompanyMessage::ToggleCompanyGroupDropdown(id) => {
+                let current_val = match self.company_group_dropdowns.get(&id) {
+                    Some(&status) => status,
+                    None => false,
+                };
+                self.company_group_dropdowns.insert(id, !current_val);
+                Task::none()
+            }
+            CompanyMessage::HideCompanyGroup(id) => {
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        CompanyGroup::hide(id, &pool)
+                            .await
+                            .expect("Failed to hide company group");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies;
+                self.company_group_dropdowns.insert(id, false);
+                self.get_filter_task()
+            }
+            CompanyMessage::SoloCompanyGroup(id) => {
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        CompanyGroup::solo(id, &pool)
+                            .await
+                            .expect("Failed to solo company group");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies;
+                self.company_group_dropdowns.insert(id, false);
+                self.get_filter_task()
+            }
+            CompanyMessage::DeleteCompanyGroup(id) => {
+                let (company_groups, companies) = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        CompanyGroup::delete(id, &pool)
+                            .await
+                            .expect("Failed to delete company group");
+                        let groups_res = CompanyGroup::fetch_all(&pool).await;
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send((groups_res, companies_res));
+                    });
+                    let (groups_res, companies_res) = receiver
+                        .recv()
+                        .expect("Failed to receive company group res");
+                    (
+                        groups_res.expect("Failed to get company groups"),
+                        companies_res.expect("Failed to get companies"),
+                    )
+                };
+                self.company_groups = company_groups;
+                self.companies = companies;
+                self.company_group_dropdowns.remove(&id);
+                self.company_group_collapsed.remove(&id);
+                Task::none()
+            }
+            CompanyMessage::ShowCreateCompanyModal => {
+                self.push_modal(Modal::CreateCompanyModal);
+                self.set_primary_modal_field();
+                self.set_last_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            CompanyMessage::ShowEditCompanyModal(id) => {
+                // let company = Company::get(&self.db, id).unwrap();
+                let company = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let company_res = Company::fetch_one(id, &pool).await;
+                        _ = sender.send(company_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive company_res")
+                        .expect("Failed to get company")
+                        .expect("Failed to get company")
+                };
+                self.company_name = company.name;
+                self.careers_url = company.careers_url.unwrap();
+                self.company_id = Some(id);
+                self.set_company_alt_names(id);
+                self.company_dropdowns.insert(id, false);
+                self.push_modal(Modal::EditCompanyModal);
+                self.set_primary_modal_field();
+                self.set_last_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            CompanyMessage::ShowCreateCompanyGroupModal => {
+                self.push_modal(Modal::CreateCompanyGroupModal);
+                self.set_primary_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            CompanyMessage::CompanyNameChanged(name) => {
+                self.company_name = name; // hmm...
+                Task::none()
+            }
+            CompanyMessage::CareersURLChanged(careers_url) => {
+                self.careers_url = careers_url;
+                Task::none()
+            }
+            CompanyMessage::CompanyGroupNameChanged(name) => {
+                self.company_group_name = name;
+                Task::none()
+            }
+            CompanyMessage::AltNameChanged(name) => {
+                self.company_alt_name = name;
+                Task::none()
+            }
+            CompanyMessage::AddAltName => {
+                let Some(company_id) = self.company_id else {
+                    return Task::none();
+                };
+                let name = self.company_alt_name.trim().to_string();
+                if name.is_empty() {
+                    return Task::none();
+                }
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = CompanyAltName::insert(company_id, &name, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to insert alt name");
+                }
+                self.company_alt_name = "".to_string();
+                self.set_company_alt_names(company_id);
+                Task::none()
+            }
+            CompanyMessage::DeleteAltName(id) => {
+                let Some(company_id) = self.company_id else {
+                    return Task::none();
+                };
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = CompanyAltName::delete(id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to delete alt name");
+                }
+                self.set_company_alt_names(company_id);
+                Task::none()
+            }
+        }
+    }
+
+    fn set_company_alt_names(&mut self, company_id: i64) {
+        self.company_alt_names = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = CompanyAltName::fetch_for_company(company_id, &pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive alt names")
+                .expect("Failed to get alt names")
+        };
+    }
+
+    pub(super) fn company_modal<'a>(&self, submit_message: Message) -> Element<'a, Message> {
+        let valid = self.form_errors().is_empty();
+        let errors = self.visible_form_errors();
+        let careers_url_error: Element<'a, Message> = match self
+            .company_id
+            .and_then(|id| self.broken_careers_urls.get(&id))
+        {
+            Some(error) => text(format!("Last check failed: {error}"))
+                .size(10)
+                .style(text::danger)
+                .into(),
+            None => column![].into(),
+        };
+        // Only a saved company has other names to edit
+        let alt_names: Element<'a, Message> = if self.company_id.is_some() {
+            let names = Column::with_children(self.company_alt_names.iter().map(|alt_name| {
+                let id = alt_name.id;
+                row![
+                    text(alt_name.name.clone()).size(12).width(Fill),
+                    IconButton::new("trash")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(move |msg| match msg {
+                            IconButtonMessage::Pressed => {
+                                Message::Company(CompanyMessage::DeleteAltName(id))
+                            }
+                        }),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center)
+                .into()
+            }))
+            .spacing(5);
+            column![
+                text("Also Known As").size(12),
+                names,
+                row![
+                    text_input("e.g. Google LLC", &self.company_alt_name)
+                        .on_input(|value| Message::Company(CompanyMessage::AltNameChanged(value)))
+                        .on_submit(Message::Company(CompanyMessage::AddAltName))
+                        .padding(5),
+                    button(text("Add")).on_press_maybe(
+                        (!self.company_alt_name.trim().is_empty())
+                            .then_some(Message::Company(CompanyMessage::AddAltName))
+                    ),
+                ]
+                .spacing(5)
+                .align_y(Alignment::Center),
+            ]
+            .spacing(5)
+            .into()
+        } else {
+            column![].into()
+        };
+        container(
+            column![
+                text("Track Company").size(24),
+                column![
+                    column![
+                        form::label("Company Name", true),
+                        text_input("", &self.company_name) // hmm...
+                            .id(self.primary_modal_field.clone().unwrap())
+                            .on_input(|value| Message::Company(CompanyMessage::CompanyNameChanged(
+                                value
+                            )))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                            .style(input_style(errors.contains(FormField::CompanyName))),
+                        field_error(&errors, FormField::CompanyName),
+                    ]
+                    .spacing(5),
+                    column![
+                        form::label("Company's Careers Page URL", true),
+                        text_input("", &self.careers_url)
+                            .id(self.last_modal_field.clone().unwrap())
+                            .on_input(|value| Message::Company(CompanyMessage::CareersURLChanged(
+                                value
+                            )))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                            .style(input_style(errors.contains(FormField::CareersURL))),
+                        field_error(&errors, FormField::CareersURL),
+                        careers_url_error,
+                    ]
+                    .spacing(5),
+                    alt_names,
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(form::save_button(submit_message.clone(), valid)),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(300)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    pub(super) fn company_group_modal<'a>(&self, submit_message: Message) -> Element<'a, Message> {
+        let valid = self.form_errors().is_empty();
+        let errors = self.visible_form_errors();
+        container(
+            column![
+                text("New Group").size(24),
+                column![
+                    column![
+                        form::label("Group Name", true),
+                        text_input("", &self.company_group_name)
+                            .id(self.primary_modal_field.clone().unwrap())
+                            .on_input(|value| Message::Company(
+                                CompanyMessage::CompanyGroupNameChanged(value)
+                            ))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                            .style(input_style(errors.contains(FormField::CompanyGroupName))),
+                        field_error(&errors, FormField::CompanyGroupName),
+                    ]
+                    .spacing(5),
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(form::save_button(submit_message.clone(), valid)),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(300)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    fn company_list_item<'a>(&'a self, company: &'a Company) -> Element<'a, Message> {
+        let company_id = company.id;
+        let underlay = ellipsis_button(self.icon_color()).on_press(Message::Company(
+            CompanyMessage::ToggleCompanyDropdown(company_id),
+        ));
+        let mut menu: Vec<Element<'a, Message>> = vec![
+            button(text("Edit"))
+                .on_press(Message::Company(CompanyMessage::ShowEditCompanyModal(
+                    company_id,
+                )))
+                .into(),
+            button(text("Hide"))
+                .on_press(Message::Company(CompanyMessage::HideCompany(company_id)))
+                .into(),
+        ];
+        for group in self
+            .company_groups
+            .iter()
+            .filter(|group| company.group_id != Some(group.id))
+        {
+            menu.push(
+                button(text(format!("Move to {}", group.name)))
+                    .on_press(Message::Company(CompanyMessage::SetCompanyGroup(
+                        company_id,
+                        Some(group.id),
+                    )))
+                    .into(),
+            );
+        }
+        if company.group_id.is_some() {
+            menu.push(
+                button(text("Ungroup"))
+                    .on_press(Message::Company(CompanyMessage::SetCompanyGroup(
+                        company_id, None,
+                    )))
+                    .into(),
+            );
+        }
+        menu.push(
+            button(text("Delete"))
+                .on_press(Message::Confirm(
+                    format!("Delete {} and all of its job posts?", company.name),
+                    Box::new(Message::Company(CompanyMessage::DeleteCompany(company_id))),
+                ))
+                .into(),
+        );
+        let dropdown = DropDown::new(
+            underlay,
+            column(menu).spacing(5),
+            match self.company_dropdowns.get(&company_id) {
+                Some(&status) => status,
+                None => false,
+            },
+        )
+        .width(Fill)
+        .alignment(drop_down::Alignment::BottomEnd)
+        .on_dismiss(Message::Company(CompanyMessage::ToggleCompanyDropdown(
+            company_id,
+        )))
+        .offset(iced_aw::drop_down::Offset::new(
+            5.0,
+            -self.company_scroll + 5.0,
+        ));
+
+        let mut name_row = row![checkbox("", self.filter.company_ids.contains(&company_id))
+            .on_toggle(
+                move |selected| Message::Filter(FilterMessage::FilterCompanyToggled(
+                    company_id, selected
+                ))
+            ),]
+        .push_maybe(self.favicon_image(company.careers_url.as_deref()))
+        .push(text(&company.name))
+        .spacing(5)
+        .align_y(Alignment::Center);
+        if let Some(error) = self.broken_careers_urls.get(&company_id) {
+            name_row = name_row.push(tooltip(
+                button(
+                    fa_icon_solid("triangle-exclamation")
+                        .size(12.0)
+                        .color(color!(255, 193, 7)),
+                )
+                .padding(0)
+                .style(button::text)
+                .on_press(Message::Company(CompanyMessage::ShowEditCompanyModal(
+                    company_id,
+                ))),
+                container(text(format!("Careers page unreachable: {error}")).size(12))
+                    .padding(5)
+                    .style(container::rounded_box),
+                tooltip::Position::Bottom,
+            ));
+        }
+        if let Some(count) = self.company_job_counts.get(&company_id) {
+            name_row = name_row.push(text(format!("({})", count.job_count)).size(12));
+            if count.active_application_count > 0 {
+                name_row = name_row.push(
+                    badge(text(count.active_application_count).size(10))
+                        .style(style::badge::warning),
+                );
+            }
+        }
+
+        row![
+            name_row,
+            container(dropdown).width(Fill).align_x(Alignment::End),
+        ]
+        .align_y(Alignment::Center)
+        .padding(Padding::from([5, 30]))
+        .width(Fill)
+        .into()
+    }
+
+    fn company_group_header<'a>(&'a self, group: &'a CompanyGroup) -> Element<'a, Message> {
+        let group_id = group.id;
+        let collapsed = match self.company_group_collapsed.get(&group_id) {
+            Some(&status) => status,
+            None => false,
+        };
+        let chevron = if collapsed {
+            "chevron-right"
+        } else {
+            "chevron-down"
+        };
+        let underlay = ellipsis_button(self.icon_color()).on_press(Message::Company(
+            CompanyMessage::ToggleCompanyGroupDropdown(group_id),
+        ));
+        let dropdown = DropDown::new(
+            underlay,
+            column(vec![
+                button(text("Hide"))
+                    .on_press(Message::Company(CompanyMessage::HideCompanyGroup(group_id)))
+                    .into(),
+                button(text("Solo"))
+                    .on_press(Message::Company(CompanyMessage::SoloCompanyGroup(group_id)))
+                    .into(),
+                button(text("Delete"))
+                    .on_press(Message::Company(CompanyMessage::DeleteCompanyGroup(
+                        group_id,
+                    )))
+                    .into(),
+            ])
+            .spacing(5),
+            match self.company_group_dropdowns.get(&group_id) {
+                Some(&status) => status,
+                None => false,
+            },
+        )
+        .width(Fill)
+        .alignment(drop_down::Alignment::BottomEnd)
+        .on_dismiss(Message::Company(
+            CompanyMessage::ToggleCompanyGroupDropdown(group_id),
+        ))
+        .offset(iced_aw::drop_down::Offset::new(
+            5.0,
+            -self.company_scroll + 5.0,
+        ));
+
+        row![
+            button(
+                row![
+                    fa_icon_solid(chevron).size(12.0).color(self.icon_color()),
+                    text(&group.name).size(14),
+                ]
+                .spacing(5)
+                .align_y(Alignment::Center)
+            )
+            .style(button::text)
+            .on_press(Message::Company(CompanyMessage::ToggleCompanyGroup(
+                group_id
+            ))),
+            container(dropdown).width(Fill).align_x(Alignment::End),
+        ]
+        .align_y(Alignment::Center)
+        .padding(Padding::from([5, 20]))
+        .width(Fill)
+        .into()
+    }
+
+    pub(super) fn company_list<'a>(&'a self) -> Column<'a, Message> {
+        let mut items: Vec<Element<'a, Message>> = Vec::new();
+        for group in &self.company_groups {
+            items.push(self.company_group_header(group));
+            let collapsed = match self.company_group_collapsed.get(&group.id) {
+                Some(&status) => status,
+                None => false,
+            };
+            if collapsed {
+                continue;
+            }
+            items.extend(
+                self.companies
+                    .iter()
+                    .filter(|company| company.group_id == Some(group.id))
+                    .map(|company| self.company_list_item(company)),
+            );
+        }
+        // Ungrouped companies
+        items.extend(
+            self.companies
+                .iter()
+                .filter(|company| company.group_id.is_none())
+                .map(|company| self.company_list_item(company)),
+        );
+        Column::with_children(items).spacing(5)
+    }
+
+    pub(super) fn set_company_job_counts(&mut self) {
+        let counts = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let counts_res = Company::fetch_job_counts(&pool).await;
+                _ = sender.send(counts_res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive counts_res")
+                .expect("Failed to get company job counts")
+        };
+
+        self.company_job_counts = counts
+            .into_iter()
+            .map(|count| (count.company_id, count))
+            .collect();
+    }
+
+    /// Loads the favicons of companies' careers URL hosts that aren't loaded yet, from the
+    /// cache or else fetched in the background
+    pub(super) fn load_favicons(&mut self) -> Task<Message> {
+        let hosts: BTreeSet<String> = self
+            .companies
+            .iter()
+            .filter_map(|company| company.careers_url.as_deref().and_then(favicon::host))
+            .filter(|host| !self.favicons.contains_key(host))
+            .collect();
+        let mut tasks = Vec::new();
+        for host in hosts {
+            if let Some(bytes) = favicon::cached(&host) {
+                self.favicons.insert(host, favicon_handle(bytes));
+                continue;
+            }
+            if self.is_offline() {
+                continue;
+            }
+            self.favicons.insert(host.clone(), None);
+            tasks.push(Task::perform(
+                favicon::fetch(self.http_client(), host.clone()),
+                move |res| {
+                    let bytes = res
+                        .inspect_err(|e| {
+                            tracing::warn!("Failed to fetch favicon for {}: {:?}", host, e)
+                        })
+                        .ok();
+                    Message::Company(CompanyMessage::FaviconFetched(host.clone(), bytes))
+                },
+            ));
+        }
+        Task::batch(tasks)
+    }
+
+    /// A small favicon for the company, if its careers URL host has one
+    pub(super) fn favicon_image<'a>(
+        &self,
+        careers_url: Option<&str>,
+    ) -> Option<Element<'a, Message>> {
+        let host = careers_url.and_then(favicon::host)?;
+        let handle = self.favicons.get(&host)?.clone()?;
+        Some(image(handle).width(14).height(14).into())
+    }
+}
+
+/// An empty cache entry means the host has no favicon
+fn favicon_handle(bytes: Vec<u8>) -> Option<image::Handle> {
+    (!bytes.is_empty()).then(|| image::Handle::from_bytes(bytes))
+}
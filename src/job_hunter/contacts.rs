@@ -0,0 +1,441 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum ContactMessage {
+    ShowContactsModal,
+    ContactNameChanged(String),
+    ContactEmailChanged(String),
+    ContactRoleChanged(String),
+    ContactLinkedinUrlChanged(String),
+    ContactNotesChanged(String),
+    ContactCompanyChanged(Company),
+    ClearContactCompany,
+    EditContact(i64),
+    CancelContactEdit,
+    SaveContact,
+    DeleteContact(i64),
+    /// Links the contact to the post open in the job post or application modal
+    LinkContact(Contact),
+    UnlinkContact(i64),
+}
+
+impl JobHunter {
+    pub(super) fn update_contacts(&mut self, message: ContactMessage) -> Task<Message> {
+        match message {
+            ContactMessage::ShowContactsModal => {
+                self.set_contacts();
+                self.push_modal(Modal::ContactsModal);
+                self.set_primary_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            ContactMessage::ContactNameChanged(name) => {
+                self.contact_name = name;
+                Task::none()
+            }
+            ContactMessage::ContactEmailChanged(email) => {
+                self.contact_email = email;
+                Task::none()
+            }
+            ContactMessage::ContactRoleChanged(role) => {
+                self.contact_role = role;
+                Task::none()
+            }
+            ContactMessage::ContactLinkedinUrlChanged(url) => {
+                self.contact_linkedin_url = url;
+                Task::none()
+            }
+            ContactMessage::ContactNotesChanged(notes) => {
+                self.contact_notes = notes;
+                Task::none()
+            }
+            ContactMessage::ContactCompanyChanged(company) => {
+                self.contact_company = Some(company);
+                Task::none()
+            }
+            ContactMessage::ClearContactCompany => {
+                self.contact_company = None;
+                Task::none()
+            }
+            ContactMessage::EditContact(id) => {
+                let Some(contact) = self.contacts.iter().find(|c| c.id == id).cloned() else {
+                    return Task::none();
+                };
+                self.contact_id = Some(contact.id);
+                self.contact_name = contact.name;
+                self.contact_email = contact.email;
+                self.contact_role = contact.role;
+                self.contact_linkedin_url = contact.linkedin_url;
+                self.contact_notes = contact.notes;
+                self.contact_company = contact
+                    .company_id
+                    .and_then(|id| self.companies.iter().find(|c| c.id == id).cloned());
+                self.contact_error = None;
+                Task::none()
+            }
+            ContactMessage::CancelContactEdit => {
+                self.clear_contact_form();
+                Task::none()
+            }
+            ContactMessage::SaveContact => {
+                let contact = Contact {
+                    id: self.contact_id.unwrap_or(0),
+                    name: self.contact_name.trim().to_string(),
+                    email: self.contact_email.trim().to_string(),
+                    company_id: self.contact_company.as_ref().map(|company| company.id),
+                    role: self.contact_role.trim().to_string(),
+                    linkedin_url: self.contact_linkedin_url.trim().to_string(),
+                    notes: self.contact_notes.trim().to_string(),
+                    company_name: None,
+                };
+                if contact.name.is_empty() {
+                    self.contact_error = Some("Enter a name".to_string());
+                    return Task::none();
+                }
+                let editing = self.contact_id.is_some();
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = if editing {
+                            contact.update(&pool).await
+                        } else {
+                            contact.insert(&pool).await.map(|_| ())
+                        };
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to save contact");
+                }
+                self.clear_contact_form();
+                self.set_contacts();
+                Task::none()
+            }
+            ContactMessage::DeleteContact(id) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = Contact::delete(id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to delete contact");
+                }
+                if self.contact_id == Some(id) {
+                    self.clear_contact_form();
+                }
+                self.set_contacts();
+                Task::none()
+            }
+            ContactMessage::LinkContact(contact) => {
+                let Some(job_post_id) = self.job_post_id else {
+                    return Task::none();
+                };
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = Contact::link(contact.id, job_post_id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to link contact");
+                }
+                self.set_linked_contacts();
+                Task::none()
+            }
+            ContactMessage::UnlinkContact(id) => {
+                let Some(job_post_id) = self.job_post_id else {
+                    return Task::none();
+                };
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = Contact::unlink(id, job_post_id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to unlink contact");
+                }
+                self.set_linked_contacts();
+                Task::none()
+            }
+        }
+    }
+
+    fn clear_contact_form(&mut self) {
+        self.contact_id = None;
+        self.contact_name = "".to_string();
+        self.contact_email = "".to_string();
+        self.contact_role = "".to_string();
+        self.contact_linkedin_url = "".to_string();
+        self.contact_notes = "".to_string();
+        self.contact_company = None;
+        self.contact_error = None;
+    }
+
+    fn set_contacts(&mut self) {
+        self.contacts = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = Contact::fetch_all(&pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get contacts")
+        };
+    }
+
+    /// Loads every contact and the ones linked to the post being edited. Call after
+    /// `job_post_id` is set
+    pub(super) fn set_linked_contacts(&mut self) {
+        self.set_contacts();
+        let Some(job_post_id) = self.job_post_id else {
+            self.linked_contacts = Vec::new();
+            return;
+        };
+        self.linked_contacts = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = Contact::fetch_for_job_post(job_post_id, &pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get linked contacts")
+        };
+    }
+
+    /// The post's contacts with buttons to unlink them, and a picker to link another
+    pub(super) fn linked_contacts_field<'a>(&self) -> Element<'a, Message> {
+        let linked = Column::with_children(self.linked_contacts.iter().map(|contact| {
+            let id = contact.id;
+            let label = match contact.role.as_str() {
+                "" => contact.to_string(),
+                role => format!("{}, {}", contact, role),
+            };
+            row![
+                text(label).size(12).width(Fill),
+                button(text("×").size(12))
+                    .padding(Padding::from([2, 5]))
+                    .style(button::secondary)
+                    .on_press(Message::Contact(ContactMessage::UnlinkContact(id))),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into()
+        }))
+        .spacing(5);
+        let unlinked: Vec<Contact> = self
+            .contacts
+            .iter()
+            .filter(|contact| !self.linked_contacts.iter().any(|c| c.id == contact.id))
+            .cloned()
+            .collect();
+        let picker: Element<'a, Message> = if self.contacts.is_empty() {
+            text("Add people from Contacts in the sidebar")
+                .size(10)
+                .into()
+        } else {
+            pick_list(unlinked, None::<Contact>, |contact| {
+                Message::Contact(ContactMessage::LinkContact(contact))
+            })
+            .placeholder("Link a contact")
+            .text_size(12)
+            .width(Fill)
+            .into()
+        };
+
+        column![text("Contacts").size(12), linked, picker]
+            .spacing(5)
+            .into()
+    }
+
+    pub(super) fn contacts_modal<'a>(&'a self) -> Element<'a, Message> {
+        let contacts = Column::with_children(self.contacts.iter().map(|contact| {
+            let id = contact.id;
+            let confirm = format!("Delete the contact {}?", contact.name);
+            let details = [contact.role.as_str(), contact.email.as_str()]
+                .into_iter()
+                .filter(|detail| !detail.is_empty())
+                .collect::<Vec<_>>()
+                .join(" | ");
+            row![
+                column![
+                    text(contact.to_string()).size(14),
+                    text(details).size(12),
+                    text(&contact.linkedin_url).size(10),
+                ]
+                .width(Fill),
+                IconButton::new("pen")
+                    .color(self.icon_color())
+                    .solid()
+                    .view()
+                    .map(move |msg| match msg {
+                        IconButtonMessage::Pressed => {
+                            Message::Contact(ContactMessage::EditContact(id))
+                        }
+                    }),
+                IconButton::new("trash")
+                    .color(self.icon_color())
+                    .solid()
+                    .view()
+                    .map(move |msg| match msg {
+                        IconButtonMessage::Pressed => Message::Confirm(
+                            confirm.clone(),
+                            Box::new(Message::Contact(ContactMessage::DeleteContact(id))),
+                        ),
+                    }),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .into()
+        }))
+        .spacing(10);
+        let contacts: Element<'a, Message> = if self.contacts.is_empty() {
+            text("No contacts yet").size(12).into()
+        } else {
+            scrollable(contacts)
+                .height(Length::Fixed(200.0))
+                .width(Fill)
+                .into()
+        };
+        let contact_error: Element<'a, Message> = match &self.contact_error {
+            Some(error) => text(error.clone()).size(12).style(text::danger).into(),
+            None => column![].into(),
+        };
+        let submit_message = Message::Contact(ContactMessage::SaveContact);
+        let text_field =
+            |label: &'a str, value: &'a str, on_input: fn(String) -> ContactMessage| {
+                column![
+                    text(label).size(12),
+                    text_input("", value)
+                        .on_input(move |value| Message::Contact(on_input(value)))
+                        .on_submit(submit_message.clone())
+                        .padding(5),
+                ]
+                .width(Length::FillPortion(1))
+                .spacing(5)
+            };
+        let (form_title, save_label) = match self.contact_id {
+            Some(_) => ("Edit Contact", "Save Contact"),
+            None => ("New Contact", "Add Contact"),
+        };
+        let cancel_edit: Element<'a, Message> = match self.contact_id {
+            Some(_) => button(text("Cancel Edit"))
+                .style(button::secondary)
+                .on_press(Message::Contact(ContactMessage::CancelContactEdit))
+                .into(),
+            None => column![].into(),
+        };
+
+        container(
+            column![
+                text("Contacts").size(24),
+                column![
+                    text("Recruiters and other people to link to job posts and applications")
+                        .size(12),
+                    contacts,
+                    text(form_title).size(16),
+                    row![
+                        column![
+                            text("Name").size(12),
+                            text_input("", &self.contact_name)
+                                .id(self.primary_modal_field.clone().unwrap())
+                                .on_input(|value| Message::Contact(
+                                    ContactMessage::ContactNameChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        text_field(
+                            "Role",
+                            &self.contact_role,
+                            ContactMessage::ContactRoleChanged
+                        ),
+                    ]
+                    .spacing(10),
+                    row![
+                        text_field(
+                            "Email",
+                            &self.contact_email,
+                            ContactMessage::ContactEmailChanged
+                        ),
+                        column![
+                            text("Company").size(12),
+                            row![
+                                pick_list(
+                                    self.companies.as_slice(),
+                                    self.contact_company.clone(),
+                                    |company| Message::Contact(
+                                        ContactMessage::ContactCompanyChanged(company)
+                                    )
+                                )
+                                .placeholder("None")
+                                .text_size(12)
+                                .width(Fill),
+                                button(text("×").size(12))
+                                    .style(button::secondary)
+                                    .on_press_maybe(self.contact_company.as_ref().map(|_| {
+                                        Message::Contact(ContactMessage::ClearContactCompany)
+                                    })),
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    row![
+                        text_field(
+                            "LinkedIn URL",
+                            &self.contact_linkedin_url,
+                            ContactMessage::ContactLinkedinUrlChanged
+                        ),
+                        text_field(
+                            "Notes",
+                            &self.contact_notes,
+                            ContactMessage::ContactNotesChanged
+                        ),
+                    ]
+                    .spacing(10),
+                    contact_error,
+                    row![
+                        container(button(text("Close")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        cancel_edit,
+                        container(button(text(save_label)).on_press(submit_message.clone())),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(600)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
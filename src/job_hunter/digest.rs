@@ -0,0 +1,297 @@
+use iced::widget::column;
+
+use super::*;
+
+/// How long a snoozed post stays out of the review queue
+const SNOOZE_DAYS: i64 = 1;
+
+#[derive(Debug, Clone)]
+pub enum DigestMessage {
+    StartReview,
+    ReviewApply,
+    ReviewHide,
+    ReviewSnooze,
+    ReviewSkip,
+    ShowAwaitingFollowUp,
+}
+
+impl JobHunter {
+    pub(super) fn update_digest(&mut self, message: DigestMessage) -> Task<Message> {
+        match message {
+            DigestMessage::StartReview => {
+                self.review_index = Some(0);
+                Task::none()
+            }
+            DigestMessage::ReviewApply => {
+                let Some(post) = self.reviewed_post() else {
+                    return Task::none();
+                };
+                let job_post_id = post.job_post.id;
+                self.next_review_post();
+                self.update_applications(ApplicationMessage::MarkApplied(job_post_id))
+            }
+            DigestMessage::ReviewHide => {
+                let Some(post) = self.reviewed_post() else {
+                    return Task::none();
+                };
+                let job_post_id = post.job_post.id;
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::archive(job_post_id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to archive job post");
+                }
+                self.next_review_post();
+                self.get_filter_task()
+            }
+            DigestMessage::ReviewSnooze => {
+                let Some(post) = self.reviewed_post() else {
+                    return Task::none();
+                };
+                let job_post_id = post.job_post.id;
+                {
+                    let pool = self.db.clone();
+                    let until = Utc::now() + chrono::Duration::days(SNOOZE_DAYS);
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::snooze(job_post_id, until, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to snooze job post");
+                }
+                self.next_review_post();
+                Task::none()
+            }
+            DigestMessage::ReviewSkip => {
+                self.next_review_post();
+                Task::none()
+            }
+            DigestMessage::ShowAwaitingFollowUp => {
+                self.hide_modal();
+                if self.filter.awaiting_response {
+                    return self.get_filter_task();
+                }
+                self.update_filters(FilterMessage::ToggleAwaitingResponse)
+            }
+        }
+    }
+
+    /// Opens the digest of what changed since the last session, unless nothing did or this
+    /// is the first one
+    pub(super) fn show_digest(&mut self) {
+        let now = Utc::now();
+        let last_session = self.config.last_session();
+        self.config.set_last_session(now.timestamp());
+        self.save_config();
+        let Some(since) = DateTime::from_timestamp(last_session, 0).filter(|_| last_session > 0)
+        else {
+            return;
+        };
+        let digest = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = Digest::fetch(since, now, &pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get digest")
+        };
+        if !digest.is_empty() {
+            self.digest = digest;
+            self.review_index = None;
+            self.push_modal(Modal::DigestModal);
+        }
+    }
+
+    /// Notifies about interviews that came within the reminder lead time since the last check
+    pub(super) fn remind_upcoming_interviews(&mut self) {
+        let now = Utc::now();
+        let lead = chrono::Duration::minutes(INTERVIEW_REMINDER_MINUTES);
+        let from = self.last_interview_reminder.map_or(now, |last| last + lead);
+        self.last_interview_reminder = Some(now);
+        let interviews = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = UpcomingInterview::fetch_between(from, now + lead, &pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get upcoming interviews")
+        };
+        for upcoming in interviews {
+            let at = DateTime::from_timestamp(upcoming.interview_at, 0).unwrap_or_default();
+            self.notify(
+                &format!("Interview with {}", upcoming.company_name),
+                &format!(
+                    "{}: {}",
+                    upcoming.job_title,
+                    interview::format_interview(at, upcoming.interview_timezone.as_deref())
+                ),
+            );
+        }
+    }
+
+    /// The post being reviewed, if the review queue is open and not finished
+    fn reviewed_post(&self) -> Option<&ReviewPost> {
+        self.review_index
+            .and_then(|index| self.digest.new_posts.get(index))
+    }
+
+    fn next_review_post(&mut self) {
+        if let Some(index) = self.review_index.as_mut() {
+            *index += 1;
+        }
+    }
+
+    /// Review queue shortcuts: A applies, H hides, S snoozes, N skips
+    pub(super) fn review_shortcut(&self, key: &str) -> Option<DigestMessage> {
+        if !matches!(self.modal(), Some(Modal::DigestModal)) {
+            return None;
+        }
+        self.reviewed_post()?;
+        match key.to_lowercase().as_str() {
+            "a" => Some(DigestMessage::ReviewApply),
+            "h" => Some(DigestMessage::ReviewHide),
+            "s" => Some(DigestMessage::ReviewSnooze),
+            "n" => Some(DigestMessage::ReviewSkip),
+            _ => None,
+        }
+    }
+
+    pub(super) fn digest_modal<'a>(&'a self) -> Element<'a, Message> {
+        let content = match self.review_index {
+            Some(index) => self.review_queue(index),
+            None => self.digest_summary(),
+        };
+        container(column![text("Since Last Time").size(24), content].spacing(20))
+            .width(600)
+            .padding(10)
+            .style(container::rounded_box)
+            .into()
+    }
+
+    fn digest_summary<'a>(&'a self) -> Element<'a, Message> {
+        let digest = &self.digest;
+        let interviews = Column::with_children(digest.upcoming_interviews.iter().map(|upcoming| {
+            let at = DateTime::from_timestamp(upcoming.interview_at, 0).unwrap_or_default();
+            text(format!(
+                "{} at {}: {}",
+                upcoming.job_title,
+                upcoming.company_name,
+                interview::format_interview(at, upcoming.interview_timezone.as_deref())
+            ))
+            .size(12)
+            .into()
+        }))
+        .spacing(5);
+        let interviews: Element<'a, Message> = if digest.upcoming_interviews.is_empty() {
+            text("No interviews in the next two days").size(12).into()
+        } else {
+            interviews.into()
+        };
+
+        let mut review_btn = button(text(format!("Review {} New", digest.new_posts.len())));
+        if !digest.new_posts.is_empty() {
+            review_btn = review_btn.on_press(Message::Digest(DigestMessage::StartReview));
+        }
+        let mut follow_up_btn = button(text("Show")).style(button::secondary);
+        if digest.awaiting_follow_up > 0 {
+            follow_up_btn =
+                follow_up_btn.on_press(Message::Digest(DigestMessage::ShowAwaitingFollowUp));
+        }
+
+        column![
+            text(format!(
+                "{} new job posts to review",
+                digest.new_posts.len()
+            ))
+            .size(16),
+            text("Upcoming Interviews").size(16),
+            interviews,
+            row![
+                text(format!(
+                    "{} applications with no reply after {} days",
+                    digest.awaiting_follow_up, FOLLOW_UP_AFTER_DAYS
+                ))
+                .size(12)
+                .width(Fill),
+                follow_up_btn,
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            text(format!("{} open offers", digest.open_offers)).size(12),
+            row![
+                container(button(text("Close")).on_press(Message::HideModal))
+                    .width(Fill)
+                    .align_x(Alignment::End),
+                review_btn,
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .into()
+    }
+
+    fn review_queue<'a>(&'a self, index: usize) -> Element<'a, Message> {
+        let total = self.digest.new_posts.len();
+        let Some(post) = self.digest.new_posts.get(index) else {
+            return column![
+                text("All caught up").size(16),
+                container(button(text("Close")).on_press(Message::HideModal))
+                    .width(Fill)
+                    .align_x(Alignment::End),
+            ]
+            .spacing(10)
+            .into();
+        };
+        let job_post = &post.job_post;
+        let skills_text = match &job_post.skills {
+            Some(skills) => format_comma_separated(skills.to_string()),
+            None => "No skills specified".to_string(),
+        };
+        let action = |label: &'a str, message: DigestMessage| {
+            button(text(label)).on_press(Message::Digest(message))
+        };
+
+        column![
+            text(format!("{} of {}", index + 1, total)).size(12),
+            text(&job_post.job_title).size(18),
+            text(&post.company_name).size(14),
+            text(format!(
+                "{} ({})",
+                job_post.location, job_post.location_type
+            ))
+            .size(12),
+            text(pay_range_text(job_post)).size(12),
+            text(skills_text).size(12),
+            text(&job_post.url).size(10),
+            row![
+                action("Apply (A)", DigestMessage::ReviewApply).style(button::success),
+                action("Hide (H)", DigestMessage::ReviewHide).style(button::danger),
+                action("Snooze (S)", DigestMessage::ReviewSnooze).style(button::secondary),
+                action("Skip (N)", DigestMessage::ReviewSkip).style(button::secondary),
+                container(button(text("Close")).on_press(Message::HideModal))
+                    .width(Fill)
+                    .align_x(Alignment::End),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .into()
+    }
+}
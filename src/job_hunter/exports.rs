@@ -0,0 +1,169 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum ExportMessage {
+    ShowExportModal,
+    PickExportWeek,
+    ExportWeekChanged(Date),
+    CancelExportWeekPicker,
+    ExportWorkSearchLog,
+    ExportAnonymizedData,
+    ExportInterviewCalendar,
+}
+
+impl JobHunter {
+    pub(super) fn update_exports(&mut self, message: ExportMessage) -> Task<Message> {
+        match message {
+            ExportMessage::ShowExportModal => {
+                self.push_modal(Modal::ExportModal);
+                // Claims are usually filed for the week that just ended
+                self.export_week =
+                    export::week_start(Utc::now().date_naive() - chrono::Duration::days(7)).into();
+                Task::none()
+            }
+            ExportMessage::PickExportWeek => {
+                self.pick_export_week = true;
+                Task::none()
+            }
+            ExportMessage::ExportWeekChanged(date) => {
+                self.export_week = export::week_start(date.into()).into();
+                self.pick_export_week = false;
+                Task::none()
+            }
+            ExportMessage::CancelExportWeekPicker => {
+                self.pick_export_week = false;
+                Task::none()
+            }
+            ExportMessage::ExportWorkSearchLog => {
+                let week_start: NaiveDate = self.export_week.into();
+                let entries = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = export::work_search_log(week_start, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver.recv().expect("Failed to receive work search log")
+                };
+                let res =
+                    entries.and_then(|entries| export::save_work_search_log(week_start, &entries));
+                self.status_message = Some(match res {
+                    Ok(path) => format!("Saved work search log to {}", path.display()),
+                    Err(error) => {
+                        tracing::error!("Work search log export failed: {error}");
+                        format!("Work search log export failed: {error}")
+                    }
+                });
+                self.hide_modal();
+                Task::none()
+            }
+            ExportMessage::ExportAnonymizedData => {
+                let entries = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = export::anonymized_data(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver.recv().expect("Failed to receive anonymized data")
+                };
+                let res = entries.and_then(|entries| export::save_anonymized_data(&entries));
+                self.status_message = Some(match res {
+                    Ok(path) => format!("Saved anonymized data to {}", path.display()),
+                    Err(error) => {
+                        tracing::error!("Anonymized data export failed: {error}");
+                        format!("Anonymized data export failed: {error}")
+                    }
+                });
+                self.hide_modal();
+                Task::none()
+            }
+            ExportMessage::ExportInterviewCalendar => {
+                let entries = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = export::interview_schedule(Utc::now(), &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive interview schedule")
+                };
+                let res = entries.and_then(|entries| export::save_interview_calendar(&entries));
+                self.status_message = Some(match res {
+                    Ok(path) => format!("Saved interview calendar to {}", path.display()),
+                    Err(error) => {
+                        tracing::error!("Interview calendar export failed: {error}");
+                        format!("Interview calendar export failed: {error}")
+                    }
+                });
+                self.hide_modal();
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn export_modal<'a>(&'a self, submit_message: Message) -> Element<'a, Message> {
+        let week_start: NaiveDate = self.export_week.into();
+        let week_end = week_start + chrono::Duration::days(6);
+        let pick_btn: iced::widget::Button<'_, Message, Theme, iced::Renderer> =
+            button(text("Pick")).on_press(Message::Export(ExportMessage::PickExportWeek));
+        let picker = date_picker(
+            self.pick_export_week,
+            self.export_week,
+            pick_btn,
+            Message::Export(ExportMessage::CancelExportWeekPicker),
+            |date| Message::Export(ExportMessage::ExportWeekChanged(date)),
+        );
+        container(
+            column![
+                text("Export").size(24),
+                column![
+                    text("Work Search Log").size(16),
+                    column![
+                        text("Week").size(12),
+                        text("Sunday through Saturday, containing the picked date").size(10),
+                        row![
+                            text(format!(
+                                "{} - {}",
+                                week_start.format("%m/%d/%Y"),
+                                week_end.format("%m/%d/%Y")
+                            ))
+                            .size(12),
+                            picker
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center),
+                    ]
+                    .spacing(5),
+                    button(text("Export Log")).on_press(submit_message),
+                    text("Anonymized Data").size(16),
+                    text("Companies become pseudonyms; URLs and notes are left out").size(12),
+                    button(text("Export Anonymized"))
+                        .on_press(Message::Export(ExportMessage::ExportAnonymizedData)),
+                    text("Interview Calendar").size(16),
+                    text("Upcoming interviews with reminders, for Google Calendar or Outlook")
+                        .size(12),
+                    button(text("Export Interviews"))
+                        .on_press(Message::Export(ExportMessage::ExportInterviewCalendar)),
+                    text("Files are saved to the exports folder, as CSV or .ics").size(10),
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+            ]
+            .spacing(20),
+        )
+        .width(400)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
@@ -0,0 +1,570 @@
+use iced::widget::column;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Under the data directory, one per profile
+pub const FILTER_STATE_FILE: &str = "filters.toml";
+
+/// Page sizes offered next to the pagination controls
+pub const PAGE_SIZES: [i64; 4] = [10, 25, 50, 100];
+
+#[derive(Debug, Clone)]
+pub enum FilterMessage {
+    ResetFilters,
+    FilterResults,
+    ResultsFiltered(Vec<JobPost>),
+    FilterMinYOEChanged(i64),
+    FilterMinAnnualPayChanged(i64),
+    FilterMaxYOEChanged(i64),
+    FilterOnsiteChanged(bool),
+    FilterHybridChanged(bool),
+    FilterRemoteChanged(bool),
+    FilterSearchChanged(String),
+    FilterJobTitleChanged(String),
+    FilterLocationChanged(String),
+    FilterCompanyNameChanged(String),
+    FilterCompanyToggled(i64, bool),
+    FilterRetrievedDaysChanged(i64),
+    PickFilterDate(FilterDateField),
+    FilterDateChanged(FilterDateField, Date),
+    ClearFilterDate(FilterDateField),
+    CancelFilterDatePicker,
+    FilterInterviewedChanged(bool),
+    FilterNoResponseChanged(bool),
+    FilterMeetsTargetChanged(bool),
+    FilterArchivedChanged(bool),
+    FilterFavoritesOnlyChanged(bool),
+    FilterIndustryChanged(String),
+    FilterTagChanged(String),
+    /// Shows only posts with the tag, from a chip on a card
+    FilterByTag(String),
+    SortByChanged(SortBy),
+    ToggleSortDirection,
+    ToggleAwaitingResponse,
+    PageSizeChanged(i64),
+}
+
+/// Job post filters and the page of results being shown, kept on disk so the next session
+/// opens where this one left off
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FilterState {
+    pub search: String,
+    pub job_title: String,
+    pub location: String,
+    pub min_yoe: i64,
+    pub max_yoe: i64,
+    pub onsite: bool,
+    pub hybrid: bool,
+    pub remote: bool,
+    pub company_name: String,
+    pub company_ids: BTreeSet<i64>,
+    pub retrieved_days: i64,
+    #[serde(with = "optional_date")]
+    pub applied_from: Option<Date>,
+    #[serde(with = "optional_date")]
+    pub applied_to: Option<Date>,
+    #[serde(with = "optional_date")]
+    pub responded_from: Option<Date>,
+    #[serde(with = "optional_date")]
+    pub responded_to: Option<Date>,
+    pub interviewed: bool,
+    pub no_response: bool,
+    /// Only posts paying at least my target salary
+    pub meets_target: bool,
+    /// Yearly, in whole currency units (0 = any)
+    pub min_annual_pay: i64,
+    pub industry: Option<String>,
+    pub tag: Option<String>,
+    /// Archived posts instead of the main list
+    pub archived: bool,
+    pub favorites_only: bool,
+    pub awaiting_response: bool,
+    pub sort_by: SortBy,
+    pub sort_descending: bool,
+    pub page: i64,
+    /// Saved in the config instead
+    #[serde(skip)]
+    pub page_size: i64,
+}
+
+/// Picker dates as "YYYY-MM-DD"
+mod optional_date {
+    use chrono::NaiveDate;
+    use iced_aw::date_picker::Date;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::db::NullableSqliteDateTime;
+
+    pub fn serialize<S: Serializer>(date: &Option<Date>, serializer: S) -> Result<S::Ok, S::Error> {
+        NullableSqliteDateTime::from(*date).0.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Date>, D::Error> {
+        Option::<NaiveDate>::deserialize(deserializer)
+            .map(|date| NullableSqliteDateTime(date).into())
+    }
+}
+
+impl Default for FilterState {
+    fn default() -> Self {
+        Self {
+            search: "".to_string(),
+            job_title: "".to_string(),
+            location: "".to_string(),
+            min_yoe: 0,
+            max_yoe: 0,
+            onsite: false,
+            hybrid: false,
+            remote: false,
+            company_name: "".to_string(),
+            company_ids: BTreeSet::new(),
+            retrieved_days: 0,
+            applied_from: None,
+            applied_to: None,
+            responded_from: None,
+            responded_to: None,
+            interviewed: false,
+            no_response: false,
+            meets_target: false,
+            min_annual_pay: 0,
+            industry: None,
+            tag: None,
+            archived: false,
+            favorites_only: false,
+            awaiting_response: false,
+            sort_by: SortBy::default(),
+            sort_descending: true,
+            page: 1,
+            page_size: 10,
+        }
+    }
+}
+
+impl FilterState {
+    /// The profile's filters and page from its last session, or none. Saved filters that
+    /// can't be read are treated as missing.
+    pub fn load(profile: &str, page_size: i64) -> Self {
+        let saved = std::fs::read_to_string(paths::profile_data_file(profile, FILTER_STATE_FILE))
+            .ok()
+            .and_then(|content| {
+                toml::from_str(&content)
+                    .map_err(|e| tracing::warn!("Ignoring unreadable saved filters: {:?}", e))
+                    .ok()
+            })
+            .unwrap_or_default();
+        Self { page_size, ..saved }
+    }
+
+    pub fn save(&self, profile: &str) -> anyhow::Result<()> {
+        let path = paths::profile_data_file(profile, FILTER_STATE_FILE);
+        paths::ensure_parent(&path)?;
+        std::fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Clears every filter and goes back to the first page. The page size and sort are kept.
+    pub fn reset(&mut self) {
+        *self = Self {
+            page_size: self.page_size,
+            sort_by: self.sort_by,
+            sort_descending: self.sort_descending,
+            ..Self::default()
+        };
+    }
+
+    /// Whether nothing narrows the results; pagination and sorting don't count
+    pub fn is_default(&self) -> bool {
+        self.search.is_empty()
+            && self.job_title.is_empty()
+            && self.location.is_empty()
+            && self.min_yoe == 0
+            && self.max_yoe == 0
+            && !self.onsite
+            && !self.hybrid
+            && !self.remote
+            && self.company_name.is_empty()
+            && self.company_ids.is_empty()
+            && self.retrieved_days == 0
+            && self.applied_from.is_none()
+            && self.applied_to.is_none()
+            && self.responded_from.is_none()
+            && self.responded_to.is_none()
+            && !self.interviewed
+            && !self.no_response
+            && !self.meets_target
+            && self.min_annual_pay == 0
+            && self.industry.is_none()
+            && self.tag.is_none()
+            && !self.archived
+            && !self.favorites_only
+            && !self.awaiting_response
+    }
+
+    /// The filters as `JobPost::filter`, `filter_count`, and `delete_where` take them, given
+    /// my yearly target salary in cents
+    pub fn to_query_params(&self, target_pay_cents: i64) -> JobPostFilter {
+        JobPostFilter {
+            search: self.search.clone(),
+            title: self.job_title.clone(),
+            location: self.location.clone(),
+            min_yoe: self.min_yoe,
+            max_yoe: self.max_yoe,
+            onsite: self.onsite,
+            hybrid: self.hybrid,
+            remote: self.remote,
+            company_ids: self.company_ids.iter().copied().collect(),
+            retrieved_within_days: self.retrieved_days,
+            meets_target_cents: if self.meets_target {
+                target_pay_cents
+            } else {
+                0
+            },
+            min_annual_pay_cents: self.min_annual_pay * 100,
+            industry: self.industry.clone().unwrap_or_default(),
+            tag: self.tag.clone().unwrap_or_default(),
+            sort_by: self.sort_by,
+            sort_descending: self.sort_descending,
+            archived: self.archived,
+            favorites_only: self.favorites_only,
+            application: JobApplicationFilter {
+                applied_from: NullableSqliteDateTime::from(self.applied_from),
+                applied_to: NullableSqliteDateTime::from(self.applied_to),
+                responded_from: NullableSqliteDateTime::from(self.responded_from),
+                responded_to: NullableSqliteDateTime::from(self.responded_to),
+                interviewed: self.interviewed,
+                no_response: self.no_response,
+                awaiting_response: self.awaiting_response,
+            },
+        }
+    }
+
+    pub fn date(&self, field: FilterDateField) -> Option<Date> {
+        match field {
+            FilterDateField::AppliedFrom => self.applied_from,
+            FilterDateField::AppliedTo => self.applied_to,
+            FilterDateField::RespondedFrom => self.responded_from,
+            FilterDateField::RespondedTo => self.responded_to,
+        }
+    }
+
+    pub fn set_date(&mut self, field: FilterDateField, date: Option<Date>) {
+        match field {
+            FilterDateField::AppliedFrom => self.applied_from = date,
+            FilterDateField::AppliedTo => self.applied_to = date,
+            FilterDateField::RespondedFrom => self.responded_from = date,
+            FilterDateField::RespondedTo => self.responded_to = date,
+        }
+    }
+}
+
+impl JobHunter {
+    pub(super) fn update_filters(&mut self, message: FilterMessage) -> Task<Message> {
+        match message {
+            FilterMessage::FilterMinYOEChanged(num) => {
+                self.filter.min_yoe = num;
+                Task::none()
+            }
+            FilterMessage::FilterMinAnnualPayChanged(pay) => {
+                self.filter.min_annual_pay = pay;
+                Task::none()
+            }
+            FilterMessage::FilterMaxYOEChanged(num) => {
+                self.filter.max_yoe = num;
+                Task::none()
+            }
+            FilterMessage::FilterOnsiteChanged(val) => {
+                self.filter.onsite = val;
+                Task::none()
+            }
+            FilterMessage::FilterHybridChanged(val) => {
+                self.filter.hybrid = val;
+                Task::none()
+            }
+            FilterMessage::FilterRemoteChanged(val) => {
+                self.filter.remote = val;
+                Task::none()
+            }
+            FilterMessage::FilterSearchChanged(search) => {
+                self.filter.search = search;
+                Task::none()
+            }
+            FilterMessage::FilterJobTitleChanged(title) => {
+                self.filter.job_title = title;
+                Task::none()
+            }
+            FilterMessage::FilterLocationChanged(location) => {
+                self.filter.location = location;
+                Task::none()
+            }
+            FilterMessage::FilterCompanyNameChanged(name) => {
+                self.filter.company_name = name;
+                let companies_by_name: Vec<Company> = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    let name = self.filter.company_name.clone();
+                    self.tokio_handle.spawn(async move {
+                        let companies_res = Company::fetch_by_name(&name, false, &pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies_by_name;
+                Task::none()
+            }
+            FilterMessage::FilterCompanyToggled(id, selected) => {
+                if selected {
+                    self.filter.company_ids.insert(id);
+                } else {
+                    self.filter.company_ids.remove(&id);
+                }
+                self.filter.page = 1;
+                self.get_filter_task()
+            }
+            FilterMessage::FilterRetrievedDaysChanged(days) => {
+                self.filter.retrieved_days = days;
+                Task::none()
+            }
+            FilterMessage::PickFilterDate(field) => {
+                self.pick_filter_date = Some(field);
+                Task::none()
+            }
+            FilterMessage::FilterDateChanged(field, date) => {
+                self.filter.set_date(field, Some(date));
+                self.pick_filter_date = None;
+                Task::none()
+            }
+            FilterMessage::ClearFilterDate(field) => {
+                self.filter.set_date(field, None);
+                Task::none()
+            }
+            FilterMessage::CancelFilterDatePicker => {
+                self.pick_filter_date = None;
+                Task::none()
+            }
+            FilterMessage::FilterInterviewedChanged(val) => {
+                self.filter.interviewed = val;
+                Task::none()
+            }
+            FilterMessage::FilterNoResponseChanged(val) => {
+                self.filter.no_response = val;
+                Task::none()
+            }
+            FilterMessage::FilterMeetsTargetChanged(val) => {
+                self.filter.meets_target = val;
+                Task::none()
+            }
+            FilterMessage::FilterArchivedChanged(val) => {
+                self.filter.archived = val;
+                Task::none()
+            }
+            FilterMessage::FilterFavoritesOnlyChanged(val) => {
+                self.filter.favorites_only = val;
+                Task::none()
+            }
+            FilterMessage::FilterIndustryChanged(industry) => {
+                self.filter.industry = (industry != "Any").then_some(industry);
+                Task::none()
+            }
+            FilterMessage::FilterTagChanged(tag) => {
+                self.filter.tag = (tag != "Any").then_some(tag);
+                Task::none()
+            }
+            FilterMessage::FilterByTag(tag) => {
+                self.filter.tag = Some(tag);
+                self.filter.page = 1;
+                self.get_filter_task()
+            }
+            FilterMessage::SortByChanged(sort_by) => {
+                self.filter.sort_by = sort_by;
+                self.filter.page = 1;
+                self.get_filter_task()
+            }
+            FilterMessage::ToggleSortDirection => {
+                self.filter.sort_descending = !self.filter.sort_descending;
+                self.filter.page = 1;
+                self.get_filter_task()
+            }
+            FilterMessage::ToggleAwaitingResponse => {
+                let awaiting = !self.filter.awaiting_response;
+                self.reset_filters();
+                self.filter.awaiting_response = awaiting;
+                self.filter.page = 1;
+                self.get_filter_task()
+            }
+            FilterMessage::PageSizeChanged(page_size) => {
+                self.filter.page_size = page_size;
+                self.filter.page = 1;
+                self.config.job_page_size = page_size;
+                self.save_config();
+                self.get_filter_task()
+            }
+            FilterMessage::ResetFilters => {
+                self.reset_filters();
+                let companies = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        // ? Probably want to decouple hiding from the filter
+                        // Company::show_all(&pool)
+                        //     .await
+                        //     .expect("Failed to show companies");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.companies = companies;
+                self.get_filter_task()
+            }
+            FilterMessage::FilterResults => {
+                // self.filter_results();
+                self.get_filter_task()
+            }
+            FilterMessage::ResultsFiltered(job_posts) => {
+                self.job_posts = job_posts;
+                // self.job_posts_total = self.job_posts.len();
+                self.set_job_count();
+                self.set_company_job_counts();
+                self.set_pay_samples();
+                if self.config.board_view {
+                    self.set_board_cards();
+                }
+                Task::none()
+            }
+        }
+    }
+
+    /// "Any" followed by every industry in the taxonomy
+    pub(super) fn industry_options(&self) -> Vec<String> {
+        std::iter::once("Any".to_string())
+            .chain(self.industries.names())
+            .collect()
+    }
+
+    /// "Any" followed by every tag in use
+    pub(super) fn tag_options(&self) -> Vec<String> {
+        std::iter::once("Any".to_string())
+            .chain(self.known_tags.iter().cloned())
+            .collect()
+    }
+
+    /// My yearly target salary in cents, 0 when it isn't set
+    pub(super) fn target_pay_cents(&self) -> i64 {
+        self.config.target_salary * 100
+    }
+
+    pub(super) fn reset_filters(&mut self) {
+        self.filter.reset();
+        self.pick_filter_date = None;
+    }
+
+    pub(super) fn get_filter_task(&mut self) -> Task<Message> {
+        if let Err(e) = self.filter.save(&self.config.profile) {
+            tracing::warn!("Failed to save filters: {:?}", e);
+        }
+        let page = self.filter.page;
+        let page_size = self.filter.page_size;
+        let filter = self.filter.to_query_params(self.target_pay_cents());
+        self.applied_filter = filter.clone();
+        let db = self.db.clone();
+        let industries = self.industries.clone();
+
+        Task::perform(
+            async move {
+                JobPost::classify_industries(&industries, &db)
+                    .await
+                    .expect("Failed to classify job posts");
+                JobPost::filter(page, page_size, filter, &db)
+                    .await
+                    .map(|jobs| Message::Filter(FilterMessage::ResultsFiltered(jobs)))
+                    .expect("Failed to filter job posts")
+            },
+            |msg| msg,
+        )
+    }
+
+    pub(super) fn set_job_count(&mut self) {
+        let total_results = {
+            let pool = self.db.clone();
+            let filter = self.applied_filter.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = JobPost::filter_count(filter, &pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get job post count")
+        };
+
+        self.job_posts_total = total_results as usize;
+    }
+
+    /// Refreshes the pay of every stored post, which cards are ranked against
+    pub(super) fn set_pay_samples(&mut self) {
+        self.pay_samples = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = JobPost::fetch_pay_samples(&pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get pay samples")
+        };
+    }
+
+    pub(super) fn filter_date_field<'a>(
+        &self,
+        label: &'a str,
+        field: FilterDateField,
+    ) -> Element<'a, Message> {
+        let date = self.filter.date(field);
+        let date_text = match &date {
+            Some(date) => format!("{}/{}/{}", date.month, date.day, date.year),
+            None => "Any".to_string(),
+        };
+        let pick_btn: iced::widget::Button<'_, Message, Theme, iced::Renderer> =
+            button(text("Pick")).on_press(Message::Filter(FilterMessage::PickFilterDate(field)));
+        let picker = date_picker(
+            self.pick_filter_date == Some(field),
+            date.unwrap_or(Date::today()),
+            pick_btn,
+            Message::Filter(FilterMessage::CancelFilterDatePicker),
+            move |date| Message::Filter(FilterMessage::FilterDateChanged(field, date)),
+        );
+        let mut date_row = row![text(date_text).size(12), picker]
+            .spacing(5)
+            .align_y(Alignment::Center);
+        if date.is_some() {
+            date_row = date_row.push(
+                IconButton::new("xmark")
+                    .color(self.icon_color())
+                    .solid()
+                    .view()
+                    .map(move |msg| match msg {
+                        IconButtonMessage::Pressed => {
+                            Message::Filter(FilterMessage::ClearFilterDate(field))
+                        }
+                    }),
+            );
+        }
+        column![text(label).size(12), date_row]
+            .width(Length::FillPortion(1))
+            .spacing(5)
+            .into()
+    }
+}
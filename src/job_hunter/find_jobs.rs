@@ -0,0 +1,558 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum FindJobsMessage {
+    FindJobs,
+    FindJobsFailed(String),
+    FindJobsResults(api::JobSearchResponse),
+    FindJobsFacetSelected(String, String),
+    FindJobsProviderChanged(api::Provider),
+    FindJobsTitleChanged(String),
+    FindJobsLocationChanged(String),
+    FindJobsCountryChanged(String),
+    FindJobsMinYOEChanged(i64),
+    FindJobsMinSalaryChanged(i64),
+    FindJobsOnsiteChanged(bool),
+    FindJobsHybridChanged(bool),
+    FindJobsRemoteChanged(bool),
+    FindJobsTrackedCompaniesChanged(bool),
+    FindJobsResultToggled(usize, bool),
+    ToggleFindJobsPreview,
+    ImportFindJobsResults,
+    ShowFindJobsModal,
+    ShowFetchHistoryModal,
+}
+
+impl JobHunter {
+    pub(super) fn update_find_jobs(&mut self, message: FindJobsMessage) -> Task<Message> {
+        match message {
+            FindJobsMessage::FindJobs => {
+                let Some(provider) = self.find_jobs_provider else {
+                    return Task::none();
+                };
+                if self.is_offline() {
+                    return Task::none();
+                }
+                self.find_jobs_searching = true;
+                self.find_jobs_status = None;
+                self.find_jobs_results = Vec::new();
+                self.find_jobs_facets = Vec::new();
+                self.find_jobs_selected = BTreeSet::new();
+                Task::perform(
+                    api::job_search(
+                        self.http_client(),
+                        provider,
+                        self.config.provider_settings(),
+                        self.find_jobs_params(),
+                        self.db.clone(),
+                    ),
+                    |res| match res {
+                        Ok(response) => {
+                            Message::FindJobs(FindJobsMessage::FindJobsResults(response))
+                        }
+                        Err(e) => Message::FindJobs(FindJobsMessage::FindJobsFailed(e.to_string())),
+                    },
+                )
+            }
+            FindJobsMessage::FindJobsResults(response) => {
+                let results = response.results;
+                self.find_jobs_searching = false;
+                self.find_jobs_facets = response.facets;
+                self.find_jobs_fetch_log_id = Some(response.fetch_log_id);
+                let new_count = results.iter().filter(|result| !result.exists).count();
+                self.find_jobs_status = Some(format!(
+                    "{} results, {} not yet saved",
+                    results.len(),
+                    new_count
+                ));
+                // New posts start selected
+                self.find_jobs_selected = results
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, result)| !result.exists)
+                    .map(|(index, _)| index)
+                    .collect();
+                self.find_jobs_results = results;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsFailed(error) => {
+                self.find_jobs_searching = false;
+                if matches!(self.modal(), Some(Modal::FindJobsModal)) {
+                    self.find_jobs_status = Some(format!("Search failed: {error}"));
+                } else {
+                    self.status_message = Some(format!("Find Jobs failed: {error}"));
+                }
+                Task::none()
+            }
+            FindJobsMessage::FindJobsFacetSelected(facet, value) => {
+                // Narrow the next search to the chosen facet value
+                match facet.as_str() {
+                    "country" => self.find_jobs_country = value,
+                    "workplace_type" => {
+                        self.find_jobs_onsite = value == "on-site";
+                        self.find_jobs_hybrid = value == "hybrid";
+                        self.find_jobs_remote = value == "remote";
+                    }
+                    _ => {}
+                }
+                Task::none()
+            }
+            FindJobsMessage::FindJobsProviderChanged(provider) => {
+                self.find_jobs_provider = Some(provider);
+                Task::none()
+            }
+            FindJobsMessage::FindJobsTitleChanged(title) => {
+                self.find_jobs_title = title;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsLocationChanged(location) => {
+                self.find_jobs_location = location;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsCountryChanged(country) => {
+                self.find_jobs_country = country;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsMinYOEChanged(min_yoe) => {
+                self.find_jobs_min_yoe = min_yoe;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsMinSalaryChanged(min_salary) => {
+                self.find_jobs_min_salary = min_salary;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsOnsiteChanged(onsite) => {
+                self.find_jobs_onsite = onsite;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsHybridChanged(hybrid) => {
+                self.find_jobs_hybrid = hybrid;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsRemoteChanged(remote) => {
+                self.find_jobs_remote = remote;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsTrackedCompaniesChanged(tracked) => {
+                self.find_jobs_tracked_companies = tracked;
+                Task::none()
+            }
+            FindJobsMessage::FindJobsResultToggled(index, checked) => {
+                if checked {
+                    self.find_jobs_selected.insert(index);
+                } else {
+                    self.find_jobs_selected.remove(&index);
+                }
+                Task::none()
+            }
+            FindJobsMessage::ToggleFindJobsPreview => {
+                self.find_jobs_preview =
+                    match (self.find_jobs_preview.is_empty(), self.find_jobs_provider) {
+                        (true, Some(provider)) => {
+                            provider.request_preview(&self.find_jobs_params())
+                        }
+                        _ => "".to_string(),
+                    };
+                Task::none()
+            }
+            FindJobsMessage::ImportFindJobsResults => {
+                let selected = self
+                    .find_jobs_results
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| self.find_jobs_selected.contains(index))
+                    .map(|(_, result)| result.clone())
+                    .collect::<Vec<_>>();
+                Task::perform(
+                    api::import_results(selected, self.find_jobs_fetch_log_id, self.db.clone()),
+                    |res| match res {
+                        Ok(imported) => Message::JobPostsImported(imported),
+                        Err(e) => Message::FindJobs(FindJobsMessage::FindJobsFailed(e.to_string())),
+                    },
+                )
+            }
+            FindJobsMessage::ShowFindJobsModal => {
+                // Keys may have been cleared or the provider turned off since it was picked
+                let configured = self.config.provider_settings().configured();
+                if !self
+                    .find_jobs_provider
+                    .is_some_and(|provider| configured.contains(&provider))
+                {
+                    self.find_jobs_provider = configured.first().copied();
+                }
+                self.push_modal(Modal::FindJobsModal);
+                self.set_primary_modal_field();
+                self.set_last_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            FindJobsMessage::ShowFetchHistoryModal => {
+                self.fetch_history = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = FetchLog::fetch_recent(100, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive fetch history")
+                        .expect("Failed to get fetch history")
+                };
+                self.push_modal(Modal::FetchHistoryModal);
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn find_jobs_modal<'a>(&'a self, submit_message: Message) -> Element<'a, Message> {
+        let mut search_btn = button(text(if self.is_offline() {
+            "Offline"
+        } else if self.find_jobs_searching {
+            "Searching..."
+        } else {
+            "Search"
+        }));
+        let configured = self
+            .find_jobs_provider
+            .is_some_and(|provider| self.config.provider_settings().is_configured(provider));
+        if configured && !self.find_jobs_searching && !self.is_offline() {
+            search_btn = search_btn.on_press(submit_message.clone());
+        }
+        let mut import_btn = button(text(format!(
+            "Import Selected ({})",
+            self.find_jobs_selected.len()
+        )));
+        if !self.find_jobs_selected.is_empty() {
+            import_btn =
+                import_btn.on_press(Message::FindJobs(FindJobsMessage::ImportFindJobsResults));
+        }
+
+        let mut save_alert_btn = button(text("Save"));
+        if !self.alert_rule_name.is_empty() {
+            save_alert_btn = save_alert_btn.on_press(Message::Alert(AlertMessage::SaveAlertRule));
+        }
+
+        let preview: Element<'a, Message> = if self.find_jobs_preview.is_empty() {
+            column![].into()
+        } else {
+            scrollable(text(&self.find_jobs_preview).font(Font::MONOSPACE).size(12))
+                .height(Length::Fixed(150.0))
+                .width(Fill)
+                .into()
+        };
+
+        let status: Element<'a, Message> = match &self.find_jobs_status {
+            Some(status) => text(status).size(12).into(),
+            None => column![].into(),
+        };
+
+        let facets = Column::with_children(self.find_jobs_facets.iter().map(|facet| {
+            // Only facets that map onto a search field can narrow the query
+            let selectable = matches!(facet.name.as_str(), "country" | "workplace_type");
+            let mut facet_row = row![text(facet.name.replace('_', " ")).size(12).width(120)]
+                .spacing(5)
+                .align_y(Alignment::Center);
+            for (value, count) in facet.values.iter().take(5) {
+                let mut value_btn = button(text(format!("{} ({})", value, count)).size(12))
+                    .padding(Padding::from([2, 5]))
+                    .style(button::secondary);
+                if selectable {
+                    value_btn = value_btn.on_press(Message::FindJobs(
+                        FindJobsMessage::FindJobsFacetSelected(facet.name.clone(), value.clone()),
+                    ));
+                }
+                facet_row = facet_row.push(value_btn);
+            }
+            facet_row.into()
+        }))
+        .spacing(5);
+
+        let results = Column::with_children(self.find_jobs_results.iter().enumerate().map(
+            |(index, result)| {
+                search_result_row(
+                    index,
+                    result,
+                    self.find_jobs_selected.contains(&index),
+                    |index, checked| {
+                        Message::FindJobs(FindJobsMessage::FindJobsResultToggled(index, checked))
+                    },
+                )
+            },
+        ))
+        .spacing(10);
+
+        container(
+            column![
+                text("Find Jobs").size(24),
+                column![
+                    row![
+                        column![
+                            form::label("Provider", true),
+                            pick_list(
+                                self.config.provider_settings().configured(),
+                                self.find_jobs_provider,
+                                |value| Message::FindJobs(
+                                    FindJobsMessage::FindJobsProviderChanged(value)
+                                )
+                            )
+                            .padding(5)
+                            .width(Fill),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Job Title").size(12),
+                            text_input("", &self.find_jobs_title)
+                                .id(self.primary_modal_field.clone().unwrap())
+                                .on_input(|value| Message::FindJobs(
+                                    FindJobsMessage::FindJobsTitleChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(2))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    row![
+                        column![
+                            text("City").size(12),
+                            text_input("", &self.find_jobs_location)
+                                .on_input(|value| Message::FindJobs(
+                                    FindJobsMessage::FindJobsLocationChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Country").size(12),
+                            text_input("", &self.find_jobs_country)
+                                .id(self.last_modal_field.clone().unwrap())
+                                .on_input(|value| Message::FindJobs(
+                                    FindJobsMessage::FindJobsCountryChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    row![
+                        column![
+                            text("Min. YOE").size(12),
+                            number_input(
+                                self.find_jobs_min_yoe,
+                                0..100,
+                                |value| Message::FindJobs(FindJobsMessage::FindJobsMinYOEChanged(
+                                    value
+                                ))
+                            )
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Min. Salary").size(12),
+                            number_input(self.find_jobs_min_salary, 0..10_000_000, |value| {
+                                Message::FindJobs(FindJobsMessage::FindJobsMinSalaryChanged(value))
+                            })
+                            .step(1000)
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    row![
+                        checkbox("Onsite", self.find_jobs_onsite).on_toggle(|value| {
+                            Message::FindJobs(FindJobsMessage::FindJobsOnsiteChanged(value))
+                        }),
+                        checkbox("Hybrid", self.find_jobs_hybrid).on_toggle(|value| {
+                            Message::FindJobs(FindJobsMessage::FindJobsHybridChanged(value))
+                        }),
+                        checkbox("Remote", self.find_jobs_remote).on_toggle(|value| {
+                            Message::FindJobs(FindJobsMessage::FindJobsRemoteChanged(value))
+                        }),
+                        checkbox("Tracked companies only", self.find_jobs_tracked_companies)
+                            .on_toggle(|value| Message::FindJobs(
+                                FindJobsMessage::FindJobsTrackedCompaniesChanged(value)
+                            )),
+                    ]
+                    .spacing(10),
+                    row![
+                        column![
+                            text("Save as Alert").size(12),
+                            text_input("", &self.alert_rule_name)
+                                .on_input(|value| Message::Alert(
+                                    AlertMessage::AlertRuleNameChanged(value)
+                                ))
+                                .on_submit(Message::Alert(AlertMessage::SaveAlertRule))
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(2))
+                        .spacing(5),
+                        column![
+                            text("Every (Hours)").size(12),
+                            number_input(self.alert_rule_interval_hours, 1..721, |value| {
+                                Message::Alert(AlertMessage::AlertRuleIntervalChanged(value))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        container(save_alert_btn)
+                            .height(Length::Fixed(55.0))
+                            .align_y(Alignment::End),
+                    ]
+                    .spacing(10),
+                    preview,
+                    status,
+                    facets,
+                    scrollable(results).height(Length::Fixed(250.0)).width(Fill),
+                    row![
+                        button(
+                            row![
+                                text(if self.find_jobs_preview.is_empty() {
+                                    "Preview"
+                                } else {
+                                    "Hide Preview"
+                                }),
+                                fa_icon_solid("eye").size(15.0).color(self.icon_color()),
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center)
+                        )
+                        .on_press(Message::FindJobs(FindJobsMessage::ToggleFindJobsPreview)),
+                        button(
+                            row![
+                                text("History"),
+                                fa_icon_solid("clock-rotate-left")
+                                    .size(15.0)
+                                    .color(self.icon_color()),
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center)
+                        )
+                        .on_press(Message::FindJobs(FindJobsMessage::ShowFetchHistoryModal)),
+                        button(
+                            row![
+                                text("Alerts"),
+                                fa_icon_solid("bell").size(15.0).color(self.icon_color()),
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center)
+                        )
+                        .on_press(Message::Alert(AlertMessage::ShowAlertRulesModal)),
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(search_btn),
+                        container(import_btn),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(700)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    pub(super) fn fetch_history_modal<'a>(&'a self) -> Element<'a, Message> {
+        let header = row![
+            text("Fetched").size(12).width(Length::FillPortion(2)),
+            text("Provider").size(12).width(Length::FillPortion(1)),
+            text("Query").size(12).width(Length::FillPortion(4)),
+            text("Results").size(12).width(Length::FillPortion(1)),
+            text("New").size(12).width(Length::FillPortion(1)),
+        ]
+        .spacing(10);
+        let rows = Column::with_children(self.fetch_history.iter().map(|fetch| {
+            row![
+                text(
+                    fetch
+                        .fetched_at
+                        .0
+                        .with_timezone(&chrono::Local)
+                        .format("%m/%d/%Y %H:%M")
+                        .to_string()
+                )
+                .size(12)
+                .width(Length::FillPortion(2)),
+                text(&fetch.provider).size(12).width(Length::FillPortion(1)),
+                text(&fetch.query).size(12).width(Length::FillPortion(4)),
+                text(fetch.results).size(12).width(Length::FillPortion(1)),
+                text(fetch.inserted).size(12).width(Length::FillPortion(1)),
+            ]
+            .spacing(10)
+            .into()
+        }))
+        .spacing(5);
+        let history: Element<'a, Message> = if self.fetch_history.is_empty() {
+            text("No fetches yet").size(12).into()
+        } else {
+            column![
+                header,
+                scrollable(rows).height(Length::Fixed(350.0)).width(Fill)
+            ]
+            .spacing(5)
+            .into()
+        };
+
+        container(
+            column![
+                text("Fetch History").size(24),
+                column![
+                    history,
+                    row![
+                        container(button(text("Close")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                    ]
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(700)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    fn find_jobs_params(&self) -> api::JobSearchParams {
+        let companies = if self.find_jobs_tracked_companies {
+            self.companies
+                .iter()
+                .map(|c| c.name.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        } else {
+            "".to_string()
+        };
+        api::JobSearchParams {
+            companies,
+            job_title: self.find_jobs_title.clone(),
+            location: self.find_jobs_location.clone(),
+            country: self.find_jobs_country.clone(),
+            min_yoe: self.find_jobs_min_yoe,
+            min_salary: self.find_jobs_min_salary,
+            onsite: self.find_jobs_onsite,
+            hybrid: self.find_jobs_hybrid,
+            remote: self.find_jobs_remote,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum HNImportMessage {
+    ShowHNImportModal,
+    FetchHNThread,
+    HNThreadFetched(hn::HNThread),
+    HNFetchFailed(String),
+    HNKeywordChanged(String),
+    HNResultToggled(usize, bool),
+    ImportHNResults,
+}
+
+impl JobHunter {
+    pub(super) fn update_hn_import(&mut self, message: HNImportMessage) -> Task<Message> {
+        match message {
+            HNImportMessage::ShowHNImportModal => {
+                self.push_modal(Modal::HNImportModal);
+                self.set_primary_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            HNImportMessage::FetchHNThread => {
+                if self.is_offline() {
+                    return Task::none();
+                }
+                self.hn_loading = true;
+                self.hn_status = None;
+                Task::perform(
+                    hn::fetch_whos_hiring(self.http_client(), self.db.clone()),
+                    |res| match res {
+                        Ok(thread) => Message::HNImport(HNImportMessage::HNThreadFetched(thread)),
+                        Err(e) => Message::HNImport(HNImportMessage::HNFetchFailed(e.to_string())),
+                    },
+                )
+            }
+            HNImportMessage::HNThreadFetched(thread) => {
+                self.hn_loading = false;
+                self.hn_status = Some(format!("{} posts parsed", thread.results.len()));
+                self.hn_thread_title = thread.title;
+                // Nothing starts selected; threads have hundreds of posts
+                self.hn_selected = BTreeSet::new();
+                self.hn_results = thread.results;
+                Task::none()
+            }
+            HNImportMessage::HNFetchFailed(error) => {
+                self.hn_loading = false;
+                self.hn_status = Some(format!("Fetch failed: {error}"));
+                Task::none()
+            }
+            HNImportMessage::HNKeywordChanged(keyword) => {
+                self.hn_keyword = keyword;
+                Task::none()
+            }
+            HNImportMessage::HNResultToggled(index, checked) => {
+                if checked {
+                    self.hn_selected.insert(index);
+                } else {
+                    self.hn_selected.remove(&index);
+                }
+                Task::none()
+            }
+            HNImportMessage::ImportHNResults => {
+                let selected = self
+                    .hn_results
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| self.hn_selected.contains(index))
+                    .map(|(_, result)| result.clone())
+                    .collect::<Vec<_>>();
+                Task::perform(
+                    api::import_results(selected, None, self.db.clone()),
+                    |res| match res {
+                        Ok(imported) => Message::JobPostsImported(imported),
+                        Err(e) => Message::HNImport(HNImportMessage::HNFetchFailed(e.to_string())),
+                    },
+                )
+            }
+        }
+    }
+
+    pub(super) fn hn_import_modal<'a>(&'a self) -> Element<'a, Message> {
+        let mut fetch_btn = button(text(if self.is_offline() {
+            "Offline"
+        } else if self.hn_loading {
+            "Fetching..."
+        } else {
+            "Fetch Latest Thread"
+        }));
+        if !self.hn_loading && !self.is_offline() {
+            fetch_btn = fetch_btn.on_press(Message::HNImport(HNImportMessage::FetchHNThread));
+        }
+        let mut import_btn = button(text(format!(
+            "Import Selected ({})",
+            self.hn_selected.len()
+        )));
+        if !self.hn_selected.is_empty() {
+            import_btn = import_btn.on_press(Message::HNImport(HNImportMessage::ImportHNResults));
+        }
+
+        let keyword = self.hn_keyword.to_lowercase();
+        let results = Column::with_children(
+            self.hn_results
+                .iter()
+                .enumerate()
+                .filter(|(_, result)| {
+                    keyword.is_empty()
+                        || result
+                            .job_post
+                            .notes
+                            .as_deref()
+                            .is_some_and(|notes| notes.to_lowercase().contains(&keyword))
+                })
+                .map(|(index, result)| {
+                    search_result_row(
+                        index,
+                        result,
+                        self.hn_selected.contains(&index),
+                        |index, checked| {
+                            Message::HNImport(HNImportMessage::HNResultToggled(index, checked))
+                        },
+                    )
+                }),
+        )
+        .spacing(10);
+
+        let status: Element<'a, Message> = match &self.hn_status {
+            Some(status) => text(status).size(12).into(),
+            None => column![].into(),
+        };
+
+        container(
+            column![
+                text("Hacker News: Who's Hiring").size(24),
+                column![
+                    text(&self.hn_thread_title).size(12),
+                    column![
+                        text("Keyword").size(12),
+                        text_input("", &self.hn_keyword)
+                            .id(self.primary_modal_field.clone().unwrap())
+                            .on_input(|value| Message::HNImport(HNImportMessage::HNKeywordChanged(
+                                value
+                            )))
+                            .padding(5),
+                    ]
+                    .spacing(5),
+                    status,
+                    scrollable(results).height(Length::Fixed(350.0)).width(Fill),
+                    row![
+                        container(fetch_btn),
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(import_btn),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(700)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
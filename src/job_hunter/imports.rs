@@ -0,0 +1,422 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum ImportMessage {
+    ShowImportModal,
+    ImportPathChanged(String),
+    ImportTrackerExport,
+    ShowUrlImportModal,
+    UrlListEdited(text_editor::Action),
+    /// Queues the pasted URLs and starts scraping them one at a time
+    QueueUrls,
+    /// index in the queue, how it went, whether the WebDriver session survived
+    QueuedUrlDone(usize, QueuedUrlStatus, bool),
+    CancelUrlQueue,
+}
+
+/// A pasted job URL and how far its import has got
+#[derive(Debug, Clone)]
+pub struct QueuedUrl {
+    pub url: String,
+    pub status: QueuedUrlStatus,
+    /// Already scraped again after the WebDriver session died on it
+    pub retried: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueuedUrlStatus {
+    Waiting,
+    Scraping,
+    /// Saved, with the scraped job title
+    Imported(String),
+    /// A job post already has the URL
+    Duplicate,
+    Failed(String),
+    Cancelled,
+}
+
+impl std::fmt::Display for QueuedUrlStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QueuedUrlStatus::Waiting => write!(f, "Waiting"),
+            QueuedUrlStatus::Scraping => write!(f, "Scraping..."),
+            QueuedUrlStatus::Imported(job_title) => write!(f, "Imported {job_title}"),
+            QueuedUrlStatus::Duplicate => write!(f, "Already tracked"),
+            QueuedUrlStatus::Failed(error) => write!(f, "Failed: {error}"),
+            QueuedUrlStatus::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+impl JobHunter {
+    pub(super) fn update_imports(&mut self, message: ImportMessage) -> Task<Message> {
+        match message {
+            ImportMessage::ShowImportModal => {
+                self.push_modal(Modal::ImportModal);
+                self.set_primary_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            ImportMessage::ImportPathChanged(path) => {
+                self.import_path = path;
+                self.import_error = None;
+                Task::none()
+            }
+            ImportMessage::ImportTrackerExport => {
+                let path = std::path::PathBuf::from(self.import_path.trim());
+                let records = match import::read_tracker_export(&path) {
+                    Ok(records) => records,
+                    Err(e) => {
+                        self.import_error = Some(format!("Couldn't read export: {e}"));
+                        return Task::none();
+                    }
+                };
+                let res = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = match import::import_tracker_records(records, &pool).await {
+                            Ok(counts) => Company::fetch_shown(&pool)
+                                .await
+                                .map(|companies| (counts, companies)),
+                            Err(e) => Err(e),
+                        };
+                        _ = sender.send(res);
+                    });
+                    receiver.recv().expect("Failed to receive import res")
+                };
+                match res {
+                    Ok((counts, companies)) => {
+                        self.companies = companies;
+                        self.hide_modal();
+                        self.set_field_suggestions();
+                        self.status_message = Some(format!("Imported {}", counts));
+                        self.get_filter_task()
+                    }
+                    Err(e) => {
+                        self.import_error = Some(format!("Import failed: {e}"));
+                        Task::none()
+                    }
+                }
+            }
+            ImportMessage::ShowUrlImportModal => {
+                self.push_modal(Modal::UrlImportModal);
+                Task::none()
+            }
+            ImportMessage::UrlListEdited(action) => {
+                self.url_import_text.perform(action);
+                self.url_import_error = None;
+                Task::none()
+            }
+            ImportMessage::QueueUrls => {
+                if self.url_queue_active() || self.awaiting {
+                    self.url_import_error =
+                        Some("Wait for the current fetch to finish".to_string());
+                    return Task::none();
+                }
+                if self.is_offline() {
+                    self.url_import_error = Some("Can't scrape while offline".to_string());
+                    return Task::none();
+                }
+                let urls = import::parse_url_list(&self.url_import_text.text());
+                if urls.is_empty() {
+                    self.url_import_error = Some("No http(s) URLs found".to_string());
+                    return Task::none();
+                }
+                let tracked = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    let urls = urls.clone();
+                    self.tokio_handle.spawn(async move {
+                        let res = import::tracked_urls(&urls, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    match receiver.recv().expect("Failed to receive tracked URLs") {
+                        Ok(tracked) => tracked,
+                        Err(e) => {
+                            self.url_import_error = Some(format!("Import failed: {e}"));
+                            return Task::none();
+                        }
+                    }
+                };
+                self.url_queue = urls
+                    .into_iter()
+                    .map(|url| QueuedUrl {
+                        status: if tracked.contains(&url) {
+                            QueuedUrlStatus::Duplicate
+                        } else {
+                            QueuedUrlStatus::Waiting
+                        },
+                        url,
+                        retried: false,
+                    })
+                    .collect();
+                self.url_import_text = text_editor::Content::new();
+                self.scrape_next_url()
+            }
+            ImportMessage::QueuedUrlDone(index, status, session_alive) => {
+                self.url_queue_handle = None;
+                if let Some(queued) = self.url_queue.get_mut(index) {
+                    // Like single fetches, a URL the session died on gets one more try
+                    // once the WebDriver restarts
+                    if !session_alive && !queued.retried {
+                        queued.status = QueuedUrlStatus::Waiting;
+                        queued.retried = true;
+                    } else {
+                        queued.status = status;
+                    }
+                }
+                if !session_alive {
+                    tracing::warn!("WebDriver session died, restarting it for the next URL");
+                    self.stop_web_driver();
+                    self.web_driver_status = DriverStatus::Failed("session ended".to_string());
+                }
+                self.scrape_next_url()
+            }
+            ImportMessage::CancelUrlQueue => {
+                if let Some(handle) = self.url_queue_handle.take() {
+                    handle.abort();
+                }
+                self.stop_url_queue(QueuedUrlStatus::Cancelled)
+            }
+        }
+    }
+
+    /// Whether pasted URLs are still waiting or being scraped
+    pub(super) fn url_queue_active(&self) -> bool {
+        self.url_queue.iter().any(|queued| {
+            matches!(
+                queued.status,
+                QueuedUrlStatus::Waiting | QueuedUrlStatus::Scraping
+            )
+        })
+    }
+
+    /// Scrapes and saves the next waiting URL in the background, starting the WebDriver first
+    /// if it isn't up. Wraps up the import once none are left.
+    pub(super) fn scrape_next_url(&mut self) -> Task<Message> {
+        let Some(index) = self
+            .url_queue
+            .iter()
+            .position(|queued| queued.status == QueuedUrlStatus::Waiting)
+        else {
+            return self.finish_url_queue();
+        };
+        let Some(driver) = self.web_driver.clone() else {
+            // Picked back up once `WebDriverConnected` arrives
+            return match self.spawn_web_driver() {
+                Ok(task) => task,
+                Err(e) => self.stop_url_queue(QueuedUrlStatus::Failed(format!(
+                    "WebDriver unavailable: {e}"
+                ))),
+            };
+        };
+        // Read per URL, like single fetches, so edits apply mid-import
+        let selectors = match scraper::Selectors::load() {
+            Ok(selectors) => selectors,
+            Err(e) => {
+                return self.stop_url_queue(QueuedUrlStatus::Failed(format!(
+                    "Failed to load selectors: {e}"
+                )))
+            }
+        };
+        let plugins = plugins::load_plugins();
+        let client = self.http_client();
+        let pool = self.db.clone();
+        let url = self.url_queue[index].url.clone();
+        self.url_queue[index].status = QueuedUrlStatus::Scraping;
+        self.status_message = Some(format!(
+            "Importing URLs: {} of {}",
+            index + 1,
+            self.url_queue.len()
+        ));
+        let (task, handle) = Task::perform(
+            async move {
+                let fetched =
+                    scraper::fetch_job_details(driver.clone(), client, url, selectors, plugins)
+                        .await;
+                let (company_name, job_post) = match fetched {
+                    Ok((company_name, Some(job_post))) => (company_name, job_post),
+                    Ok((_, None)) => {
+                        let error = "No scraper for this site".to_string();
+                        return (QueuedUrlStatus::Failed(error), true);
+                    }
+                    Err(e) => {
+                        let session_alive = scraper::session_alive(&driver).await;
+                        return (QueuedUrlStatus::Failed(e.to_string()), session_alive);
+                    }
+                };
+                let Some(company_name) =
+                    company_name.or_else(|| scraper::company_from_url(&job_post.url))
+                else {
+                    let error = "No company name found".to_string();
+                    return (QueuedUrlStatus::Failed(error), true);
+                };
+                let job_title = job_post.job_title.clone();
+                let status = match import::import_scraped_post(&company_name, job_post, &pool).await
+                {
+                    Ok(Some(_)) => QueuedUrlStatus::Imported(job_title),
+                    Ok(None) => QueuedUrlStatus::Duplicate,
+                    Err(e) => QueuedUrlStatus::Failed(e.to_string()),
+                };
+                (status, true)
+            },
+            move |(status, session_alive)| {
+                Message::Import(ImportMessage::QueuedUrlDone(index, status, session_alive))
+            },
+        )
+        .abortable();
+        self.url_queue_handle = Some(handle);
+        task
+    }
+
+    /// Marks every URL not yet done with `status`, then wraps up the import
+    pub(super) fn stop_url_queue(&mut self, status: QueuedUrlStatus) -> Task<Message> {
+        for queued in self.url_queue.iter_mut() {
+            if matches!(
+                queued.status,
+                QueuedUrlStatus::Waiting | QueuedUrlStatus::Scraping
+            ) {
+                queued.status = status.clone();
+            }
+        }
+        self.finish_url_queue()
+    }
+
+    fn finish_url_queue(&mut self) -> Task<Message> {
+        self.url_queue_handle = None;
+        let imported = self
+            .url_queue
+            .iter()
+            .filter(|queued| matches!(queued.status, QueuedUrlStatus::Imported(_)))
+            .count();
+        let summary = format!("Imported {imported} of {} URLs", self.url_queue.len());
+        self.notify("URL import finished", &summary);
+        self.status_message = Some(summary);
+        self.set_field_suggestions();
+        self.get_filter_task()
+    }
+
+    pub(super) fn import_modal<'a>(&'a self, submit_message: Message) -> Element<'a, Message> {
+        let import_error: Element<'a, Message> = match &self.import_error {
+            Some(error) => text(error.clone()).size(12).style(text::danger).into(),
+            None => column![].into(),
+        };
+        container(
+            column![
+                text("Import From Job Tracker").size(24),
+                column![
+                    column![
+                        text("Export File").size(12),
+                        text("A Huntr or Teal export, as CSV or JSON").size(10),
+                        text_input("/path/to/export.csv", &self.import_path)
+                            .id(self.primary_modal_field.clone().unwrap())
+                            .on_input(|value| Message::Import(ImportMessage::ImportPathChanged(
+                                value
+                            )))
+                            .on_submit(submit_message.clone())
+                            .padding(5),
+                    ]
+                    .spacing(5),
+                    text("Jobs already tracked by URL are skipped").size(12),
+                    import_error,
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(button(text("Import")).on_press_maybe(
+                            (!self.import_path.trim().is_empty()).then_some(submit_message)
+                        )),
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+            ]
+            .spacing(20),
+        )
+        .width(500)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    pub(super) fn url_import_modal<'a>(&'a self) -> Element<'a, Message> {
+        let active = self.url_queue_active();
+        let url_import_error: Element<'a, Message> = match &self.url_import_error {
+            Some(error) => text(error.clone()).size(12).style(text::danger).into(),
+            None => column![].into(),
+        };
+        let done = self
+            .url_queue
+            .iter()
+            .filter(|queued| {
+                !matches!(
+                    queued.status,
+                    QueuedUrlStatus::Waiting | QueuedUrlStatus::Scraping
+                )
+            })
+            .count();
+        let progress: Element<'a, Message> = if self.url_queue.is_empty() {
+            column![].into()
+        } else {
+            text(format!("{done} of {} done", self.url_queue.len()))
+                .size(12)
+                .into()
+        };
+        let queue = Column::with_children(self.url_queue.iter().map(|queued| {
+            let status = text(queued.status.to_string()).size(12);
+            let status = match queued.status {
+                QueuedUrlStatus::Failed(_) => status.style(text::danger),
+                QueuedUrlStatus::Imported(_) => status.style(text::success),
+                _ => status,
+            };
+            column![text(&queued.url).size(12), status]
+                .spacing(2)
+                .into()
+        }))
+        .spacing(8);
+        let mut queue_btn = button(text(if active { "Importing..." } else { "Import" }));
+        if !active && !self.url_import_text.text().trim().is_empty() {
+            queue_btn = queue_btn.on_press(Message::Import(ImportMessage::QueueUrls));
+        }
+
+        container(
+            column![
+                text("Import Job URLs").size(24),
+                column![
+                    column![
+                        text("Job URLs").size(12),
+                        text("One per line. Each is scraped and saved in turn; posts already tracked are skipped").size(10),
+                        text_editor(&self.url_import_text)
+                            .placeholder("https://jobs.lever.co/...")
+                            .on_action(|action| Message::Import(ImportMessage::UrlListEdited(
+                                action
+                            )))
+                            .height(120)
+                            .padding(5),
+                    ]
+                    .spacing(5),
+                    url_import_error,
+                    progress,
+                    scrollable(queue).height(Length::Fixed(200.0)).width(Fill),
+                    row![
+                        container(button(text("Cancel Import")).style(button::danger).on_press_maybe(
+                            active.then_some(Message::Import(ImportMessage::CancelUrlQueue))
+                        ))
+                        .width(Fill),
+                        button(text("Close")).on_press(Message::HideModal),
+                        queue_btn,
+                    ]
+                    .spacing(10),
+                ]
+                .spacing(10)
+            ]
+            .spacing(20),
+        )
+        .width(600)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
@@ -0,0 +1,529 @@
+use iced::widget::column;
+
+use super::*;
+
+impl JobHunter {
+    /// The page of job posts matching the filters, with its sort and pagination controls
+    pub(super) fn job_list(&self) -> Element<'_, Message> {
+        column![
+            // Job list
+            container(
+                row![
+                    text(format!("{} results", self.job_posts_total)).width(Fill),
+                    text("Sort By").size(12),
+                    pick_list(SortBy::ALL, Some(self.filter.sort_by), |value| {
+                        Message::Filter(FilterMessage::SortByChanged(value))
+                    })
+                    .text_size(12)
+                    .padding(5),
+                    button(
+                        fa_icon_solid(if self.filter.sort_descending {
+                            "arrow-down-wide-short"
+                        } else {
+                            "arrow-up-short-wide"
+                        })
+                        .size(12.0)
+                        .color(self.icon_color())
+                    )
+                    .padding(Padding::from([5, 8]))
+                    .on_press_maybe(
+                        (self.filter.sort_by != SortBy::Activity)
+                            .then_some(Message::Filter(FilterMessage::ToggleSortDirection))
+                    ),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center)
+            )
+            .width(Fill)
+            .padding(Padding::from([0, 30])),
+            scrollable(
+                Column::with_children(
+                    self.job_posts
+                        .clone()
+                        .into_iter()
+                        .map(|job_post| self.job_card(job_post))
+                )
+                .spacing(15)
+                .padding(Padding::from([20, 30]).top(0))
+            )
+            .on_scroll(|viewport| { Message::JobPost(JobPostMessage::JobPostScroll(viewport)) })
+            .height(Length::FillPortion(1)),
+            // Pagination
+            container(
+                row![
+                    IconButton::new("angles-left")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed =>
+                                Message::JobPost(JobPostMessage::JobPageButtonPressed(1)),
+                        }),
+                    IconButton::new("angle-left")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed => Message::JobPost(
+                                JobPostMessage::JobPageButtonPressed(self.filter.page - 1)
+                            ),
+                        }),
+                    text(self.filter.page),
+                    IconButton::new("angle-right")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed => Message::JobPost(
+                                JobPostMessage::JobPageButtonPressed(self.filter.page + 1)
+                            ),
+                        }),
+                    IconButton::new("angles-right")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed =>
+                                Message::JobPost(JobPostMessage::JobPageButtonPressed(total_pages(
+                                    self.job_posts_total as i64,
+                                    self.filter.page_size
+                                ))),
+                        }),
+                    pick_list(filters::PAGE_SIZES, Some(self.filter.page_size), |value| {
+                        Message::Filter(FilterMessage::PageSizeChanged(value))
+                    })
+                    .text_size(12),
+                    text("per page").size(12),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center)
+            )
+            .height(Length::Shrink)
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .padding(Padding::from([0, 30]).bottom(20))
+        ]
+        .spacing(15)
+        .height(Fill)
+        .into()
+    }
+
+    fn job_card(&self, job_post: JobPost) -> Element<'_, Message> {
+        // println!("job_post.id: {} job_post.company_id: {}", job_post.id, job_post.company_id);
+        // let company = Company::get(&self.db, job_post.company_id).unwrap();
+        let company = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let company_res = Company::fetch_one(job_post.company_id, &pool).await;
+                _ = sender.send(company_res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive company_res")
+                .expect("Failed to get company")
+                .expect("Failed to get company")
+        };
+        // let location_text = format!("{} ({})", &job_post.location, &job_post.location_type);
+        let location_type_style = match &job_post.location_type {
+            JobPostLocationType::Onsite => style::badge::secondary,
+            JobPostLocationType::Hybrid => style::badge::info,
+            JobPostLocationType::Remote => style::badge::primary,
+            JobPostLocationType::Unknown => style::badge::warning,
+        };
+        // let posted_text = format!("{}", &job_post.date_posted.unwrap().format("%m/%d/%Y"));
+        // let posted_text = match &job_post.date_posted {
+        //     Some(date) => format!("{}", date.format("%m/%d/%Y")),
+        //     None => "".to_string(),
+        // };
+        let posted_text = job_post.date_posted.format("%m/%d/%Y");
+
+        let min_yoe = &job_post.min_yoe.unwrap_or(-1);
+        let max_yoe = &job_post.max_yoe.unwrap_or(-1);
+        let yoe_text = match (*max_yoe > -1, *min_yoe > -1) {
+            (true, true) => format!("{} - {} years", min_yoe, max_yoe),
+            (false, true) => format!("{}+ years", min_yoe),
+            _ => "No YOE found".to_string(),
+        };
+
+        let pay_text = pay_range_text(&job_post);
+        let pay_gap_badge: Element<Message> = match job_post.pay_gap(self.target_pay_cents()) {
+            Some(PayGap::Below(cents)) => badge(
+                text(format!(
+                    "{}/yr below target",
+                    format_pay(cents, job_post.currency.as_deref())
+                ))
+                .size(12),
+            )
+            .style(style::badge::danger)
+            .into(),
+            Some(PayGap::InRange) => badge(text("Target in range").size(12))
+                .style(style::badge::warning)
+                .into(),
+            Some(PayGap::Meets) => badge(text("Meets target").size(12))
+                .style(style::badge::success)
+                .into(),
+            None => column![].into(),
+        };
+        let pay_percentile_badge: Element<Message> = match job_post
+            .pay_percentile(&self.pay_samples)
+        {
+            Some((percentile, similar)) => {
+                let label = match percentile {
+                    50.. => format!("Top {}%", (100 - percentile).max(1)),
+                    _ => format!("Bottom {}%", percentile.max(1)),
+                };
+                let percentile_badge = badge(text(label).size(12));
+                let percentile_badge = match percentile {
+                    75.. => percentile_badge.style(style::badge::success),
+                    26.. => percentile_badge.style(style::badge::secondary),
+                    _ => percentile_badge.style(style::badge::danger),
+                };
+                tooltip(
+                    percentile_badge,
+                    container(text(format!("Pay midpoint ranked among the {} posts you've tracked with a similar title and currency", similar)).size(12))
+                        .padding(5)
+                        .style(container::rounded_box),
+                    tooltip::Position::Bottom,
+                )
+                .into()
+            }
+            None => column![].into(),
+        };
+        let tag_badges = iced::widget::Row::with_children(
+            split_comma_separated(job_post.tags.as_deref().unwrap_or_default())
+                .into_iter()
+                .map(|tag| {
+                    mouse_area(badge(text(tag.clone()).size(10)).style(style::badge::info))
+                        .on_press(Message::Filter(FilterMessage::FilterByTag(tag)))
+                        .interaction(iced::mouse::Interaction::Pointer)
+                        .into()
+                }),
+        )
+        .spacing(5);
+        // let app_sql = "SELECT id FROM job_application WHERE job_post_id = ?";
+        // let app_id: Option<i32> = self.db.prepare(app_sql)
+        //     .unwrap()
+        //     .query_row([job_post.id], |row| {
+        //         row.get(0)
+        //     }).unwrap_or(None);
+        let application_opt: Option<JobApplication> = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let job_app_res =
+                    JobApplication::fetch_one_by_job_post_id(job_post.id, &pool).await;
+                _ = sender.send(job_app_res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive job_app_res")
+                .expect("Failed to get job application")
+        };
+        let application: JobApplication;
+        // application = match app_id {
+        //     Some(id) => JobApplication::get(&self.db, id).unwrap(),
+        //     None => JobApplication {
+        //         id: -1,
+        //         job_post_id: job_post.id,
+        //         status: JobApplicationStatus::New,
+        //         date_applied: None,
+        //         date_responded: None,
+        //     },
+        // };
+        // Dropdown init
+        let underlay = ellipsis_button(self.icon_color()).on_press(Message::JobPost(
+            JobPostMessage::ToggleJobDropdown(job_post.id),
+        ));
+        let apply_text: &str;
+        let apply_msg: Message;
+        match application_opt {
+            Some(app) => {
+                apply_text = "Mark as";
+                apply_msg =
+                    Message::Application(ApplicationMessage::ShowEditApplicationModal(app.id));
+                application = app;
+            }
+            None => {
+                application = JobApplication {
+                    id: -1,
+                    job_post_id: job_post.id,
+                    status: JobApplicationStatus::New,
+                    date_applied: Default::default(),
+                    date_responded: Default::default(),
+                    interviewed: SqliteBoolean(false),
+                    resume_version: None,
+                    channel: None,
+                    interview_at: None,
+                    interview_timezone: None,
+                    cover_letter_path: None,
+                    cover_letter: None,
+                };
+                apply_text = "Mark as";
+                apply_msg = Message::Application(ApplicationMessage::ShowCreateApplicationModal(
+                    job_post.id,
+                ));
+            }
+        };
+        let status_text = format!("{}", application.status);
+        let status_style = match application.status {
+            JobApplicationStatus::New => style::badge::info,
+            JobApplicationStatus::Applied => style::badge::warning,
+            JobApplicationStatus::Interview => style::badge::primary,
+            JobApplicationStatus::Offer => style::badge::success,
+            JobApplicationStatus::Closed => style::badge::danger,
+            JobApplicationStatus::Rejected => style::badge::danger,
+            JobApplicationStatus::Withdrawn => style::badge::danger,
+        };
+
+        let applied_text = match application.status {
+            JobApplicationStatus::Applied if self.filter.awaiting_response => {
+                match application.date_applied.0 {
+                    Some(date) => format!(
+                        "{} ({} days)",
+                        application.date_applied.format("%m/%d/%Y"),
+                        (Utc::now().date_naive() - date).num_days()
+                    ),
+                    None => "".to_string(),
+                }
+            }
+            JobApplicationStatus::Applied => application.date_applied.format("%m/%d/%Y"),
+            JobApplicationStatus::Interview
+            | JobApplicationStatus::Offer
+            | JobApplicationStatus::Rejected => application.date_responded.format("%m/%d/%Y"),
+            _ => "".to_string(),
+        };
+        let interview_text: Element<'_, Message> = match application
+            .interview_at
+            .and_then(|at| DateTime::from_timestamp(at, 0))
+        {
+            Some(at) if at > Utc::now() => text(format!(
+                "Interview {}",
+                interview::format_interview(at, application.interview_timezone.as_deref())
+            ))
+            .size(12)
+            .into(),
+            _ => column![].into(),
+        };
+        let quick_apply: Element<'_, Message> = if application.id == -1 {
+            button(text("I applied").size(12))
+                .padding(Padding::from([2, 5]))
+                .on_press(Message::Application(ApplicationMessage::MarkApplied(
+                    job_post.id,
+                )))
+                .into()
+        } else {
+            column![].into()
+        };
+
+        // match app_id {
+        //     Some(id) => {
+        //         apply_text = "Apply";
+        //         apply_msg = Message::Application(ApplicationMessage::ShowEditApplicationModal(id))
+        //     },
+        //     None => {
+        //         apply_text = "Apply";
+        //         apply_msg = Message::Application(ApplicationMessage::ShowCreateApplicationModal(job_post.id));
+        //     },
+        // };
+
+        // Dropdown cont.
+        let platform_url = job_post
+            .platform_url
+            .clone()
+            .filter(|url| !url.trim().is_empty());
+        let dropdown = DropDown::new(
+            underlay,
+            column(vec![
+                button(text(apply_text)).on_press(apply_msg).into(),
+                button(text("Open"))
+                    .on_press(Message::JobPost(JobPostMessage::OpenJobURL(
+                        job_post.id,
+                        job_post.url.clone(),
+                    )))
+                    .into(),
+            ])
+            .push_maybe(platform_url.map(|url| {
+                button(text("Open on Platform")).on_press(Message::JobPost(
+                    JobPostMessage::OpenJobURL(job_post.id, url),
+                ))
+            }))
+            .extend(vec![
+                button(text("Edit"))
+                    .on_press(Message::JobPost(JobPostMessage::ShowEditJobPostModal(
+                        job_post.id,
+                    )))
+                    .into(),
+                button(text("Duplicate"))
+                    .on_press(Message::JobPost(JobPostMessage::DuplicateJobPost(
+                        job_post.id,
+                    )))
+                    .into(),
+                button(text("Copy"))
+                    .on_press(Message::JobPost(JobPostMessage::CopyJobSummary(
+                        job_post.id,
+                    )))
+                    .into(),
+                if job_post.archived.0 {
+                    button(text("Restore"))
+                        .on_press(Message::JobPost(JobPostMessage::RestoreJobPost(
+                            job_post.id,
+                        )))
+                        .into()
+                } else {
+                    button(text("Archive"))
+                        .on_press(Message::JobPost(JobPostMessage::ArchiveJobPost(
+                            job_post.id,
+                        )))
+                        .into()
+                },
+                button(text("Delete"))
+                    .on_press(Message::Confirm(
+                        format!("Delete {} and its application?", job_post.job_title),
+                        Box::new(Message::JobPost(JobPostMessage::DeleteJobPost(job_post.id))),
+                    ))
+                    .into(),
+            ])
+            .spacing(5),
+            match self.job_dropdowns.get(&job_post.id) {
+                Some(&status) => status,
+                None => false,
+            },
+        )
+        .width(Fill)
+        .alignment(drop_down::Alignment::Bottom)
+        .on_dismiss(Message::JobPost(JobPostMessage::ToggleJobDropdown(
+            job_post.id,
+        )))
+        .offset(iced_aw::drop_down::Offset::from(
+            -self.job_post_scroll + 5.0,
+        ));
+
+        let skills_text = match &job_post.skills {
+            Some(skills) => format_comma_separated(skills.to_string()),
+            None => "No skills specified".to_string(),
+        };
+        let benefits_text = match &job_post.benefits {
+            Some(benefits) => format_comma_separated(benefits.to_string()),
+            None => "No benefits specified".to_string(),
+        };
+        let company_text = match job_post.industry.as_deref() {
+            Some(industry) if !industry.is_empty() => format!("{} | {}", company.name, industry),
+            _ => company.name,
+        };
+
+        let favorite = job_post.favorite.0;
+        let mut favorite_button = IconButton::new("star").color(self.icon_color());
+        if favorite {
+            favorite_button = favorite_button.solid();
+        }
+        let favorite_button = favorite_button.view().map(move |msg| match msg {
+            IconButtonMessage::Pressed => {
+                Message::JobPost(JobPostMessage::ToggleFavorite(job_post.id, !favorite))
+            }
+        });
+
+        let notes = notes_preview(
+            job_post.id,
+            job_post.notes.clone(),
+            self.expanded_notes.contains(&job_post.id),
+            theme::muted(&self.app_theme),
+        );
+
+        let job_post_id = job_post.id;
+        mouse_area(
+            container(
+                column![
+                    row![
+                        column![
+                            text(job_post.job_title),
+                            row![]
+                                .push_maybe(self.favicon_image(company.careers_url.as_deref()))
+                                .push(text(company_text).size(12))
+                                .spacing(5)
+                                .align_y(Alignment::Center),
+                            row![text(job_post.location).size(12),]
+                                .spacing(5)
+                                .align_y(Alignment::Center),
+                            text(posted_text).size(12),
+                            badge(text(format!("{}", &job_post.location_type)).size(12))
+                                .style(location_type_style),
+                            tag_badges,
+                        ]
+                        .spacing(5)
+                        .width(Length::FillPortion(2)),
+                        column![
+                            text("Qualifications").size(12),
+                            text(yoe_text),
+                            text(skills_text),
+                        ]
+                        .spacing(5)
+                        .width(Length::FillPortion(2)),
+                        column![
+                            text("Compensation").size(12),
+                            text(pay_text),
+                            pay_gap_badge,
+                            pay_percentile_badge,
+                            text(benefits_text),
+                        ]
+                        .spacing(5)
+                        .width(Length::FillPortion(2)),
+                        column![
+                            text("Status").size(12),
+                            badge(text(status_text)).style(status_style),
+                            text(applied_text).size(12),
+                            interview_text,
+                            quick_apply,
+                        ]
+                        .spacing(5)
+                        .width(Length::FillPortion(1)),
+                        row![favorite_button, container(dropdown).center_x(Fill),]
+                            .align_y(Alignment::Start),
+                    ]
+                    .width(Fill),
+                    notes,
+                ]
+                .spacing(10),
+            )
+            .padding(Padding::from(10))
+            .style(theme::card),
+        )
+        .on_press(Message::JobPost(JobPostMessage::ShowJobPostDetail(
+            job_post_id,
+        )))
+        .interaction(iced::mouse::Interaction::Pointer)
+        .into()
+    }
+}
+
+/// A card's notes, cut short unless `expanded`, with a button to toggle between the two
+fn notes_preview<'a>(
+    job_post_id: i64,
+    notes: Option<String>,
+    expanded: bool,
+    icon_color: iced::Color,
+) -> Element<'a, Message> {
+    let Some(notes) = notes.filter(|notes| !notes.trim().is_empty()) else {
+        return column![].into();
+    };
+    let preview = truncate_preview(&notes, 120);
+    let toggle: Element<'a, Message> = match &preview {
+        Some(_) => button(text(if expanded { "Less" } else { "More" }).size(12))
+            .padding(0)
+            .style(button::text)
+            .on_press(Message::JobPost(JobPostMessage::ToggleNotes(job_post_id)))
+            .into(),
+        None => column![].into(),
+    };
+    let shown = match preview {
+        Some(preview) if !expanded => preview,
+        _ => notes,
+    };
+    row![
+        fa_icon_solid("note-sticky").size(12.0).color(icon_color),
+        text(shown).size(12).width(Fill),
+        toggle,
+    ]
+    .spacing(10)
+    .into()
+}
@@ -0,0 +1,1973 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum JobPostMessage {
+    DeleteJobPost(i64),
+    /// Hides a post from the main list, keeping its application
+    ArchiveJobPost(i64),
+    RestoreJobPost(i64),
+    /// Stars or unstars a post
+    ToggleFavorite(i64, bool),
+    ShowBulkDeleteJobPostsModal,
+    BulkDeleteJobPosts,
+    EditJobPost,
+    CreateJobPost,
+    JobPostScroll(iced::widget::scrollable::Viewport),
+    JobPageButtonPressed(i64),
+    FetchJobDetails,
+    JobDetailsFetched(Option<String>, Option<Box<JobPost>>),
+    /// url, error, whether the session survived, whether this was already a retry
+    JobDetailsFetchFailed(String, String, bool, bool),
+    CancelFetchJobDetails,
+    CreateJobPostCompany,
+    ToggleJobDropdown(i64),
+    ShowEditJobPostModal(i64),
+    /// Opens every field of a post, with its application's history, from a click on its card
+    ShowJobPostDetail(i64),
+    /// Opens a post's URL or platform URL in the default browser, closing its dropdown
+    OpenJobURL(i64, String),
+    /// Puts a post's title, company, pay, and URL on the clipboard
+    CopyJobSummary(i64),
+    DuplicateJobPost(i64),
+    JobTitleChanged(String),
+    MinYOEChanged(String),
+    MaxYOEChanged(String),
+    MinPayChanged(String),
+    MaxPayChanged(String),
+    ExpectedPayChanged(String),
+    TagInputChanged(String),
+    AddTag(String),
+    RemoveTag(usize),
+    CurrencyChanged(String),
+    PayUnitChanged(PayUnit),
+    BenefitsChanged(String),
+    LocationChanged(String),
+    PickJobPosted,
+    JobPostedChanged(Date),
+    JobPostedTyped(String),
+    CancelJobPostedPicker,
+    LocationTypeChanged(usize, JobPostLocationType),
+    JobURLChanged(String),
+    SkillInputChanged(String),
+    AddSkill(String),
+    RemoveSkill(usize),
+    NotesEdited(text_editor::Action),
+    /// Shows all or just the start of a card's notes
+    ToggleNotes(i64),
+    ShowAddJobPostModal,
+    JobPostCompanyNameChanged(String),
+    JobPostCompanyChanged(usize, Company),
+    JobPostCompanySubmitted,
+    AutosaveJobPostDraft,
+    RestoreJobPostDraft,
+    DiscardJobPostDraft,
+    JobPostTemplateSelected(JobPostTemplate),
+    JobPostTemplateNameChanged(String),
+    SaveJobPostTemplate,
+    DeleteJobPostTemplate,
+}
+
+impl JobHunter {
+    pub(super) fn update_job_posts(&mut self, message: JobPostMessage) -> Task<Message> {
+        match message {
+            JobPostMessage::DeleteJobPost(id) => {
+                // let _ = JobPost::delete(&self.db, id);
+                // println!("id: {}", id);
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        JobPost::delete(id, &pool)
+                            .await
+                            .expect("Failed to delete job post");
+                        // let jobs_res = JobPost::fetch_all(&pool).await;
+                        _ = sender.send(());
+                    });
+                    receiver.recv().expect("Failed to receive jobs_res")
+                }
+                // self.job_posts = JobPost::get_all(&self.db).expect("Failed to get job posts");
+                // self.job_posts.retain(|job_post| job_post.id != id);
+                // self.filter_results();
+                // Task::none()
+                self.get_filter_task()
+            }
+            JobPostMessage::ArchiveJobPost(id) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::archive(id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to archive job post")
+                }
+                self.job_dropdowns.remove(&id);
+                self.get_filter_task()
+            }
+            JobPostMessage::RestoreJobPost(id) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::restore(id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to restore job post")
+                }
+                self.job_dropdowns.remove(&id);
+                self.get_filter_task()
+            }
+            JobPostMessage::ToggleFavorite(id, favorite) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::set_favorite(id, favorite, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to star job post")
+                }
+                self.get_filter_task()
+            }
+            JobPostMessage::ShowBulkDeleteJobPostsModal => {
+                // The listed posts, not whatever the filter bar has been edited to since
+                let filter = self.applied_filter.clone();
+                let count = {
+                    let pool = self.db.clone();
+                    let filter = filter.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::filter_count(filter, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to get job post count")
+                };
+                if count == 0 {
+                    return Task::none();
+                }
+                self.bulk_delete = Some((filter, count));
+                self.push_modal(Modal::BulkDeleteJobPostsModal);
+                Task::none()
+            }
+            JobPostMessage::BulkDeleteJobPosts => {
+                let Some((filter, _)) = self.bulk_delete.take() else {
+                    return Task::none();
+                };
+                let deleted = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::delete_where(filter, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to delete job posts")
+                };
+                self.status_message = Some(format!("Deleted {} job posts", deleted));
+                self.filter.page = 1;
+                self.hide_modal();
+                self.get_filter_task()
+            }
+            JobPostMessage::ToggleJobDropdown(id) => {
+                // println!("id: {}", id);
+                let current_val = match self.job_dropdowns.get(&id) {
+                    Some(&status) => status,
+                    None => false,
+                };
+                self.job_dropdowns.insert(id, !current_val);
+                Task::none()
+            }
+            JobPostMessage::EditJobPost => {
+                let post_id = match self.job_post_id {
+                    Some(id) => id,
+                    None => return Task::none(),
+                };
+                let mut post = self
+                    .job_posts
+                    .iter()
+                    .find(|post| post.id == post_id)
+                    .unwrap()
+                    .clone();
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                let min_pay = match self.min_pay.as_str() {
+                    "" => None,
+                    _ => Some(get_pay_i64(&self.min_pay).unwrap()),
+                };
+                let max_pay = match self.max_pay.as_str() {
+                    "" => None,
+                    _ => Some(get_pay_i64(&self.max_pay).unwrap()),
+                };
+                let expected_pay = match self.expected_pay.as_str() {
+                    "" => None,
+                    _ => Some(get_pay_i64(&self.expected_pay).unwrap()),
+                };
+                post.location = self.location.clone();
+                post.location_type = self.location_type.unwrap();
+                post.url = self.url.clone();
+                post.min_yoe = self.min_yoe;
+                post.max_yoe = self.max_yoe;
+                post.min_pay_cents = min_pay;
+                post.max_pay_cents = max_pay;
+                post.expected_pay_cents = expected_pay;
+                self.add_pending_tag();
+                post.tags = Some(split_comma_separated(&self.job_post_tags).join(", "))
+                    .filter(|tags| !tags.is_empty());
+                post.currency = Some(self.currency.clone());
+                post.pay_unit = Some(self.pay_unit.name().to_string());
+                post.date_posted = NullableSqliteDateTime::from(self.job_posted);
+                post.job_title = self.job_title.clone();
+                post.benefits = Some(self.benefits.clone());
+                self.add_pending_skill();
+                post.skills = Some(self.skills.clone());
+                post.notes = Some(self.job_post_notes_text()).filter(|notes| !notes.is_empty());
+                post.description = self.job_post_description.clone();
+                post.platform_url = self.job_post_platform_url.clone();
+                // let _ = JobPost::update(&self.db, post).expect("Failed to update job post");
+                // let job_posts = {
+                let updated = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = post.update(&pool).await;
+                        // let jobs_res = JobPost::fetch_all(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to update job post")
+                };
+                // self.job_posts = JobPost::get_all(&self.db).expect("Failed to get job posts");
+                // self.job_posts = job_posts;
+                if let Some(job_post) = self.job_posts.iter_mut().find(|x| x.id == updated.id) {
+                    *job_post = updated;
+                }
+                // self.filter_results();
+                self.hide_modal();
+                JobPostDraft::discard();
+                self.set_field_suggestions();
+                Task::none()
+            }
+            JobPostMessage::CreateJobPost => {
+                if !self.validate_form() {
+                    return Task::none();
+                }
+                self.add_pending_skill();
+                self.add_pending_tag();
+                let min_pay = match self.min_pay.as_str() {
+                    "" => None,
+                    _ => Some(get_pay_i64(&self.min_pay).unwrap()),
+                };
+                let max_pay = match self.max_pay.as_str() {
+                    "" => None,
+                    _ => Some(get_pay_i64(&self.max_pay).unwrap()),
+                };
+                let expected_pay = match self.expected_pay.as_str() {
+                    "" => None,
+                    _ => Some(get_pay_i64(&self.expected_pay).unwrap()),
+                };
+                let post = JobPost {
+                    id: -1,
+                    company_id: self.job_post_company.clone().unwrap().id,
+                    location: self.location.clone(),
+                    location_type: self.location_type.unwrap(),
+                    url: self.url.clone(),
+                    min_yoe: self.min_yoe,
+                    max_yoe: self.max_yoe,
+                    min_pay_cents: min_pay,
+                    max_pay_cents: max_pay,
+                    date_posted: NullableSqliteDateTime::from(self.job_posted),
+                    date_retrieved: SqliteDateTime(Utc::now()),
+                    job_title: self.job_title.clone(),
+                    benefits: Some(self.benefits.clone()),
+                    skills: Some(self.skills.clone()),
+                    pay_unit: Some(self.pay_unit.name().to_string()),
+                    currency: Some(self.currency.clone()),
+                    apijobs_id: None,
+                    industry: None, // TODO
+                    notes: Some(self.job_post_notes_text()).filter(|notes| !notes.is_empty()),
+                    platform_url: self.job_post_platform_url.clone(),
+                    archived: SqliteBoolean(false),
+                    favorite: SqliteBoolean(false),
+                    expected_pay_cents: expected_pay,
+                    tags: Some(split_comma_separated(&self.job_post_tags).join(", "))
+                        .filter(|tags| !tags.is_empty()),
+                    description: self.job_post_description.clone(),
+                };
+                // let _ = JobPost::create(&self.db, post).expect("Failed to create job post");
+                // let job_posts = {
+                let (_, inserted) = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = post.insert(&pool).await;
+                        // let jobs_res = JobPost::fetch_all(page, page_size, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive jobs_res")
+                        .expect("Failed to get job posts")
+                };
+                if inserted.archived > 0 {
+                    self.status_message =
+                        Some("Saved the job post, archived by a post rule".to_string());
+                }
+                // self.job_posts = JobPost::get_all(&self.db).expect("Failed to get job posts");
+                // self.job_posts = job_posts;
+                // self.filter_results();
+                self.hide_modal();
+                JobPostDraft::discard();
+                self.set_field_suggestions();
+                self.get_filter_task()
+            }
+            // https://github.com/iced-rs/iced_aw/issues/300#issuecomment-2563377964
+            JobPostMessage::JobPostScroll(viewport) => {
+                self.job_post_scroll = viewport.absolute_offset().y;
+                Task::none()
+            }
+            JobPostMessage::JobPageButtonPressed(page) => {
+                if page > 0
+                    && page <= total_pages(self.job_posts_total as i64, self.filter.page_size)
+                {
+                    self.filter.page = page;
+                    return self.get_filter_task();
+                }
+                Task::none()
+            }
+            JobPostMessage::FetchJobDetails => {
+                if self.url.is_empty() || self.is_offline() {
+                    return Task::none();
+                }
+                if self.url_queue_active() {
+                    self.status_message =
+                        Some("Wait for the URL import to finish before fetching".to_string());
+                    return Task::none();
+                }
+                if self.web_driver.is_none() {
+                    return self.launch_web_driver(self.url.clone(), false);
+                }
+                self.fetch_job_details_task(self.url.clone(), false)
+            }
+            JobPostMessage::JobDetailsFetchFailed(url, error, session_alive, retried) => {
+                if !session_alive && !retried {
+                    tracing::warn!("WebDriver session died, restarting it");
+                    return self.launch_web_driver(url, true);
+                }
+                self.reset_scrape();
+                self.notify("Couldn't fetch job details", &error);
+                self.status_message = Some(format!("Failed to fetch job details: {error}"));
+                Task::none()
+            }
+            JobPostMessage::CancelFetchJobDetails => {
+                self.reset_scrape();
+                Task::none()
+            }
+            JobPostMessage::JobDetailsFetched(company_name, job) => {
+                let url = self.scrape_url.clone().unwrap_or_default();
+                self.reset_scrape();
+                self.notify(
+                    "Job details fetched",
+                    job.as_ref().map_or(&url, |job| &job.job_title),
+                );
+                if let Some(job) = job.map(|job| *job) {
+                    self.job_title = job.job_title;
+                    self.location = job.location;
+                    self.location_type = Some(job.location_type);
+                    self.location_type_index = JobPostLocationType::ALL
+                        .iter()
+                        .position(|x| x == &job.location_type);
+                    self.job_posted = job.date_posted.into();
+                    self.job_posted_input = None;
+                    self.min_yoe = job.min_yoe;
+                    self.max_yoe = job.max_yoe;
+                    if let Some(currency) = job.currency {
+                        self.currency = currency;
+                    }
+                    self.min_pay = money_value(job.min_pay_cents, &self.currency);
+                    self.max_pay = money_value(job.max_pay_cents, &self.currency);
+                    if job.pay_unit.is_some() {
+                        self.pay_unit = PayUnit::parse(job.pay_unit.as_deref());
+                    }
+                    if let Some(skills) = job.skills {
+                        self.skills = skills;
+                    }
+                    if let Some(benefits) = job.benefits {
+                        self.benefits = benefits;
+                    }
+                    if job.description.is_some() {
+                        self.job_post_description = job.description;
+                    }
+                    if job.platform_url.is_some() {
+                        self.job_post_platform_url = job.platform_url;
+                    }
+                }
+                if let Some(company_name) = company_name {
+                    return Task::perform(
+                        async {
+                            Message::JobPost(JobPostMessage::JobPostCompanyNameChanged(
+                                company_name,
+                            ))
+                        },
+                        |msg| msg,
+                    );
+                }
+                Task::none()
+            }
+            JobPostMessage::CreateJobPostCompany => {
+                let company_name = self.job_post_company_name.clone();
+                if company_name.is_empty() {
+                    return Task::none();
+                }
+                let mut company = Company {
+                    id: 0,
+                    name: company_name.trim().to_string(),
+                    careers_url: None,
+                    hidden: SqliteBoolean(false),
+                    group_id: None,
+                };
+                let (id, companies) = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    let company = company.clone();
+                    self.tokio_handle.spawn(async move {
+                        let id = Company::insert(&company, &pool)
+                            .await
+                            .expect("Failed to insert company");
+                        let companies_res = Company::fetch_shown(&pool).await;
+                        _ = sender.send((id, companies_res));
+                    });
+                    let (id, companies_res) = receiver.recv().expect("Failed to get companies_res");
+                    (id, companies_res.expect("Failed to get companies"))
+                };
+                self.companies = companies;
+                company.id = id;
+                // Pick the new company right away
+                self.job_post_company_results = vec![company.clone()];
+                Task::done(Message::JobPost(JobPostMessage::JobPostCompanyChanged(
+                    0, company,
+                )))
+            }
+            JobPostMessage::AutosaveJobPostDraft => {
+                self.save_job_post_draft();
+                Task::none()
+            }
+            JobPostMessage::RestoreJobPostDraft => {
+                if let Some(draft) = self.job_post_draft.take() {
+                    self.restore_job_post_draft(draft);
+                }
+                Task::none()
+            }
+            JobPostMessage::DiscardJobPostDraft => {
+                self.job_post_draft = None;
+                JobPostDraft::discard();
+                Task::none()
+            }
+            JobPostMessage::ShowEditJobPostModal(job_post_id) => {
+                let job_post = self
+                    .job_posts
+                    .iter()
+                    .find(|post| post.id == job_post_id)
+                    .unwrap()
+                    .clone();
+                let company = self
+                    .companies
+                    .iter()
+                    .find(|company| company.id == job_post.company_id)
+                    .unwrap();
+                self.company_name = company.name.clone();
+                self.job_post_id = Some(job_post.id);
+                self.company_id = Some(company.id);
+                self.set_job_post_fields(&job_post);
+                self.url = job_post.url.clone();
+                self.set_linked_contacts();
+                self.push_modal(Modal::EditJobPostModal);
+                self.open_job_post_draft();
+                self.set_primary_modal_field();
+                self.set_last_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            JobPostMessage::ShowJobPostDetail(job_post_id) => {
+                let (application, events, tags) = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = async {
+                            let application =
+                                JobApplication::fetch_one_by_job_post_id(job_post_id, &pool)
+                                    .await?;
+                            let events = match &application {
+                                Some(application) => {
+                                    JobApplicationEvent::fetch_for_application(
+                                        application.id,
+                                        &pool,
+                                    )
+                                    .await?
+                                }
+                                None => Vec::new(),
+                            };
+                            let tags = Tag::fetch_for_post(job_post_id, &pool).await?;
+                            anyhow::Ok((application, events, tags))
+                        }
+                        .await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to get job application")
+                };
+                self.job_post_detail_id = Some(job_post_id);
+                self.job_post_detail_application = application;
+                self.job_post_detail_events = events;
+                self.job_post_detail_tags = tags;
+                self.push_modal(Modal::JobPostDetailModal);
+                Task::none()
+            }
+            JobPostMessage::OpenJobURL(job_post_id, url) => {
+                self.job_dropdowns.insert(job_post_id, false);
+                if let Err(e) = open::that(&url) {
+                    tracing::warn!("Failed to open {}: {:?}", url, e);
+                    self.status_message = Some(format!("Couldn't open {url}: {e}"));
+                }
+                Task::none()
+            }
+            JobPostMessage::CopyJobSummary(job_post_id) => {
+                self.job_dropdowns.insert(job_post_id, false);
+                let Some(job_post) = self.job_posts.iter().find(|post| post.id == job_post_id)
+                else {
+                    return Task::none();
+                };
+                let company_name = self
+                    .companies
+                    .iter()
+                    .find(|company| company.id == job_post.company_id)
+                    .map(|company| company.name.as_str())
+                    .unwrap_or_default();
+                let summary = job_summary(job_post, company_name);
+                self.status_message =
+                    Some(format!("Copied {} to the clipboard", job_post.job_title));
+                iced::clipboard::write(summary)
+            }
+            JobPostMessage::DuplicateJobPost(job_post_id) => {
+                let job_post = self
+                    .job_posts
+                    .iter()
+                    .find(|post| post.id == job_post_id)
+                    .unwrap()
+                    .clone();
+                let company = self
+                    .companies
+                    .iter()
+                    .find(|company| company.id == job_post.company_id)
+                    .unwrap()
+                    .clone();
+                // A new post for the same role, so it gets its own URL and no application
+                self.job_post_company_name = company.name.clone();
+                self.job_post_company = Some(company);
+                self.set_job_post_fields(&job_post);
+                self.push_modal(Modal::AddJobPostModal);
+                self.open_job_post_draft();
+                self.set_primary_modal_field();
+                self.set_last_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            JobPostMessage::ShowAddJobPostModal => {
+                self.push_modal(Modal::AddJobPostModal);
+                self.set_job_post_templates();
+                self.open_job_post_draft();
+                self.set_primary_modal_field();
+                self.set_last_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            JobPostMessage::JobPostTemplateSelected(template) => {
+                self.apply_job_post_template(&template);
+                self.job_post_template = Some(template);
+                Task::none()
+            }
+            JobPostMessage::JobPostTemplateNameChanged(name) => {
+                self.job_post_template_name = name;
+                Task::none()
+            }
+            JobPostMessage::SaveJobPostTemplate => {
+                let name = self.job_post_template_name.trim().to_string();
+                if name.is_empty() {
+                    return Task::none();
+                }
+                self.add_pending_skill();
+                let template = JobPostTemplate {
+                    id: 0,
+                    name,
+                    job_title: self.job_title.trim().to_string(),
+                    location: self.location.trim().to_string(),
+                    location_type: self.location_type,
+                    skills: self.skills.clone(),
+                    benefits: self.benefits.trim().to_string(),
+                    min_yoe: self.min_yoe,
+                    max_yoe: self.max_yoe,
+                    currency: Some(self.currency.clone()),
+                };
+                let id = {
+                    let pool = self.db.clone();
+                    let template = template.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = template.insert(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive template insert res")
+                        .expect("Failed to save job post template")
+                };
+                self.job_post_template = Some(JobPostTemplate { id, ..template });
+                self.job_post_template_name = "".to_string();
+                self.set_job_post_templates();
+                Task::none()
+            }
+            JobPostMessage::DeleteJobPostTemplate => {
+                let Some(template) = self.job_post_template.take() else {
+                    return Task::none();
+                };
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPostTemplate::delete(template.id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive template delete res")
+                        .expect("Failed to delete job post template")
+                }
+                self.set_job_post_templates();
+                Task::none()
+            }
+            JobPostMessage::PickJobPosted => {
+                self.pick_job_posted = true;
+                Task::none()
+            }
+            JobPostMessage::CancelJobPostedPicker => {
+                self.pick_job_posted = false;
+                Task::none()
+            }
+            JobPostMessage::JobTitleChanged(title) => {
+                self.job_title = title;
+                Task::none()
+            }
+            JobPostMessage::MinYOEChanged(yoe_str) => {
+                let yoe: Result<i32, _> = yoe_str.parse();
+                match yoe {
+                    Ok(num) => {
+                        self.min_yoe = Some(num as i64);
+                    }
+                    Err(_) => {
+                        self.min_yoe = None;
+                    }
+                };
+                Task::none()
+            }
+            JobPostMessage::MaxYOEChanged(yoe_str) => {
+                let yoe: Result<i32, _> = yoe_str.parse();
+                match yoe {
+                    Ok(num) => {
+                        self.max_yoe = Some(num as i64);
+                    }
+                    Err(_) => {
+                        self.max_yoe = None;
+                    }
+                };
+                Task::none()
+            }
+            JobPostMessage::MinPayChanged(pay_str) => {
+                self.min_pay = pay_str;
+                Task::none()
+            }
+            JobPostMessage::MaxPayChanged(pay_str) => {
+                self.max_pay = pay_str;
+                Task::none()
+            }
+            JobPostMessage::ExpectedPayChanged(pay_str) => {
+                self.expected_pay = pay_str;
+                Task::none()
+            }
+            JobPostMessage::TagInputChanged(input) => {
+                self.tag_input = input;
+                // Typing a comma finishes the tag
+                if self.tag_input.ends_with(',') {
+                    self.add_pending_tag();
+                }
+                Task::none()
+            }
+            JobPostMessage::AddTag(tag) => {
+                self.add_tag(&tag);
+                self.tag_input = "".to_string();
+                Task::none()
+            }
+            JobPostMessage::RemoveTag(index) => {
+                let mut tags = split_comma_separated(&self.job_post_tags);
+                if index < tags.len() {
+                    tags.remove(index);
+                }
+                self.job_post_tags = tags.join(", ");
+                Task::none()
+            }
+            JobPostMessage::PayUnitChanged(pay_unit) => {
+                self.pay_unit = pay_unit;
+                Task::none()
+            }
+            JobPostMessage::CurrencyChanged(currency) => {
+                // Typed amounts are kept, minus any cents the new currency doesn't have
+                self.min_pay = convert_money_value(&self.min_pay, &currency);
+                self.max_pay = convert_money_value(&self.max_pay, &currency);
+                self.expected_pay = convert_money_value(&self.expected_pay, &currency);
+                self.currency = currency;
+                Task::none()
+            }
+            JobPostMessage::BenefitsChanged(benefits) => {
+                self.benefits = benefits;
+                Task::none()
+            }
+            JobPostMessage::LocationChanged(location) => {
+                self.location = location;
+                Task::none()
+            }
+            JobPostMessage::JobPostedChanged(date) => {
+                self.job_posted = Some(date);
+                self.pick_job_posted = false;
+                self.job_posted_input = None;
+                Task::none()
+            }
+            JobPostMessage::JobPostedTyped(input) => {
+                if let Some(date) = typed_date(&input) {
+                    self.job_posted = date;
+                }
+                self.job_posted_input = Some(input);
+                Task::none()
+            }
+            JobPostMessage::LocationTypeChanged(index, loc_type) => {
+                self.location_type = Some(loc_type);
+                self.location_type_index = Some(index);
+                Task::none()
+            }
+            JobPostMessage::JobURLChanged(url) => {
+                self.url = url;
+                Task::none()
+            }
+            JobPostMessage::SkillInputChanged(input) => {
+                // Typing a comma finishes the skill
+                if input.ends_with(',') {
+                    self.skill_input = input;
+                    self.add_pending_skill();
+                } else {
+                    self.skill_input = input;
+                }
+                Task::none()
+            }
+            JobPostMessage::AddSkill(skill) => {
+                self.add_skill(&skill);
+                self.skill_input = "".to_string();
+                Task::none()
+            }
+            JobPostMessage::NotesEdited(action) => {
+                self.job_post_notes.perform(action);
+                Task::none()
+            }
+            JobPostMessage::ToggleNotes(job_post_id) => {
+                if !self.expanded_notes.remove(&job_post_id) {
+                    self.expanded_notes.insert(job_post_id);
+                }
+                Task::none()
+            }
+            JobPostMessage::RemoveSkill(index) => {
+                let mut skills = split_comma_separated(&self.skills);
+                if index < skills.len() {
+                    skills.remove(index);
+                }
+                self.skills = skills.join(", ");
+                Task::none()
+            }
+            JobPostMessage::JobPostCompanyNameChanged(company_name) => {
+                self.job_post_company_name = company_name.clone();
+                // self.job_post_company_results =
+                //     Company::list_by_name(&self.db, company_name.clone())
+                //         .expect("Failed to get companies");
+                let companies_by_name = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let companies_res =
+                            Company::fetch_by_name(&company_name, true, &pool).await;
+                        _ = sender.send(companies_res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive companies_res")
+                        .expect("Failed to get companies")
+                };
+                self.job_post_company_results = companies_by_name;
+                // A different name means the picked company no longer applies
+                if self
+                    .job_post_company
+                    .as_ref()
+                    .is_some_and(|company| company.name != self.job_post_company_name)
+                {
+                    self.job_post_company = None;
+                }
+                self.job_post_company_index = match self.company_typeahead_rows() {
+                    0 => None,
+                    _ => Some(0),
+                };
+                Task::none()
+            }
+            JobPostMessage::JobPostCompanyChanged(index, company) => {
+                self.job_post_company = Some(company);
+                self.job_post_company_index = Some(index);
+                self.job_post_company_name = self.job_post_company.clone().unwrap().name;
+                Task::none()
+            }
+            JobPostMessage::JobPostCompanySubmitted => {
+                let Some(index) = self.job_post_company_index else {
+                    return Task::none();
+                };
+                match self.job_post_company_results.get(index).cloned() {
+                    Some(company) => Task::done(Message::JobPost(
+                        JobPostMessage::JobPostCompanyChanged(index, company),
+                    )),
+                    None if self.can_create_typed_company() => {
+                        Task::done(Message::JobPost(JobPostMessage::CreateJobPostCompany))
+                    }
+                    None => Task::none(),
+                }
+            }
+        }
+    }
+
+    pub(super) fn job_post_modal<'a>(&'a self, submit_message: Message) -> Element<'a, Message> {
+        let title = match &self.job_post_id {
+            Some(_) => "Edit Job Post",
+            None => "New Job Post",
+        };
+        let valid = self.form_errors().is_empty();
+        let errors = self.visible_form_errors();
+        // let company name =
+        let company_element: Element<'_, Message, Theme, iced::Renderer> = match &self.job_post_id {
+            Some(_) => text(self.company_name.clone()).into(),
+            None => text_input("", &self.job_post_company_name)
+                .id(self.primary_modal_field.clone().unwrap())
+                .on_input(|value| {
+                    Message::JobPost(JobPostMessage::JobPostCompanyNameChanged(value))
+                })
+                .on_submit(Message::JobPost(JobPostMessage::JobPostCompanySubmitted))
+                .padding(5)
+                .style(input_style(errors.contains(FormField::JobPostCompany)))
+                .into(),
+        };
+        // Results plus a "create" row; arrow keys move the highlight and Enter picks it
+        let company_select: Element<'_, Message, Theme, iced::Renderer> =
+            if self.company_typeahead_rows() == 0 {
+                column![].into()
+            } else {
+                let highlight_style =
+                    |highlighted: bool| -> fn(&Theme, button::Status) -> button::Style {
+                        if highlighted {
+                            button::primary
+                        } else {
+                            button::text
+                        }
+                    };
+                let mut rows =
+                    Column::with_children(self.job_post_company_results.iter().enumerate().map(
+                        |(index, company)| {
+                            button(text(company.name.clone()).size(12))
+                                .width(Fill)
+                                .padding(Padding::from([2, 5]))
+                                .style(highlight_style(self.job_post_company_index == Some(index)))
+                                .on_press(Message::JobPost(JobPostMessage::JobPostCompanyChanged(
+                                    index,
+                                    company.clone(),
+                                )))
+                                .into()
+                        },
+                    ));
+                if self.can_create_typed_company() {
+                    let index = self.job_post_company_results.len();
+                    rows = rows.push(
+                        button(
+                            text(format!("Create '{}'", self.job_post_company_name.trim()))
+                                .size(12),
+                        )
+                        .width(Fill)
+                        .padding(Padding::from([2, 5]))
+                        .style(highlight_style(self.job_post_company_index == Some(index)))
+                        .on_press(Message::JobPost(JobPostMessage::CreateJobPostCompany)),
+                    );
+                }
+                scrollable(rows.spacing(2))
+                    .height(Length::Fixed(70.0))
+                    .into()
+            };
+        let company_row = row![company_element];
+        let min_yoe = match self.min_yoe {
+            Some(num) => num.to_string(),
+            None => "".to_string(),
+        };
+        let max_yoe = match self.max_yoe {
+            Some(num) => num.to_string(),
+            None => "".to_string(),
+        };
+        let posted_btn: iced::widget::Button<'_, Message, Theme, iced::Renderer> =
+            button(text("Pick")).on_press(Message::JobPost(JobPostMessage::PickJobPosted));
+        let job_posted_picker = date_picker(
+            self.pick_job_posted,
+            self.job_posted.unwrap_or(Date::today()),
+            posted_btn,
+            Message::JobPost(JobPostMessage::CancelJobPostedPicker),
+            |value| Message::JobPost(JobPostMessage::JobPostedChanged(value)),
+        );
+        let loc_type_select: SelectionList<
+            '_,
+            JobPostLocationType,
+            Message,
+            Theme,
+            iced::Renderer,
+        > = SelectionList::new_with(
+            &JobPostLocationType::ALL,
+            |index, value| Message::JobPost(JobPostMessage::LocationTypeChanged(index, value)),
+            12.0,
+            5.0,
+            style::selection_list::primary,
+            self.location_type_index,
+            Font::default(),
+        )
+        .height(Length::Fixed(70.0));
+        let mut job_title_field = text_input("", &self.job_title)
+            .on_input(|value| Message::JobPost(JobPostMessage::JobTitleChanged(value)))
+            .on_submit(submit_message.clone())
+            .padding(5)
+            .style(input_style(errors.contains(FormField::JobTitle)));
+        if self.job_post_id.is_some() {
+            job_title_field = job_title_field.id(self.primary_modal_field.clone().unwrap());
+        }
+        // Fetch button
+        let mut fetch_btn: iced::widget::Button<'_, Message, Theme, iced::Renderer> =
+            button(text("Fetch"));
+        if !self.awaiting && !self.is_offline() {
+            fetch_btn = fetch_btn.on_press(Message::JobPost(JobPostMessage::FetchJobDetails));
+        }
+        let draft_banner: Element<'_, Message, Theme, iced::Renderer> = match &self.job_post_draft {
+            Some(draft) => row![
+                text(match draft.saved_at {
+                    Some(saved_at) =>
+                        format!("Unsaved draft from {}", saved_at.format("%m/%d/%Y %H:%M")),
+                    None => "Unsaved draft".to_string(),
+                })
+                .size(12)
+                .width(Fill),
+                button(text("Restore").size(12))
+                    .on_press(Message::JobPost(JobPostMessage::RestoreJobPostDraft)),
+                button(text("Discard").size(12))
+                    .style(button::secondary)
+                    .on_press(Message::JobPost(JobPostMessage::DiscardJobPostDraft)),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into(),
+            None => column![].into(),
+        };
+        let template_row: Element<'_, Message, Theme, iced::Renderer> = match &self.job_post_id {
+            Some(_) => column![].into(),
+            None => row![
+                pick_list(
+                    &self.job_post_templates[..],
+                    self.job_post_template.clone(),
+                    |template| Message::JobPost(JobPostMessage::JobPostTemplateSelected(template))
+                )
+                .placeholder("Apply template")
+                .text_size(12)
+                .padding(5),
+                button(text("Delete").size(12))
+                    .style(button::secondary)
+                    .on_press_maybe(
+                        self.job_post_template
+                            .as_ref()
+                            .map(|_| Message::JobPost(JobPostMessage::DeleteJobPostTemplate))
+                    ),
+                text_input("Template name", &self.job_post_template_name)
+                    .on_input(|value| {
+                        Message::JobPost(JobPostMessage::JobPostTemplateNameChanged(value))
+                    })
+                    .on_submit(Message::JobPost(JobPostMessage::SaveJobPostTemplate))
+                    .size(12)
+                    .padding(5),
+                button(text("Save as Template").size(12)).on_press_maybe(
+                    (!self.job_post_template_name.trim().is_empty())
+                        .then_some(Message::JobPost(JobPostMessage::SaveJobPostTemplate))
+                ),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into(),
+        };
+        let content = container(
+            column![
+                text(title).size(24),
+                column![
+                    draft_banner,
+                    template_row,
+                    row![
+                        // Company name
+                        column![
+                            form::label("Company", true),
+                            company_row,
+                            company_select,
+                            field_error(&errors, FormField::JobPostCompany),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Date posted
+                        column![
+                            text("Date Posted").size(12),
+                            row![
+                                text_input(
+                                    "YYYY-MM-DD",
+                                    &date_input_value(&self.job_posted_input, self.job_posted)
+                                )
+                                .on_input(|value| Message::JobPost(JobPostMessage::JobPostedTyped(
+                                    value
+                                )))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::DatePosted))),
+                                job_posted_picker,
+                            ]
+                            .spacing(5)
+                            .align_y(Alignment::Center),
+                            field_error(&errors, FormField::DatePosted),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(15),
+                    row![
+                        // Title field
+                        column![
+                            form::label("Job Title", true),
+                            job_title_field,
+                            field_error(&errors, FormField::JobTitle),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // URL
+                        column![
+                            form::label("Job URL", true),
+                            row![
+                                text_input("", &self.url)
+                                    .on_input(|value| Message::JobPost(
+                                        JobPostMessage::JobURLChanged(value)
+                                    ))
+                                    .on_submit(submit_message.clone())
+                                    .padding(5)
+                                    .style(input_style(errors.contains(FormField::JobURL))),
+                                fetch_btn,
+                            ]
+                            .spacing(5),
+                            field_error(&errors, FormField::JobURL),
+                            text(self.web_driver_status_text()).size(10),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(15),
+                    row![
+                        // Location field
+                        column![
+                            form::label("Location", true),
+                            text_input("", &self.location)
+                                .on_input(|value| Message::JobPost(
+                                    JobPostMessage::LocationChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::Location))),
+                            location_suggestions(&self.locations, &self.location, |value| {
+                                Message::JobPost(JobPostMessage::LocationChanged(value))
+                            }),
+                            field_error(&errors, FormField::Location),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Location type
+                        column![
+                            form::label("Location Type", true),
+                            loc_type_select,
+                            // .padding(5),
+                            field_error(&errors, FormField::LocationType),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(15),
+                    row![
+                        // Min years
+                        column![
+                            text("Min. Years").size(12),
+                            text_input("", &min_yoe)
+                                .on_input(|value| Message::JobPost(JobPostMessage::MinYOEChanged(
+                                    value
+                                )))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Max years
+                        column![
+                            text("Max. Years").size(12),
+                            text_input("", &max_yoe)
+                                .on_input(|value| Message::JobPost(JobPostMessage::MaxYOEChanged(
+                                    value
+                                )))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                                .style(input_style(errors.contains(FormField::MaxYOE))),
+                            field_error(&errors, FormField::MaxYOE),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Min pay
+                        column![
+                            text("Min. Pay").size(12),
+                            MoneyInput::new(&self.min_pay, &self.currency, |value| {
+                                Message::JobPost(JobPostMessage::MinPayChanged(value))
+                            })
+                            .on_submit(submit_message.clone())
+                            .invalid(errors.contains(FormField::MinPay))
+                            .view(),
+                            field_error(&errors, FormField::MinPay),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Max pay
+                        column![
+                            text("Max. Pay").size(12),
+                            MoneyInput::new(&self.max_pay, &self.currency, |value| {
+                                Message::JobPost(JobPostMessage::MaxPayChanged(value))
+                            })
+                            .on_submit(submit_message.clone())
+                            .invalid(errors.contains(FormField::MaxPay))
+                            .view(),
+                            field_error(&errors, FormField::MaxPay),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Currency
+                        column![
+                            text("Currency").size(12),
+                            pick_list(
+                                CURRENCIES.map(String::from),
+                                Some(self.currency.clone()),
+                                |value| Message::JobPost(JobPostMessage::CurrencyChanged(value))
+                            )
+                            .text_size(12)
+                            .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Pay unit
+                        column![
+                            text("Per").size(12),
+                            pick_list(PayUnit::ALL, Some(self.pay_unit), |value| {
+                                Message::JobPost(JobPostMessage::PayUnitChanged(value))
+                            })
+                            .text_size(12)
+                            .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(15),
+                    row![
+                        // Expected pay
+                        column![
+                            text("My Expectation (Yearly)").size(12),
+                            text("Overrides your target salary for this post").size(10),
+                            MoneyInput::new(&self.expected_pay, &self.currency, |value| {
+                                Message::JobPost(JobPostMessage::ExpectedPayChanged(value))
+                            })
+                            .on_submit(submit_message.clone())
+                            .invalid(errors.contains(FormField::ExpectedPay))
+                            .view(),
+                            field_error(&errors, FormField::ExpectedPay),
+                        ]
+                        .width(Length::FillPortion(2))
+                        .spacing(5),
+                        // Tags
+                        column![
+                            text("Tags").size(12),
+                            text("Post rules add theirs to new posts").size(10),
+                            ChipInput::new(
+                                split_comma_separated(&self.job_post_tags),
+                                &self.tag_input,
+                                |value| Message::JobPost(JobPostMessage::TagInputChanged(value)),
+                                |value| Message::JobPost(JobPostMessage::AddTag(value)),
+                                |value| Message::JobPost(JobPostMessage::RemoveTag(value))
+                            )
+                            .suggestions(&self.known_tags)
+                            .view(),
+                        ]
+                        .width(Length::FillPortion(3))
+                        .spacing(5),
+                    ]
+                    .spacing(15),
+                    row![
+                        // Skills
+                        column![
+                            text("Skills").size(12),
+                            ChipInput::new(
+                                split_comma_separated(&self.skills),
+                                &self.skill_input,
+                                |value| Message::JobPost(JobPostMessage::SkillInputChanged(value)),
+                                |value| Message::JobPost(JobPostMessage::AddSkill(value)),
+                                |value| Message::JobPost(JobPostMessage::RemoveSkill(value))
+                            )
+                            .suggestions(&self.known_skills)
+                            .view(),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        // Benefits
+                        column![
+                            text("Benefits").size(12),
+                            text("Comma-separated").size(10),
+                            text_input("", &self.benefits)
+                                .id(self.last_modal_field.clone().unwrap())
+                                .on_input(|value| Message::JobPost(
+                                    JobPostMessage::BenefitsChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(15),
+                    row![
+                        // Notes
+                        column![
+                            text("Notes").size(12),
+                            text_editor(&self.job_post_notes)
+                                .on_action(|value| Message::JobPost(JobPostMessage::NotesEdited(
+                                    value
+                                )))
+                                .height(Length::Fixed(80.0))
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(2))
+                        .spacing(5),
+                        // Contacts, once the post is saved
+                        container(if self.job_post_id.is_some() {
+                            self.linked_contacts_field()
+                        } else {
+                            column![].into()
+                        })
+                        .width(Length::FillPortion(1)),
+                    ]
+                    .spacing(15),
+                    // Save row
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(form::save_button(submit_message.clone(), valid))
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10)
+            ]
+            .spacing(5),
+        )
+        .width(500)
+        .padding(10)
+        .style(container::rounded_box);
+
+        if !self.awaiting {
+            return content.into();
+        }
+        // Covers the form while scraping so it can't be edited mid-fetch
+        let progress = container(
+            column![
+                Spinner::new()
+                    .width(Length::Fixed(30.0))
+                    .height(Length::Fixed(30.0)),
+                text(if self.web_driver_status == DriverStatus::Starting {
+                    format!("Starting {}...", self.config.driver)
+                } else {
+                    format!(
+                        "Fetching {}",
+                        self.scrape_url.as_deref().unwrap_or_default()
+                    )
+                })
+                .size(12),
+                button(text("Cancel"))
+                    .on_press(Message::JobPost(JobPostMessage::CancelFetchJobDetails)),
+            ]
+            .spacing(10)
+            .align_x(Alignment::Center),
+        )
+        .padding(10)
+        .style(container::rounded_box);
+        stack![content, opaque(center(progress).width(500))].into()
+    }
+
+    pub(super) fn job_post_detail_modal<'a>(&'a self) -> Element<'a, Message> {
+        let Some(job_post) = self
+            .job_post_detail_id
+            .and_then(|id| self.job_posts.iter().find(|post| post.id == id))
+        else {
+            return column![].into();
+        };
+        let company_name = self
+            .companies
+            .iter()
+            .find(|company| company.id == job_post.company_id)
+            .map(|company| company.name.clone())
+            .unwrap_or_default();
+        let field =
+            |label: &'a str, value: String| column![text(label).size(12), text(value)].spacing(3);
+        let or_none = |value: Option<&str>, none: &str| match value {
+            Some(value) if !value.trim().is_empty() => value.to_string(),
+            _ => none.to_string(),
+        };
+        let open_btn =
+            |url: Option<&String>| {
+                let url = url.filter(|url| !url.trim().is_empty());
+                button(text("Open")).on_press_maybe(url.map(|url| {
+                    Message::JobPost(JobPostMessage::OpenJobURL(job_post.id, url.clone()))
+                }))
+            };
+        let yoe_text = match (job_post.min_yoe, job_post.max_yoe) {
+            (Some(min_yoe), Some(max_yoe)) => format!("{} - {} years", min_yoe, max_yoe),
+            (Some(min_yoe), None) => format!("{}+ years", min_yoe),
+            _ => "No YOE found".to_string(),
+        };
+        let tags_text = match self.job_post_detail_tags.as_slice() {
+            [] => "No tags".to_string(),
+            tags => tags
+                .iter()
+                .map(|tag| tag.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        let expected_text = match job_post.expected_pay_cents {
+            Some(cents) => format!("{}/yr", format_pay(cents, job_post.currency.as_deref())),
+            None => "My target salary".to_string(),
+        };
+
+        let (application_text, application_btn) = match &self.job_post_detail_application {
+            Some(application) => {
+                let mut summary = application.status.to_string();
+                if application.date_applied.0.is_some() {
+                    summary.push_str(&format!(
+                        ", applied {}",
+                        application.date_applied.format("%m/%d/%Y")
+                    ));
+                }
+                if application.date_responded.0.is_some() {
+                    summary.push_str(&format!(
+                        ", responded {}",
+                        application.date_responded.format("%m/%d/%Y")
+                    ));
+                }
+                (
+                    summary,
+                    button(text("Edit Application")).on_press(Message::Application(
+                        ApplicationMessage::ShowEditApplicationModal(application.id),
+                    )),
+                )
+            }
+            None => (
+                "Not applied".to_string(),
+                button(text("Apply")).on_press(Message::Application(
+                    ApplicationMessage::ShowCreateApplicationModal(job_post.id),
+                )),
+            ),
+        };
+        let timeline = Column::with_children(self.job_post_detail_events.iter().map(|event| {
+            row![
+                text(
+                    event
+                        .occurred_at
+                        .0
+                        .with_timezone(&chrono::Local)
+                        .format("%m/%d/%Y")
+                        .to_string()
+                )
+                .size(11)
+                .width(70),
+                text(event.summary()).size(11),
+            ]
+            .spacing(10)
+            .into()
+        }))
+        .spacing(3);
+
+        container(
+            column![
+                text(&job_post.job_title).size(24),
+                text(company_name).size(14),
+                scrollable(
+                    column![
+                        row![
+                            field(
+                                "Location",
+                                format!("{} ({})", job_post.location, job_post.location_type)
+                            )
+                            .width(Fill),
+                            field("Posted", job_post.date_posted.format("%m/%d/%Y")).width(Fill),
+                            field(
+                                "Retrieved",
+                                job_post
+                                    .date_retrieved
+                                    .0
+                                    .with_timezone(&chrono::Local)
+                                    .format("%m/%d/%Y")
+                                    .to_string()
+                            )
+                            .width(Fill),
+                        ]
+                        .spacing(10),
+                        row![
+                            field("Experience", yoe_text).width(Fill),
+                            field("Pay", pay_range_text(job_post)).width(Fill),
+                            field("Expecting", expected_text).width(Fill),
+                        ]
+                        .spacing(10),
+                        row![
+                            field(
+                                "Industry",
+                                or_none(job_post.industry.as_deref(), "Unclassified")
+                            )
+                            .width(Fill),
+                            field("Tags", tags_text).width(Fill),
+                        ]
+                        .spacing(10),
+                        field(
+                            "Skills",
+                            format_comma_separated(or_none(
+                                job_post.skills.as_deref(),
+                                "No skills specified"
+                            ))
+                        ),
+                        field(
+                            "Benefits",
+                            format_comma_separated(or_none(
+                                job_post.benefits.as_deref(),
+                                "No benefits specified"
+                            ))
+                        ),
+                        row![
+                            field("URL", job_post.url.clone()).width(Fill),
+                            open_btn(Some(&job_post.url)),
+                        ]
+                        .spacing(10)
+                        .align_y(Alignment::Center),
+                        row![
+                            field(
+                                "Platform URL",
+                                or_none(job_post.platform_url.as_deref(), "None")
+                            )
+                            .width(Fill),
+                            open_btn(job_post.platform_url.as_ref()),
+                        ]
+                        .spacing(10)
+                        .align_y(Alignment::Center),
+                        field("Notes", or_none(job_post.notes.as_deref(), "No notes")),
+                        field(
+                            "Description",
+                            or_none(job_post.description.as_deref(), "No description")
+                        ),
+                        column![
+                            text("Application").size(12),
+                            text(application_text),
+                            timeline,
+                        ]
+                        .spacing(3),
+                    ]
+                    .spacing(10)
+                    .padding(Padding::from(0).right(15)),
+                )
+                .height(Length::Shrink),
+                row![
+                    container(button(text("Close")).on_press(Message::HideModal))
+                        .width(Fill)
+                        .align_x(Alignment::End),
+                    button(text("Copy")).on_press(Message::JobPost(
+                        JobPostMessage::CopyJobSummary(job_post.id)
+                    )),
+                    button(text("Edit")).on_press(Message::JobPost(
+                        JobPostMessage::ShowEditJobPostModal(job_post.id)
+                    )),
+                    application_btn,
+                ]
+                .spacing(10),
+            ]
+            .spacing(10),
+        )
+        .width(700)
+        .max_height(700)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    pub(super) fn bulk_delete_modal<'a>(&self, submit_message: Message) -> Element<'a, Message> {
+        container(
+            column![
+                text("Delete Matching Jobs").size(24),
+                column![
+                    text(format!(
+                        "Delete all {} job posts matching the current filter? Their applications will be deleted too. This cannot be undone.",
+                        self.bulk_delete.as_ref().map_or(0, |(_, count)| *count)
+                    )),
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(
+                            button(text("Delete"))
+                                .style(button::danger)
+                                .on_press(submit_message.clone())
+                        ),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(300)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+
+    pub(super) fn is_job_post_modal(&self) -> bool {
+        matches!(
+            self.modal(),
+            Some(Modal::AddJobPostModal | Modal::EditJobPostModal)
+        )
+    }
+
+    fn current_job_post_draft(&self) -> JobPostDraft {
+        JobPostDraft {
+            job_post_id: self.job_post_id,
+            company_id: match self.job_post_id {
+                Some(_) => self.company_id,
+                None => self.job_post_company.as_ref().map(|company| company.id),
+            },
+            job_title: self.job_title.clone(),
+            location: self.location.clone(),
+            location_type: self.location_type,
+            min_yoe: self.min_yoe,
+            max_yoe: self.max_yoe,
+            min_pay: self.min_pay.clone(),
+            max_pay: self.max_pay.clone(),
+            expected_pay: self.expected_pay.clone(),
+            currency: self.currency.clone(),
+            pay_unit: self.pay_unit.name().to_string(),
+            benefits: self.benefits.clone(),
+            skills: self.skills.clone(),
+            notes: self.job_post_notes_text(),
+            url: self.url.clone(),
+            date_posted: self
+                .job_posted
+                .and_then(|date| NaiveDate::from_ymd_opt(date.year, date.month, date.day)),
+            saved_at: None,
+        }
+    }
+
+    /// Call after the job post modal's fields are filled in
+    fn set_job_post_templates(&mut self) {
+        self.job_post_templates = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = JobPostTemplate::fetch_all(&pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive templates")
+                .expect("Failed to get job post templates")
+        };
+    }
+
+    /// Fills in the fields the template sets, leaving the rest as typed
+    fn apply_job_post_template(&mut self, template: &JobPostTemplate) {
+        if !template.job_title.is_empty() {
+            self.job_title = template.job_title.clone();
+        }
+        if !template.location.is_empty() {
+            self.location = template.location.clone();
+        }
+        if let Some(location_type) = template.location_type {
+            self.location_type = Some(location_type);
+            self.location_type_index = JobPostLocationType::ALL
+                .iter()
+                .position(|x| x == &location_type);
+        }
+        if !template.skills.is_empty() {
+            self.add_skill(&template.skills);
+        }
+        if !template.benefits.is_empty() {
+            self.benefits = template.benefits.clone();
+        }
+        if template.min_yoe.is_some() {
+            self.min_yoe = template.min_yoe;
+        }
+        if template.max_yoe.is_some() {
+            self.max_yoe = template.max_yoe;
+        }
+        if let Some(currency) = &template.currency {
+            self.currency = currency.clone();
+        }
+    }
+
+    /// Fills the job post modal from `job_post`, except its company and URL
+    fn set_job_post_fields(&mut self, job_post: &JobPost) {
+        self.job_title = job_post.job_title.clone();
+        self.job_posted = job_post.date_posted.into();
+        self.location = job_post.location.clone();
+        self.location_type = Some(job_post.location_type);
+        self.location_type_index = JobPostLocationType::ALL
+            .iter()
+            .position(|x| x == &job_post.location_type);
+        self.min_yoe = job_post.min_yoe;
+        self.max_yoe = job_post.max_yoe;
+        self.currency = job_post.currency.clone().unwrap_or("USD".to_string());
+        self.min_pay = money_value(job_post.min_pay_cents, &self.currency);
+        self.max_pay = money_value(job_post.max_pay_cents, &self.currency);
+        self.expected_pay = money_value(job_post.expected_pay_cents, &self.currency);
+        self.job_post_tags = job_post.tags.clone().unwrap_or_default();
+        self.pay_unit = PayUnit::parse(job_post.pay_unit.as_deref());
+        self.benefits = job_post.benefits.clone().unwrap_or("".to_string());
+        self.skills = job_post.skills.clone().unwrap_or("".to_string());
+        self.job_post_notes =
+            text_editor::Content::with_text(job_post.notes.as_deref().unwrap_or_default());
+        self.job_post_description = job_post.description.clone();
+        self.job_post_platform_url = job_post.platform_url.clone();
+    }
+
+    /// The notes as typed, without trailing blank lines
+    fn job_post_notes_text(&self) -> String {
+        self.job_post_notes.text().trim_end().to_string()
+    }
+
+    fn open_job_post_draft(&mut self) {
+        let baseline = self.current_job_post_draft();
+        self.job_post_draft = JobPostDraft::load()
+            .filter(|draft| draft.job_post_id == self.job_post_id && !draft.is_empty());
+        self.job_post_draft_baseline = Some(baseline);
+        self.last_saved_job_post_draft = None;
+    }
+
+    /// Writes the job post modal's fields to disk if they were changed and not yet saved
+    pub(super) fn save_job_post_draft(&mut self) {
+        if !self.is_job_post_modal() {
+            return;
+        }
+        let draft = self.current_job_post_draft();
+        if Some(&draft) == self.job_post_draft_baseline.as_ref()
+            || Some(&draft) == self.last_saved_job_post_draft.as_ref()
+        {
+            return;
+        }
+        match draft.save() {
+            Ok(_) => {
+                // Typing over an offered draft replaces it
+                self.job_post_draft = None;
+                self.last_saved_job_post_draft = Some(draft);
+            }
+            Err(e) => tracing::warn!("Failed to save job post draft: {:?}", e),
+        }
+    }
+
+    fn restore_job_post_draft(&mut self, draft: JobPostDraft) {
+        if draft.job_post_id.is_none() {
+            if let Some(company) = self
+                .companies
+                .iter()
+                .find(|company| Some(company.id) == draft.company_id)
+                .cloned()
+            {
+                self.job_post_company_name = company.name.clone();
+                self.job_post_company_results = vec![company.clone()];
+                self.job_post_company_index = Some(0);
+                self.job_post_company = Some(company);
+            }
+        }
+        self.job_title = draft.job_title;
+        self.location = draft.location;
+        self.location_type = draft.location_type;
+        self.location_type_index = draft.location_type.and_then(|location_type| {
+            JobPostLocationType::ALL
+                .iter()
+                .position(|x| *x == location_type)
+        });
+        self.min_yoe = draft.min_yoe;
+        self.max_yoe = draft.max_yoe;
+        self.min_pay = draft.min_pay;
+        self.max_pay = draft.max_pay;
+        self.expected_pay = draft.expected_pay;
+        if !draft.currency.is_empty() {
+            self.currency = draft.currency;
+        }
+        self.pay_unit = PayUnit::parse(Some(&draft.pay_unit));
+        self.benefits = draft.benefits;
+        self.skills = draft.skills;
+        self.job_post_notes = text_editor::Content::with_text(&draft.notes);
+        self.url = draft.url;
+        self.job_posted_input = None;
+        self.job_posted = draft
+            .date_posted
+            .map(|date| Date::from_ymd(date.year(), date.month(), date.day()));
+    }
+
+    /// Whether the typed company name doesn't match any result, so it can be created
+    fn can_create_typed_company(&self) -> bool {
+        let name = self.job_post_company_name.trim();
+        !name.is_empty()
+            && !self
+                .job_post_company_results
+                .iter()
+                .any(|company| company.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Rows in the company typeahead, none once a company has been picked
+    pub(super) fn company_typeahead_rows(&self) -> usize {
+        let picked = self
+            .job_post_company
+            .as_ref()
+            .is_some_and(|company| company.name == self.job_post_company_name);
+        if picked {
+            return 0;
+        }
+        self.job_post_company_results.len() + usize::from(self.can_create_typed_company())
+    }
+
+    /// Loads the stored locations, skills, and tags offered while typing
+    pub(super) fn set_field_suggestions(&mut self) {
+        let (locations, skills, tags) = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let locations_res = JobPost::distinct_locations(&pool).await;
+                let skills_res = JobPost::distinct_skills(&pool).await;
+                let tags_res = Tag::fetch_all(&pool).await;
+                _ = sender.send((locations_res, skills_res, tags_res));
+            });
+            let (locations_res, skills_res, tags_res) = receiver
+                .recv()
+                .expect("Failed to receive field suggestions");
+            (
+                locations_res.expect("Failed to get locations"),
+                skills_res.expect("Failed to get skills"),
+                tags_res.expect("Failed to get tags"),
+            )
+        };
+        self.locations = locations;
+        self.known_skills = skills;
+        self.known_tags = tags.into_iter().map(|tag| tag.name).collect();
+    }
+
+    /// Turns any skill still in the input into a chip
+    fn add_pending_skill(&mut self) {
+        let skill = std::mem::take(&mut self.skill_input);
+        self.add_skill(&skill);
+    }
+
+    /// Turns any tag still in the input into a chip
+    fn add_pending_tag(&mut self) {
+        let tag = std::mem::take(&mut self.tag_input);
+        self.add_tag(&tag);
+    }
+
+    fn add_tag(&mut self, tag: &str) {
+        let mut tags = split_comma_separated(&self.job_post_tags);
+        for tag in split_comma_separated(tag) {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+                tags.push(tag);
+            }
+        }
+        self.job_post_tags = tags.join(", ");
+    }
+
+    fn add_skill(&mut self, skill: &str) {
+        let mut skills = split_comma_separated(&self.skills);
+        for skill in split_comma_separated(skill) {
+            if !skills.iter().any(|s| s.eq_ignore_ascii_case(&skill)) {
+                skills.push(skill);
+            }
+        }
+        self.skills = skills.join(", ");
+    }
+
+    pub(super) fn fetch_job_details_task(&mut self, url: String, retried: bool) -> Task<Message> {
+        let Some(driver) = self.web_driver.clone() else {
+            return Task::none();
+        };
+        // Read on every fetch so edits to the file apply without a restart
+        let selectors = match scraper::Selectors::load() {
+            Ok(selectors) => selectors,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load selectors: {e}"));
+                return Task::none();
+            }
+        };
+        let plugins = plugins::load_plugins();
+        let client = self.http_client();
+        self.awaiting = true;
+        self.scrape_url = Some(url.clone());
+        let (task, handle) = Task::perform(
+            async move {
+                match scraper::fetch_job_details(
+                    driver.clone(),
+                    client,
+                    url.clone(),
+                    selectors,
+                    plugins,
+                )
+                .await
+                {
+                    Ok(res) => Message::JobPost(JobPostMessage::JobDetailsFetched(
+                        res.0,
+                        res.1.map(Box::new),
+                    )),
+                    Err(e) => Message::JobPost(JobPostMessage::JobDetailsFetchFailed(
+                        url,
+                        e.to_string(),
+                        scraper::session_alive(&driver).await,
+                        retried,
+                    )),
+                }
+            },
+            |msg| msg,
+        )
+        .abortable();
+        self.scrape_handle = Some(handle);
+        task
+    }
+
+    pub(super) fn reset_scrape(&mut self) {
+        self.awaiting = false;
+        self.scrape_url = None;
+        self.pending_fetch = None;
+        if let Some(handle) = self.scrape_handle.take() {
+            handle.abort();
+        }
+    }
+
+    /// Starts a fresh WebDriver server and connects to it in the background, fetching `url`
+    /// once it's up
+    fn launch_web_driver(&mut self, url: String, retried: bool) -> Task<Message> {
+        match self.spawn_web_driver() {
+            Ok(task) => {
+                self.awaiting = true;
+                self.scrape_url = Some(url.clone());
+                self.pending_fetch = Some((url, retried));
+                task
+            }
+            Err(e) => {
+                self.reset_scrape();
+                self.status_message = Some(format!("WebDriver unavailable: {e}"));
+                Task::none()
+            }
+        }
+    }
+
+    /// Starts a fresh WebDriver server, connecting to it in the background. The task ends in
+    /// `Message::WebDriverConnected`
+    pub(super) fn spawn_web_driver(&mut self) -> anyhow::Result<Task<Message>> {
+        tracing::info!("Starting WebDriver...");
+        self.stop_web_driver();
+        let settings = self.config.driver_settings();
+        let started = settings
+            .driver
+            .capabilities(&self.config.network_settings())
+            .and_then(|caps| Ok((scraper::spawn_driver(&settings)?, caps)));
+        let caps = match started {
+            Ok((process, caps)) => {
+                self.driver_process = Some(process);
+                caps
+            }
+            Err(e) => {
+                tracing::error!("Failed to start WebDriver: {:?}", e);
+                self.web_driver_status = DriverStatus::Failed(e.to_string());
+                return Err(e);
+            }
+        };
+        self.web_driver_status = DriverStatus::Starting;
+        Ok(Task::perform(
+            scraper::connect_web_driver(settings.port(), caps),
+            |res| Message::WebDriverConnected(res.map_err(|e| e.to_string())),
+        ))
+    }
+
+    /// Ends the session and kills the WebDriver server, if either is running
+    pub(super) fn stop_web_driver(&mut self) {
+        self.web_driver = None;
+        if let Some(mut process) = self.driver_process.take() {
+            _ = process.kill();
+            _ = process.wait();
+        }
+    }
+
+    /// Notices a WebDriver server that exited on its own, so the next fetch starts a new one
+    /// instead of failing against the dead session
+    pub(super) fn check_web_driver(&mut self) {
+        let Some(process) = self.driver_process.as_mut() else {
+            return;
+        };
+        if let Ok(Some(status)) = process.try_wait() {
+            tracing::warn!("WebDriver exited: {status}");
+            self.driver_process = None;
+            self.web_driver = None;
+            self.web_driver_status = DriverStatus::Failed(format!("exited ({status})"));
+        }
+    }
+
+    /// Shown under the job URL, since fetching is what starts the browser
+    fn web_driver_status_text(&self) -> String {
+        let driver = self.config.driver;
+        match &self.web_driver_status {
+            DriverStatus::Stopped => format!("{driver} starts on the first fetch"),
+            DriverStatus::Starting => format!("Starting {driver}..."),
+            DriverStatus::Ready => format!("{driver} ready"),
+            DriverStatus::Failed(error) => {
+                format!("{driver} unavailable ({error}), restarted on the next fetch")
+            }
+        }
+    }
+}
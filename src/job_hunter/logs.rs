@@ -0,0 +1,99 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum LogMessage {
+    OpenLogViewer,
+    LogLevelSelected(tracing::Level),
+    RefreshLogs,
+    ClearLogs,
+}
+
+impl JobHunter {
+    pub(super) fn update_logs(&mut self, message: LogMessage) -> Task<Message> {
+        match message {
+            LogMessage::OpenLogViewer => {
+                if let Some(id) = self.log_window {
+                    return window::gain_focus(id);
+                }
+                let (id, open) = window::open(window::Settings {
+                    size: iced::Size::new(900.0, 600.0),
+                    ..window::Settings::default()
+                });
+                self.log_window = Some(id);
+                open.discard()
+            }
+            LogMessage::LogLevelSelected(level) => {
+                self.log_level = level;
+                Task::none()
+            }
+            // The viewer reads the buffer on every draw, so this only needs to trigger one
+            LogMessage::RefreshLogs => Task::none(),
+            LogMessage::ClearLogs => {
+                logging::clear();
+                Task::none()
+            }
+        }
+    }
+
+    pub(super) fn log_viewer<'a>(&'a self) -> Element<'a, Message> {
+        let levels = [
+            tracing::Level::ERROR,
+            tracing::Level::WARN,
+            tracing::Level::INFO,
+            tracing::Level::DEBUG,
+            tracing::Level::TRACE,
+        ];
+        let entries = logging::recent(self.log_level);
+        let rows = Column::with_children(entries.into_iter().map(|entry| {
+            let level = text(entry.level.to_string())
+                .size(12)
+                .font(Font::MONOSPACE)
+                .width(Length::Fixed(50.0));
+            let level = match entry.level {
+                tracing::Level::ERROR => level.style(text::danger),
+                tracing::Level::WARN => level.style(text::primary),
+                _ => level,
+            };
+            row![
+                text(entry.time.format("%m/%d/%Y %H:%M:%S").to_string())
+                    .size(12)
+                    .font(Font::MONOSPACE)
+                    .width(Length::Fixed(150.0)),
+                level,
+                text(entry.target)
+                    .size(12)
+                    .font(Font::MONOSPACE)
+                    .width(Length::Fixed(200.0)),
+                text(entry.message)
+                    .size(12)
+                    .font(Font::MONOSPACE)
+                    .width(Fill),
+            ]
+            .spacing(10)
+            .into()
+        }))
+        .spacing(5);
+
+        container(
+            column![
+                row![
+                    text("Logs").size(24).width(Fill),
+                    text("Level").size(12),
+                    pick_list(levels, Some(self.log_level), |value| Message::Log(
+                        LogMessage::LogLevelSelected(value)
+                    )),
+                    button(text("Clear")).on_press(Message::Log(LogMessage::ClearLogs)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+                text("Older entries are written to the logs folder").size(10),
+                scrollable(rows).height(Fill).width(Fill),
+            ]
+            .spacing(10),
+        )
+        .padding(10)
+        .into()
+    }
+}
@@ -0,0 +1,2220 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use iced::event::Event;
+use iced::keyboard;
+use iced::keyboard::key;
+use iced::widget::{
+    button, center, checkbox, column, container, focus_next, focus_previous, image, mouse_area,
+    opaque, pick_list, row, scrollable, stack, text, text_editor, text_input, tooltip, Column,
+};
+use iced::{
+    color, window, Alignment, Element, Fill, Font, Length, Padding, Subscription, Task, Theme,
+};
+use iced_aw::{
+    date_picker, date_picker::Date, drop_down, helpers::badge, number_input, style, DropDown,
+    SelectionList, Spinner,
+};
+use iced_font_awesome::fa_icon_solid;
+
+// use self::data::{
+//     format_comma_separated, get_iced_date, get_pay_i64, get_pay_str, get_utc, migrate,
+//     opt_str_from_db, Company, JobApplication, JobApplicationStatus, JobPost, JobPostLocationType,
+// };
+
+use crate::api;
+use crate::boards;
+use crate::components::{
+    form,
+    money_input::{convert_money_value, format_pay, money_value, CURRENCIES},
+    ChipInput, IconButton, IconButtonMessage, MoneyInput,
+};
+use crate::db::{
+    alert_rule::AlertRule,
+    company::{Company, CompanyJobCount},
+    company_alt_name::CompanyAltName,
+    company_group::CompanyGroup,
+    contact::Contact,
+    digest::{
+        Digest, ReviewPost, UpcomingInterview, FOLLOW_UP_AFTER_DAYS, INTERVIEW_REMINDER_MINUTES,
+    },
+    fetch_log::FetchLog,
+    job_application::{
+        ApplicationChannel, ApplicationStats, BoardCard, JobApplication, JobApplicationEvent,
+        JobApplicationFilter, JobApplicationStatus,
+    },
+    job_post::{JobPost, JobPostFilter, JobPostLocationType, PayGap, PaySample, SortBy},
+    job_post_template::JobPostTemplate,
+    post_rule::{InsertCounts, PostRule, PostRuleAction},
+    tag::Tag,
+    NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+use crate::draft::JobPostDraft;
+use crate::export;
+use crate::favicon;
+use crate::hn;
+use crate::import;
+use crate::industry::{self, Taxonomy};
+use crate::interview;
+use crate::logging;
+use crate::notifications;
+use crate::paths;
+use crate::plugins;
+use crate::scraper;
+use crate::theme;
+use crate::utils::{compensation::PayUnit, *};
+use crate::validation::{field_error, input_style, FormErrors, FormField};
+use crate::AppConfig;
+
+mod alerts;
+mod analytics;
+mod applications;
+mod board;
+mod companies;
+mod contacts;
+mod digest;
+mod exports;
+mod filters;
+mod find_jobs;
+mod hn_import;
+mod imports;
+mod job_list;
+mod job_posts;
+mod logs;
+mod rules;
+mod scheduler;
+mod settings;
+mod sync;
+
+pub use alerts::AlertMessage;
+pub use analytics::AnalyticsMessage;
+pub use applications::ApplicationMessage;
+pub use board::BoardMessage;
+pub use companies::CompanyMessage;
+pub use contacts::ContactMessage;
+pub use digest::DigestMessage;
+pub use exports::ExportMessage;
+pub use filters::{FilterMessage, FILTER_STATE_FILE};
+pub use find_jobs::FindJobsMessage;
+pub use hn_import::HNImportMessage;
+pub use imports::{ImportMessage, QueuedUrl, QueuedUrlStatus};
+pub use job_posts::JobPostMessage;
+pub use logs::LogMessage;
+pub use rules::RuleMessage;
+pub use scheduler::SchedulerMessage;
+pub use settings::SettingsMessage;
+pub use sync::SyncMessage;
+
+use filters::FilterState;
+
+pub struct JobHunter {
+    // Runtime
+    tokio_handle: tokio::runtime::Handle,
+    // Set until the database is migrated; only the splash screen is shown meanwhile
+    starting_up: bool,
+    startup_error: Option<String>,
+    // Window
+    windows: BTreeMap<window::Id, Window>,
+    main_window: window::Id,
+    log_window: Option<window::Id>,
+    log_level: tracing::Level,
+    // Databse
+    db: sqlx::SqlitePool,
+    // Config
+    config: AppConfig,
+    // Resolved from the config's theme and accent color
+    app_theme: Theme,
+    // Webdriver, started on the first fetch
+    web_driver: Option<thirtyfour::WebDriver>,
+    driver_process: Option<std::process::Child>,
+    web_driver_status: DriverStatus,
+    // Fetch waiting on the WebDriver to connect: url, whether it's a retry
+    pending_fetch: Option<(String, bool)>,
+    // Interface
+    awaiting: bool,
+    scrape_url: Option<String>,
+    scrape_handle: Option<iced::task::Handle>,
+    network_available: bool,
+    status_message: Option<String>,
+    // Company
+    companies: Vec<Company>,
+    company_dropdowns: BTreeMap<i64, bool>,
+    company_scroll: f32,
+    company_job_counts: BTreeMap<i64, CompanyJobCount>,
+    // Favicons by careers URL host; `None` while fetching, or when the host has none
+    favicons: BTreeMap<String, Option<image::Handle>>,
+    // CompanyGroup
+    company_groups: Vec<CompanyGroup>,
+    company_group_dropdowns: BTreeMap<i64, bool>,
+    company_group_collapsed: BTreeMap<i64, bool>,
+    // JobPosts
+    job_posts: Vec<JobPost>,
+    // Cards showing their notes in full
+    expanded_notes: BTreeSet<i64>,
+    pay_samples: Vec<PaySample>,
+    job_dropdowns: BTreeMap<i64, bool>,
+    job_post_scroll: f32,
+    job_posts_total: usize,
+    // Post open in the detail modal, with its application and that application's history
+    job_post_detail_id: Option<i64>,
+    job_post_detail_application: Option<JobApplication>,
+    job_post_detail_events: Vec<JobApplicationEvent>,
+    job_post_detail_tags: Vec<Tag>,
+    // Board
+    board_cards: Vec<BoardCard>,
+    // Filter
+    filter: FilterState,
+    // The filter the job list was last loaded with; the filter bar may have moved on since
+    applied_filter: JobPostFilter,
+    // What "Delete Matching" deletes, and how many posts that was when it was opened
+    bulk_delete: Option<(JobPostFilter, i64)>,
+    pick_filter_date: Option<FilterDateField>,
+    /// Keywords posts are classified into industries by
+    industries: Taxonomy,
+    // Export
+    export_week: Date,
+    pick_export_week: bool,
+    // Import
+    import_path: String,
+    import_error: Option<String>,
+    // URL import: the pasted list, then each URL's progress through the queue
+    url_import_text: text_editor::Content,
+    url_import_error: Option<String>,
+    url_queue: Vec<QueuedUrl>,
+    url_queue_handle: Option<iced::task::Handle>,
+    resume_stats: Vec<ApplicationStats>,
+    channel_stats: Vec<ApplicationStats>,
+    // PostRule
+    post_rules: Vec<PostRule>,
+    post_rule_name: String,
+    post_rule_title: String,
+    post_rule_skills: String,
+    post_rule_location: String,
+    post_rule_company: String,
+    post_rule_min_pay: i64,
+    post_rule_pay_below: i64,
+    post_rule_action: PostRuleAction,
+    post_rule_tag: String,
+    post_rule_error: Option<String>,
+    // Contacts
+    contacts: Vec<Contact>,
+    // The contact being edited, `None` while adding one
+    contact_id: Option<i64>,
+    contact_name: String,
+    contact_email: String,
+    contact_role: String,
+    contact_linkedin_url: String,
+    contact_notes: String,
+    contact_company: Option<Company>,
+    contact_error: Option<String>,
+    // Contacts linked to the post open in the job post or application modal
+    linked_contacts: Vec<Contact>,
+    // Digest
+    digest: Digest,
+    /// Position in the review queue of new posts, `None` while showing the summary
+    review_index: Option<usize>,
+    // Distinct stored locations, suggested while typing a location
+    locations: Vec<String>,
+    known_skills: Vec<String>,
+    // Tags on at least one post, offered in the tag editor and filter
+    known_tags: Vec<String>,
+    // Modal
+    // Open modals, bottom to top; only the top one takes input
+    modals: Vec<Modal>,
+    company_name: String,
+    careers_url: String,
+    company_id: Option<i64>,
+    // The edited company's other names, saved as they're added or removed
+    company_alt_names: Vec<CompanyAltName>,
+    company_alt_name: String,
+    company_group_name: String,
+    job_post_id: Option<i64>,
+    job_app_id: Option<i64>,
+    job_app_status: Option<JobApplicationStatus>,
+    job_app_status_index: Option<usize>,
+    job_app_applied: Option<Date>,
+    job_app_interviewed: bool,
+    pick_job_app_applied: bool,
+    job_app_responded: Option<Date>,
+    pick_job_app_responded: bool,
+    // Typed date text, `None` while the field shows the picked date
+    job_app_applied_input: Option<String>,
+    job_app_responded_input: Option<String>,
+    job_app_resume_version_input: String,
+    job_app_channel: Option<ApplicationChannel>,
+    job_app_interview_date: String,
+    job_app_interview_time: String,
+    job_app_interview_timezone: String,
+    job_app_cover_letter_path: String,
+    job_app_cover_letter: text_editor::Content,
+    // Status history of the application being edited
+    job_app_events: Vec<JobApplicationEvent>,
+    job_title: String,
+    min_yoe: Option<i64>,
+    max_yoe: Option<i64>,
+    min_pay: String,
+    max_pay: String,
+    expected_pay: String,
+    job_post_tags: String,
+    tag_input: String,
+    job_post_notes: text_editor::Content,
+    /// The full text from the last fetch or the post being edited, kept as is
+    job_post_description: Option<String>,
+    /// Where the post was found, from the last fetch or the post being edited
+    job_post_platform_url: Option<String>,
+    currency: String,
+    pay_unit: PayUnit,
+    benefits: String,
+    location: String,
+    job_posted: Option<Date>,
+    pick_job_posted: bool,
+    job_posted_input: Option<String>,
+    location_type: Option<JobPostLocationType>,
+    location_type_index: Option<usize>,
+    url: String,
+    skills: String,
+    skill_input: String,
+    job_post_company_name: String,
+    job_post_company_results: Vec<Company>,
+    job_post_company: Option<Company>,
+    job_post_company_index: Option<usize>,
+    // Templates offered when adding a job post by hand
+    job_post_templates: Vec<JobPostTemplate>,
+    job_post_template: Option<JobPostTemplate>,
+    job_post_template_name: String,
+    // Draft offered for restore when the job post modal opens
+    job_post_draft: Option<JobPostDraft>,
+    // Fields as they were when the job post modal opened, so untouched modals aren't saved
+    job_post_draft_baseline: Option<JobPostDraft>,
+    last_saved_job_post_draft: Option<JobPostDraft>,
+    primary_modal_field: Option<iced::widget::text_input::Id>,
+    // Set after a failed save so errors only appear once the user has tried
+    show_form_errors: bool,
+    last_modal_field: Option<iced::widget::text_input::Id>,
+    apijobs_key: String,
+    apijobs_monthly_quota: i64,
+    request_interval_secs: u64,
+    apijobs_requests_this_month: i64,
+    adzuna_app_id: String,
+    adzuna_app_key: String,
+    // Provider ids unchecked in Settings
+    disabled_providers: BTreeSet<String>,
+    retention_days: i64,
+    target_salary: i64,
+    board_title_keywords: String,
+    board_sync_interval_hours: i64,
+    alert_interval_hours: i64,
+    proxy_url: String,
+    proxy_username: String,
+    proxy_password: String,
+    user_agent: String,
+    extra_headers: text_editor::Content,
+    driver: scraper::Driver,
+    driver_path: String,
+    driver_port: u16,
+    // Result of the last WebDriver connection test
+    driver_status: Option<String>,
+    driver_testing: bool,
+    notifications_enabled: bool,
+    theme_name: String,
+    accent_color: String,
+    profile_name: String,
+    // Typed in Settings to create a profile, used over `profile_name`
+    new_profile: String,
+    settings_error: Option<String>,
+    boards_syncing: bool,
+    remoteok_syncing: bool,
+    last_board_sync: Option<std::time::Instant>,
+    // Companies whose careers URL failed its last check, with why
+    broken_careers_urls: BTreeMap<i64, String>,
+    careers_urls_checking: bool,
+    last_careers_url_check: Option<std::time::Instant>,
+    // Interviews starting within the reminder lead time of this were already notified about
+    last_interview_reminder: Option<DateTime<Utc>>,
+    find_jobs_provider: Option<api::Provider>,
+    find_jobs_title: String,
+    find_jobs_location: String,
+    find_jobs_country: String,
+    find_jobs_min_yoe: i64,
+    find_jobs_min_salary: i64,
+    find_jobs_onsite: bool,
+    find_jobs_hybrid: bool,
+    find_jobs_remote: bool,
+    find_jobs_tracked_companies: bool,
+    find_jobs_searching: bool,
+    find_jobs_status: Option<String>,
+    find_jobs_results: Vec<api::JobSearchResult>,
+    find_jobs_facets: Vec<api::JobSearchFacet>,
+    find_jobs_selected: BTreeSet<usize>,
+    find_jobs_fetch_log_id: Option<i64>,
+    find_jobs_preview: String,
+    fetch_history: Vec<FetchLog>,
+    alert_rules: Vec<AlertRule>,
+    alert_rule_name: String,
+    alert_rule_interval_hours: i64,
+    alert_rules_running: bool,
+    hn_thread_title: String,
+    hn_results: Vec<api::JobSearchResult>,
+    hn_selected: BTreeSet<usize>,
+    hn_keyword: String,
+    hn_loading: bool,
+    hn_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    //Runtime
+    Shutdown,
+    /// Job posts archived by the retention policy, or why startup failed
+    StartupFinished(Result<u64, String>),
+    WebDriverConnected(Result<thirtyfour::WebDriver, String>),
+    // Domains
+    Settings(SettingsMessage),
+    Company(CompanyMessage),
+    Application(ApplicationMessage),
+    JobPost(JobPostMessage),
+    Filter(FilterMessage),
+    Export(ExportMessage),
+    Import(ImportMessage),
+    Analytics(AnalyticsMessage),
+    Rule(RuleMessage),
+    Digest(DigestMessage),
+    Board(BoardMessage),
+    Contact(ContactMessage),
+    FindJobs(FindJobsMessage),
+    HNImport(HNImportMessage),
+    Alert(AlertMessage),
+    Scheduler(SchedulerMessage),
+    Sync(SyncMessage),
+    Log(LogMessage),
+    // Window
+    WindowOpened(window::Id),
+    WindowClosed(window::Id),
+    // Event
+    Event(Event),
+    // Sidebar
+    ToggleSidebar,
+    // Interface
+    DismissStatus,
+    /// Results saved from Find Jobs or a Who's Hiring thread
+    JobPostsImported(InsertCounts),
+    // Modal
+    HideModal,
+    /// Asks before sending the message, e.g. ("Delete Acme?", DeleteCompany)
+    Confirm(String, Box<Message>),
+    Confirmed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterDateField {
+    AppliedFrom,
+    AppliedTo,
+    RespondedFrom,
+    RespondedTo,
+}
+
+pub struct Window {}
+
+impl Window {
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+pub fn ellipsis_button<'a>(color: iced::Color) -> iced::widget::Button<'a, Message> {
+    button(fa_icon_solid("ellipsis").color(color).size(15.0))
+}
+
+/// Where the scraping browser is at
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DriverStatus {
+    /// Not started yet, or stopped after a settings change; the next fetch starts it
+    #[default]
+    Stopped,
+    Starting,
+    Ready,
+    /// Failed to start, or died since; the next fetch restarts it
+    Failed(String),
+}
+
+#[allow(clippy::enum_variant_names)]
+pub enum Modal {
+    CreateCompanyModal,
+    EditCompanyModal,
+    CreateCompanyGroupModal,
+    CreateApplicationModal,
+    EditApplicationModal,
+    EditJobPostModal,
+    AddJobPostModal,
+    BulkDeleteJobPostsModal,
+    FindJobsModal,
+    FetchHistoryModal,
+    AlertRulesModal,
+    HNImportModal,
+    SettingsModal,
+    ExportModal,
+    ImportModal,
+    UrlImportModal,
+    ApplicationStatsModal,
+    PostRulesModal,
+    ContactsModal,
+    DigestModal,
+    JobPostDetailModal,
+    /// Yes/Cancel dialog guarding a destructive action
+    Confirm {
+        message: String,
+        on_confirm: Box<Message>,
+    },
+}
+
+fn confirm_modal(message: &str) -> Element<'_, Message> {
+    container(
+        column![
+            text(message),
+            row![
+                container(button(text("Cancel")).on_press(Message::HideModal))
+                    .width(Fill)
+                    .align_x(Alignment::End),
+                button(text("Yes"))
+                    .style(button::danger)
+                    .on_press(Message::Confirmed),
+            ]
+            .spacing(10),
+        ]
+        .spacing(20),
+    )
+    .width(400)
+    .padding(10)
+    .style(container::rounded_box)
+    .into()
+}
+
+/// Up to five stored locations containing what's been typed, as buttons that fill the field
+fn location_suggestions<'a>(
+    locations: &'a [String],
+    input: &str,
+    on_select: fn(String) -> Message,
+) -> Element<'a, Message> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return column![].into();
+    }
+    let suggestions = locations
+        .iter()
+        .filter(|location| {
+            let location = location.to_lowercase();
+            location.contains(&input) && location != input
+        })
+        .take(5)
+        .map(|location| {
+            button(text(location).size(12))
+                .padding(Padding::from([2, 5]))
+                .style(button::secondary)
+                .on_press(on_select(location.clone()))
+                .into()
+        });
+    Column::with_children(suggestions).spacing(2).into()
+}
+
+/// Text for a date field: what the user typed, else the picked date
+fn date_input_value(input: &Option<String>, date: Option<Date>) -> String {
+    match (input, date) {
+        (Some(input), _) => input.clone(),
+        (None, Some(date)) => NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        (None, None) => "".to_string(),
+    }
+}
+
+/// The date a typed value sets: `Some(None)` clears it, `None` means it doesn't parse yet
+fn typed_date(input: &str) -> Option<Option<Date>> {
+    if input.trim().is_empty() {
+        return Some(None);
+    }
+    parse_date_input(input).map(|date| Some(Date::from_ymd(date.year(), date.month(), date.day())))
+}
+
+/// Title, company, pay, and URL on their own lines, for pasting elsewhere
+fn job_summary(job_post: &JobPost, company_name: &str) -> String {
+    format!(
+        "{} at {}\n{}\n{}",
+        job_post.job_title,
+        company_name,
+        pay_range_text(job_post),
+        job_post.url
+    )
+}
+
+/// E.g. "$80,000 - $100,000", in the post's currency. Pay quoted per hour, day, and so on
+/// is followed by its yearly equivalent, e.g. "$45/hr (~$93,600/yr)"
+fn pay_range_text(job_post: &JobPost) -> String {
+    let currency = job_post.currency.as_deref();
+    let range = |min_pay: Option<i64>, max_pay: Option<i64>| match (min_pay, max_pay) {
+        (Some(min_pay), Some(max_pay)) => Some(format!(
+            "{} - {}",
+            format_pay(min_pay, currency),
+            format_pay(max_pay, currency)
+        )),
+        (Some(min_pay), None) => Some(format!("{}+", format_pay(min_pay, currency))),
+        (None, Some(max_pay)) => Some(format_pay(max_pay, currency)),
+        (None, None) => None,
+    };
+    let Some(raw) = range(job_post.min_pay_cents, job_post.max_pay_cents) else {
+        return "No salary specified".to_string();
+    };
+    let unit = PayUnit::parse(job_post.pay_unit.as_deref());
+    if unit == PayUnit::Year {
+        return raw;
+    }
+    let annual = range(
+        job_post.annual_pay_cents(job_post.min_pay_cents),
+        job_post.annual_pay_cents(job_post.max_pay_cents),
+    )
+    .unwrap_or_default();
+    format!("{}{} (~{}/yr)", raw, unit.suffix(), annual)
+}
+
+/// A fetched job post with an "import" checkbox, shared by the search and import modals
+fn search_result_row<'a>(
+    index: usize,
+    result: &'a api::JobSearchResult,
+    selected: bool,
+    on_toggle: fn(usize, bool) -> Message,
+) -> Element<'a, Message> {
+    let job_post = &result.job_post;
+    let mut import_checkbox = checkbox("", selected);
+    if !result.exists {
+        import_checkbox = import_checkbox.on_toggle(move |checked| on_toggle(index, checked));
+    }
+    let pay_text = pay_range_text(job_post);
+    row![
+        import_checkbox,
+        column![
+            text(&job_post.job_title).size(14),
+            text(format!("{} | {}", result.company_name, job_post.location)).size(12),
+        ]
+        .width(Fill),
+        text(if result.exists {
+            "Already saved".to_string()
+        } else {
+            pay_text
+        })
+        .size(12),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+// https://github.com/iced-rs/iced/blob/latest/examples/modal/src/main.rs
+fn modal<'a, Message>(
+    base: impl Into<Element<'a, Message>>,
+    content: impl Into<Element<'a, Message>>,
+    on_blur: Message,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    stack![
+        base.into(),
+        opaque(
+            mouse_area(center(opaque(content)).style(|_theme| {
+                container::Style {
+                    background: Some(
+                        iced::Color {
+                            a: 0.8,
+                            ..iced::Color::BLACK
+                        }
+                        .into(),
+                    ),
+                    ..container::Style::default()
+                }
+            }))
+            .on_press(on_blur)
+        )
+    ]
+    .into()
+}
+
+/// Migrates the database, then applies the retention policy
+async fn startup(
+    pool: sqlx::SqlitePool,
+    db_existed: bool,
+    retention_days: i64,
+) -> anyhow::Result<u64> {
+    crate::db::prepare(&pool, db_existed).await?;
+    if retention_days > 0 {
+        JobPost::archive_stale(retention_days, &pool).await
+    } else {
+        Ok(0)
+    }
+}
+
+impl JobHunter {
+    /// Opens the window right away; the database and WebDriver are readied in the background
+    pub fn new(
+        conn: sqlx::SqlitePool,
+        db_existed: bool,
+        handle: tokio::runtime::Handle,
+        config: AppConfig,
+    ) -> (Self, Task<Message>) {
+        // Open main window
+        let (id, open) = window::open(window::Settings::default());
+        let filter = FilterState::load(&config.profile, config.job_page_size());
+        let startup = Task::perform(
+            startup(conn.clone(), db_existed, config.retention_days),
+            |res| Message::StartupFinished(res.map_err(|e| e.to_string())),
+        );
+        (
+            Self {
+                tokio_handle: handle,
+                starting_up: true,
+                startup_error: None,
+                companies: Vec::new(),
+                db: conn,
+                app_theme: theme::resolve(&config.theme, &config.accent_color),
+                config,
+                windows: BTreeMap::new(),
+                main_window: id,
+                log_window: None,
+                log_level: tracing::Level::INFO,
+                modals: Vec::new(),
+                company_name: "".to_string(),
+                careers_url: "".to_string(),
+                company_dropdowns: BTreeMap::new(),
+                company_id: None,
+                company_alt_names: Vec::new(),
+                company_alt_name: "".to_string(),
+                company_job_counts: BTreeMap::new(),
+                favicons: BTreeMap::new(),
+                company_groups: Vec::new(),
+                company_group_dropdowns: BTreeMap::new(),
+                company_group_collapsed: BTreeMap::new(),
+                company_group_name: "".to_string(),
+                job_posts: Vec::new(),
+                expanded_notes: BTreeSet::new(),
+                pay_samples: Vec::new(),
+                filter,
+                applied_filter: JobPostFilter::default(),
+                bulk_delete: None,
+                industries: Taxonomy::load().unwrap_or_else(|e| {
+                    tracing::warn!("Failed to load industries, using defaults: {:?}", e);
+                    Taxonomy::default()
+                }),
+                locations: Vec::new(),
+                known_skills: Vec::new(),
+                known_tags: Vec::new(),
+                pick_filter_date: None,
+                export_week: Date::today(),
+                pick_export_week: false,
+                import_path: "".to_string(),
+                import_error: None,
+                url_import_text: text_editor::Content::new(),
+                url_import_error: None,
+                url_queue: Vec::new(),
+                url_queue_handle: None,
+                resume_stats: Vec::new(),
+                channel_stats: Vec::new(),
+                post_rules: Vec::new(),
+                post_rule_name: "".to_string(),
+                post_rule_title: "".to_string(),
+                post_rule_skills: "".to_string(),
+                post_rule_location: "".to_string(),
+                post_rule_company: "".to_string(),
+                post_rule_min_pay: 0,
+                post_rule_pay_below: 0,
+                post_rule_action: PostRuleAction::Tag,
+                post_rule_tag: "".to_string(),
+                post_rule_error: None,
+                contacts: Vec::new(),
+                contact_id: None,
+                contact_name: "".to_string(),
+                contact_email: "".to_string(),
+                contact_role: "".to_string(),
+                contact_linkedin_url: "".to_string(),
+                contact_notes: "".to_string(),
+                contact_company: None,
+                contact_error: None,
+                linked_contacts: Vec::new(),
+                digest: Digest::default(),
+                review_index: None,
+                job_dropdowns: BTreeMap::new(),
+                job_post_id: None,
+                job_app_id: None,
+                job_app_status: None,
+                job_app_status_index: None,
+                job_app_applied: None,
+                job_app_interviewed: false,
+                pick_job_app_applied: false,
+                job_app_responded: None,
+                pick_job_app_responded: false,
+                job_app_applied_input: None,
+                job_app_responded_input: None,
+                job_app_resume_version_input: "".to_string(),
+                job_app_channel: None,
+                job_app_interview_date: "".to_string(),
+                job_app_interview_time: "".to_string(),
+                job_app_interview_timezone: "".to_string(),
+                job_app_cover_letter_path: "".to_string(),
+                job_app_cover_letter: text_editor::Content::new(),
+                job_app_events: Vec::new(),
+                job_title: "".to_string(),
+                min_pay: "".to_string(),
+                max_pay: "".to_string(),
+                expected_pay: "".to_string(),
+                job_post_tags: "".to_string(),
+                tag_input: "".to_string(),
+                job_post_notes: text_editor::Content::new(),
+                job_post_description: None,
+                job_post_platform_url: None,
+                currency: "USD".to_string(),
+                pay_unit: PayUnit::Year,
+                min_yoe: None,
+                max_yoe: None,
+                benefits: "".to_string(),
+                location: "".to_string(),
+                job_posted: None,
+                pick_job_posted: false,
+                job_posted_input: None,
+                location_type: None,
+                location_type_index: None,
+                skills: "".to_string(),
+                skill_input: "".to_string(),
+                url: "".to_string(),
+                job_post_company_name: "".to_string(),
+                job_post_company_results: Vec::new(),
+                job_post_company: None,
+                job_post_company_index: None,
+                job_post_templates: Vec::new(),
+                job_post_template: None,
+                job_post_template_name: "".to_string(),
+                job_post_draft: None,
+                show_form_errors: false,
+                job_post_draft_baseline: None,
+                last_saved_job_post_draft: None,
+                company_scroll: 0.0,
+                job_post_scroll: 0.0,
+                board_cards: Vec::new(),
+                primary_modal_field: None,
+                last_modal_field: None,
+                apijobs_key: "".to_string(),
+                apijobs_monthly_quota: 0,
+                request_interval_secs: 0,
+                apijobs_requests_this_month: 0,
+                adzuna_app_id: "".to_string(),
+                adzuna_app_key: "".to_string(),
+                disabled_providers: BTreeSet::new(),
+                retention_days: 0,
+                target_salary: 0,
+                board_title_keywords: "".to_string(),
+                board_sync_interval_hours: 0,
+                alert_interval_hours: 0,
+                proxy_url: "".to_string(),
+                proxy_username: "".to_string(),
+                proxy_password: "".to_string(),
+                user_agent: "".to_string(),
+                extra_headers: text_editor::Content::new(),
+                driver: scraper::Driver::default(),
+                driver_path: "".to_string(),
+                driver_port: 0,
+                driver_status: None,
+                driver_testing: false,
+                notifications_enabled: true,
+                theme_name: theme::SYSTEM.to_string(),
+                accent_color: "".to_string(),
+                profile_name: paths::DEFAULT_PROFILE.to_string(),
+                new_profile: "".to_string(),
+                settings_error: None,
+                boards_syncing: false,
+                remoteok_syncing: false,
+                last_board_sync: None,
+                broken_careers_urls: BTreeMap::new(),
+                careers_urls_checking: false,
+                last_careers_url_check: None,
+                last_interview_reminder: None,
+                find_jobs_provider: api::PROVIDERS.first().copied(),
+                find_jobs_title: "".to_string(),
+                find_jobs_location: "".to_string(),
+                find_jobs_country: "".to_string(),
+                find_jobs_min_yoe: 0,
+                find_jobs_min_salary: 0,
+                find_jobs_onsite: false,
+                find_jobs_hybrid: false,
+                find_jobs_remote: false,
+                find_jobs_tracked_companies: true,
+                find_jobs_searching: false,
+                find_jobs_status: None,
+                find_jobs_results: Vec::new(),
+                find_jobs_facets: Vec::new(),
+                find_jobs_selected: BTreeSet::new(),
+                find_jobs_fetch_log_id: None,
+                find_jobs_preview: "".to_string(),
+                fetch_history: Vec::new(),
+                alert_rules: Vec::new(),
+                alert_rule_name: "".to_string(),
+                alert_rule_interval_hours: 24,
+                alert_rules_running: false,
+                hn_thread_title: "".to_string(),
+                hn_results: Vec::new(),
+                hn_selected: BTreeSet::new(),
+                hn_keyword: "".to_string(),
+                hn_loading: false,
+                hn_status: None,
+                job_posts_total: 0,
+                job_post_detail_id: None,
+                job_post_detail_application: None,
+                job_post_detail_events: Vec::new(),
+                job_post_detail_tags: Vec::new(),
+                web_driver: None,
+                awaiting: false,
+                scrape_url: None,
+                scrape_handle: None,
+                network_available: true,
+                status_message: None,
+                driver_process: None,
+                web_driver_status: DriverStatus::Stopped,
+                pending_fetch: None,
+            },
+            Task::batch([open.map(Message::WindowOpened), startup]),
+        )
+    }
+
+    pub fn title(&self, id: window::Id) -> String {
+        if self.log_window == Some(id) {
+            return String::from("Job Hunter - Logs");
+        }
+        if paths::is_default_profile(&self.config.profile) {
+            return String::from("Job Hunter");
+        }
+        format!("Job Hunter - {}", self.config.profile)
+    }
+
+    pub fn theme(&self, _id: window::Id) -> Theme {
+        self.app_theme.clone()
+    }
+
+    /// For icons on primary buttons
+    fn icon_color(&self) -> iced::Color {
+        theme::on_primary(&self.app_theme)
+    }
+
+    pub fn subscription(&self) -> Subscription<Message> {
+        let mut subscriptions = vec![
+            window::close_events().map(Message::WindowClosed),
+            iced::event::listen().map(Message::Event),
+            iced::time::every(std::time::Duration::from_secs(60))
+                .map(|_| Message::Scheduler(SchedulerMessage::SchedulerTick)),
+        ];
+        if self.is_job_post_modal() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(5))
+                    .map(|_| Message::JobPost(JobPostMessage::AutosaveJobPostDraft)),
+            );
+        }
+        // Keep the log viewer current while it's open
+        if self.log_window.is_some() {
+            subscriptions.push(
+                iced::time::every(std::time::Duration::from_secs(1))
+                    .map(|_| Message::Log(LogMessage::RefreshLogs)),
+            );
+        }
+        Subscription::batch(subscriptions)
+    }
+
+    /// Validation errors for the open modal's fields
+    fn form_errors(&self) -> FormErrors {
+        let mut errors = FormErrors::default();
+        let Some(modal) = self.modal() else {
+            return errors;
+        };
+        match modal {
+            Modal::CreateCompanyModal | Modal::EditCompanyModal => {
+                errors.require(FormField::CompanyName, &self.company_name);
+                errors.url(FormField::CareersURL, &self.careers_url);
+            }
+            Modal::CreateCompanyGroupModal => {
+                errors.require(FormField::CompanyGroupName, &self.company_group_name);
+            }
+            Modal::CreateApplicationModal | Modal::EditApplicationModal => {
+                errors.require_some(FormField::ApplicationStatus, &self.job_app_status);
+                errors.date(
+                    FormField::DateApplied,
+                    self.job_app_applied_input.as_deref(),
+                );
+                errors.date(
+                    FormField::DateResponded,
+                    self.job_app_responded_input.as_deref(),
+                );
+                if let Err(e) = self.job_app_interview() {
+                    errors.add(FormField::InterviewTime, e);
+                }
+            }
+            Modal::AddJobPostModal | Modal::EditJobPostModal => {
+                if self.job_post_id.is_none() {
+                    errors.require_some(FormField::JobPostCompany, &self.job_post_company);
+                }
+                errors.require(FormField::JobTitle, &self.job_title);
+                errors.url(FormField::JobURL, &self.url);
+                errors.require(FormField::Location, &self.location);
+                errors.require_some(FormField::LocationType, &self.location_type);
+                errors.date(FormField::DatePosted, self.job_posted_input.as_deref());
+                errors.yoe_range(self.min_yoe, self.max_yoe);
+                errors.pay_range(&self.min_pay, &self.max_pay);
+                errors.expected_pay(&self.expected_pay);
+            }
+            _ => {}
+        }
+        errors
+    }
+
+    /// Errors to render, hidden until the user tries to save
+    fn visible_form_errors(&self) -> FormErrors {
+        if self.show_form_errors {
+            self.form_errors()
+        } else {
+            FormErrors::default()
+        }
+    }
+
+    /// Shows the open modal's errors, if any, and returns whether it can be saved
+    fn validate_form(&mut self) -> bool {
+        self.show_form_errors = true;
+        self.form_errors().is_empty()
+    }
+
+    /// The top modal, if any are open
+    fn modal(&self) -> Option<&Modal> {
+        self.modals.last()
+    }
+
+    /// Opens a modal on top of any that are already open
+    fn push_modal(&mut self, modal: Modal) {
+        self.modals.push(modal);
+    }
+
+    /// Closes the top modal without saving, keeping a draft of an unsaved job post.
+    /// Fields are only reset once the last modal closes, so the one below is left as it was.
+    fn close_modal(&mut self) {
+        self.save_job_post_draft();
+        self.modals.pop();
+        if self.modals.is_empty() {
+            self.hide_modal();
+        }
+    }
+
+    /// Closes an open date picker, returning whether there was one
+    fn close_date_picker(&mut self) -> bool {
+        let open = self.pick_job_posted
+            || self.pick_job_app_applied
+            || self.pick_job_app_responded
+            || self.pick_filter_date.is_some()
+            || self.pick_export_week;
+        self.pick_job_posted = false;
+        self.pick_job_app_applied = false;
+        self.pick_job_app_responded = false;
+        self.pick_filter_date = None;
+        self.pick_export_week = false;
+        open
+    }
+
+    /// Closes every open modal and resets their fields
+    fn hide_modal(&mut self) {
+        self.modals.clear();
+        self.reset_scrape();
+        self.bulk_delete = None;
+        self.company_name = "".to_string(); // hmm...
+        self.careers_url = "".to_string();
+        self.company_id = None;
+        self.company_alt_names = Vec::new();
+        self.company_alt_name = "".to_string();
+        self.company_group_name = "".to_string();
+        self.job_post_id = None;
+        self.job_app_id = None;
+        self.job_app_status = None;
+        self.job_app_status_index = None;
+        self.job_app_applied = None;
+        self.pick_job_app_applied = false;
+        self.job_app_responded = None;
+        self.pick_job_app_responded = false;
+        self.job_app_applied_input = None;
+        self.job_app_responded_input = None;
+        self.job_app_resume_version_input = "".to_string();
+        self.job_app_channel = None;
+        self.job_app_interview_date = "".to_string();
+        self.job_app_interview_time = "".to_string();
+        self.job_app_interview_timezone = "".to_string();
+        self.job_app_cover_letter_path = "".to_string();
+        self.job_app_cover_letter = text_editor::Content::new();
+        self.job_app_events = Vec::new();
+        self.job_post_detail_id = None;
+        self.job_post_detail_application = None;
+        self.job_post_detail_events = Vec::new();
+        self.job_post_detail_tags = Vec::new();
+        self.job_title = "".to_string();
+        self.min_yoe = None;
+        self.max_yoe = None;
+        self.min_pay = "".to_string();
+        self.max_pay = "".to_string();
+        self.expected_pay = "".to_string();
+        self.job_post_tags = "".to_string();
+        self.tag_input = "".to_string();
+        self.job_post_notes = text_editor::Content::new();
+        self.job_post_description = None;
+        self.job_post_platform_url = None;
+        self.currency = "USD".to_string();
+        self.pay_unit = PayUnit::Year;
+        self.benefits = "".to_string();
+        self.location = "".to_string();
+        self.job_posted = None;
+        self.pick_job_posted = false;
+        self.job_posted_input = None;
+        self.location_type = None;
+        self.location_type_index = None;
+        self.skills = "".to_string();
+        self.skill_input = "".to_string();
+        self.url = "".to_string();
+        self.job_post_company_name = "".to_string();
+        self.job_post_company_results = Vec::new();
+        self.job_post_company = None;
+        self.job_post_company_index = None;
+        self.job_post_templates = Vec::new();
+        self.job_post_template = None;
+        self.job_post_template_name = "".to_string();
+        self.job_post_draft = None;
+        self.show_form_errors = false;
+        self.job_post_draft_baseline = None;
+        self.last_saved_job_post_draft = None;
+        self.primary_modal_field = None;
+        self.last_modal_field = None;
+        self.apijobs_key = "".to_string();
+        self.apijobs_monthly_quota = 0;
+        self.request_interval_secs = 0;
+        self.adzuna_app_id = "".to_string();
+        self.adzuna_app_key = "".to_string();
+        self.disabled_providers.clear();
+        self.retention_days = 0;
+        self.target_salary = 0;
+        self.board_title_keywords = "".to_string();
+        self.board_sync_interval_hours = 0;
+        self.alert_interval_hours = 0;
+        self.proxy_url = "".to_string();
+        self.proxy_username = "".to_string();
+        self.proxy_password = "".to_string();
+        self.user_agent = "".to_string();
+        self.extra_headers = text_editor::Content::new();
+        self.driver = scraper::Driver::default();
+        self.driver_path = "".to_string();
+        self.driver_port = 0;
+        self.driver_status = None;
+        self.driver_testing = false;
+        self.notifications_enabled = true;
+        self.theme_name = theme::SYSTEM.to_string();
+        self.accent_color = "".to_string();
+        self.profile_name = paths::DEFAULT_PROFILE.to_string();
+        self.new_profile = "".to_string();
+        self.settings_error = None;
+        self.pick_export_week = false;
+        self.import_path = "".to_string();
+        self.import_error = None;
+        // The queue keeps going in the background, so only the form is cleared
+        self.url_import_text = text_editor::Content::new();
+        self.url_import_error = None;
+        self.post_rules = Vec::new();
+        self.post_rule_name = "".to_string();
+        self.post_rule_title = "".to_string();
+        self.post_rule_skills = "".to_string();
+        self.post_rule_location = "".to_string();
+        self.post_rule_company = "".to_string();
+        self.post_rule_min_pay = 0;
+        self.post_rule_pay_below = 0;
+        self.post_rule_action = PostRuleAction::Tag;
+        self.post_rule_tag = "".to_string();
+        self.post_rule_error = None;
+        self.contacts = Vec::new();
+        self.contact_id = None;
+        self.contact_name = "".to_string();
+        self.contact_email = "".to_string();
+        self.contact_role = "".to_string();
+        self.contact_linkedin_url = "".to_string();
+        self.contact_notes = "".to_string();
+        self.contact_company = None;
+        self.contact_error = None;
+        self.linked_contacts = Vec::new();
+        self.digest = Digest::default();
+        self.review_index = None;
+        // Search fields are kept so the next search starts where this one left off
+        self.find_jobs_status = None;
+        self.find_jobs_results = Vec::new();
+        self.find_jobs_facets = Vec::new();
+        self.find_jobs_selected = BTreeSet::new();
+        self.find_jobs_fetch_log_id = None;
+        self.find_jobs_preview = "".to_string();
+        self.fetch_history = Vec::new();
+        self.alert_rules = Vec::new();
+        self.alert_rule_name = "".to_string();
+        self.alert_rule_interval_hours = 24;
+        self.hn_thread_title = "".to_string();
+        self.hn_results = Vec::new();
+        self.hn_selected = BTreeSet::new();
+        self.hn_keyword = "".to_string();
+        self.hn_status = None;
+    }
+
+    // fn filter_results(&mut self) {
+    //     self.job_posts = JobPost::filter(
+    //         &self.db,
+    //         opt_str_from_db(Some(self.filter_job_title.clone())),
+    //         opt_str_from_db(Some(self.filter_location.clone())),
+    //         if self.filter_min_yoe == 0 && self.filter_max_yoe == 0 {
+    //             None
+    //         } else {
+    //             Some(self.filter_min_yoe)
+    //         },
+    //         if self.filter_max_yoe > 0 {
+    //             Some(self.filter_max_yoe)
+    //         } else {
+    //             None
+    //         },
+    //         self.filter_onsite,
+    //         self.filter_hybrid,
+    //         self.filter_remote,
+    //     )
+    //     .expect("Failed to filter job posts");
+    // }
+
+    /// Offline when toggled by the user or when the last connectivity check failed
+    fn is_offline(&self) -> bool {
+        self.config.offline || !self.network_available
+    }
+
+    /// Client for network tasks; network settings are validated when saved
+    fn http_client(&self) -> reqwest::Client {
+        api::http_client(&self.config.network_settings()).unwrap_or_else(|e| {
+            tracing::warn!(
+                "Failed to build HTTP client, ignoring network settings: {:?}",
+                e
+            );
+            reqwest::Client::new()
+        })
+    }
+
+    fn save_config(&self) {
+        self.config.save().expect("Failed to write config");
+    }
+
+    /// Shows a desktop notification unless they're muted in settings
+    fn notify(&self, summary: &str, body: &str) {
+        if !self.config.mute_notifications {
+            notifications::show(summary, body);
+        }
+    }
+
+    /// Shown while the database is readied, or instead of the app if that failed
+    fn splash(&self) -> Element<'_, Message> {
+        let status: Element<Message> = match &self.startup_error {
+            Some(error) => column![
+                text("Couldn't open the database").style(text::danger),
+                text(error).size(12),
+                text("See the logs folder for details").size(10),
+            ]
+            .spacing(5)
+            .align_x(Alignment::Center)
+            .into(),
+            None => column![Spinner::new(), text("Loading...").size(12)]
+                .spacing(10)
+                .align_x(Alignment::Center)
+                .into(),
+        };
+        center(
+            column![text("Job Hunter").size(24), status]
+                .spacing(20)
+                .align_x(Alignment::Center),
+        )
+        .into()
+    }
+
+    /// Loads what the main window lists: companies, groups, and the first page of job posts
+    fn load_main_window(&mut self) {
+        self.set_field_suggestions();
+        let companies = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let companies_res = Company::fetch_shown(&pool).await;
+                _ = sender.send(companies_res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive companies_res")
+                .expect("Failed to get companies")
+        };
+        let company_groups = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let groups_res = CompanyGroup::fetch_all(&pool).await;
+                _ = sender.send(groups_res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive groups_res")
+                .expect("Failed to get company groups")
+        };
+        self.companies = companies;
+        self.company_groups = company_groups;
+        // The last session's filters and page, back to the first page if it no longer exists
+        self.set_job_count();
+        let last_page = total_pages(self.job_posts_total as i64, self.filter.page_size).max(1);
+        if self.filter.page > last_page {
+            self.filter.page = 1;
+        }
+        let jobs = {
+            let pool = self.db.clone();
+            let page = self.filter.page;
+            let page_size = self.filter.page_size;
+            let filter = self.filter.to_query_params(self.target_pay_cents());
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let jobs_res = JobPost::filter(page, page_size, filter, &pool).await;
+                _ = sender.send(jobs_res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive jobs_res")
+                .expect("Failed to get jobs")
+        };
+        self.job_posts = jobs;
+        self.set_company_job_counts();
+        self.set_pay_samples();
+        if self.config.board_view {
+            self.set_board_cards();
+        }
+    }
+
+    fn set_primary_modal_field(&mut self) {
+        self.primary_modal_field = Some(iced::widget::text_input::Id::unique());
+    }
+
+    fn set_last_modal_field(&mut self) {
+        self.last_modal_field = Some(iced::widget::text_input::Id::unique());
+    }
+
+    /********************
+     * fn UPDATE
+     ********************/
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        // Nothing else can touch the database until it's migrated
+        if self.starting_up
+            && !matches!(
+                message,
+                Message::Shutdown
+                    | Message::StartupFinished(_)
+                    | Message::WebDriverConnected(_)
+                    | Message::WindowOpened(_)
+                    | Message::WindowClosed(_)
+            )
+        {
+            return Task::none();
+        }
+        match message {
+            /* Runtime */
+            Message::Shutdown => {
+                tracing::info!("Shutdown");
+                if let Some(process) = self.driver_process.as_mut() {
+                    process.kill().expect("Failed to kill WebDriver process");
+                }
+                tracing::info!("Exiting...");
+                iced::exit()
+            }
+            Message::StartupFinished(Ok(archived)) => {
+                self.starting_up = false;
+                if archived > 0 {
+                    self.status_message = Some(format!(
+                        "Archived {} job posts older than {} days with no application",
+                        archived, self.config.retention_days
+                    ));
+                }
+                self.load_main_window();
+                self.show_digest();
+                self.load_favicons()
+            }
+            Message::StartupFinished(Err(error)) => {
+                tracing::error!("Startup failed: {error}");
+                self.startup_error = Some(error);
+                Task::none()
+            }
+            Message::WebDriverConnected(Ok(driver)) => {
+                self.web_driver = Some(driver);
+                self.web_driver_status = DriverStatus::Ready;
+                match self.pending_fetch.take() {
+                    Some((url, retried)) => self.fetch_job_details_task(url, retried),
+                    None if self.url_queue_active() => self.scrape_next_url(),
+                    None => Task::none(),
+                }
+            }
+            Message::WebDriverConnected(Err(error)) => {
+                tracing::warn!("Failed to connect to WebDriver: {error}");
+                self.stop_web_driver();
+                self.web_driver_status = DriverStatus::Failed(error.clone());
+                if self.pending_fetch.is_some() {
+                    self.reset_scrape();
+                    self.status_message = Some(format!("WebDriver unavailable: {error}"));
+                }
+                if self.url_queue_active() {
+                    return self.stop_url_queue(QueuedUrlStatus::Failed(format!(
+                        "WebDriver unavailable: {error}"
+                    )));
+                }
+                Task::none()
+            }
+            /* Window */
+            Message::WindowOpened(id) => {
+                // println!("WindowOpened");
+                let window = Window::new();
+                let focus_input = text_input::focus(format!("input-{id}")); // ?
+                self.windows.insert(id, window);
+                if !self.starting_up {
+                    self.load_main_window();
+                }
+                focus_input
+            }
+            Message::WindowClosed(id) => {
+                if self.log_window == Some(id) {
+                    self.log_window = None;
+                    return Task::none();
+                }
+                self.windows.remove(&id);
+
+                let db = self.db.clone();
+
+                if self.windows.is_empty() || self.main_window == id {
+                    Task::perform(crate::db::shutdown(db), |_| Message::Shutdown)
+                } else {
+                    Task::none()
+                }
+            }
+            /* Sidebar */
+            Message::DismissStatus => {
+                self.status_message = None;
+                Task::none()
+            }
+            Message::ToggleSidebar => {
+                self.config.sidebar_collapsed = !self.config.sidebar_collapsed;
+                self.save_config();
+                Task::none()
+            }
+            /* Imports */
+            Message::JobPostsImported(imported) => {
+                self.hide_modal();
+                self.set_field_suggestions();
+                self.status_message = Some(format!("Imported {}", imported));
+                self.get_filter_task()
+            }
+            /* Hide Modal */
+            Message::HideModal => {
+                self.close_modal();
+                Task::none()
+            }
+            Message::Confirm(message, on_confirm) => {
+                // Open menus would be left floating over the dialog
+                self.company_dropdowns.clear();
+                self.job_dropdowns.clear();
+                self.push_modal(Modal::Confirm {
+                    message,
+                    on_confirm,
+                });
+                Task::none()
+            }
+            Message::Confirmed => {
+                let Some(Modal::Confirm { on_confirm, .. }) = self.modals.pop() else {
+                    return Task::none();
+                };
+                if self.modals.is_empty() {
+                    self.hide_modal();
+                }
+                self.update(*on_confirm)
+            }
+            /* Domains */
+            Message::Settings(message) => self.update_settings(message),
+            Message::Company(message) => self.update_companies(message),
+            Message::Application(message) => self.update_applications(message),
+            Message::JobPost(message) => self.update_job_posts(message),
+            Message::Filter(message) => self.update_filters(message),
+            Message::Export(message) => self.update_exports(message),
+            Message::Import(message) => self.update_imports(message),
+            Message::Analytics(message) => self.update_analytics(message),
+            Message::Rule(message) => self.update_rules(message),
+            Message::Digest(message) => self.update_digest(message),
+            Message::Board(message) => self.update_board(message),
+            Message::Contact(message) => self.update_contacts(message),
+            Message::FindJobs(message) => self.update_find_jobs(message),
+            Message::HNImport(message) => self.update_hn_import(message),
+            Message::Alert(message) => self.update_alerts(message),
+            Message::Scheduler(message) => self.update_scheduler(message),
+            Message::Sync(message) => self.update_sync(message),
+            Message::Log(message) => self.update_logs(message),
+            /* Event */
+            Message::Event(event) => match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key::Named::Tab),
+                    modifiers,
+                    ..
+                }) => {
+                    if modifiers.shift() {
+                        focus_previous()
+                    } else {
+                        focus_next()
+                    }
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(named @ (key::Named::ArrowDown | key::Named::ArrowUp)),
+                    ..
+                }) if matches!(self.modal(), Some(Modal::AddJobPostModal)) => {
+                    let rows = self.company_typeahead_rows();
+                    if rows > 0 {
+                        let index = self.job_post_company_index.unwrap_or(0);
+                        self.job_post_company_index = Some(match named {
+                            key::Named::ArrowDown => (index + 1).min(rows - 1),
+                            _ => index.saturating_sub(1),
+                        });
+                    }
+                    Task::none()
+                }
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Character(character),
+                    ..
+                }) => match self.review_shortcut(character.as_str()) {
+                    Some(message) => self.update_digest(message),
+                    None => Task::none(),
+                },
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Named(key::Named::Escape),
+                    ..
+                }) => {
+                    // Escape closes the innermost layer: an open date picker, then the top modal
+                    if !self.close_date_picker() {
+                        self.close_modal();
+                    }
+                    Task::none()
+                }
+                _ => Task::none(),
+            },
+        }
+    }
+
+    fn sidebar(&self) -> Element<'_, Message> {
+        if self.config.sidebar_collapsed {
+            return container(
+                column![
+                    IconButton::new("angles-right")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed => Message::ToggleSidebar,
+                        }),
+                    IconButton::new("plus")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed =>
+                                Message::Company(CompanyMessage::ShowCreateCompanyModal),
+                        }),
+                    container(
+                        column![
+                            IconButton::new("file-lines")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed =>
+                                        Message::Log(LogMessage::OpenLogViewer),
+                                }),
+                            IconButton::new("file-import")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed =>
+                                        Message::Import(ImportMessage::ShowImportModal),
+                                }),
+                            IconButton::new("file-export")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed =>
+                                        Message::Export(ExportMessage::ShowExportModal),
+                                }),
+                            IconButton::new("tags")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed =>
+                                        Message::Rule(RuleMessage::ShowPostRulesModal),
+                                }),
+                            IconButton::new("address-book")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed =>
+                                        Message::Contact(ContactMessage::ShowContactsModal),
+                                }),
+                            IconButton::new("chart-column")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed => Message::Analytics(
+                                        AnalyticsMessage::ShowApplicationStatsModal
+                                    ),
+                                }),
+                            IconButton::new("gear")
+                                .color(self.icon_color())
+                                .solid()
+                                .view()
+                                .map(|msg| match msg {
+                                    IconButtonMessage::Pressed =>
+                                        Message::Settings(SettingsMessage::ShowSettingsModal),
+                                }),
+                        ]
+                        .spacing(10)
+                    )
+                    .height(Fill)
+                    .align_y(Alignment::End),
+                ]
+                .spacing(10)
+                .align_x(Alignment::Center)
+                .padding(Padding::from([30, 0])),
+            )
+            .width(Length::Fixed(50.0))
+            .height(Fill)
+            .style(theme::surface)
+            .into();
+        }
+
+        container(column![
+            row![
+                text_input("", &self.filter.company_name)
+                    .on_input(
+                        |value| Message::Filter(FilterMessage::FilterCompanyNameChanged(value))
+                    )
+                    .padding(5),
+                IconButton::new("angles-left")
+                    .color(self.icon_color())
+                    .solid()
+                    .view()
+                    .map(|msg| match msg {
+                        IconButtonMessage::Pressed => Message::ToggleSidebar,
+                    }),
+            ]
+            .spacing(5)
+            .align_y(iced::Alignment::Center)
+            .padding(Padding::from([0, 30]).top(30))
+            .width(Fill),
+            row![
+                button(text("Show All"))
+                    .on_press(Message::Company(CompanyMessage::ShowAllCompanies)),
+                container(
+                    button(
+                        row![
+                            text("Group"),
+                            fa_icon_solid("plus").size(15.0).color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Company(
+                        CompanyMessage::ShowCreateCompanyGroupModal
+                    ))
+                )
+                .width(Fill)
+                .align_x(Alignment::End),
+                container(
+                    button(
+                        row![
+                            text("New"),
+                            fa_icon_solid("plus").size(15.0).color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Company(CompanyMessage::ShowCreateCompanyModal))
+                )
+            ]
+            .spacing(5)
+            .align_y(iced::Alignment::Center)
+            .padding(Padding::from([20, 30]).top(10))
+            .width(Fill),
+            scrollable(self.company_list())
+                .width(Fill)
+                .height(Length::FillPortion(3))
+                .on_scroll(|viewport| {
+                    Message::Company(CompanyMessage::CompanyScroll(viewport))
+                }),
+            // Settings area
+            container(
+                row![
+                    button(
+                        row![
+                            text("Logs"),
+                            fa_icon_solid("file-lines")
+                                .size(15.0)
+                                .color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Log(LogMessage::OpenLogViewer)),
+                    button(
+                        row![
+                            text("Import"),
+                            fa_icon_solid("file-import")
+                                .size(15.0)
+                                .color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Import(ImportMessage::ShowImportModal)),
+                    button(
+                        row![
+                            text("Export"),
+                            fa_icon_solid("file-export")
+                                .size(15.0)
+                                .color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Export(ExportMessage::ShowExportModal)),
+                    button(
+                        row![
+                            text("Rules"),
+                            fa_icon_solid("tags").size(15.0).color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Rule(RuleMessage::ShowPostRulesModal)),
+                    button(
+                        row![
+                            text("Contacts"),
+                            fa_icon_solid("address-book")
+                                .size(15.0)
+                                .color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Contact(ContactMessage::ShowContactsModal)),
+                    button(
+                        row![
+                            text("Stats"),
+                            fa_icon_solid("chart-column")
+                                .size(15.0)
+                                .color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Analytics(
+                        AnalyticsMessage::ShowApplicationStatsModal
+                    )),
+                    button(
+                        row![
+                            text("Settings"),
+                            fa_icon_solid("gear").size(15.0).color(self.icon_color()),
+                        ]
+                        .spacing(5)
+                        .align_y(Alignment::Center)
+                    )
+                    .on_press(Message::Settings(SettingsMessage::ShowSettingsModal)),
+                ]
+                .spacing(10)
+            )
+            .height(Length::FillPortion(1))
+            .width(Fill)
+            .align_x(Alignment::Center)
+            .padding(Padding::from([0, 0]).top(50))
+        ])
+        .width(Length::FillPortion(1))
+        .height(Fill)
+        .style(theme::surface)
+        .into()
+    }
+
+    fn modal_content<'a>(&'a self, layer: &'a Modal) -> Element<'a, Message> {
+        match layer {
+            Modal::SettingsModal => {
+                self.settings_modal(Message::Settings(SettingsMessage::SaveSettings))
+            }
+            Modal::ExportModal => {
+                self.export_modal(Message::Export(ExportMessage::ExportWorkSearchLog))
+            }
+            Modal::ImportModal => {
+                self.import_modal(Message::Import(ImportMessage::ImportTrackerExport))
+            }
+            Modal::UrlImportModal => self.url_import_modal(),
+            Modal::ApplicationStatsModal => self.application_stats_modal(),
+            Modal::PostRulesModal => self.post_rules_modal(),
+            Modal::ContactsModal => self.contacts_modal(),
+            Modal::DigestModal => self.digest_modal(),
+            Modal::JobPostDetailModal => self.job_post_detail_modal(),
+            Modal::Confirm { message, .. } => confirm_modal(message),
+            Modal::CreateCompanyModal => {
+                self.company_modal(Message::Company(CompanyMessage::TrackNewCompany))
+            }
+            Modal::EditCompanyModal => {
+                self.company_modal(Message::Company(CompanyMessage::EditCompany))
+            }
+            Modal::CreateCompanyGroupModal => {
+                self.company_group_modal(Message::Company(CompanyMessage::CreateCompanyGroup))
+            }
+            Modal::CreateApplicationModal => {
+                self.job_app_modal(Message::Application(ApplicationMessage::CreateApplication))
+            }
+            Modal::EditApplicationModal => {
+                self.job_app_modal(Message::Application(ApplicationMessage::EditApplication))
+            }
+            Modal::EditJobPostModal => {
+                self.job_post_modal(Message::JobPost(JobPostMessage::EditJobPost))
+            }
+            Modal::AddJobPostModal => {
+                self.job_post_modal(Message::JobPost(JobPostMessage::CreateJobPost))
+            }
+            Modal::BulkDeleteJobPostsModal => {
+                self.bulk_delete_modal(Message::JobPost(JobPostMessage::BulkDeleteJobPosts))
+            }
+            Modal::HNImportModal => self.hn_import_modal(),
+            Modal::AlertRulesModal => self.alert_rules_modal(),
+            Modal::FetchHistoryModal => self.fetch_history_modal(),
+            Modal::FindJobsModal => {
+                self.find_jobs_modal(Message::FindJobs(FindJobsMessage::FindJobs))
+            }
+        }
+    }
+
+    /********************
+     * fn VIEW
+     ********************/
+    pub fn view(&self, id: window::Id) -> Element<'_, Message> {
+        if self.log_window == Some(id) {
+            return self.log_viewer();
+        }
+        if self.starting_up {
+            return self.splash();
+        }
+        let mut find_jobs_btn = button(
+            row![
+                text("Find Jobs"),
+                fa_icon_solid("magnifying-glass")
+                    .size(15.0)
+                    .color(self.icon_color()),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        );
+        if !self.config.provider_settings().configured().is_empty() && !self.is_offline() {
+            find_jobs_btn =
+                find_jobs_btn.on_press(Message::FindJobs(FindJobsMessage::ShowFindJobsModal));
+        }
+        let mut sync_boards_btn = button(
+            row![
+                text(if self.boards_syncing {
+                    "Syncing..."
+                } else {
+                    "Sync Boards"
+                }),
+                fa_icon_solid("rotate").size(15.0).color(self.icon_color()),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        );
+        if !self.boards_syncing && !self.is_offline() {
+            sync_boards_btn = sync_boards_btn.on_press(Message::Sync(SyncMessage::SyncBoards));
+        }
+        let mut remoteok_btn = button(
+            row![
+                text(if self.remoteok_syncing {
+                    "Importing..."
+                } else {
+                    "RemoteOK"
+                }),
+                fa_icon_solid("earth-americas")
+                    .size(15.0)
+                    .color(self.icon_color()),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        );
+        if !self.remoteok_syncing && !self.is_offline() && self.remoteok_enabled() {
+            remoteok_btn = remoteok_btn.on_press(Message::Sync(SyncMessage::SyncRemoteOk));
+        }
+        let offline_btn = button(
+            row![
+                text(match (self.config.offline, self.network_available) {
+                    (true, _) => "Offline",
+                    (false, false) => "No Connection",
+                    (false, true) => "Online",
+                }),
+                fa_icon_solid(if self.is_offline() {
+                    "plug-circle-xmark"
+                } else {
+                    "plug"
+                })
+                .size(15.0)
+                .color(self.icon_color()),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        )
+        .style(if self.is_offline() {
+            button::secondary
+        } else {
+            button::primary
+        })
+        .on_press(Message::Scheduler(SchedulerMessage::ToggleOffline));
+        let status_banner: Element<'_, Message, Theme, iced::Renderer> = match &self.status_message
+        {
+            Some(status_message) => container(
+                row![
+                    text(status_message).width(Fill),
+                    IconButton::new("xmark")
+                        .color(self.icon_color())
+                        .solid()
+                        .view()
+                        .map(|msg| match msg {
+                            IconButtonMessage::Pressed => Message::DismissStatus,
+                        }),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+            .width(Fill)
+            .padding(Padding::from([0, 30]))
+            .into(),
+            None => column![].into(),
+        };
+        let main_window_content = row![
+            // Sidemenu container
+            self.sidebar(),
+            // Main content container
+            container(
+                column![
+                    // Search and filter area
+                    column![
+                        text_input(
+                            "Search titles, skills, benefits, notes, and companies",
+                            &self.filter.search
+                        )
+                        .on_input(|value| Message::Filter(FilterMessage::FilterSearchChanged(
+                            value
+                        )))
+                        .on_submit(Message::Filter(FilterMessage::FilterResults))
+                        .padding(5),
+                        row![
+                            column![
+                                text("Title or Skill").size(12),
+                                text_input("", &self.filter.job_title)
+                                    .on_input(|value| Message::Filter(
+                                        FilterMessage::FilterJobTitleChanged(value)
+                                    ))
+                                    .padding(5)
+                            ]
+                            .spacing(5),
+                            column![
+                                text("Location").size(12),
+                                text_input("", &self.filter.location)
+                                    .on_input(|value| Message::Filter(
+                                        FilterMessage::FilterLocationChanged(value)
+                                    ))
+                                    .padding(5),
+                                location_suggestions(
+                                    &self.locations,
+                                    &self.filter.location,
+                                    |value| Message::Filter(FilterMessage::FilterLocationChanged(
+                                        value
+                                    ))
+                                ),
+                            ]
+                            .spacing(5),
+                            column![
+                                text("Industry").size(12),
+                                pick_list(
+                                    self.industry_options(),
+                                    Some(self.filter.industry.clone().unwrap_or("Any".to_string())),
+                                    |value| Message::Filter(FilterMessage::FilterIndustryChanged(
+                                        value
+                                    ))
+                                )
+                                .text_size(12)
+                                .padding(5),
+                            ]
+                            .spacing(5),
+                            column![
+                                text("Tag").size(12),
+                                pick_list(
+                                    self.tag_options(),
+                                    Some(self.filter.tag.clone().unwrap_or("Any".to_string())),
+                                    |value| Message::Filter(FilterMessage::FilterTagChanged(value))
+                                )
+                                .text_size(12)
+                                .padding(5),
+                            ]
+                            .spacing(5)
+                        ]
+                        .spacing(10),
+                        row![
+                            column![
+                                text("Min. Years").size(12),
+                                number_input(self.filter.min_yoe, 0..100, |value| Message::Filter(
+                                    FilterMessage::FilterMinYOEChanged(value)
+                                ))
+                                .padding(5)
+                                .style(number_input::number_input::primary)
+                            ]
+                            .width(Length::FillPortion(1))
+                            .spacing(5),
+                            column![
+                                text("Max. Years").size(12),
+                                number_input(self.filter.max_yoe, 0..100, |value| Message::Filter(
+                                    FilterMessage::FilterMaxYOEChanged(value)
+                                ))
+                                .padding(5)
+                                .style(number_input::number_input::primary)
+                            ]
+                            .width(Length::FillPortion(1))
+                            .spacing(5),
+                            column![
+                                text("Min. Pay (Yearly, USD)").size(12),
+                                number_input(self.filter.min_annual_pay, 0..100_000_000, |value| {
+                                    Message::Filter(FilterMessage::FilterMinAnnualPayChanged(value))
+                                })
+                                .padding(5)
+                                .style(number_input::number_input::primary)
+                            ]
+                            .width(Length::FillPortion(1))
+                            .spacing(5),
+                            column![
+                                text("Retrieved Within (Days)").size(12),
+                                number_input(self.filter.retrieved_days, 0..366, |value| {
+                                    Message::Filter(FilterMessage::FilterRetrievedDaysChanged(
+                                        value,
+                                    ))
+                                })
+                                .padding(5)
+                                .style(number_input::number_input::primary)
+                            ]
+                            .width(Length::FillPortion(1))
+                            .spacing(5),
+                            row![
+                                checkbox("On-site", self.filter.onsite)
+                                    .on_toggle(|value| Message::Filter(
+                                        FilterMessage::FilterOnsiteChanged(value)
+                                    ))
+                                    .width(Fill),
+                                checkbox("Hybrid", self.filter.hybrid)
+                                    .on_toggle(|value| Message::Filter(
+                                        FilterMessage::FilterHybridChanged(value)
+                                    ))
+                                    .width(Fill),
+                                checkbox("Remote", self.filter.remote)
+                                    .on_toggle(|value| Message::Filter(
+                                        FilterMessage::FilterRemoteChanged(value)
+                                    ))
+                                    .width(Fill),
+                            ]
+                            .width(Length::FillPortion(2))
+                            .spacing(25),
+                        ]
+                        .spacing(10),
+                        row![
+                            self.filter_date_field("Applied From", FilterDateField::AppliedFrom),
+                            self.filter_date_field("Applied To", FilterDateField::AppliedTo),
+                            self.filter_date_field(
+                                "Responded From",
+                                FilterDateField::RespondedFrom
+                            ),
+                            self.filter_date_field("Responded To", FilterDateField::RespondedTo),
+                            column![
+                                checkbox("Interviewed", self.filter.interviewed).on_toggle(
+                                    |value| Message::Filter(
+                                        FilterMessage::FilterInterviewedChanged(value)
+                                    )
+                                ),
+                                checkbox("No Response", self.filter.no_response).on_toggle(
+                                    |value| Message::Filter(
+                                        FilterMessage::FilterNoResponseChanged(value)
+                                    )
+                                ),
+                                checkbox("Meets My Target", self.filter.meets_target)
+                                    .on_toggle_maybe((self.config.target_salary > 0).then_some(
+                                        |value| Message::Filter(
+                                            FilterMessage::FilterMeetsTargetChanged(value)
+                                        )
+                                    )),
+                                checkbox("Favorites Only", self.filter.favorites_only).on_toggle(
+                                    |value| Message::Filter(
+                                        FilterMessage::FilterFavoritesOnlyChanged(value)
+                                    )
+                                ),
+                                checkbox("Archived", self.filter.archived).on_toggle(|value| {
+                                    Message::Filter(FilterMessage::FilterArchivedChanged(value))
+                                }),
+                            ]
+                            .width(Length::FillPortion(1))
+                            .spacing(5),
+                        ]
+                        .spacing(10)
+                        .align_y(Alignment::End),
+                        row![
+                            button(
+                                row![
+                                    "Add Job",
+                                    fa_icon_solid("plus").size(15.0).color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .on_press(Message::JobPost(JobPostMessage::ShowAddJobPostModal)),
+                            button(
+                                row![
+                                    text("Awaiting Response"),
+                                    fa_icon_solid("hourglass-half")
+                                        .size(15.0)
+                                        .color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .style(if self.filter.awaiting_response {
+                                button::success
+                            } else {
+                                button::primary
+                            })
+                            .on_press(Message::Filter(FilterMessage::ToggleAwaitingResponse)),
+                            button(
+                                row![
+                                    text(if self.config.board_view {
+                                        "List"
+                                    } else {
+                                        "Board"
+                                    }),
+                                    fa_icon_solid(if self.config.board_view {
+                                        "list"
+                                    } else {
+                                        "table-columns"
+                                    })
+                                    .size(15.0)
+                                    .color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .on_press(Message::Board(BoardMessage::ToggleBoardView)),
+                            container(
+                                button(
+                                    row![
+                                        text("Reset"),
+                                        fa_icon_solid("filter-circle-xmark")
+                                            .size(15.0)
+                                            .color(self.icon_color()),
+                                    ]
+                                    .spacing(5)
+                                    .align_y(Alignment::Center)
+                                )
+                                .on_press_maybe(
+                                    (!self.filter.is_default())
+                                        .then_some(Message::Filter(FilterMessage::ResetFilters))
+                                )
+                            )
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                            button(
+                                row![
+                                    text("Filter Results"),
+                                    fa_icon_solid("filter").size(15.0).color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .on_press(Message::Filter(FilterMessage::FilterResults)),
+                            button(
+                                row![
+                                    text("Delete Matching"),
+                                    fa_icon_solid("trash").size(15.0).color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .style(button::danger)
+                            .on_press(Message::JobPost(
+                                JobPostMessage::ShowBulkDeleteJobPostsModal
+                            )),
+                            offline_btn,
+                            sync_boards_btn,
+                            remoteok_btn,
+                            button(
+                                row![
+                                    text("Who's Hiring"),
+                                    fa_icon_solid("newspaper")
+                                        .size(15.0)
+                                        .color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .on_press(Message::HNImport(HNImportMessage::ShowHNImportModal)),
+                            button(
+                                row![
+                                    text("Import URLs"),
+                                    fa_icon_solid("link").size(15.0).color(self.icon_color()),
+                                ]
+                                .spacing(5)
+                                .align_y(Alignment::Center)
+                            )
+                            .on_press(Message::Import(ImportMessage::ShowUrlImportModal)),
+                            find_jobs_btn,
+                        ]
+                        .spacing(10)
+                        .width(Fill)
+                        .padding(Padding::from([0, 0]).top(10))
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                    .padding(Padding::from([0, 30]).top(20)),
+                    status_banner,
+                    if self.config.board_view {
+                        self.application_board()
+                    } else {
+                        self.job_list()
+                    },
+                ]
+                .spacing(15)
+            )
+            .width(Length::FillPortion(3))
+            .height(Fill)
+            .style(theme::panel)
+        ];
+
+        // Each open modal is layered over the ones below it
+        let base: Element<Message> = main_window_content.into();
+        self.modals.iter().fold(base, |base, layer| {
+            modal(base, self.modal_content(layer), Message::HideModal)
+        })
+    }
+}
+
+// impl Drop for JobHunter {
+//     fn drop(&mut self) {
+//         let pool = self.db.clone();
+//         tokio::runtime::Handle::current().block_on(async move {
+//             pool.close().await;
+//         });
+//     }
+// }
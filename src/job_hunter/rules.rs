@@ -0,0 +1,363 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum RuleMessage {
+    ShowPostRulesModal,
+    PostRuleNameChanged(String),
+    PostRuleTitleChanged(String),
+    PostRuleSkillsChanged(String),
+    PostRuleLocationChanged(String),
+    PostRuleCompanyChanged(String),
+    PostRuleMinPayChanged(i64),
+    PostRulePayBelowChanged(i64),
+    PostRuleActionChanged(PostRuleAction),
+    PostRuleTagChanged(String),
+    SavePostRule,
+    TogglePostRule(i64, bool),
+    DeletePostRule(i64),
+}
+
+impl JobHunter {
+    pub(super) fn update_rules(&mut self, message: RuleMessage) -> Task<Message> {
+        match message {
+            RuleMessage::ShowPostRulesModal => {
+                self.set_post_rules();
+                self.push_modal(Modal::PostRulesModal);
+                self.set_primary_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+            RuleMessage::PostRuleNameChanged(name) => {
+                self.post_rule_name = name;
+                Task::none()
+            }
+            RuleMessage::PostRuleTitleChanged(title) => {
+                self.post_rule_title = title;
+                Task::none()
+            }
+            RuleMessage::PostRuleSkillsChanged(skills) => {
+                self.post_rule_skills = skills;
+                Task::none()
+            }
+            RuleMessage::PostRuleLocationChanged(location) => {
+                self.post_rule_location = location;
+                Task::none()
+            }
+            RuleMessage::PostRuleCompanyChanged(company) => {
+                self.post_rule_company = company;
+                Task::none()
+            }
+            RuleMessage::PostRuleMinPayChanged(min_pay) => {
+                self.post_rule_min_pay = min_pay;
+                Task::none()
+            }
+            RuleMessage::PostRulePayBelowChanged(pay_below) => {
+                self.post_rule_pay_below = pay_below;
+                Task::none()
+            }
+            RuleMessage::PostRuleActionChanged(action) => {
+                self.post_rule_action = action;
+                Task::none()
+            }
+            RuleMessage::PostRuleTagChanged(tag) => {
+                self.post_rule_tag = tag;
+                Task::none()
+            }
+            RuleMessage::SavePostRule => {
+                let rule = PostRule {
+                    id: 0,
+                    name: self.post_rule_name.trim().to_string(),
+                    title_contains: self.post_rule_title.trim().to_string(),
+                    skills_contains: self.post_rule_skills.trim().to_string(),
+                    location_contains: self.post_rule_location.trim().to_string(),
+                    company_contains: self.post_rule_company.trim().to_string(),
+                    min_pay: self.post_rule_min_pay,
+                    pay_below: self.post_rule_pay_below,
+                    action: self.post_rule_action,
+                    tag: match self.post_rule_action {
+                        PostRuleAction::Tag => self.post_rule_tag.trim().to_string(),
+                        PostRuleAction::Archive => "".to_string(),
+                    },
+                    enabled: SqliteBoolean(true),
+                };
+                if rule.name.is_empty() {
+                    self.post_rule_error = Some("Name the rule".to_string());
+                    return Task::none();
+                }
+                if rule.action == PostRuleAction::Tag && rule.tag.is_empty() {
+                    self.post_rule_error = Some("Enter the tag to add".to_string());
+                    return Task::none();
+                }
+                if !rule.has_conditions() {
+                    self.post_rule_error = Some("Add at least one condition".to_string());
+                    return Task::none();
+                }
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = rule.insert(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to insert post rule");
+                }
+                self.post_rule_name = "".to_string();
+                self.post_rule_title = "".to_string();
+                self.post_rule_skills = "".to_string();
+                self.post_rule_location = "".to_string();
+                self.post_rule_company = "".to_string();
+                self.post_rule_min_pay = 0;
+                self.post_rule_pay_below = 0;
+                self.post_rule_tag = "".to_string();
+                self.post_rule_error = None;
+                self.set_post_rules();
+                Task::none()
+            }
+            RuleMessage::TogglePostRule(id, enabled) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = PostRule::set_enabled(id, enabled, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to toggle post rule");
+                }
+                self.set_post_rules();
+                Task::none()
+            }
+            RuleMessage::DeletePostRule(id) => {
+                {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = PostRule::delete(id, &pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to delete post rule");
+                }
+                self.set_post_rules();
+                Task::none()
+            }
+        }
+    }
+
+    fn set_post_rules(&mut self) {
+        self.post_rules = {
+            let pool = self.db.clone();
+            let (sender, receiver) = std::sync::mpsc::channel();
+            self.tokio_handle.spawn(async move {
+                let res = PostRule::fetch_all(&pool).await;
+                _ = sender.send(res);
+            });
+            receiver
+                .recv()
+                .expect("Failed to receive res")
+                .expect("Failed to get post rules")
+        };
+    }
+
+    pub(super) fn post_rules_modal<'a>(&'a self) -> Element<'a, Message> {
+        let rules = Column::with_children(self.post_rules.iter().map(|rule| {
+            let id = rule.id;
+            let confirm = format!("Delete the rule {}?", rule.name);
+            row![
+                checkbox("", rule.enabled.0).on_toggle(move |enabled| Message::Rule(
+                    RuleMessage::TogglePostRule(id, enabled)
+                )),
+                column![text(&rule.name).size(14), text(rule_summary(rule)).size(12)].width(Fill),
+                IconButton::new("trash")
+                    .color(self.icon_color())
+                    .solid()
+                    .view()
+                    .map(move |msg| match msg {
+                        IconButtonMessage::Pressed => Message::Confirm(
+                            confirm.clone(),
+                            Box::new(Message::Rule(RuleMessage::DeletePostRule(id))),
+                        ),
+                    }),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center)
+            .into()
+        }))
+        .spacing(10);
+        let rules: Element<'a, Message> = if self.post_rules.is_empty() {
+            text("No rules yet").size(12).into()
+        } else {
+            scrollable(rules)
+                .height(Length::Fixed(200.0))
+                .width(Fill)
+                .into()
+        };
+        let rule_error: Element<'a, Message> = match &self.post_rule_error {
+            Some(error) => text(error.clone()).size(12).style(text::danger).into(),
+            None => column![].into(),
+        };
+        let submit_message = Message::Rule(RuleMessage::SavePostRule);
+        let text_field = |label: &'a str, value: &'a str, on_input: fn(String) -> RuleMessage| {
+            column![
+                text(label).size(12),
+                text_input("", value)
+                    .on_input(move |value| Message::Rule(on_input(value)))
+                    .on_submit(submit_message.clone())
+                    .padding(5),
+            ]
+            .width(Length::FillPortion(1))
+            .spacing(5)
+        };
+
+        let tag_field: Element<'a, Message> = match self.post_rule_action {
+            PostRuleAction::Tag => {
+                text_field("Tag", &self.post_rule_tag, RuleMessage::PostRuleTagChanged).into()
+            }
+            PostRuleAction::Archive => column![].width(Length::FillPortion(1)).into(),
+        };
+
+        container(
+            column![
+                text("Post Rules").size(24),
+                column![
+                    text("Tag or archive new posts from any source when they meet every condition")
+                        .size(12),
+                    rules,
+                    text("New Rule").size(16),
+                    row![
+                        column![
+                            text("Name").size(12),
+                            text_input("", &self.post_rule_name)
+                                .id(self.primary_modal_field.clone().unwrap())
+                                .on_input(|value| Message::Rule(RuleMessage::PostRuleNameChanged(
+                                    value
+                                )))
+                                .on_submit(submit_message.clone())
+                                .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Action").size(12),
+                            pick_list(PostRuleAction::ALL, Some(self.post_rule_action), |value| {
+                                Message::Rule(RuleMessage::PostRuleActionChanged(value))
+                            })
+                            .text_size(12)
+                            .padding(5),
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        tag_field,
+                    ]
+                    .spacing(10),
+                    text("Comma-separated; any one matching is enough").size(10),
+                    row![
+                        text_field(
+                            "Title Contains",
+                            &self.post_rule_title,
+                            RuleMessage::PostRuleTitleChanged
+                        ),
+                        text_field(
+                            "Skills Contain",
+                            &self.post_rule_skills,
+                            RuleMessage::PostRuleSkillsChanged
+                        ),
+                    ]
+                    .spacing(10),
+                    row![
+                        text_field(
+                            "Location Contains",
+                            &self.post_rule_location,
+                            RuleMessage::PostRuleLocationChanged
+                        ),
+                        text_field(
+                            "Company Contains",
+                            &self.post_rule_company,
+                            RuleMessage::PostRuleCompanyChanged
+                        ),
+                    ]
+                    .spacing(10),
+                    row![
+                        column![
+                            text("Pays At Least (Yearly)").size(12),
+                            text("0 = any").size(10),
+                            number_input(self.post_rule_min_pay, 0..100_000_000, |value| {
+                                Message::Rule(RuleMessage::PostRuleMinPayChanged(value))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Pays Less Than (Yearly)").size(12),
+                            text("0 = any").size(10),
+                            number_input(self.post_rule_pay_below, 0..100_000_000, |value| {
+                                Message::Rule(RuleMessage::PostRulePayBelowChanged(value))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    rule_error,
+                    row![
+                        container(button(text("Close")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(button(text("Add Rule")).on_press(submit_message.clone())),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(600)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
+
+/// E.g. "Title has rust, go | Pays $100,000+ → Tag backend"
+fn rule_summary(rule: &PostRule) -> String {
+    let mut conditions = Vec::new();
+    if !rule.title_contains.is_empty() {
+        conditions.push(format!("Title has {}", rule.title_contains));
+    }
+    if !rule.skills_contains.is_empty() {
+        conditions.push(format!("Skills have {}", rule.skills_contains));
+    }
+    if !rule.location_contains.is_empty() {
+        conditions.push(format!("Location has {}", rule.location_contains));
+    }
+    if !rule.company_contains.is_empty() {
+        conditions.push(format!("Company has {}", rule.company_contains));
+    }
+    if rule.min_pay > 0 {
+        conditions.push(format!("Pays {}+", format_pay(rule.min_pay * 100, None)));
+    }
+    if rule.pay_below > 0 {
+        conditions.push(format!(
+            "Pays under {}",
+            format_pay(rule.pay_below * 100, None)
+        ));
+    }
+    let action = match rule.action {
+        PostRuleAction::Tag => format!("Tag {}", rule.tag),
+        PostRuleAction::Archive => "Archive".to_string(),
+    };
+    format!("{} → {}", conditions.join(" | "), action)
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum SchedulerMessage {
+    SchedulerTick,
+    ConnectivityChecked(bool),
+    ToggleOffline,
+}
+
+impl JobHunter {
+    pub(super) fn update_scheduler(&mut self, message: SchedulerMessage) -> Task<Message> {
+        match message {
+            SchedulerMessage::SchedulerTick => {
+                self.remind_upcoming_interviews();
+                self.check_web_driver();
+                let connectivity =
+                    Task::perform(api::check_connectivity(self.http_client()), |available| {
+                        Message::Scheduler(SchedulerMessage::ConnectivityChecked(available))
+                    });
+                // Picks up companies added or edited since, from the cache when offline
+                let favicons = self.load_favicons();
+                if self.is_offline() {
+                    // Background pollers pause instead of failing every tick
+                    return if self.config.offline {
+                        favicons
+                    } else {
+                        Task::batch([connectivity, favicons])
+                    };
+                }
+                let mut tasks = vec![
+                    connectivity,
+                    favicons,
+                    Task::done(Message::Alert(AlertMessage::RunDueAlertRules)),
+                ];
+                let interval_hours = self.config.board_sync_interval_hours;
+                let sync_due = interval_hours > 0
+                    && self.last_board_sync.is_none_or(|last_sync| {
+                        last_sync.elapsed()
+                            >= std::time::Duration::from_secs(interval_hours as u64 * 3600)
+                    });
+                if sync_due {
+                    tasks.push(Task::done(Message::Sync(SyncMessage::SyncBoards)));
+                }
+                let check_due = self.last_careers_url_check.is_none_or(|last_check| {
+                    last_check.elapsed()
+                        >= std::time::Duration::from_secs(
+                            companies::CAREERS_URL_CHECK_INTERVAL_HOURS * 3600,
+                        )
+                });
+                if check_due {
+                    tasks.push(Task::done(Message::Company(
+                        CompanyMessage::CheckCareersUrls,
+                    )));
+                }
+                Task::batch(tasks)
+            }
+            SchedulerMessage::ConnectivityChecked(available) => {
+                self.network_available = available;
+                Task::none()
+            }
+            SchedulerMessage::ToggleOffline => {
+                self.config.offline = !self.config.offline;
+                self.save_config();
+                if self.config.offline {
+                    return Task::none();
+                }
+                Task::perform(api::check_connectivity(self.http_client()), |available| {
+                    Message::Scheduler(SchedulerMessage::ConnectivityChecked(available))
+                })
+            }
+        }
+    }
+}
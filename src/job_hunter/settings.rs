@@ -0,0 +1,780 @@
+use iced::widget::column;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum SettingsMessage {
+    SaveSettings,
+    APIJobsKeyChanged(String),
+    APIJobsMonthlyQuotaChanged(i64),
+    RequestIntervalChanged(u64),
+    AdzunaAppIdChanged(String),
+    AdzunaAppKeyChanged(String),
+    /// A provider's id, and whether it's on
+    ProviderToggled(&'static str, bool),
+    RetentionDaysChanged(i64),
+    TargetSalaryChanged(i64),
+    BoardKeywordsChanged(String),
+    BoardSyncIntervalChanged(i64),
+    AlertIntervalChanged(i64),
+    ProxyURLChanged(String),
+    ProxyUsernameChanged(String),
+    ProxyPasswordChanged(String),
+    UserAgentChanged(String),
+    ExtraHeadersEdited(text_editor::Action),
+    DriverChanged(scraper::Driver),
+    DriverPathChanged(String),
+    DriverPortChanged(u16),
+    NotificationsToggled(bool),
+    ThemeChanged(String),
+    AccentColorChanged(String),
+    ProfileChanged(String),
+    NewProfileChanged(String),
+    TestWebDriver,
+    /// The driver settings that were tested, and whether a session could be opened
+    WebDriverTested(scraper::DriverSettings, Result<(), String>),
+    /// Re-reads the industries file and classifies every post again
+    ReclassifyIndustries,
+    ShowSettingsModal,
+}
+
+impl JobHunter {
+    pub(super) fn update_settings(&mut self, message: SettingsMessage) -> Task<Message> {
+        match message {
+            SettingsMessage::SaveSettings => {
+                let headers = match api::parse_headers(&self.extra_headers.text()) {
+                    Ok(headers) => headers,
+                    Err(e) => {
+                        self.settings_error = Some(format!("Invalid headers: {e}"));
+                        return Task::none();
+                    }
+                };
+                let network = api::NetworkSettings {
+                    proxy_url: self.proxy_url.clone(),
+                    proxy_username: self.proxy_username.clone(),
+                    proxy_password: self.proxy_password.clone(),
+                    user_agent: self.user_agent.clone(),
+                    headers: headers.clone(),
+                };
+                let network_res = api::http_client(&network)
+                    .and_then(|_| self.driver.capabilities(&network).map(|_| ()));
+                if let Err(e) = network_res {
+                    self.settings_error = Some(format!("Invalid network settings: {e}"));
+                    return Task::none();
+                }
+                let accent_color = self.accent_color.trim().to_string();
+                if !accent_color.is_empty() && theme::parse_accent(&accent_color).is_none() {
+                    self.settings_error = Some(format!(
+                        "Invalid accent color {accent_color}, expected #rrggbb"
+                    ));
+                    return Task::none();
+                }
+                let profile = if self.new_profile.trim().is_empty() {
+                    self.profile_name.clone()
+                } else {
+                    match paths::parse_profile_name(&self.new_profile) {
+                        Ok(profile) => profile,
+                        Err(e) => {
+                            self.settings_error = Some(e);
+                            return Task::none();
+                        }
+                    }
+                };
+                let driver = self.driver_settings();
+                if driver != self.config.driver_settings() {
+                    // The next fetch starts the new one
+                    self.stop_web_driver();
+                    self.web_driver_status = DriverStatus::Stopped;
+                }
+                self.config.apijobs_key = self.apijobs_key.clone();
+                self.config.apijobs_monthly_quota = self.apijobs_monthly_quota;
+                self.config.request_interval_secs = self.request_interval_secs;
+                self.config.adzuna_app_id = self.adzuna_app_id.trim().to_string();
+                self.config.adzuna_app_key = self.adzuna_app_key.trim().to_string();
+                self.config.disabled_providers = self.disabled_providers.clone();
+                self.config.retention_days = self.retention_days;
+                self.config.target_salary = self.target_salary;
+                self.config.board_title_keywords = self.board_title_keywords.clone();
+                self.config.board_sync_interval_hours = self.board_sync_interval_hours;
+                self.config.alert_interval_hours = self.alert_interval_hours;
+                self.config.proxy_url = self.proxy_url.clone();
+                self.config.proxy_username = self.proxy_username.clone();
+                self.config.proxy_password = self.proxy_password.clone();
+                self.config.user_agent = self.user_agent.clone();
+                self.config.extra_headers = headers;
+                self.config.driver = driver.driver;
+                self.config.driver_path = driver.path;
+                self.config.driver_port = driver.port;
+                self.config.mute_notifications = !self.notifications_enabled;
+                self.config.theme = self.theme_name.clone();
+                self.config.accent_color = accent_color;
+                self.app_theme = theme::resolve(&self.config.theme, &self.config.accent_color);
+                let switch_profile = profile != self.config.profile
+                    && !(paths::is_default_profile(&profile)
+                        && paths::is_default_profile(&self.config.profile));
+                if switch_profile {
+                    self.config.profile = profile;
+                    self.config.saved_profile = None;
+                }
+                self.save_config();
+                self.hide_modal();
+                if switch_profile {
+                    return self.open_profile();
+                }
+                Task::none()
+            }
+            SettingsMessage::APIJobsKeyChanged(key) => {
+                self.apijobs_key = key;
+                Task::none()
+            }
+            SettingsMessage::APIJobsMonthlyQuotaChanged(quota) => {
+                self.apijobs_monthly_quota = quota;
+                Task::none()
+            }
+            SettingsMessage::RequestIntervalChanged(secs) => {
+                self.request_interval_secs = secs;
+                Task::none()
+            }
+            SettingsMessage::AdzunaAppIdChanged(app_id) => {
+                self.adzuna_app_id = app_id;
+                Task::none()
+            }
+            SettingsMessage::AdzunaAppKeyChanged(app_key) => {
+                self.adzuna_app_key = app_key;
+                Task::none()
+            }
+            SettingsMessage::ProviderToggled(id, enabled) => {
+                if enabled {
+                    self.disabled_providers.remove(id);
+                } else {
+                    self.disabled_providers.insert(id.to_string());
+                }
+                Task::none()
+            }
+            SettingsMessage::RetentionDaysChanged(days) => {
+                self.retention_days = days;
+                Task::none()
+            }
+            SettingsMessage::TargetSalaryChanged(salary) => {
+                self.target_salary = salary;
+                Task::none()
+            }
+            SettingsMessage::ProxyURLChanged(url) => {
+                self.proxy_url = url;
+                Task::none()
+            }
+            SettingsMessage::ProxyUsernameChanged(username) => {
+                self.proxy_username = username;
+                Task::none()
+            }
+            SettingsMessage::ProxyPasswordChanged(password) => {
+                self.proxy_password = password;
+                Task::none()
+            }
+            SettingsMessage::UserAgentChanged(user_agent) => {
+                self.user_agent = user_agent;
+                Task::none()
+            }
+            SettingsMessage::ExtraHeadersEdited(action) => {
+                self.extra_headers.perform(action);
+                Task::none()
+            }
+            SettingsMessage::DriverChanged(driver) => {
+                self.driver = driver;
+                self.driver_status = None;
+                Task::none()
+            }
+            SettingsMessage::DriverPathChanged(path) => {
+                self.driver_path = path;
+                self.driver_status = None;
+                Task::none()
+            }
+            SettingsMessage::DriverPortChanged(port) => {
+                self.driver_port = port;
+                self.driver_status = None;
+                Task::none()
+            }
+            SettingsMessage::NotificationsToggled(enabled) => {
+                self.notifications_enabled = enabled;
+                Task::none()
+            }
+            SettingsMessage::ThemeChanged(name) => {
+                self.theme_name = name;
+                Task::none()
+            }
+            SettingsMessage::AccentColorChanged(color) => {
+                self.accent_color = color;
+                Task::none()
+            }
+            SettingsMessage::ProfileChanged(profile) => {
+                self.profile_name = profile;
+                self.new_profile = "".to_string();
+                Task::none()
+            }
+            SettingsMessage::NewProfileChanged(profile) => {
+                self.new_profile = profile;
+                Task::none()
+            }
+            SettingsMessage::TestWebDriver => {
+                let mut driver = self.driver_settings();
+                let caps = match driver.driver.capabilities(&self.config.network_settings()) {
+                    Ok(caps) => caps,
+                    Err(e) => {
+                        self.settings_error = Some(format!("WebDriver test failed: {e}"));
+                        return Task::none();
+                    }
+                };
+                // The app's own server keeps running, so the test can't share its port
+                if self.driver_process.is_some()
+                    && driver.port() == self.config.driver_settings().port()
+                {
+                    match scraper::free_port() {
+                        Ok(port) => driver.port = port,
+                        Err(e) => {
+                            self.settings_error = Some(format!("WebDriver test failed: {e}"));
+                            return Task::none();
+                        }
+                    }
+                }
+                self.driver_testing = true;
+                self.settings_error = None;
+                self.driver_status = Some(format!("Starting {}...", driver.driver));
+                let settings = self.driver_settings();
+                Task::perform(scraper::test_driver(driver, caps), move |res| {
+                    Message::Settings(SettingsMessage::WebDriverTested(
+                        settings.clone(),
+                        res.map_err(|e| e.to_string()),
+                    ))
+                })
+            }
+            SettingsMessage::WebDriverTested(settings, res) => {
+                let testing = std::mem::take(&mut self.driver_testing);
+                // Stale if the form was closed or the driver fields changed since
+                if !testing || settings != self.driver_settings() {
+                    return Task::none();
+                }
+                match res {
+                    Ok(()) => {
+                        self.driver_status = Some(format!("Connected to {}", settings.driver));
+                    }
+                    Err(e) => {
+                        tracing::warn!("WebDriver test failed: {e}");
+                        self.driver_status = None;
+                        self.settings_error = Some(format!("WebDriver test failed: {e}"));
+                    }
+                }
+                Task::none()
+            }
+            SettingsMessage::ReclassifyIndustries => {
+                self.industries = match Taxonomy::load() {
+                    Ok(taxonomy) => taxonomy,
+                    Err(e) => {
+                        self.settings_error = Some(format!("Failed to load industries: {e}"));
+                        return Task::none();
+                    }
+                };
+                let reset = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = JobPost::reset_industries(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to reset industries")
+                };
+                // The filtered industry may have been renamed or removed
+                if let Some(industry) = &self.filter.industry {
+                    if !self.industries.names().contains(industry) {
+                        self.filter.industry = None;
+                    }
+                }
+                self.settings_error = None;
+                self.status_message = Some(format!("Reclassifying {reset} job posts"));
+                self.get_filter_task()
+            }
+            SettingsMessage::BoardKeywordsChanged(keywords) => {
+                self.board_title_keywords = keywords;
+                Task::none()
+            }
+            SettingsMessage::BoardSyncIntervalChanged(hours) => {
+                self.board_sync_interval_hours = hours;
+                Task::none()
+            }
+            SettingsMessage::AlertIntervalChanged(hours) => {
+                self.alert_interval_hours = hours;
+                Task::none()
+            }
+            SettingsMessage::ShowSettingsModal => {
+                self.push_modal(Modal::SettingsModal);
+                self.apijobs_key = self.config.apijobs_key.clone();
+                self.apijobs_monthly_quota = self.config.apijobs_monthly_quota;
+                self.request_interval_secs = self.config.request_interval_secs;
+                self.adzuna_app_id = self.config.adzuna_app_id.clone();
+                self.adzuna_app_key = self.config.adzuna_app_key.clone();
+                self.disabled_providers = self.config.disabled_providers.clone();
+                self.retention_days = self.config.retention_days;
+                self.target_salary = self.config.target_salary;
+                self.board_title_keywords = self.config.board_title_keywords.clone();
+                self.board_sync_interval_hours = self.config.board_sync_interval_hours;
+                self.alert_interval_hours = self.config.alert_interval_hours;
+                self.proxy_url = self.config.proxy_url.clone();
+                self.proxy_username = self.config.proxy_username.clone();
+                self.proxy_password = self.config.proxy_password.clone();
+                self.user_agent = self.config.user_agent.clone();
+                self.extra_headers = text_editor::Content::with_text(&api::format_headers(
+                    &self.config.extra_headers,
+                ));
+                self.driver = self.config.driver;
+                self.driver_path = self.config.driver_path.clone();
+                self.driver_port = self.config.driver_port;
+                self.notifications_enabled = !self.config.mute_notifications;
+                self.theme_name = if self.config.theme.is_empty() {
+                    theme::SYSTEM.to_string()
+                } else {
+                    self.config.theme.clone()
+                };
+                self.accent_color = self.config.accent_color.clone();
+                self.profile_name = if paths::is_default_profile(&self.config.profile) {
+                    paths::DEFAULT_PROFILE.to_string()
+                } else {
+                    self.config.profile.clone()
+                };
+                self.apijobs_requests_this_month = {
+                    let pool = self.db.clone();
+                    let (sender, receiver) = std::sync::mpsc::channel();
+                    self.tokio_handle.spawn(async move {
+                        let res = api::apijobs_requests_this_month(&pool).await;
+                        _ = sender.send(res);
+                    });
+                    receiver
+                        .recv()
+                        .expect("Failed to receive res")
+                        .expect("Failed to get APIJobs request count")
+                };
+                self.set_primary_modal_field();
+                text_input::focus(self.primary_modal_field.clone().unwrap())
+            }
+        }
+    }
+
+    /// Closes the current database and opens the configured profile's, migrating it like at
+    /// startup
+    fn open_profile(&mut self) -> Task<Message> {
+        let path = paths::profile_db_path(&self.config.profile);
+        let existed = path.exists();
+        if let Err(e) = paths::ensure_parent(&path) {
+            self.status_message = Some(format!("Failed to create profile directory: {e}"));
+            return Task::none();
+        }
+        let Some(url) = path.to_str() else {
+            self.status_message = Some(format!("Invalid database path {}", path.display()));
+            return Task::none();
+        };
+        tracing::info!(
+            "Opening profile {} at {}",
+            self.config.profile,
+            path.display()
+        );
+        let pool = {
+            let _guard = self.tokio_handle.enter();
+            crate::db::connect_lazy(url)
+        };
+        let old_pool = std::mem::replace(&mut self.db, pool);
+        self.tokio_handle.spawn(crate::db::shutdown(old_pool));
+        self.starting_up = true;
+        self.startup_error = None;
+        // Company ids and tags in another profile's filters don't mean anything here
+        self.filter = FilterState::load(&self.config.profile, self.config.job_page_size());
+        self.pick_filter_date = None;
+        Task::perform(
+            startup(self.db.clone(), existed, self.config.retention_days),
+            |res| Message::StartupFinished(res.map_err(|e| e.to_string())),
+        )
+    }
+
+    /// The WebDriver settings as entered in the settings modal
+    fn driver_settings(&self) -> scraper::DriverSettings {
+        scraper::DriverSettings {
+            driver: self.driver,
+            path: self.driver_path.trim().to_string(),
+            port: self.driver_port,
+        }
+    }
+
+    pub(super) fn settings_modal<'a>(&'a self, submit_message: Message) -> Element<'a, Message> {
+        let settings_error: Element<'a, Message> = match &self.settings_error {
+            Some(error) => text(error.clone()).size(12).style(text::danger).into(),
+            None => column![].into(),
+        };
+        // Each provider's switch, and what it still needs before it can search
+        let settings = api::ProviderSettings {
+            apijobs_key: self.apijobs_key.trim().to_string(),
+            adzuna_app_id: self.adzuna_app_id.trim().to_string(),
+            adzuna_app_key: self.adzuna_app_key.trim().to_string(),
+            ..Default::default()
+        };
+        let providers = row(api::PROVIDERS.into_iter().map(|provider| {
+            let missing: Vec<&str> = provider
+                .credentials(&settings)
+                .into_iter()
+                .filter(|(_, value)| value.is_empty())
+                .map(|(label, _)| label)
+                .collect();
+            let id = provider.id();
+            column![
+                checkbox(provider.name(), !self.disabled_providers.contains(id)).on_toggle(
+                    move |enabled| Message::Settings(SettingsMessage::ProviderToggled(id, enabled))
+                ),
+                text(if missing.is_empty() {
+                    "Ready".to_string()
+                } else {
+                    format!("Needs {}", missing.join(", "))
+                })
+                .size(10),
+            ]
+            .spacing(5)
+            .width(Length::FillPortion(1))
+            .into()
+        }))
+        .spacing(10);
+        container(
+            column![
+                text("Settings").size(24),
+                column![
+                    column![
+                        text("Job Search Providers").size(12),
+                        text("Unchecked ones aren't offered in Find Jobs and skip their alerts")
+                            .size(10),
+                        providers,
+                    ]
+                    .spacing(5),
+                    column![
+                        text("APIJobs API Key").size(12),
+                        text("Kept in the OS keyring when one is available").size(10),
+                        text_input("", &self.apijobs_key)
+                            .id(self.primary_modal_field.clone().unwrap())
+                            .on_input(|value| Message::Settings(
+                                SettingsMessage::APIJobsKeyChanged(value)
+                            ))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                    ]
+                    .spacing(5),
+                    row![
+                        column![
+                            text("Monthly Quota").size(12),
+                            text("0 = unlimited").size(10),
+                            number_input(self.apijobs_monthly_quota, 0..1_000_000, |value| {
+                                Message::Settings(SettingsMessage::APIJobsMonthlyQuotaChanged(
+                                    value,
+                                ))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Request Interval").size(12),
+                            text("Seconds, for each provider").size(10),
+                            number_input(self.request_interval_secs, 0..3600, |value| {
+                                Message::Settings(SettingsMessage::RequestIntervalChanged(
+                                    value,
+                                ))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    text(if self.apijobs_monthly_quota > 0 {
+                        format!(
+                            "{} of {} requests used this month ({} remaining)",
+                            self.apijobs_requests_this_month,
+                            self.apijobs_monthly_quota,
+                            (self.apijobs_monthly_quota - self.apijobs_requests_this_month).max(0)
+                        )
+                    } else {
+                        format!(
+                            "{} requests made this month",
+                            self.apijobs_requests_this_month
+                        )
+                    })
+                    .size(12),
+                    row![
+                        column![
+                            text("Adzuna App ID").size(12),
+                            text_input("", &self.adzuna_app_id)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::AdzunaAppIdChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Adzuna App Key").size(12),
+                            text_input("", &self.adzuna_app_key)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::AdzunaAppKeyChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .secure(true)
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    column![
+                        text("Archive Unapplied Posts After (Days)").size(12),
+                        text("0 = never").size(10),
+                        number_input(self.retention_days, 0..3651, |value| Message::Settings(
+                            SettingsMessage::RetentionDaysChanged(value)
+                        ))
+                        .padding(5)
+                        .style(number_input::number_input::primary)
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Target Salary (Yearly, USD)").size(12),
+                        text("Posts are compared against this, 0 = not set").size(10),
+                        number_input(self.target_salary, 0..100_000_000, |value| {
+                            Message::Settings(SettingsMessage::TargetSalaryChanged(value))
+                        })
+                        .padding(5)
+                        .style(number_input::number_input::primary)
+                    ]
+                    .spacing(5),
+                    row![
+                        button(text("Reclassify Industries"))
+                            .on_press(Message::Settings(SettingsMessage::ReclassifyIndustries)),
+                        text(format!("After editing {}", industry::INDUSTRIES_FILE)).size(10),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                    column![
+                        text("Run Alerts Every (Hours)").size(12),
+                        text("Saved searches import new matches, 0 = each alert's own").size(10),
+                        number_input(self.alert_interval_hours, 0..721, |value| {
+                            Message::Settings(SettingsMessage::AlertIntervalChanged(value))
+                        })
+                        .padding(5)
+                        .style(number_input::number_input::primary)
+                    ]
+                    .spacing(5),
+                    row![
+                        column![
+                            text("Job Board Title Keywords").size(12),
+                            text("Comma-separated, empty = all").size(10),
+                            text_input("", &self.board_title_keywords)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::BoardKeywordsChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(2))
+                        .spacing(5),
+                        column![
+                            text("Sync Every (Hours)").size(12),
+                            text("0 = manual").size(10),
+                            number_input(self.board_sync_interval_hours, 0..721, |value| {
+                                Message::Settings(SettingsMessage::BoardSyncIntervalChanged(value))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    column![
+                        text("Proxy URL").size(12),
+                        text("http://host:port or socks5://host:port, browser changes apply on restart")
+                            .size(10),
+                        text_input("", &self.proxy_url)
+                            .on_input(|value| Message::Settings(SettingsMessage::ProxyURLChanged(
+                                value
+                            )))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                    ]
+                    .spacing(5),
+                    row![
+                        column![
+                            text("Proxy Username").size(12),
+                            text_input("", &self.proxy_username)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::ProxyUsernameChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Proxy Password").size(12),
+                            text_input("", &self.proxy_password)
+                                .secure(true)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::ProxyPasswordChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    column![
+                        text("User Agent").size(12),
+                        text("Empty = default, browser changes apply on restart").size(10),
+                        text_input("", &self.user_agent)
+                            .on_input(|value| Message::Settings(SettingsMessage::UserAgentChanged(
+                                value
+                            )))
+                            .on_submit(submit_message.clone())
+                            .padding(5)
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Extra Request Headers").size(12),
+                        text("One \"Name: value\" per line, API requests only").size(10),
+                        text_editor(&self.extra_headers)
+                            .on_action(|value| Message::Settings(
+                                SettingsMessage::ExtraHeadersEdited(value)
+                            ))
+                            .height(Length::Fixed(60.0))
+                            .padding(5)
+                    ]
+                    .spacing(5),
+                    column![
+                        text("Browser").size(12),
+                        text("Scrapes with its WebDriver server, e.g. chromedriver for Chrome")
+                            .size(10),
+                        pick_list(scraper::Driver::ALL, Some(self.driver), |value| {
+                            Message::Settings(SettingsMessage::DriverChanged(value))
+                        })
+                        .padding(5)
+                    ]
+                    .spacing(5),
+                    row![
+                        column![
+                            text("WebDriver Path").size(12),
+                            text(format!("Empty = {}", self.driver.default_command())).size(10),
+                            text_input("", &self.driver_path)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::DriverPathChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(2))
+                        .spacing(5),
+                        column![
+                            text("Port").size(12),
+                            text(format!("0 = {}", self.driver.default_port())).size(10),
+                            number_input(self.driver_port, 0..=u16::MAX, |value| {
+                                Message::Settings(SettingsMessage::DriverPortChanged(value))
+                            })
+                            .padding(5)
+                            .style(number_input::number_input::primary)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    row![
+                        button(text("Test Connection")).on_press_maybe(
+                            (!self.is_offline() && !self.driver_testing)
+                                .then_some(Message::Settings(SettingsMessage::TestWebDriver))
+                        ),
+                        text(self.driver_status.clone().unwrap_or_default()).size(12),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                    column![
+                        checkbox("Desktop Notifications", self.notifications_enabled).on_toggle(
+                            |value| Message::Settings(SettingsMessage::NotificationsToggled(value))
+                        ),
+                        text("Interview reminders, new alert matches, and finished syncs and fetches")
+                            .size(10),
+                    ]
+                    .spacing(5),
+                    row![
+                        column![
+                            text("Theme").size(12),
+                            text("System follows the OS's light or dark mode").size(10),
+                            pick_list(theme::choices(), Some(self.theme_name.clone()), |value| {
+                                Message::Settings(SettingsMessage::ThemeChanged(value))
+                            })
+                            .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("Accent Color").size(12),
+                            text("#rrggbb, empty = the theme's").size(10),
+                            text_input("", &self.accent_color)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::AccentColorChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    row![
+                        column![
+                            text("Profile").size(12),
+                            text("Each profile has its own database").size(10),
+                            pick_list(paths::profiles(), Some(self.profile_name.clone()), |value| {
+                                Message::Settings(SettingsMessage::ProfileChanged(value))
+                            })
+                            .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                        column![
+                            text("New Profile").size(12),
+                            text("Creates and switches to it").size(10),
+                            text_input("", &self.new_profile)
+                                .on_input(|value| Message::Settings(
+                                    SettingsMessage::NewProfileChanged(value)
+                                ))
+                                .on_submit(submit_message.clone())
+                                .padding(5)
+                        ]
+                        .width(Length::FillPortion(1))
+                        .spacing(5),
+                    ]
+                    .spacing(10),
+                    settings_error,
+                    row![
+                        container(button(text("Cancel")).on_press(Message::HideModal))
+                            .width(Fill)
+                            .align_x(Alignment::End),
+                        container(button(text("Save")).on_press(submit_message.clone())),
+                    ]
+                    .spacing(10)
+                    .width(Fill)
+                ]
+                .spacing(10),
+            ]
+            .spacing(20),
+        )
+        .width(300)
+        .padding(10)
+        .style(container::rounded_box)
+        .into()
+    }
+}
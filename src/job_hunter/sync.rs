@@ -0,0 +1,86 @@
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum SyncMessage {
+    SyncBoards,
+    BoardsSynced(InsertCounts),
+    BoardSyncFailed(String),
+    /// Imports RemoteOK's feed, narrowed by the job title filter
+    SyncRemoteOk,
+    RemoteOkSynced(InsertCounts),
+    RemoteOkSyncFailed(String),
+}
+
+impl JobHunter {
+    pub(super) fn update_sync(&mut self, message: SyncMessage) -> Task<Message> {
+        match message {
+            SyncMessage::SyncBoards => {
+                if self.boards_syncing || self.is_offline() {
+                    return Task::none();
+                }
+                self.boards_syncing = true;
+                self.last_board_sync = Some(std::time::Instant::now());
+                Task::perform(
+                    boards::sync_boards(
+                        self.http_client(),
+                        self.config.board_title_keywords.clone(),
+                        self.db.clone(),
+                    ),
+                    |res| match res {
+                        Ok(inserted) => Message::Sync(SyncMessage::BoardsSynced(inserted)),
+                        Err(e) => Message::Sync(SyncMessage::BoardSyncFailed(e.to_string())),
+                    },
+                )
+            }
+            SyncMessage::BoardsSynced(inserted) => {
+                self.boards_syncing = false;
+                self.set_field_suggestions();
+                self.status_message = Some(format!("Imported {} from job boards", inserted));
+                if inserted.inserted > 0 {
+                    self.notify("Job board sync finished", &format!("Imported {}", inserted));
+                }
+                self.get_filter_task()
+            }
+            SyncMessage::BoardSyncFailed(error) => {
+                self.boards_syncing = false;
+                self.status_message = Some(format!("Job board sync failed: {error}"));
+                Task::none()
+            }
+            SyncMessage::SyncRemoteOk => {
+                if self.remoteok_syncing || self.is_offline() || !self.remoteok_enabled() {
+                    return Task::none();
+                }
+                self.remoteok_syncing = true;
+                Task::perform(
+                    api::remoteok::sync_feed(
+                        self.http_client(),
+                        self.config.user_agent.clone(),
+                        self.filter.job_title.clone(),
+                        self.db.clone(),
+                    ),
+                    |res| match res {
+                        Ok(inserted) => Message::Sync(SyncMessage::RemoteOkSynced(inserted)),
+                        Err(e) => Message::Sync(SyncMessage::RemoteOkSyncFailed(e.to_string())),
+                    },
+                )
+            }
+            SyncMessage::RemoteOkSynced(inserted) => {
+                self.remoteok_syncing = false;
+                self.set_field_suggestions();
+                self.status_message = Some(format!("Imported {} from RemoteOK", inserted));
+                self.get_filter_task()
+            }
+            SyncMessage::RemoteOkSyncFailed(error) => {
+                self.remoteok_syncing = false;
+                self.status_message = Some(format!("RemoteOK import failed: {error}"));
+                Task::none()
+            }
+        }
+    }
+
+    /// Whether RemoteOK is turned on in Settings, which its feed import goes by too
+    pub(super) fn remoteok_enabled(&self) -> bool {
+        api::Provider::from_id(api::remoteok::REMOTEOK_PROVIDER)
+            .is_some_and(|provider| self.config.provider_settings().is_enabled(provider))
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Local};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+use crate::paths;
+
+/// Under the data directory
+const LOG_DIR: &str = "logs";
+const LOG_FILE_PREFIX: &str = "job-hunter";
+const MAX_LOG_FILES: usize = 7;
+// Entries kept in memory for the log viewer
+const MAX_BUFFERED_ENTRIES: usize = 1000;
+
+static LOG_BUFFER: Mutex<VecDeque<LogEntry>> = Mutex::new(VecDeque::new());
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Collects the `message` field followed by any other fields as `name=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            _ = write!(self.message, "{:?}", value);
+        } else {
+            _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+}
+
+/// Keeps recent events in memory so they can be shown in the log viewer
+struct BufferLayer;
+
+impl<S: Subscriber> Layer<S> for BufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let entry = LogEntry {
+            time: Local::now(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.message + visitor.fields.as_str(),
+        };
+        let mut buffer = LOG_BUFFER.lock().expect("Failed to lock log buffer");
+        if buffer.len() >= MAX_BUFFERED_ENTRIES {
+            buffer.pop_front();
+        }
+        buffer.push_back(entry);
+    }
+}
+
+/// Logs to stdout, a daily rotating file in the data directory's `logs/`, and the in-memory
+/// buffer. When the log directory can't be written, logs go to stdout and the buffer only.
+/// The returned guard flushes the file writer when dropped, so keep it alive until exit.
+pub fn init() -> anyhow::Result<Option<WorkerGuard>> {
+    let log_dir = paths::data_file(LOG_DIR);
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(&log_dir);
+    let (file_layer, guard, file_error) = match appender {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false);
+            (Some(layer), Some(guard), None)
+        }
+        Err(e) => (None, None, Some(e)),
+    };
+
+    tracing_subscriber::registry()
+        .with(file_layer)
+        .with(tracing_subscriber::fmt::layer())
+        .with(BufferLayer)
+        // Dependencies (sqlx, hyper, etc.) are noisy below INFO
+        .with(
+            tracing_subscriber::filter::Targets::new()
+                .with_default(LevelFilter::INFO)
+                .with_target("job_hunter", LevelFilter::DEBUG),
+        )
+        .try_init()?;
+
+    if let Some(e) = file_error {
+        tracing::warn!("Not logging to {}: {:?}", log_dir.display(), e);
+    }
+    Ok(guard)
+}
+
+/// Buffered entries at or above `min_level`, newest first
+pub fn recent(min_level: Level) -> Vec<LogEntry> {
+    let buffer = LOG_BUFFER.lock().expect("Failed to lock log buffer");
+    buffer
+        .iter()
+        .rev()
+        // tracing orders levels by verbosity, so TRACE is the greatest
+        .filter(|entry| entry.level <= min_level)
+        .cloned()
+        .collect()
+}
+
+pub fn clear() {
+    LOG_BUFFER
+        .lock()
+        .expect("Failed to lock log buffer")
+        .clear();
+}
@@ -1,75 +1,291 @@
 mod api;
+mod boards;
 mod components;
 mod db;
+mod draft;
+mod export;
+mod favicon;
+mod hn;
+mod import;
+mod industry;
+mod interview;
 mod job_hunter;
+mod logging;
+mod notifications;
+mod paths;
+mod plugins;
 mod scraper;
+mod secrets;
+mod theme;
 mod utils;
+mod validation;
 
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::io::Write;
+use std::path::{Path, PathBuf};
 
-use db::{bootstrap_sqlx_migrations, connect, migrate};
 use job_hunter::JobHunter;
 
 #[derive(Parser)]
 pub struct Cli {
-    db_path: Option<std::path::PathBuf>,
+    /// Database file, instead of the one in the platform's data directory
+    #[arg(long)]
+    db_path: Option<PathBuf>,
+    /// Config file, instead of the one in the platform's config directory
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Profile to open for this run, each with its own database, e.g. one per job search
+    #[arg(long, value_parser = paths::parse_profile_name)]
+    profile: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct AppConfig {
     apijobs_key: String,
+    /// Requests allowed per calendar month (0 = unlimited)
+    apijobs_monthly_quota: i64,
+    /// Minimum delay between consecutive requests to each provider. Named for APIJobs, the
+    /// only provider, in older configs
+    #[serde(alias = "apijobs_request_interval_secs")]
+    request_interval_secs: u64,
+    adzuna_app_id: String,
+    adzuna_app_key: String,
+    /// Ids of job search providers turned off in Settings, so new ones start out on
+    disabled_providers: std::collections::BTreeSet<String>,
+    sidebar_collapsed: bool,
+    /// Shows applications as a board of status columns instead of the job post list
+    board_view: bool,
+    /// Archive unapplied posts older than this many days on startup (0 = never)
+    retention_days: i64,
+    /// Yearly salary I'm aiming for, in whole US dollars (0 = not set). Only posts paid in
+    /// dollars are held to it
+    target_salary: i64,
+    /// Comma-separated title keywords for job board syncs (empty = all postings)
+    board_title_keywords: String,
+    /// Hours between automatic job board syncs (0 = manual only)
+    board_sync_interval_hours: i64,
+    /// Hours between scheduled runs of every saved alert (0 = each alert's own interval)
+    alert_interval_hours: i64,
+    /// HTTP(S) or SOCKS proxy for API requests and the scraping browser (empty = none)
+    proxy_url: String,
+    proxy_username: String,
+    proxy_password: String,
+    /// Overrides the user agent for API requests and the scraping browser (empty = default)
+    user_agent: String,
+    /// Extra headers sent with API requests
+    extra_headers: std::collections::BTreeMap<String, String>,
+    /// Disables all network features until turned off
+    offline: bool,
+    /// Browser to scrape with
+    driver: scraper::Driver,
+    /// WebDriver server executable, e.g. geckodriver (empty = the bundled default)
+    #[serde(alias = "geckodriver_path")]
+    driver_path: String,
+    /// Port the WebDriver server listens on (0 = the browser's default)
+    #[serde(alias = "geckodriver_port")]
+    driver_port: u16,
+    /// When each profile was last opened, as UTC timestamps by profile name; the startup
+    /// digest covers what changed since
+    last_sessions: std::collections::BTreeMap<String, i64>,
+    /// When the app was last opened, from versions before profiles kept their own
+    #[serde(skip_serializing)]
+    last_session: i64,
+    /// Turns off desktop notifications for interview reminders and finished searches
+    mute_notifications: bool,
+    /// Name of a built-in theme, or "System" (empty = "System")
+    theme: String,
+    /// Hex color replacing the theme's primary color (empty = the theme's own)
+    accent_color: String,
+    /// Job posts per page (0 = 10)
+    job_page_size: i64,
+    /// Profile opened last, whose database is used (empty = "Default")
+    profile: String,
+    /// The file's profile while `--profile` opens another for this run only, saved in its
+    /// place until one is picked in Settings
+    #[serde(skip)]
+    saved_profile: Option<String>,
+    /// Where the config was read from, and is saved back to
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl AppConfig {
+    /// Writes `config.toml`, keeping credentials in the OS keyring when there is one
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut value = toml::Value::try_from(self)?;
+        if let Some(table) = value.as_table_mut() {
+            secrets::store(self, table);
+            if let Some(profile) = &self.saved_profile {
+                table.insert("profile".to_string(), profile.clone().into());
+            }
+        }
+        paths::ensure_parent(&self.path)?;
+        fs::write(&self.path, toml::to_string_pretty(&value)?)?;
+        Ok(())
+    }
+
+    pub fn network_settings(&self) -> api::NetworkSettings {
+        api::NetworkSettings {
+            proxy_url: self.proxy_url.clone(),
+            proxy_username: self.proxy_username.clone(),
+            proxy_password: self.proxy_password.clone(),
+            user_agent: self.user_agent.clone(),
+            headers: self.extra_headers.clone(),
+        }
+    }
+
+    pub fn provider_settings(&self) -> api::ProviderSettings {
+        api::ProviderSettings {
+            apijobs_key: self.apijobs_key.clone(),
+            apijobs_monthly_quota: self.apijobs_monthly_quota,
+            request_interval_secs: self.request_interval_secs,
+            adzuna_app_id: self.adzuna_app_id.clone(),
+            adzuna_app_key: self.adzuna_app_key.clone(),
+            user_agent: self.user_agent.clone(),
+            disabled: self.disabled_providers.clone(),
+        }
+    }
+
+    /// The open profile's name, "Default" for the default one
+    fn profile_name(&self) -> &str {
+        if paths::is_default_profile(&self.profile) {
+            paths::DEFAULT_PROFILE
+        } else {
+            &self.profile
+        }
+    }
+
+    /// When the open profile was last opened, as a UTC timestamp (0 = never)
+    pub fn last_session(&self) -> i64 {
+        match self.last_sessions.get(self.profile_name()) {
+            Some(timestamp) => *timestamp,
+            None if paths::is_default_profile(&self.profile) => self.last_session,
+            None => 0,
+        }
+    }
+
+    pub fn set_last_session(&mut self, timestamp: i64) {
+        self.last_sessions
+            .insert(self.profile_name().to_string(), timestamp);
+    }
+
+    pub fn job_page_size(&self) -> i64 {
+        if self.job_page_size > 0 {
+            self.job_page_size
+        } else {
+            10
+        }
+    }
+
+    pub fn driver_settings(&self) -> scraper::DriverSettings {
+        scraper::DriverSettings {
+            driver: self.driver,
+            path: self.driver_path.clone(),
+            port: self.driver_port,
+        }
+    }
+}
+
+/// Moves `file_name` from the working directory to `path` with `migrate`, logging the outcome
+fn migrate_legacy(file_name: &str, path: &Path, migrate: fn(&Path, &Path) -> anyhow::Result<bool>) {
+    match migrate(Path::new(file_name), path) {
+        Ok(true) => tracing::info!("Moved {} to {}", file_name, path.display()),
+        Ok(false) => {}
+        Err(e) => tracing::warn!(
+            "Failed to move {} to {}: {:?}",
+            file_name,
+            path.display(),
+            e
+        ),
+    }
 }
 
 fn main() -> iced::Result {
+    // Flushes the log file when dropped
+    let _log_guard = logging::init().unwrap_or_else(|e| {
+        eprintln!("Failed to initialize logging: {:?}", e);
+        None
+    });
+
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
         .unwrap();
 
-    let cfg: AppConfig = {
-        let path = std::path::Path::new("config.toml");
-        if path.exists() {
-            let content = fs::read_to_string(path).expect("Failed to read config");
-            toml::from_str(&content).expect("Failed to initiliaze config")
+    let args = Cli::parse();
+    // Files older versions kept in the working directory move to the platform's directories
+    let config_path = args.config.unwrap_or_else(|| {
+        let path = paths::default_config_path();
+        migrate_legacy(paths::CONFIG_FILE, &path, paths::migrate_legacy);
+        path
+    });
+    let mut cfg: AppConfig = {
+        if config_path.exists() {
+            let content = fs::read_to_string(&config_path).expect("Failed to read config");
+            AppConfig {
+                path: config_path,
+                ..toml::from_str(&content).expect("Failed to initiliaze config")
+            }
         } else {
             let default = AppConfig {
-                apijobs_key: String::new(),
+                path: config_path,
+                ..AppConfig::default()
             };
-            let toml_str = toml::to_string_pretty(&default).expect("Failed to initiliaze config");
-            let mut file = fs::File::create(path).expect("Failed to create config");
-            file.write_all(toml_str.as_bytes())
-                .expect("Failed to write config");
+            default.save().expect("Failed to write config");
             default
         }
     };
+    // Credentials left in the file by older versions move to the keyring
+    if secrets::load(&mut cfg) {
+        cfg.save().expect("Failed to write config");
+    }
 
-    let conn = runtime.block_on(async {
-        // Get db path argument (mostly for dev purposes)
-        let args = Cli::parse();
-        let db_path = args.db_path.unwrap_or_else(|| "jobhunter.db".into());
+    // Files the user edits go with the config, and files the app keeps with the data
+    for file_name in [
+        scraper::SELECTORS_FILE,
+        industry::INDUSTRIES_FILE,
+        plugins::PLUGINS_DIR,
+    ] {
+        migrate_legacy(
+            file_name,
+            &paths::config_file(file_name),
+            paths::migrate_legacy,
+        );
+    }
+    for file_name in [draft::JOB_POST_DRAFT_FILE, job_hunter::FILTER_STATE_FILE] {
+        migrate_legacy(
+            file_name,
+            &paths::data_file(file_name),
+            paths::migrate_legacy,
+        );
+    }
 
-        let db_existed: bool = db_path.exists();
-
-        if !db_existed {
-            db::create(db_path.to_str().expect("Invalid database path")).await;
+    if let Some(profile) = args.profile {
+        cfg.saved_profile = Some(std::mem::replace(&mut cfg.profile, profile));
+    }
+    let db_path = args.db_path.unwrap_or_else(|| {
+        let path = paths::profile_db_path(&cfg.profile);
+        if paths::is_default_profile(&cfg.profile) {
+            migrate_legacy(paths::DB_FILE, &path, paths::migrate_legacy_db);
         }
-
-        let conn = connect(db_path.to_str().expect("Invalid database path")).await;
-        if db_existed {
-            bootstrap_sqlx_migrations(&conn).await;
-        }
-        migrate(&conn).await;
-
-        conn
+        path
     });
+    let db_existed: bool = db_path.exists();
+    if !db_existed {
+        paths::ensure_parent(&db_path).expect("Failed to create database directory");
+    }
+    // Migrations run once the window is up; see `JobHunter::new`
+    let conn = {
+        let _guard = runtime.enter();
+        db::connect_lazy(db_path.to_str().expect("Invalid database path"))
+    };
 
     let handle = runtime.handle().clone();
 
     iced::daemon(JobHunter::title, JobHunter::update, JobHunter::view)
         .theme(JobHunter::theme)
         .subscription(JobHunter::subscription)
-        .run_with(|| JobHunter::new(conn, handle, cfg))
+        .run_with(move || JobHunter::new(conn, db_existed, handle, cfg))
 }
@@ -0,0 +1,16 @@
+/* OS notifications (Notification Center, toasts, freedesktop) */
+
+const APP_NAME: &str = "Job Hunter";
+
+/// Shows a desktop notification. Failures are only logged, since a missing notification
+/// daemon shouldn't get in the way of anything else.
+pub fn show(summary: &str, body: &str) {
+    let res = notify_rust::Notification::new()
+        .appname(APP_NAME)
+        .summary(summary)
+        .body(body)
+        .show();
+    if let Err(e) = res {
+        tracing::warn!("Failed to show notification: {:?}", e);
+    }
+}
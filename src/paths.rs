@@ -0,0 +1,229 @@
+use std::path::{Path, PathBuf};
+
+use directories::ProjectDirs;
+
+pub const CONFIG_FILE: &str = "config.toml";
+pub const DB_FILE: &str = "jobhunter.db";
+/// Under the data directory, a database per profile other than the default
+const PROFILES_DIR: &str = "profiles";
+/// The profile using `jobhunter.db`
+pub const DEFAULT_PROFILE: &str = "Default";
+/// Files SQLite keeps beside the database while it's open
+const DB_SIDECARS: [&str; 2] = ["-wal", "-shm"];
+
+fn project_dirs() -> Option<ProjectDirs> {
+    ProjectDirs::from("", "", "job-hunter")
+}
+
+/// The platform's config directory, e.g. `~/.config/job-hunter` on Linux. The working
+/// directory when the platform has none.
+fn config_dir() -> PathBuf {
+    match project_dirs() {
+        Some(dirs) => dirs.config_dir().to_path_buf(),
+        None => PathBuf::new(),
+    }
+}
+
+/// `config.toml` in the platform's config directory
+pub fn default_config_path() -> PathBuf {
+    config_file(CONFIG_FILE)
+}
+
+/// A file or directory the user edits, e.g. `selectors.toml`, in the config directory
+pub fn config_file(name: &str) -> PathBuf {
+    config_dir().join(name)
+}
+
+/// The platform's data directory, e.g. `~/.local/share/job-hunter` on Linux. The working
+/// directory when the platform has none.
+fn data_dir() -> PathBuf {
+    match project_dirs() {
+        Some(dirs) => dirs.data_dir().to_path_buf(),
+        None => PathBuf::new(),
+    }
+}
+
+/// A file or directory the app writes, e.g. `logs`, in the data directory
+pub fn data_file(name: &str) -> PathBuf {
+    data_dir().join(name)
+}
+
+/// A directory of files that can be fetched again, e.g. `favicons`, in the platform's cache
+/// directory such as `~/.cache/job-hunter` on Linux. The working directory when the platform
+/// has none.
+pub fn cache_dir(name: &str) -> PathBuf {
+    match project_dirs() {
+        Some(dirs) => dirs.cache_dir().join(name),
+        None => PathBuf::from(name),
+    }
+}
+
+/// `jobhunter.db` in the platform's data directory
+pub fn default_db_path() -> PathBuf {
+    data_dir().join(DB_FILE)
+}
+
+/// Whether `profile` means the default profile; older configs leave it empty
+pub fn is_default_profile(profile: &str) -> bool {
+    profile.is_empty() || profile == DEFAULT_PROFILE
+}
+
+/// The database of a profile, e.g. `profiles/2025.db` in the data directory
+pub fn profile_db_path(profile: &str) -> PathBuf {
+    if is_default_profile(profile) {
+        return default_db_path();
+    }
+    data_dir().join(PROFILES_DIR).join(format!("{profile}.db"))
+}
+
+/// A file kept for each profile, e.g. `profiles/2025/filters.toml` in the data directory.
+/// The default profile's is in the data directory itself.
+pub fn profile_data_file(profile: &str, name: &str) -> PathBuf {
+    if is_default_profile(profile) {
+        return data_file(name);
+    }
+    data_dir().join(PROFILES_DIR).join(profile).join(name)
+}
+
+/// "Default" followed by every other profile with a database, by name
+pub fn profiles() -> Vec<String> {
+    let mut profiles: Vec<String> = std::fs::read_dir(data_dir().join(PROFILES_DIR))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "db").then_some(path.file_stem()?.to_str()?.to_string())
+        })
+        .filter(|profile| !is_default_profile(profile))
+        .collect();
+    profiles.sort_by_key(|profile| profile.to_lowercase());
+    std::iter::once(DEFAULT_PROFILE.to_string())
+        .chain(profiles)
+        .collect()
+}
+
+/// A profile name that's safe as a file name: letters, digits, spaces, '-', and '_'
+pub fn parse_profile_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Profile name is empty".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == ' ' || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Profile name {name} can only have letters, digits, spaces, '-', and '_'"
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Moves `legacy`, where older versions kept a file, to `path` unless something is already
+/// there. Returns whether it moved.
+pub fn migrate_legacy(legacy: &Path, path: &Path) -> anyhow::Result<bool> {
+    if path.exists() || !legacy.exists() || legacy == path {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    move_file(legacy, path)?;
+    Ok(true)
+}
+
+/// Like `migrate_legacy`, bringing along the database's write-ahead log
+pub fn migrate_legacy_db(legacy: &Path, path: &Path) -> anyhow::Result<bool> {
+    if !migrate_legacy(legacy, path)? {
+        return Ok(false);
+    }
+    for suffix in DB_SIDECARS {
+        let sidecar = |db: &Path| {
+            let mut name = db.as_os_str().to_owned();
+            name.push(suffix);
+            PathBuf::from(name)
+        };
+        let legacy_sidecar = sidecar(legacy);
+        if legacy_sidecar.exists() {
+            move_file(&legacy_sidecar, &sidecar(path))?;
+        }
+    }
+    Ok(true)
+}
+
+/// Renames, or copies and deletes when the two are on different file systems
+fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    std::fs::copy(from, to)?;
+    std::fs::remove_file(from)
+}
+
+/// Makes sure the directory a file goes in exists
+pub fn ensure_parent(path: &Path) -> std::io::Result<()> {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => std::fs::create_dir_all(parent),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profiles_get_their_own_databases() {
+        assert_eq!(profile_db_path(""), default_db_path());
+        assert_eq!(profile_db_path(DEFAULT_PROFILE), default_db_path());
+        assert!(profile_db_path("2025 Search").ends_with("profiles/2025 Search.db"));
+        assert_eq!(
+            parse_profile_name(" 2025 Search ").as_deref(),
+            Ok("2025 Search")
+        );
+        assert!(parse_profile_name("../other").is_err());
+        assert!(parse_profile_name("  ").is_err());
+        assert_eq!(profiles()[0], DEFAULT_PROFILE);
+        assert_eq!(
+            profile_data_file(DEFAULT_PROFILE, "filters.toml"),
+            data_file("filters.toml")
+        );
+        assert!(profile_data_file("2025 Search", "filters.toml")
+            .ends_with("profiles/2025 Search/filters.toml"));
+    }
+
+    #[test]
+    fn app_files_stay_out_of_the_working_directory() {
+        assert_eq!(default_config_path(), config_file(CONFIG_FILE));
+        assert_eq!(default_db_path(), data_file(DB_FILE));
+        if project_dirs().is_some() {
+            assert!(config_file("selectors.toml").is_absolute());
+            assert!(data_file("logs").is_absolute());
+            assert!(cache_dir("favicons").is_absolute());
+        }
+    }
+
+    #[test]
+    fn legacy_files_move_unless_replaced() {
+        let dir = std::env::temp_dir().join(format!("job-hunter-paths-{}", std::process::id()));
+        let legacy = dir.join(DB_FILE);
+        let path = dir.join("data").join(DB_FILE);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&legacy, "db").unwrap();
+        std::fs::write(dir.join("jobhunter.db-wal"), "wal").unwrap();
+
+        assert!(migrate_legacy_db(&legacy, &path).unwrap());
+        assert!(!legacy.exists());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "db");
+        assert_eq!(
+            std::fs::read_to_string(dir.join("data").join("jobhunter.db-wal")).unwrap(),
+            "wal"
+        );
+
+        std::fs::write(&legacy, "older").unwrap();
+        assert!(!migrate_legacy_db(&legacy, &path).unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "db");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,229 @@
+use chrono::{NaiveDate, Utc};
+
+use crate::db::{
+    job_post::{JobPost, JobPostLocationType},
+    NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
+};
+use crate::paths;
+
+/// Under the config directory
+pub const PLUGINS_DIR: &str = "plugins";
+
+/// Stops a runaway script from hanging a scrape
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// A site scraper written in Rhai, loaded from `plugins/*.rhai`.
+///
+/// A script defines `url_pattern()`, returning text the job post URL must contain, and
+/// `scrape(url, html)`, returning a map of job post fields: `company`, `job_title`,
+/// `location`, `location_type` ("Onsite", "Hybrid", or "Remote"), `date_posted`
+/// ("YYYY-MM-DD" or "3 days ago"), `min_yoe`, `max_yoe`, `min_pay`, `max_pay`, `pay_unit`,
+/// `currency`, `skills`, `benefits`, and `description`. Missing fields are left empty
+#[derive(Debug, Clone)]
+pub struct ScraperPlugin {
+    pub name: String,
+    pub url_pattern: String,
+    ast: rhai::AST,
+}
+
+fn engine() -> rhai::Engine {
+    let mut engine = rhai::Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    // First capture group of the first match, or "" when there is none
+    engine.register_fn("regex_capture", |text: &str, pattern: &str| -> String {
+        regex::Regex::new(pattern)
+            .ok()
+            .and_then(|re| re.captures(text))
+            .and_then(|caps| caps.get(1).or_else(|| caps.get(0)))
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_default()
+    });
+    engine.register_fn("strip_tags", |html: &str| -> String {
+        let re = regex::Regex::new(r"<[^>]*>").expect("Failed to make regex");
+        re.replace_all(html, " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+    engine
+}
+
+impl ScraperPlugin {
+    pub fn compile(name: &str, script: &str) -> anyhow::Result<Self> {
+        let engine = engine();
+        let ast = engine
+            .compile(script)
+            .map_err(|e| anyhow::anyhow!("{name}: {e}"))?;
+        let url_pattern: String = engine
+            .call_fn(&mut rhai::Scope::new(), &ast, "url_pattern", ())
+            .map_err(|e| anyhow::anyhow!("{name}: url_pattern: {e}"))?;
+        if url_pattern.trim().is_empty() {
+            anyhow::bail!("{name}: url_pattern is empty");
+        }
+        Ok(Self {
+            name: name.to_string(),
+            url_pattern,
+            ast,
+        })
+    }
+
+    pub fn matches(&self, url: &str) -> bool {
+        url.contains(&self.url_pattern)
+    }
+
+    /// Runs the script on a page, returning the company name and job post it found
+    pub fn scrape(&self, url: &str, html: &str) -> anyhow::Result<(Option<String>, JobPost)> {
+        let fields: rhai::Map = engine()
+            .call_fn(
+                &mut rhai::Scope::new(),
+                &self.ast,
+                "scrape",
+                (url.to_string(), html.to_string()),
+            )
+            .map_err(|e| anyhow::anyhow!("{}: scrape: {e}", self.name))?;
+
+        let string = |key: &str| {
+            fields
+                .get(key)
+                .and_then(|value| value.clone().into_string().ok())
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let int = |key: &str| fields.get(key).and_then(|value| value.as_int().ok());
+        let pay_cents = |key: &str| {
+            fields.get(key).and_then(|value| {
+                value
+                    .as_float()
+                    .ok()
+                    .or_else(|| value.as_int().ok().map(|value| value as f64))
+                    .map(|value| (value * 100.0).round() as i64)
+            })
+        };
+        let location_type = string("location_type")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(JobPostLocationType::Onsite);
+        let date_posted = match string("date_posted") {
+            Some(value) => match NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+                Ok(date) => NullableSqliteDateTime(Some(date)),
+                Err(_) => NullableSqliteDateTime::from_relative(&value),
+            },
+            None => NullableSqliteDateTime(None),
+        };
+
+        Ok((
+            string("company"),
+            JobPost {
+                id: -1,
+                company_id: -1,
+                location: string("location").unwrap_or_default(),
+                location_type,
+                url: url.to_string(),
+                min_yoe: int("min_yoe"),
+                max_yoe: int("max_yoe"),
+                min_pay_cents: pay_cents("min_pay"),
+                max_pay_cents: pay_cents("max_pay"),
+                date_posted,
+                date_retrieved: SqliteDateTime(Utc::now()),
+                job_title: string("job_title").unwrap_or_default(),
+                benefits: string("benefits"),
+                skills: string("skills"),
+                industry: None,
+                pay_unit: string("pay_unit"),
+                currency: string("currency"),
+                platform_url: None,
+                apijobs_id: None,
+                notes: None,
+                archived: SqliteBoolean(false),
+                favorite: SqliteBoolean(false),
+                expected_pay_cents: None,
+                tags: None,
+                description: string("description"),
+            },
+        ))
+    }
+}
+
+/// Compiles every script in the config directory's `plugins/`. Broken scripts are logged and skipped so one bad
+/// plugin doesn't take the rest down with it
+pub fn load_plugins() -> Vec<ScraperPlugin> {
+    let Ok(entries) = std::fs::read_dir(paths::config_file(PLUGINS_DIR)) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rhai"))
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            let res = std::fs::read_to_string(&path)
+                .map_err(Into::into)
+                .and_then(|script| ScraperPlugin::compile(&name, &script));
+            match res {
+                Ok(plugin) => Some(plugin),
+                Err(e) => {
+                    tracing::warn!("Skipping scraper plugin {}: {:?}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = r#"
+        fn url_pattern() { "boards.example.com/" }
+
+        fn scrape(url, html) {
+            #{
+                company: regex_capture(html, "<h2>([^<]+)</h2>"),
+                job_title: strip_tags(regex_capture(html, "<h1>(.+?)</h1>")),
+                location_type: "Remote",
+                date_posted: "2025-03-02",
+                min_yoe: 3,
+                max_pay: 150000.5,
+            }
+        }
+    "#;
+
+    #[test]
+    fn plugin_scrapes_fields_from_html() {
+        let plugin = ScraperPlugin::compile("example", SCRIPT).unwrap();
+        assert!(plugin.matches("https://boards.example.com/jobs/1"));
+        assert!(!plugin.matches("https://linkedin.com/jobs/view/1"));
+
+        let html = "<h1><b>Rust</b> Engineer</h1><h2>Acme</h2>";
+        let (company, post) = plugin
+            .scrape("https://boards.example.com/jobs/1", html)
+            .unwrap();
+        assert_eq!(company.as_deref(), Some("Acme"));
+        assert_eq!(post.job_title, "Rust Engineer");
+        assert_eq!(post.location_type, JobPostLocationType::Remote);
+        assert_eq!(post.date_posted.0, NaiveDate::from_ymd_opt(2025, 3, 2));
+        assert_eq!(post.min_yoe, Some(3));
+        assert_eq!(post.max_yoe, None);
+        assert_eq!(post.max_pay_cents, Some(15_000_050));
+        assert_eq!(post.url, "https://boards.example.com/jobs/1");
+    }
+
+    #[test]
+    fn scripts_without_a_pattern_are_rejected() {
+        assert!(ScraperPlugin::compile("broken", "fn scrape(url, html) { #{} }").is_err());
+        assert!(ScraperPlugin::compile("empty", r#"fn url_pattern() { "" }"#).is_err());
+    }
+
+    #[test]
+    fn runaway_scripts_are_stopped() {
+        let plugin = ScraperPlugin::compile(
+            "loop",
+            r#"fn url_pattern() { "x" } fn scrape(url, html) { loop {} }"#,
+        )
+        .unwrap();
+        assert!(plugin.scrape("x", "").is_err());
+    }
+}
@@ -1,45 +1,399 @@
 use chrono::Utc;
-use thirtyfour::By;
+use serde::{Deserialize, Serialize};
+use thirtyfour::common::capabilities::firefox::FirefoxPreferences;
+use thirtyfour::{
+    prelude::ElementQueryable, By, CapabilitiesHelper, ChromiumLikeCapabilities,
+    DesiredCapabilities, FirefoxCapabilities, Proxy,
+};
 
+use crate::api::NetworkSettings;
 use crate::db::{
     job_post::{JobPost, JobPostLocationType},
-    NullableSqliteDateTime, SqliteDateTime,
+    NullableSqliteDateTime, SqliteBoolean, SqliteDateTime,
 };
-use crate::utils::*;
+use crate::paths;
+use crate::plugins::ScraperPlugin;
+use crate::utils::{compensation::PayUnit, *};
+
+/// The browser scraping drives, through its WebDriver server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Driver {
+    /// geckodriver
+    #[default]
+    Firefox,
+    /// chromedriver
+    Chrome,
+    /// msedgedriver
+    Edge,
+}
+
+impl Driver {
+    pub const ALL: [Driver; 3] = [Driver::Firefox, Driver::Chrome, Driver::Edge];
+
+    /// The WebDriver server used when no path is set, expected beside the app on Linux and
+    /// macOS and on the PATH on Windows
+    pub fn default_command(&self) -> &'static str {
+        #[cfg(target_os = "windows")]
+        match self {
+            Driver::Firefox => "geckodriver",
+            Driver::Chrome => "chromedriver",
+            Driver::Edge => "msedgedriver",
+        }
+        #[cfg(not(target_os = "windows"))]
+        match self {
+            Driver::Firefox => "./geckodriver",
+            Driver::Chrome => "./chromedriver",
+            Driver::Edge => "./msedgedriver",
+        }
+    }
+
+    /// The port each server listens on by default
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Driver::Firefox => 4444,
+            Driver::Chrome | Driver::Edge => 9515,
+        }
+    }
 
-#[cfg(target_os = "windows")]
-pub const GECKODRIVER_CMD: &str = "geckodriver";
-#[cfg(not(target_os = "windows"))]
-pub const GECKODRIVER_CMD: &str = "./geckodriver";
+    /// Headless, routed through the configured proxy if there is one. Extra headers only
+    /// apply to API requests.
+    pub fn capabilities(
+        &self,
+        network: &NetworkSettings,
+    ) -> anyhow::Result<thirtyfour::Capabilities> {
+        match self {
+            Driver::Firefox => firefox_capabilities(network).map(Into::into),
+            Driver::Chrome => {
+                let mut caps = DesiredCapabilities::chrome();
+                chromium_options(&mut caps, network)?;
+                Ok(caps.into())
+            }
+            Driver::Edge => {
+                let mut caps = DesiredCapabilities::edge();
+                chromium_options(&mut caps, network)?;
+                Ok(caps.into())
+            }
+        }
+    }
+}
 
-pub const GECKODRIVER_PORT: &str = "4444";
+impl std::fmt::Display for Driver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Driver::Firefox => "Firefox",
+                Driver::Chrome => "Chrome",
+                Driver::Edge => "Edge",
+            }
+        )
+    }
+}
 
+/// Which browser to drive, where its WebDriver server is, and the port it listens on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriverSettings {
+    pub driver: Driver,
+    /// Empty = `Driver::default_command`
+    pub path: String,
+    /// 0 = `Driver::default_port`
+    pub port: u16,
+}
+
+impl DriverSettings {
+    pub fn command(&self) -> &str {
+        match self.path.trim() {
+            "" => self.driver.default_command(),
+            path => path,
+        }
+    }
+
+    pub fn port(&self) -> u16 {
+        match self.port {
+            0 => self.driver.default_port(),
+            port => port,
+        }
+    }
+}
+
+/// Starts the WebDriver server, first making sure nothing else holds its port
+pub fn spawn_driver(settings: &DriverSettings) -> anyhow::Result<std::process::Child> {
+    let port = settings.port();
+    if std::net::TcpListener::bind(("127.0.0.1", port)).is_err() {
+        anyhow::bail!("Port {port} is already in use");
+    }
+    // Both spellings work for geckodriver; chromedriver and msedgedriver only take this one
+    std::process::Command::new(settings.command())
+        .arg(format!("--port={port}"))
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Failed to start {}: {e}", settings.command()))
+}
+
+pub async fn connect_web_driver(
+    port: u16,
+    caps: thirtyfour::Capabilities,
+) -> anyhow::Result<thirtyfour::WebDriver> {
+    // Give a freshly spawned server a moment to start listening
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    thirtyfour::WebDriver::new(format!("http://127.0.0.1:{port}"), caps)
+        .await
+        .map_err(Into::into)
+}
+
+/// Starts a throwaway server from `settings`, opens and closes a session on it, then kills
+/// it. Any server the app is already running is left alone.
+pub async fn test_driver(
+    settings: DriverSettings,
+    caps: thirtyfour::Capabilities,
+) -> anyhow::Result<()> {
+    let mut process = spawn_driver(&settings)?;
+    let res = match connect_web_driver(settings.port(), caps).await {
+        Ok(driver) => driver.quit().await.map_err(Into::into),
+        Err(e) => Err(e),
+    };
+    _ = process.kill();
+    _ = process.wait();
+    res
+}
+
+/// A port nothing is listening on, for a test server that can't take the configured one
+pub fn free_port() -> anyhow::Result<u16> {
+    Ok(std::net::TcpListener::bind(("127.0.0.1", 0))?
+        .local_addr()?
+        .port())
+}
+
+/// A failed fetch might just be a page that didn't match; this tells whether
+/// the session itself is gone (e.g. the WebDriver server or browser died)
+pub async fn session_alive(driver: &thirtyfour::WebDriver) -> bool {
+    driver.title().await.is_ok()
+}
+
+/// The configured proxy, `None` if there isn't one. `socks_credentials` is whether the
+/// browser takes a SOCKS proxy's username and password through WebDriver.
+fn manual_proxy(
+    network: &NetworkSettings,
+    socks_credentials: bool,
+) -> anyhow::Result<Option<Proxy>> {
+    if network.proxy_url.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let url = reqwest::Url::parse(network.proxy_url.trim())?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow::anyhow!("Proxy URL has no host"))?;
+    let address = match url.port_or_known_default() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    };
+    let credentials =
+        |value: &str| (socks_credentials && !value.is_empty()).then(|| value.to_string());
+    let manual = match url.scheme() {
+        "http" | "https" => Proxy::Manual {
+            ftp_proxy: None,
+            http_proxy: Some(address.clone()),
+            ssl_proxy: Some(address),
+            socks_proxy: None,
+            socks_version: None,
+            socks_username: None,
+            socks_password: None,
+            no_proxy: None,
+        },
+        "socks4" | "socks5" | "socks5h" => Proxy::Manual {
+            ftp_proxy: None,
+            http_proxy: None,
+            ssl_proxy: None,
+            socks_proxy: Some(address),
+            socks_version: Some(if url.scheme() == "socks4" { 4 } else { 5 }),
+            socks_username: credentials(&network.proxy_username),
+            socks_password: credentials(&network.proxy_password),
+            no_proxy: None,
+        },
+        scheme => anyhow::bail!("Unsupported proxy scheme: {scheme}"),
+    };
+
+    Ok(Some(manual))
+}
+
+/// Headless Firefox, routed through the configured proxy if there is one.
+/// Firefox only accepts credentials for SOCKS proxies here; HTTP proxies that need
+/// auth will prompt inside the browser instead.
+pub fn firefox_capabilities(network: &NetworkSettings) -> anyhow::Result<FirefoxCapabilities> {
+    let mut caps = DesiredCapabilities::firefox();
+    caps.set_headless()?;
+    if !network.user_agent.trim().is_empty() {
+        let mut prefs = FirefoxPreferences::new();
+        prefs.set_user_agent(network.user_agent.trim().to_string())?;
+        caps.set_preferences(prefs)?;
+    }
+    if let Some(proxy) = manual_proxy(network, true)? {
+        caps.set_proxy(proxy)?;
+    }
+
+    Ok(caps)
+}
+
+/// Chrome and Edge take the same options. Neither accepts proxy credentials through
+/// WebDriver, so a proxy that needs them prompts inside the browser.
+fn chromium_options(
+    caps: &mut impl ChromiumLikeCapabilities,
+    network: &NetworkSettings,
+) -> anyhow::Result<()> {
+    caps.set_headless()?;
+    if !network.user_agent.trim().is_empty() {
+        caps.add_arg(&format!("--user-agent={}", network.user_agent.trim()))?;
+    }
+    if let Some(proxy) = manual_proxy(network, false)? {
+        caps.set_proxy(proxy)?;
+    }
+
+    Ok(())
+}
+
+/// Under the config directory
+pub const SELECTORS_FILE: &str = "selectors.toml";
+
+/// CSS selectors the site scrapers look for. Any of them can be overridden in `selectors.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Selectors {
+    pub linkedin: LinkedInSelectors,
+    pub indeed: IndeedSelectors,
+    pub workday: WorkdaySelectors,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LinkedInSelectors {
+    pub company: String,
+    pub job_title: String,
+    pub location: String,
+    pub description: String,
+    pub posted: String,
+    pub salary: String,
+}
+
+impl Default for LinkedInSelectors {
+    fn default() -> Self {
+        Self {
+            company: ".topcard__flavor a".to_string(),
+            // .job-details-jobs-unified-top-card__job-title h1
+            job_title: ".top-card-layout__title".to_string(),
+            // .job-details-jobs-unified-top-card__primary-description-container span.tvm__text
+            location: ".topcard__flavor.topcard__flavor--bullet".to_string(),
+            description: ".show-more-less-html__markup".to_string(),
+            posted: ".posted-time-ago__text".to_string(),
+            salary: ".salary.compensation__salary".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct IndeedSelectors {
+    pub company: String,
+    pub job_title: String,
+    pub location: String,
+    pub description: String,
+    pub salary: String,
+}
+
+impl Default for IndeedSelectors {
+    fn default() -> Self {
+        Self {
+            company: "[data-testid='inlineHeader-companyName']".to_string(),
+            job_title: "[data-testid='jobsearch-JobInfoHeader-title']".to_string(),
+            location: "[data-testid='inlineHeader-companyLocation'], [data-testid='job-location']"
+                .to_string(),
+            description: "#jobDescriptionText".to_string(),
+            salary: "#salaryInfoAndJobType".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WorkdaySelectors {
+    pub job_title: String,
+    pub location: String,
+    pub posted: String,
+    pub description: String,
+}
+
+impl Default for WorkdaySelectors {
+    fn default() -> Self {
+        Self {
+            job_title: "[data-automation-id='jobPostingHeader']".to_string(),
+            location: "[data-automation-id='locations'] dd".to_string(),
+            posted: "[data-automation-id='postedOn'] dd".to_string(),
+            description: "[data-automation-id='jobPostingDescription']".to_string(),
+        }
+    }
+}
+
+impl Selectors {
+    /// Reads `selectors.toml`, writing out the defaults first if it doesn't exist yet
+    pub fn load() -> anyhow::Result<Self> {
+        let path = paths::config_file(SELECTORS_FILE);
+        if !path.exists() {
+            let selectors = Self::default();
+            let content = toml::to_string_pretty(&selectors)?;
+            let res = paths::ensure_parent(&path).and_then(|_| std::fs::write(&path, content));
+            if let Err(e) = res {
+                tracing::warn!("Failed to write default selectors: {:?}", e);
+            }
+            return Ok(selectors);
+        }
+        Self::parse(&std::fs::read_to_string(&path)?)
+    }
+
+    /// Missing selectors fall back to the defaults
+    pub fn parse(content: &str) -> anyhow::Result<Self> {
+        toml::from_str(content)
+            .map_err(|e| anyhow::anyhow!("Invalid {SELECTORS_FILE}: {}", e.message()))
+    }
+}
+
+#[tracing::instrument(skip(driver, client, selectors, plugins), err)]
 pub async fn fetch_job_details(
     driver: thirtyfour::WebDriver,
+    client: reqwest::Client,
     url: String,
+    selectors: Selectors,
+    plugins: Vec<ScraperPlugin>,
 ) -> anyhow::Result<(Option<String>, Option<JobPost>)> {
+    // Plugins come first so one can stand in for a broken built-in scraper
+    if let Some(plugin) = plugins.iter().find(|plugin| plugin.matches(&url)) {
+        driver.goto(&url).await?;
+        let html = driver.source().await?;
+        let (company_name, job) = plugin.scrape(&url, &html)?;
+        return Ok((company_name, Some(job)));
+    }
+    // Lever serves postings as JSON, so the browser isn't needed
+    if let Some(api_url) = lever_api_url(&url) {
+        return fetch_lever(&client, &api_url, url).await;
+    }
     if url.contains("linkedin.com/jobs/view") {
+        let selectors = selectors.linkedin;
         driver.goto(&url).await?;
         // company name
-        let company = driver.find(By::Css(".topcard__flavor a")).await?;
+        let company = driver.find(By::Css(&selectors.company)).await?;
         let company_name = company.text().await?;
         // job title
-        let title = driver
-            // .find(By::Css(".job-details-jobs-unified-top-card__job-title h1"))
-            .find(By::Css(".top-card-layout__title"))
-            .await?;
+        let title = driver.find(By::Css(&selectors.job_title)).await?;
         let title_text = title.text().await?;
         // location
-        let location = driver
-            .find(By::Css(
-                // ".job-details-jobs-unified-top-card__primary-description-container span.tvm__text",
-                ".topcard__flavor.topcard__flavor--bullet",
-            ))
-            .await?;
+        let location = driver.find(By::Css(&selectors.location)).await?;
         let location_text = location.text().await?;
 
-        let desc = driver.find(By::Css(".show-more-less-html__markup")).await?;
+        let desc = driver.find(By::Css(&selectors.description)).await?;
         let desc_text = desc.outer_html().await?;
+        let description = description_from(&desc.text().await?);
         // location type
         let location_type;
         if desc_text.to_lowercase().contains("remote") {
@@ -50,14 +404,14 @@ pub async fn fetch_job_details(
             location_type = JobPostLocationType::Onsite;
         }
         // posted time
-        let posted = driver.find(By::Css(".posted-time-ago__text")).await?;
+        let posted = driver.find(By::Css(&selectors.posted)).await?;
         let posted_text = posted.text().await?;
         let posted_date = NullableSqliteDateTime::from_relative(&posted_text);
         // yoe (desc_text)
         // println!("desc_text {}", &desc_text);
         let (min_yoe, max_yoe) = find_yoe_naive(&desc_text);
         // pay (.salary.compensation__salary)
-        let salary = driver.find(By::Css(".salary.compensation__salary")).await;
+        let salary = driver.find(By::Css(&selectors.salary)).await;
         let salary_text = match salary {
             Ok(element) => element.text().await?,
             Err(_) => "".to_string(),
@@ -71,7 +425,7 @@ pub async fn fetch_job_details(
         } else {
             max_pay = None;
         }
-        if let Some((min_salary, _)) = parsed.get(0) {
+        if let Some((min_salary, _)) = parsed.first() {
             min_pay =
                 Some(get_pay_i64(format!("{min_salary}").as_str()).expect("Failed to get pay i64"));
         } else {
@@ -85,10 +439,10 @@ pub async fn fetch_job_details(
                 id: -1,
                 company_id: -1,
                 location: location_text,
-                location_type: location_type,
-                url: url,
-                min_yoe: min_yoe,
-                max_yoe: max_yoe,
+                location_type,
+                url,
+                min_yoe,
+                max_yoe,
                 min_pay_cents: min_pay,
                 max_pay_cents: max_pay,
                 date_posted: posted_date,
@@ -102,8 +456,669 @@ pub async fn fetch_job_details(
                 platform_url: Some("https://linkedin.com".to_string()),
                 apijobs_id: None,
                 notes: None,
+                archived: SqliteBoolean(false),
+                favorite: SqliteBoolean(false),
+                expected_pay_cents: None,
+                tags: None,
+                description,
             }),
         ));
     }
+    if let Some(url) = indeed_job_url(&url) {
+        return scrape_indeed(&driver, url, &selectors.indeed).await;
+    }
+    if is_workday_job_url(&url) {
+        return scrape_workday(&driver, url, &selectors.workday).await;
+    }
     Ok((None, None))
 }
+
+/// The post's text, trimmed, `None` when there's none
+fn description_from(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Checks the location first, since descriptions often mention remote teams or hybrid roles
+/// other than the one posted
+fn location_type_from_text(location: &str, description: &str) -> JobPostLocationType {
+    let location = location.to_lowercase();
+    let description = description.to_lowercase();
+    if location.contains("remote") {
+        JobPostLocationType::Remote
+    } else if location.contains("hybrid") {
+        JobPostLocationType::Hybrid
+    } else if description.contains("remote") {
+        JobPostLocationType::Remote
+    } else if description.contains("hybrid") {
+        JobPostLocationType::Hybrid
+    } else {
+        JobPostLocationType::Onsite
+    }
+}
+
+/// The canonical `/viewjob?jk=` page of an Indeed job, from any link that names one, e.g. a
+/// search result's `vjk` or a tracking `/rc/clk` redirect
+fn indeed_job_url(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let host = url.host_str()?;
+    if host != "indeed.com" && !host.ends_with(".indeed.com") {
+        return None;
+    }
+    let (_, job_key) = url
+        .query_pairs()
+        .find(|(key, value)| (key == "jk" || key == "vjk") && !value.is_empty())?;
+    Some(format!("https://{host}/viewjob?jk={job_key}"))
+}
+
+/// Indeed quotes pay like "$120,000 - $150,000 a year", "From $25 an hour", or "Up to
+/// $4,000 a month". Returns the min and max in cents and the pay unit.
+fn parse_indeed_salary(salary: &str) -> (Option<i64>, Option<i64>, Option<String>) {
+    let re = regex::Regex::new(
+        r"(?i)([\d,]+(?:\.\d+)?)(?:\s*[-–]\s*\D?([\d,]+(?:\.\d+)?))?\s+(?:an?|per)\s+(hour|day|week|month|year)",
+    )
+    .expect("Failed to make regex");
+    let Some(cap) = re.captures(salary) else {
+        return (None, None, None);
+    };
+    let cents = |i: usize| {
+        cap.get(i)
+            .and_then(|m| get_pay_i64(&m.as_str().replace(',', "")).ok())
+    };
+    let unit = cap.get(3).map(|m| m.as_str().to_lowercase());
+    match (cents(1), cents(2)) {
+        (Some(max), None) if salary.trim_start().to_lowercase().starts_with("up to") => {
+            (None, Some(max), unit)
+        }
+        (min, max) => (min, max, unit),
+    }
+}
+
+/// The postings API URL of a `jobs.lever.co/<company>/<id>` page, or its apply page
+fn lever_api_url(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    // EU accounts are served from their own region
+    let api_host = match url.host_str()? {
+        "jobs.lever.co" => "api.lever.co",
+        "jobs.eu.lever.co" => "api.eu.lever.co",
+        _ => return None,
+    };
+    let mut segments = url.path_segments()?.filter(|segment| !segment.is_empty());
+    let company = segments.next()?;
+    let posting_id = segments.next()?;
+    Some(format!(
+        "https://{api_host}/v0/postings/{company}/{posting_id}?mode=json"
+    ))
+}
+
+/* Lever */
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeverJobPosting {
+    text: String,
+    #[serde(default)]
+    categories: LeverJobCategories,
+    workplace_type: Option<String>,
+    /// Milliseconds since the epoch
+    created_at: Option<i64>,
+    #[serde(default)]
+    description_plain: String,
+    /// Closing text after the lists, e.g. an equal opportunity statement
+    #[serde(default)]
+    additional_plain: String,
+    #[serde(default)]
+    lists: Vec<LeverList>,
+    salary_range: Option<LeverSalaryRange>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LeverJobCategories {
+    location: Option<String>,
+}
+
+/// A titled section of the posting, e.g. "Requirements", with its items as `<li>` HTML
+#[derive(Debug, Deserialize)]
+struct LeverList {
+    text: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LeverSalaryRange {
+    currency: Option<String>,
+    /// e.g. "per-year-salary" or "per-hour-wage"
+    interval: Option<String>,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+/// Lists whose title mentions one of these hold skills; ones mentioning benefits or perks
+/// hold benefits
+const LEVER_SKILL_LISTS: [&str; 5] = [
+    "requirement",
+    "qualification",
+    "skill",
+    "you bring",
+    "you have",
+];
+const LEVER_BENEFIT_LISTS: [&str; 3] = ["benefit", "perk", "we offer"];
+
+/// The items of an HTML list, as plain text
+fn html_list_items(html: &str) -> Vec<String> {
+    let item = regex::Regex::new(r"(?is)<li[^>]*>(.*?)</li>").expect("Failed to make regex");
+    let tag = regex::Regex::new(r"<[^>]*>").expect("Failed to make regex");
+    item.captures_iter(html)
+        .filter_map(|cap| {
+            let text = tag.replace_all(&cap[1], "");
+            let text = text.replace("&amp;", "&").replace("&nbsp;", " ");
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            (!text.is_empty()).then_some(text)
+        })
+        .collect()
+}
+
+/// The items of an HTML list, as plain text. Commas would split an item into several
+/// skills, so they become semicolons.
+fn list_items(html: &str) -> Vec<String> {
+    html_list_items(html)
+        .into_iter()
+        .filter_map(|text| {
+            let text = text.replace(',', ";");
+            let text = text.trim_end_matches(['.', ';']).to_string();
+            (!text.is_empty()).then_some(text)
+        })
+        .collect()
+}
+
+/// The items of every list titled like one of `titles`, comma-separated
+fn lever_list_items(lists: &[LeverList], titles: &[&str]) -> Option<String> {
+    let items: Vec<String> = lists
+        .iter()
+        .filter(|list| {
+            let title = list.text.to_lowercase();
+            titles.iter().any(|t| title.contains(t))
+        })
+        .flat_map(|list| list_items(&list.content))
+        .collect();
+    (!items.is_empty()).then(|| items.join(", "))
+}
+
+impl LeverJobPosting {
+    /// The plain description, each list under its title as bullets, and the closing text
+    fn description(&self) -> Option<String> {
+        let lists = self.lists.iter().map(|list| {
+            let items: Vec<String> = html_list_items(&list.content)
+                .into_iter()
+                .map(|item| format!("- {item}"))
+                .collect();
+            format!("{}\n{}", list.text.trim(), items.join("\n"))
+        });
+        let sections: Vec<String> = std::iter::once(self.description_plain.trim().to_string())
+            .chain(lists)
+            .chain(std::iter::once(self.additional_plain.trim().to_string()))
+            .filter(|section| !section.is_empty())
+            .collect();
+        description_from(&sections.join("\n\n"))
+    }
+
+    fn into_job_post(self, url: String) -> JobPost {
+        let description = self.description();
+        let location = self.categories.location.unwrap_or_default();
+        let location_type = match self.workplace_type.as_deref() {
+            Some("onsite") => JobPostLocationType::Onsite,
+            Some("hybrid") => JobPostLocationType::Hybrid,
+            Some("remote") => JobPostLocationType::Remote,
+            _ if location.to_lowercase().contains("remote") => JobPostLocationType::Remote,
+            _ => JobPostLocationType::Unknown,
+        };
+        let requirements = self
+            .lists
+            .iter()
+            .map(|list| list.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (min_yoe, max_yoe) =
+            find_yoe_naive(&format!("{}\n{}", self.description_plain, requirements));
+        let cents = |pay: Option<f64>| pay.map(|pay| (pay * 100.0).round() as i64);
+        let (min_pay_cents, max_pay_cents, pay_unit, currency) = match self.salary_range {
+            Some(range) => (
+                cents(range.min),
+                cents(range.max),
+                range.interval.map(|interval| {
+                    PayUnit::parse(Some(interval.trim_start_matches("per-")))
+                        .name()
+                        .to_string()
+                }),
+                range.currency,
+            ),
+            None => (None, None, None, None),
+        };
+        JobPost {
+            id: -1,
+            company_id: -1,
+            location,
+            location_type,
+            url,
+            min_yoe,
+            max_yoe,
+            min_pay_cents,
+            max_pay_cents,
+            date_posted: NullableSqliteDateTime::from(self.created_at.map(|ms| ms / 1000)),
+            date_retrieved: SqliteDateTime(Utc::now()),
+            job_title: self.text,
+            benefits: lever_list_items(&self.lists, &LEVER_BENEFIT_LISTS),
+            skills: lever_list_items(&self.lists, &LEVER_SKILL_LISTS),
+            industry: None,
+            pay_unit,
+            currency,
+            platform_url: Some("https://lever.co".to_string()),
+            apijobs_id: None,
+            notes: None,
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            description,
+        }
+    }
+}
+
+/// Lever's JSON has no company name, only the account slug in the URL, so the company is
+/// left for the user to pick
+async fn fetch_lever(
+    client: &reqwest::Client,
+    api_url: &str,
+    url: String,
+) -> anyhow::Result<(Option<String>, Option<JobPost>)> {
+    let posting: LeverJobPosting = client
+        .get(api_url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok((None, Some(posting.into_job_post(url))))
+}
+
+async fn scrape_indeed(
+    driver: &thirtyfour::WebDriver,
+    url: String,
+    selectors: &IndeedSelectors,
+) -> anyhow::Result<(Option<String>, Option<JobPost>)> {
+    driver.goto(&url).await?;
+    let company_name = driver
+        .find(By::Css(&selectors.company))
+        .await?
+        .text()
+        .await?;
+    let title_text = driver
+        .find(By::Css(&selectors.job_title))
+        .await?
+        .text()
+        .await?;
+    // Indeed appends " - job post" to the title for screen readers
+    let title_text = title_text
+        .trim()
+        .trim_end_matches("- job post")
+        .trim()
+        .to_string();
+    // Missing on some remote posts
+    let location_text = match driver.find(By::Css(&selectors.location)).await {
+        Ok(element) => element.text().await?,
+        Err(_) => "".to_string(),
+    };
+    let desc_text = driver
+        .find(By::Css(&selectors.description))
+        .await?
+        .text()
+        .await?;
+    let location_type = location_type_from_text(&location_text, &desc_text);
+    let (min_yoe, max_yoe) = find_yoe_naive(&desc_text);
+    let salary_text = match driver.find(By::Css(&selectors.salary)).await {
+        Ok(element) => element.text().await?,
+        Err(_) => "".to_string(),
+    };
+    let (min_pay, max_pay, pay_unit) = parse_indeed_salary(&salary_text);
+    Ok((
+        Some(company_name.trim().to_string()),
+        Some(JobPost {
+            id: -1,
+            company_id: -1,
+            location: location_text.trim().to_string(),
+            location_type,
+            url,
+            min_yoe,
+            max_yoe,
+            min_pay_cents: min_pay,
+            max_pay_cents: max_pay,
+            // Indeed only shows how long ago a post went up on search results
+            date_posted: NullableSqliteDateTime(None),
+            date_retrieved: SqliteDateTime(Utc::now()),
+            job_title: title_text,
+            benefits: None,
+            skills: None,
+            industry: None,
+            pay_unit,
+            currency: None,
+            platform_url: Some("https://indeed.com".to_string()),
+            apijobs_id: None,
+            notes: None,
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            description: description_from(&desc_text),
+        }),
+    ))
+}
+
+/* Workday */
+
+/// How long to wait for a Workday job page to render; the page is an app that fetches the
+/// post after loading
+const WORKDAY_LOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+/// A job page like `acme.wd5.myworkdayjobs.com/en-US/careers/job/Austin-TX/Engineer_R123`
+fn is_workday_job_url(url: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(url) else {
+        return false;
+    };
+    url.host_str()
+        .is_some_and(|host| host.ends_with(".myworkdayjobs.com"))
+        && url
+            .path_segments()
+            .is_some_and(|mut segments| segments.any(|segment| segment == "job"))
+}
+
+/// A company name from the account in a Lever or Workday URL, e.g. "Acme Robotics" for
+/// `jobs.lever.co/acme-robotics/...`, since neither page names the company
+pub fn company_from_url(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let host = url.host_str()?;
+    let slug = if host == "jobs.lever.co" || host == "jobs.eu.lever.co" {
+        url.path_segments()?.find(|segment| !segment.is_empty())?
+    } else if host.ends_with(".myworkdayjobs.com") {
+        host.split('.').next()?
+    } else {
+        return None;
+    };
+    let name = slug
+        .split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!name.is_empty()).then_some(name)
+}
+
+/// Workday shows "Posted Today", "Posted Yesterday", "Posted 3 Days Ago", or "Posted 30+
+/// Days Ago"
+fn workday_posted_date(posted: &str) -> NullableSqliteDateTime {
+    let posted = posted.trim().to_lowercase();
+    let posted = posted.strip_prefix("posted").unwrap_or(&posted).trim();
+    match posted {
+        "today" => NullableSqliteDateTime(Some(Utc::now().date_naive())),
+        "yesterday" => NullableSqliteDateTime::from_relative("1 day ago"),
+        posted => NullableSqliteDateTime::from_relative(&posted.replace('+', "")),
+    }
+}
+
+/// Drives the browser through the careers app, waiting for the post to render. The company
+/// isn't on the page, so it's left for the user to pick.
+async fn scrape_workday(
+    driver: &thirtyfour::WebDriver,
+    url: String,
+    selectors: &WorkdaySelectors,
+) -> anyhow::Result<(Option<String>, Option<JobPost>)> {
+    driver.goto(&url).await?;
+    let title_text = driver
+        .query(By::Css(&selectors.job_title))
+        .wait(WORKDAY_LOAD_TIMEOUT, std::time::Duration::from_millis(500))
+        .first()
+        .await?
+        .text()
+        .await?;
+    // Rendered with the title, so there's no need to wait again
+    let text_of = |selector: &str| {
+        let query = driver.query(By::Css(selector)).nowait();
+        async move {
+            anyhow::Ok(match query.first_opt().await? {
+                Some(element) => element.text().await?,
+                None => "".to_string(),
+            })
+        }
+    };
+    let location_text = text_of(&selectors.location).await?;
+    let posted_text = text_of(&selectors.posted).await?;
+    let desc_text = text_of(&selectors.description).await?;
+    let (min_yoe, max_yoe) = find_yoe_naive(&desc_text);
+    Ok((
+        None,
+        Some(JobPost {
+            id: -1,
+            company_id: -1,
+            location: location_text.trim().to_string(),
+            location_type: location_type_from_text(&location_text, &desc_text),
+            url,
+            min_yoe,
+            max_yoe,
+            min_pay_cents: None,
+            max_pay_cents: None,
+            date_posted: workday_posted_date(&posted_text),
+            date_retrieved: SqliteDateTime(Utc::now()),
+            job_title: title_text.trim().to_string(),
+            benefits: None,
+            skills: None,
+            industry: None,
+            pay_unit: None,
+            currency: None,
+            platform_url: Some("https://myworkdayjobs.com".to_string()),
+            apijobs_id: None,
+            notes: None,
+            archived: SqliteBoolean(false),
+            favorite: SqliteBoolean(false),
+            expected_pay_cents: None,
+            tags: None,
+            description: description_from(&desc_text),
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn chromium_browsers_get_their_options_as_args() {
+        let network = NetworkSettings {
+            proxy_url: "socks5://proxy.local:1080".to_string(),
+            proxy_username: "me".to_string(),
+            user_agent: "JobHunter".to_string(),
+            ..Default::default()
+        };
+        let caps = serde_json::Value::Object(Driver::Chrome.capabilities(&network).unwrap());
+        let args = caps["goog:chromeOptions"]["args"].to_string();
+        assert!(args.contains("--headless"));
+        assert!(args.contains("--user-agent=JobHunter"));
+        assert_eq!(caps["proxy"]["socksProxy"], "proxy.local:1080");
+        assert!(caps["proxy"].get("socksUsername").is_none());
+
+        let caps = serde_json::Value::Object(Driver::Edge.capabilities(&network).unwrap());
+        assert!(caps["ms:edgeOptions"]["args"]
+            .to_string()
+            .contains("--headless"));
+        assert_eq!(
+            DriverSettings {
+                driver: Driver::Chrome,
+                path: " ".to_string(),
+                port: 0,
+            }
+            .port(),
+            9515
+        );
+    }
+
+    #[test]
+    fn selector_overrides_keep_other_defaults() {
+        let selectors = Selectors::parse(
+            r#"
+            [linkedin]
+            job_title = "h1.job-title"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(selectors.linkedin.job_title, "h1.job-title");
+        assert_eq!(
+            selectors.linkedin.company,
+            LinkedInSelectors::default().company
+        );
+        assert_eq!(Selectors::parse("").unwrap(), Selectors::default());
+        assert!(Selectors::parse("linkedin = 5").is_err());
+        assert_eq!(selectors.indeed, IndeedSelectors::default());
+    }
+
+    #[test]
+    fn lever_links_resolve_to_the_postings_api() {
+        assert_eq!(
+            lever_api_url("https://jobs.lever.co/acme/0a1b2c3d/apply").as_deref(),
+            Some("https://api.lever.co/v0/postings/acme/0a1b2c3d?mode=json")
+        );
+        assert_eq!(
+            lever_api_url("https://jobs.eu.lever.co/acme/0a1b2c3d").as_deref(),
+            Some("https://api.eu.lever.co/v0/postings/acme/0a1b2c3d?mode=json")
+        );
+        assert_eq!(lever_api_url("https://jobs.lever.co/acme"), None);
+        assert_eq!(lever_api_url("https://lever.co/acme/0a1b2c3d"), None);
+    }
+
+    #[test]
+    fn lever_postings_take_skills_and_benefits_from_their_lists() {
+        let posting: LeverJobPosting = serde_json::from_str(
+            r#"{
+                "text": "Backend Engineer",
+                "categories": {"location": "Berlin", "team": "Platform"},
+                "workplaceType": "hybrid",
+                "createdAt": 1735689600000,
+                "descriptionPlain": "Join us. 3-5 years of experience.",
+                "lists": [
+                    {"text": "Requirements", "content": "<li>Rust</li><li>SQL, ideally <b>SQLite</b>.</li>"},
+                    {"text": "What We Offer", "content": "<li>Remote Fridays</li>"},
+                    {"text": "About the Team", "content": "<li>Small</li>"}
+                ],
+                "salaryRange": {"currency": "EUR", "interval": "per-year-salary", "min": 70000, "max": 90000}
+            }"#,
+        )
+        .unwrap();
+        let job = posting.into_job_post("https://jobs.lever.co/acme/1".to_string());
+        assert_eq!(job.job_title, "Backend Engineer");
+        assert_eq!(job.location, "Berlin");
+        assert_eq!(job.location_type, JobPostLocationType::Hybrid);
+        assert_eq!(job.skills.as_deref(), Some("Rust, SQL; ideally SQLite"));
+        assert_eq!(job.benefits.as_deref(), Some("Remote Fridays"));
+        assert_eq!(job.min_pay_cents, Some(7_000_000));
+        assert_eq!(job.max_pay_cents, Some(9_000_000));
+        assert_eq!(job.pay_unit.as_deref(), Some("year"));
+        assert_eq!(job.currency.as_deref(), Some("EUR"));
+        assert_eq!(job.date_posted.0, NaiveDate::from_ymd_opt(2025, 1, 1));
+        let description = job.description.unwrap();
+        assert!(description.starts_with("Join us."));
+        assert!(description.contains("Requirements\n- Rust\n- SQL, ideally SQLite."));
+        assert!(description.ends_with("About the Team\n- Small"));
+    }
+
+    #[test]
+    fn companies_come_from_lever_and_workday_accounts() {
+        assert_eq!(
+            company_from_url("https://jobs.lever.co/acme-robotics/0a1b2c3d").as_deref(),
+            Some("Acme Robotics")
+        );
+        assert_eq!(
+            company_from_url("https://globex.wd5.myworkdayjobs.com/en-US/careers/job/R1")
+                .as_deref(),
+            Some("Globex")
+        );
+        assert_eq!(
+            company_from_url("https://www.linkedin.com/jobs/view/1"),
+            None
+        );
+    }
+
+    #[test]
+    fn workday_job_pages_and_posted_dates() {
+        assert!(is_workday_job_url(
+            "https://acme.wd5.myworkdayjobs.com/en-US/careers/job/Austin-TX/Engineer_R123"
+        ));
+        assert!(!is_workday_job_url(
+            "https://acme.wd5.myworkdayjobs.com/en-US/careers"
+        ));
+        assert!(!is_workday_job_url("https://example.com/job/Engineer"));
+
+        let today = Utc::now().date_naive();
+        assert_eq!(workday_posted_date("Posted Today").0, Some(today));
+        assert_eq!(workday_posted_date("Posted Yesterday").0, today.pred_opt());
+        assert_eq!(
+            workday_posted_date("Posted 30+ Days Ago").0,
+            Some(today - chrono::Duration::days(30))
+        );
+        assert_eq!(workday_posted_date("").0, None);
+    }
+
+    #[test]
+    fn indeed_links_resolve_to_the_job_page() {
+        assert_eq!(
+            indeed_job_url("https://www.indeed.com/viewjob?jk=abc123&from=serp").as_deref(),
+            Some("https://www.indeed.com/viewjob?jk=abc123")
+        );
+        assert_eq!(
+            indeed_job_url("https://uk.indeed.com/jobs?q=rust&vjk=def456").as_deref(),
+            Some("https://uk.indeed.com/viewjob?jk=def456")
+        );
+        assert_eq!(indeed_job_url("https://www.indeed.com/jobs?q=rust"), None);
+        assert_eq!(indeed_job_url("https://notindeed.com/viewjob?jk=abc"), None);
+        assert_eq!(
+            indeed_job_url("https://www.linkedin.com/jobs/view/123"),
+            None
+        );
+    }
+
+    #[test]
+    fn indeed_salaries_parse_ranges_and_bounds() {
+        assert_eq!(
+            parse_indeed_salary("$120,000 - $150,000 a year"),
+            (Some(12_000_000), Some(15_000_000), Some("year".to_string()))
+        );
+        assert_eq!(
+            parse_indeed_salary("From $25.50 an hour - Full-time"),
+            (Some(2550), None, Some("hour".to_string()))
+        );
+        assert_eq!(
+            parse_indeed_salary("Up to £4,000 per month"),
+            (None, Some(400_000), Some("month".to_string()))
+        );
+        assert_eq!(parse_indeed_salary("Full-time"), (None, None, None));
+    }
+
+    #[tokio::test]
+    async fn driver_test_reports_a_missing_command() {
+        let settings = DriverSettings {
+            driver: Driver::Chrome,
+            path: "/nonexistent/chromedriver".to_string(),
+            port: free_port().unwrap(),
+        };
+        let caps = settings
+            .driver
+            .capabilities(&NetworkSettings::default())
+            .unwrap();
+        let error = test_driver(settings, caps).await.unwrap_err();
+        assert!(error.to_string().contains("Failed to start"));
+    }
+}
@@ -0,0 +1,64 @@
+use crate::AppConfig;
+
+/* OS keyring (Keychain, Credential Manager, Secret Service) */
+
+const SERVICE: &str = "job-hunter";
+
+/// Config fields kept in the keyring, by their `config.toml` key
+fn fields(config: &mut AppConfig) -> [(&'static str, &mut String); 3] {
+    [
+        ("apijobs_key", &mut config.apijobs_key),
+        ("adzuna_app_key", &mut config.adzuna_app_key),
+        ("proxy_password", &mut config.proxy_password),
+    ]
+}
+
+fn entry(name: &str) -> keyring::Result<keyring::Entry> {
+    keyring::Entry::new(SERVICE, name)
+}
+
+/// Fills credentials that aren't in `config.toml` from the keyring.
+/// Returns whether any were found in the file, so the caller can move them out of it.
+pub fn load(config: &mut AppConfig) -> bool {
+    let mut in_file = false;
+    for (name, value) in fields(config) {
+        if !value.is_empty() {
+            in_file = true;
+            continue;
+        }
+        match entry(name).and_then(|entry| entry.get_password()) {
+            Ok(secret) => *value = secret,
+            Err(keyring::Error::NoEntry) => {}
+            Err(e) => tracing::warn!("Failed to read {name} from the keyring: {e}"),
+        }
+    }
+    in_file
+}
+
+/// Saves the credentials to the keyring and drops them from the serialized config.
+/// Any the keyring can't take, e.g. with no Secret Service running, stay in the file.
+pub fn store(config: &AppConfig, table: &mut toml::Table) {
+    let values = [
+        ("apijobs_key", &config.apijobs_key),
+        ("adzuna_app_key", &config.adzuna_app_key),
+        ("proxy_password", &config.proxy_password),
+    ];
+    for (name, value) in values {
+        let res = entry(name).and_then(|entry| {
+            if value.is_empty() {
+                match entry.delete_credential() {
+                    Err(keyring::Error::NoEntry) => Ok(()),
+                    res => res,
+                }
+            } else {
+                entry.set_password(value)
+            }
+        });
+        match res {
+            Ok(()) => {
+                table.remove(name);
+            }
+            Err(e) => tracing::warn!("Keyring unavailable, keeping {name} in config.toml: {e}"),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use iced::widget::container;
+use iced::{theme::Palette, Color, Theme};
+
+/// Follows the OS's light or dark mode
+pub const SYSTEM: &str = "System";
+
+/// "System" followed by the names of iced's built-in themes
+pub fn choices() -> Vec<String> {
+    std::iter::once(SYSTEM.to_string())
+        .chain(Theme::ALL.iter().map(|theme| theme.to_string()))
+        .collect()
+}
+
+/// A hex color like "#3b82f6", `None` when empty or invalid
+pub fn parse_accent(accent: &str) -> Option<Color> {
+    let accent = accent.trim();
+    if accent.is_empty() {
+        return None;
+    }
+    let hex = accent.strip_prefix('#').unwrap_or(accent);
+    Color::parse(&format!("#{hex}"))
+}
+
+/// The theme named `name`, or the OS's light or dark theme for "System" and unknown names.
+/// A valid `accent` replaces its primary color.
+pub fn resolve(name: &str, accent: &str) -> Theme {
+    let base = Theme::ALL
+        .iter()
+        .find(|theme| theme.to_string() == name)
+        .cloned()
+        .unwrap_or_default();
+    match parse_accent(accent) {
+        Some(primary) => Theme::custom(
+            format!("{base} ({})", accent.trim()),
+            Palette {
+                primary,
+                ..base.palette()
+            },
+        ),
+        None => base,
+    }
+}
+
+/// Behind the job list
+pub fn panel(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(theme.extended_palette().background.base.color.into()),
+        ..Default::default()
+    }
+}
+
+/// Sidebars, a shade off the panel
+pub fn surface(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(theme.extended_palette().background.weak.color.into()),
+        ..Default::default()
+    }
+}
+
+/// Job and board cards
+pub fn card(theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(theme.extended_palette().background.weak.color.into()),
+        ..container::rounded_box(theme)
+    }
+}
+
+/// Icons drawn on primary buttons
+pub fn on_primary(theme: &Theme) -> Color {
+    theme.extended_palette().primary.strong.text
+}
+
+/// Icons drawn on secondary buttons
+pub fn on_secondary(theme: &Theme) -> Color {
+    theme.extended_palette().secondary.base.text
+}
+
+/// Icons beside secondary text
+pub fn muted(theme: &Theme) -> Color {
+    theme.extended_palette().background.strong.color
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accents_are_hex_with_or_without_a_hash() {
+        assert_eq!(parse_accent("#ff0000"), Some(Color::from_rgb8(255, 0, 0)));
+        assert_eq!(parse_accent(" 00ff00 "), Some(Color::from_rgb8(0, 255, 0)));
+        assert_eq!(parse_accent(""), None);
+        assert_eq!(parse_accent("blue"), None);
+    }
+
+    #[test]
+    fn resolves_built_ins_and_accents() {
+        assert_eq!(resolve("Nord", ""), Theme::Nord);
+        assert_eq!(resolve(SYSTEM, ""), Theme::default());
+        assert_eq!(resolve("Nonexistent", ""), Theme::default());
+
+        let accented = resolve("Dracula", "#ff0000");
+        assert_eq!(accented.palette().primary, Color::from_rgb8(255, 0, 0));
+        assert_eq!(
+            accented.palette().background,
+            Theme::Dracula.palette().background
+        );
+        assert!(choices().contains(&"Dracula".to_string()));
+    }
+}
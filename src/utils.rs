@@ -1,5 +1,8 @@
+use chrono::NaiveDate;
 use regex::Regex;
 
+pub mod compensation;
+
 pub fn get_pay_i64(s: &str) -> Result<i64, String> {
     if let Ok(num) = s.parse::<f64>() {
         return Ok((num * 100.0).round() as i64);
@@ -28,6 +31,15 @@ pub fn format_comma_separated(str: String) -> String {
         .join(", ")
 }
 
+/// Non-empty trimmed values of a comma-separated column
+pub fn split_comma_separated(str: &str) -> Vec<String> {
+    str.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 pub fn format_location(city: &str, region: &str, country: &str) -> String {
     [city, region, country]
         .iter()
@@ -81,3 +93,60 @@ pub fn find_yoe_naive(text: &str) -> (Option<i64>, Option<i64>) {
     }
     results
 }
+
+/// Parses a typed date such as "2025-03-01", "3/1/25", "03/01/2025", or "March 1, 2025"
+pub fn parse_date_input(input: &str) -> Option<NaiveDate> {
+    let input = input.trim();
+    let mut segments = input.split(['/', '-']);
+    let year_first = segments
+        .next()
+        .is_some_and(|year| year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()));
+    // chrono's %Y accepts two-digit years as-is, so pick %y when the year is short
+    let short_year = segments.next_back().is_some_and(|year| year.len() == 2);
+    let formats: &[&str] = if year_first {
+        &["%Y-%m-%d", "%Y/%m/%d"]
+    } else if short_year {
+        &["%m/%d/%y", "%m-%d-%y"]
+    } else {
+        &["%m/%d/%Y", "%m-%d-%Y", "%B %d, %Y", "%b %d, %Y"]
+    };
+    formats
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(input, format).ok())
+}
+
+/// The start of `text` on one line, cut at `max_chars` with an ellipsis, or `None` when the
+/// whole text already fits
+pub fn truncate_preview(text: &str, max_chars: usize) -> Option<String> {
+    let first_line = text.lines().next().unwrap_or_default();
+    if first_line.len() == text.len() && text.chars().count() <= max_chars {
+        return None;
+    }
+    let preview: String = first_line.chars().take(max_chars).collect();
+    Some(format!("{}…", preview.trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_parse_year_first_or_last() {
+        let march_first = NaiveDate::from_ymd_opt(2025, 3, 1);
+        assert_eq!(parse_date_input("2025-03-01"), march_first);
+        assert_eq!(parse_date_input(" 2025/03/01 "), march_first);
+        assert_eq!(parse_date_input("3/1/25"), march_first);
+        assert_eq!(parse_date_input("03-01-25"), march_first);
+        assert_eq!(parse_date_input("03/01/2025"), march_first);
+        assert_eq!(parse_date_input("March 1, 2025"), march_first);
+        assert_eq!(parse_date_input("Mar 1, 2025"), march_first);
+    }
+
+    #[test]
+    fn dates_that_dont_parse_are_none() {
+        assert_eq!(parse_date_input(""), None);
+        assert_eq!(parse_date_input("2025-13-01"), None);
+        assert_eq!(parse_date_input("1/2"), None);
+        assert_eq!(parse_date_input("tomorrow"), None);
+    }
+}
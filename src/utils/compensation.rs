@@ -0,0 +1,136 @@
+/* Pay normalization */
+
+/// How often a post's pay is quoted. Kept in `job_post.pay_unit` by name; sources spell
+/// it all sorts of ways, so anything unrecognized is taken as yearly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PayUnit {
+    Hour,
+    Day,
+    Week,
+    Month,
+    #[default]
+    Year,
+}
+
+impl PayUnit {
+    pub const ALL: [PayUnit; 5] = [
+        PayUnit::Hour,
+        PayUnit::Day,
+        PayUnit::Week,
+        PayUnit::Month,
+        PayUnit::Year,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PayUnit::Hour => "hour",
+            PayUnit::Day => "day",
+            PayUnit::Week => "week",
+            PayUnit::Month => "month",
+            PayUnit::Year => "year",
+        }
+    }
+
+    /// Lowercase prefixes a stored unit is recognized by, so "Hourly", "HOUR", and "hr"
+    /// agree
+    fn prefixes(&self) -> &'static [&'static str] {
+        match self {
+            PayUnit::Hour => &["hour", "hr"],
+            PayUnit::Day => &["day", "daily"],
+            PayUnit::Week => &["week"],
+            PayUnit::Month => &["month"],
+            PayUnit::Year => &["year", "annual"],
+        }
+    }
+
+    pub fn parse(unit: Option<&str>) -> Self {
+        let unit = unit.unwrap_or_default().trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|pay_unit| {
+                pay_unit
+                    .prefixes()
+                    .iter()
+                    .any(|prefix| unit.starts_with(prefix))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Rough multiplier to a full-time year
+    pub fn yearly_factor(&self) -> i64 {
+        match self {
+            PayUnit::Hour => 2080,
+            PayUnit::Day => 260,
+            PayUnit::Week => 52,
+            PayUnit::Month => 12,
+            PayUnit::Year => 1,
+        }
+    }
+
+    /// E.g. "/hr", for after an amount
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            PayUnit::Hour => "/hr",
+            PayUnit::Day => "/day",
+            PayUnit::Week => "/wk",
+            PayUnit::Month => "/mo",
+            PayUnit::Year => "/yr",
+        }
+    }
+}
+
+impl std::fmt::Display for PayUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PayUnit::Hour => write!(f, "Hourly"),
+            PayUnit::Day => write!(f, "Daily"),
+            PayUnit::Week => write!(f, "Weekly"),
+            PayUnit::Month => write!(f, "Monthly"),
+            PayUnit::Year => write!(f, "Yearly"),
+        }
+    }
+}
+
+/// Pay quoted per `unit`, as cents a year
+pub fn annualize_cents(cents: i64, unit: Option<&str>) -> i64 {
+    cents * PayUnit::parse(unit).yearly_factor()
+}
+
+/// `PayUnit::parse(..).yearly_factor()` as SQL over a pay unit column, so filters and sorts
+/// agree with `annualize_cents`
+pub fn yearly_factor_sql(column: &str) -> String {
+    let mut sql = "CASE".to_string();
+    for unit in PayUnit::ALL {
+        if unit == PayUnit::Year {
+            continue;
+        }
+        let conditions = unit
+            .prefixes()
+            .iter()
+            .map(|prefix| format!("LOWER({column}) LIKE '{prefix}%'"))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        sql.push_str(&format!(" WHEN {conditions} THEN {}", unit.yearly_factor()));
+    }
+    sql.push_str(" ELSE 1 END");
+    sql
+}
+
+#[cfg(test)]
+// Cents are written apart from the dollars, e.g. 85_000_00
+#[allow(clippy::inconsistent_digit_grouping)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units_are_recognized_however_they_are_spelled() {
+        assert_eq!(PayUnit::parse(Some("HOUR")), PayUnit::Hour);
+        assert_eq!(PayUnit::parse(Some("hourly")), PayUnit::Hour);
+        assert_eq!(PayUnit::parse(Some("Daily")), PayUnit::Day);
+        assert_eq!(PayUnit::parse(Some("per annum")), PayUnit::Year);
+        assert_eq!(PayUnit::parse(None), PayUnit::Year);
+        assert_eq!(annualize_cents(50_00, Some("hour")), 104_000_00);
+        assert_eq!(annualize_cents(8_000_00, Some("month")), 96_000_00);
+        assert_eq!(annualize_cents(90_000_00, Some("year")), 90_000_00);
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use iced::widget::{column, text, text_input};
+use iced::{Element, Theme};
+
+use crate::utils::{get_pay_i64, parse_date_input};
+
+/// Modal fields that can fail validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FormField {
+    CompanyName,
+    CareersURL,
+    CompanyGroupName,
+    ApplicationStatus,
+    JobPostCompany,
+    JobTitle,
+    JobURL,
+    Location,
+    LocationType,
+    MaxYOE,
+    DatePosted,
+    DateApplied,
+    DateResponded,
+    InterviewTime,
+    MinPay,
+    MaxPay,
+    ExpectedPay,
+}
+
+/// Validation messages by field; empty when the form can be saved
+#[derive(Debug, Clone, Default)]
+pub struct FormErrors(BTreeMap<FormField, String>);
+
+impl FormErrors {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, field: FormField) -> Option<&str> {
+        self.0.get(&field).map(String::as_str)
+    }
+
+    pub fn contains(&self, field: FormField) -> bool {
+        self.0.contains_key(&field)
+    }
+
+    /// Keeps the first error reported for a field
+    pub fn add(&mut self, field: FormField, message: impl Into<String>) {
+        self.0.entry(field).or_insert_with(|| message.into());
+    }
+
+    pub fn require(&mut self, field: FormField, value: &str) {
+        if value.trim().is_empty() {
+            self.add(field, "Required");
+        }
+    }
+
+    pub fn require_some<T>(&mut self, field: FormField, value: &Option<T>) {
+        if value.is_none() {
+            self.add(field, "Required");
+        }
+    }
+
+    /// Required http(s) URL
+    pub fn url(&mut self, field: FormField, value: &str) {
+        self.require(field, value);
+        if value.trim().is_empty() {
+            return;
+        }
+        match reqwest::Url::parse(value.trim()) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            _ => self.add(field, "Enter a full URL starting with http:// or https://"),
+        }
+    }
+
+    /// Optional pay amounts, where the max can't be less than the min
+    pub fn pay_range(&mut self, min_pay: &str, max_pay: &str) {
+        let min = self.pay(FormField::MinPay, min_pay);
+        let max = self.pay(FormField::MaxPay, max_pay);
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                self.add(FormField::MaxPay, "Max. pay is less than min. pay");
+            }
+        }
+    }
+
+    /// Optional yearly pay I'm after for one post
+    pub fn expected_pay(&mut self, expected_pay: &str) {
+        self.pay(FormField::ExpectedPay, expected_pay);
+    }
+
+    fn pay(&mut self, field: FormField, value: &str) -> Option<i64> {
+        if value.is_empty() {
+            return None;
+        }
+        match get_pay_i64(value) {
+            Ok(cents) if cents >= 0 => Some(cents),
+            _ => {
+                self.add(field, "Enter an amount, e.g. 85000");
+                None
+            }
+        }
+    }
+
+    /// Optional typed date; `None` when the date was picked or left untouched
+    pub fn date(&mut self, field: FormField, input: Option<&str>) {
+        if let Some(input) = input {
+            if !input.trim().is_empty() && parse_date_input(input).is_none() {
+                self.add(field, "Enter a date like 2025-03-01 or 3/1/25");
+            }
+        }
+    }
+
+    pub fn yoe_range(&mut self, min_yoe: Option<i64>, max_yoe: Option<i64>) {
+        if let (Some(min), Some(max)) = (min_yoe, max_yoe) {
+            if max < min {
+                self.add(FormField::MaxYOE, "Max. years is less than min. years");
+            }
+        }
+    }
+}
+
+/// The field's message in red, or nothing when it's valid
+pub fn field_error<'a, Message: 'a>(errors: &FormErrors, field: FormField) -> Element<'a, Message> {
+    match errors.get(field) {
+        Some(message) => text(message.to_string())
+            .size(10)
+            .style(text::danger)
+            .into(),
+        None => column![].into(),
+    }
+}
+
+/// Text input style with a red border when the field is invalid
+pub fn input_style(invalid: bool) -> impl Fn(&Theme, text_input::Status) -> text_input::Style {
+    move |theme, status| {
+        let style = text_input::default(theme, status);
+        if !invalid {
+            return style;
+        }
+        text_input::Style {
+            border: style
+                .border
+                .color(theme.extended_palette().danger.base.color),
+            ..style
+        }
+    }
+}